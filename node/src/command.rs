@@ -373,7 +373,13 @@ pub fn run() -> Result<()> {
 						sp_core::crypto::set_default_ss58_version(
 							pop_runtime_devnet::SS58Prefix::get().into(),
 						);
-						crate::service::start_parachain_node::<pop_runtime_devnet::RuntimeApi>(
+						// The messaging RPC methods are served for the runtimes which include the
+						// messaging pallet.
+						#[cfg(not(feature = "ismp"))]
+						use crate::service::start_parachain_node;
+						#[cfg(feature = "ismp")]
+						use crate::service::start_parachain_node_with_messaging as start_parachain_node;
+						start_parachain_node::<pop_runtime_devnet::RuntimeApi>(
 							config,
 							polkadot_config,
 							collator_options,
@@ -388,7 +394,11 @@ pub fn run() -> Result<()> {
 						sp_core::crypto::set_default_ss58_version(
 							pop_runtime_testnet::config::system::SS58Prefix::get().into(),
 						);
-						crate::service::start_parachain_node::<pop_runtime_testnet::RuntimeApi>(
+						#[cfg(not(feature = "ismp"))]
+						use crate::service::start_parachain_node;
+						#[cfg(feature = "ismp")]
+						use crate::service::start_parachain_node_with_messaging as start_parachain_node;
+						start_parachain_node::<pop_runtime_testnet::RuntimeApi>(
 							config,
 							polkadot_config,
 							collator_options,
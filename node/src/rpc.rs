@@ -18,6 +18,10 @@ use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 use sp_core::H256;
 use substrate_frame_rpc_system::{System, SystemApiServer};
 
+/// Messaging RPC methods.
+#[cfg(feature = "ismp")]
+pub mod messaging;
+
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
@@ -97,3 +101,19 @@ where
 
 	Ok(module)
 }
+
+/// Extend the RPC extensions with the messaging RPC methods, for runtimes which include the
+/// messaging pallet.
+#[cfg(feature = "ismp")]
+pub fn extend_with_messaging<C>(
+	module: &mut RpcExtension,
+	client: Arc<C>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: pallet_api_vnext::messaging::runtime_api::MessagingApi<Block, Balance>,
+{
+	use messaging::{Messaging, MessagingApiServer};
+	module.merge(Messaging::new(client).into_rpc())?;
+	Ok(())
+}
@@ -0,0 +1,230 @@
+//! RPC methods for enumerating and inspecting the messages of the messaging pallet, and quoting
+//! the amounts taken for new messages.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_api_vnext::messaging::{
+	runtime_api::MessagingApi as MessagingRuntimeApi, MessageDetails, MessageId, MessageQuote,
+};
+use pop_runtime_common::{Balance, Block, Hash};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{H160, H256};
+use sp_runtime::Weight;
+
+/// The maximum number of messages returned per page.
+const MAX_LIMIT: u32 = 100;
+/// The error code used for runtime API errors.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Messaging RPC methods.
+#[rpc(client, server)]
+pub trait MessagingApi<BlockHash> {
+	/// Get the messages of an origin.
+	///
+	/// Messages are returned in storage order, with `start_after` used as a cursor to continue
+	/// from the last message of a previous page. At most `MAX_LIMIT` messages are returned.
+	#[method(name = "messaging_messages")]
+	fn messages(
+		&self,
+		origin: H160,
+		start_after: Option<MessageId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<MessageDetails<Balance>>>;
+
+	/// Get a message by its identifier.
+	#[method(name = "messaging_message")]
+	fn message(
+		&self,
+		id: MessageId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<MessageDetails<Balance>>>;
+
+	/// Get a message by the commitment of its ISMP request.
+	#[method(name = "messaging_ismpMessage")]
+	fn ismp_message(
+		&self,
+		commitment: H256,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<MessageDetails<Balance>>>;
+
+	/// Get a message by the identifier of its XCM query.
+	#[method(name = "messaging_xcmMessage")]
+	fn xcm_message(
+		&self,
+		query_id: u64,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<MessageDetails<Balance>>>;
+
+	/// The total deposit held for the messages of an origin, including any deposits held for
+	/// callback execution.
+	#[method(name = "messaging_heldDeposit")]
+	fn held_deposit(&self, origin: H160, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+	/// Quote the amounts taken for a new ISMP `Get` request, excluding the fee paid to relayers.
+	#[method(name = "messaging_quoteGet")]
+	fn quote_get(
+		&self,
+		callback_gas_limit: Option<Weight>,
+		at: Option<BlockHash>,
+	) -> RpcResult<MessageQuote<Balance>>;
+
+	/// Quote the amounts taken for a new ISMP `Post` request, excluding the fee paid to relayers.
+	#[method(name = "messaging_quotePost")]
+	fn quote_post(
+		&self,
+		callback_gas_limit: Option<Weight>,
+		at: Option<BlockHash>,
+	) -> RpcResult<MessageQuote<Balance>>;
+
+	/// Quote the amounts taken for a new subscription to recurring ISMP `Get` requests, including
+	/// the fees paid to relayers for all executions.
+	#[method(name = "messaging_quoteSubscribe")]
+	fn quote_subscribe(
+		&self,
+		executions: u32,
+		fee: Balance,
+		callback_gas_limit: Weight,
+		at: Option<BlockHash>,
+	) -> RpcResult<MessageQuote<Balance>>;
+
+	/// Quote the amounts taken for a new XCM query.
+	#[method(name = "messaging_quoteNewQuery")]
+	fn quote_new_query(
+		&self,
+		callback_gas_limit: Option<Weight>,
+		at: Option<BlockHash>,
+	) -> RpcResult<MessageQuote<Balance>>;
+}
+
+/// Provides RPC methods to query the messages of the messaging pallet.
+pub struct Messaging<C> {
+	client: Arc<C>,
+}
+
+impl<C> Messaging<C> {
+	/// Creates a new instance of the messaging RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+
+	fn at(&self, at: Option<Hash>) -> Hash
+	where
+		C: HeaderBackend<Block>,
+	{
+		at.unwrap_or_else(|| self.client.info().best_hash)
+	}
+}
+
+impl<C> MessagingApiServer<Hash> for Messaging<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: MessagingRuntimeApi<Block, Balance>,
+{
+	fn messages(
+		&self,
+		origin: H160,
+		start_after: Option<MessageId>,
+		limit: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<MessageDetails<Balance>>> {
+		let limit = limit.map_or(MAX_LIMIT, |limit| limit.min(MAX_LIMIT));
+		self.client
+			.runtime_api()
+			.messages(self.at(at), origin, start_after, limit)
+			.map_err(runtime_error)
+	}
+
+	fn message(
+		&self,
+		id: MessageId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<MessageDetails<Balance>>> {
+		self.client.runtime_api().message(self.at(at), id).map_err(runtime_error)
+	}
+
+	fn ismp_message(
+		&self,
+		commitment: H256,
+		at: Option<Hash>,
+	) -> RpcResult<Option<MessageDetails<Balance>>> {
+		self.client
+			.runtime_api()
+			.ismp_message(self.at(at), commitment)
+			.map_err(runtime_error)
+	}
+
+	fn xcm_message(
+		&self,
+		query_id: u64,
+		at: Option<Hash>,
+	) -> RpcResult<Option<MessageDetails<Balance>>> {
+		self.client
+			.runtime_api()
+			.xcm_message(self.at(at), query_id)
+			.map_err(runtime_error)
+	}
+
+	fn held_deposit(&self, origin: H160, at: Option<Hash>) -> RpcResult<Balance> {
+		self.client
+			.runtime_api()
+			.held_deposit(self.at(at), origin)
+			.map_err(runtime_error)
+	}
+
+	fn quote_get(
+		&self,
+		callback_gas_limit: Option<Weight>,
+		at: Option<Hash>,
+	) -> RpcResult<MessageQuote<Balance>> {
+		self.client
+			.runtime_api()
+			.quote_get(self.at(at), callback_gas_limit)
+			.map_err(runtime_error)
+	}
+
+	fn quote_post(
+		&self,
+		callback_gas_limit: Option<Weight>,
+		at: Option<Hash>,
+	) -> RpcResult<MessageQuote<Balance>> {
+		self.client
+			.runtime_api()
+			.quote_post(self.at(at), callback_gas_limit)
+			.map_err(runtime_error)
+	}
+
+	fn quote_subscribe(
+		&self,
+		executions: u32,
+		fee: Balance,
+		callback_gas_limit: Weight,
+		at: Option<Hash>,
+	) -> RpcResult<MessageQuote<Balance>> {
+		self.client
+			.runtime_api()
+			.quote_subscribe(self.at(at), executions, fee, callback_gas_limit)
+			.map_err(runtime_error)
+	}
+
+	fn quote_new_query(
+		&self,
+		callback_gas_limit: Option<Weight>,
+		at: Option<Hash>,
+	) -> RpcResult<MessageQuote<Balance>> {
+		self.client
+			.runtime_api()
+			.quote_new_query(self.at(at), callback_gas_limit)
+			.map_err(runtime_error)
+	}
+}
+
+fn runtime_error(error: sp_api::ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(RUNTIME_ERROR, "Unable to query messages.", Some(error.to_string()))
+}
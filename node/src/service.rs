@@ -153,18 +153,25 @@ where
 /// Start a node with the given parachain `Configuration` and relay chain `Configuration`.
 ///
 /// This is the actual implementation that is abstract over the executor and the runtime api.
+/// Any RPC methods specific to the runtime are added via `extend_rpc`.
 #[sc_tracing::logging::prefix_logs_with("Parachain")]
-async fn start_node_impl<RuntimeApi, SC>(
+async fn start_node_impl<RuntimeApi, SC, RB>(
 	parachain_config: Configuration,
 	polkadot_config: Configuration,
 	collator_options: CollatorOptions,
 	para_id: ParaId,
 	start_consensus: SC,
+	extend_rpc: RB,
 	hwbench: Option<sc_sysinfo::HwBench>,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient<RuntimeApi>>)>
 where
 	RuntimeApi: ConstructRuntimeApi<Block, ParachainClient<RuntimeApi>> + Send + Sync + 'static,
 	RuntimeApi::RuntimeApi: RuntimeApiExt<RuntimeApi>,
+	RB: Fn(
+			&mut crate::rpc::RpcExtension,
+			Arc<ParachainClient<RuntimeApi>>,
+		) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+		+ 'static,
 	SC: FnOnce(
 		Arc<ParachainClient<RuntimeApi>>,
 		Arc<ParachainBackend>,
@@ -266,7 +273,9 @@ where
 				backend: backend.clone(),
 			};
 
-			crate::rpc::create_full(deps).map_err(Into::into)
+			let mut module = crate::rpc::create_full(deps)?;
+			extend_rpc(&mut module, client.clone())?;
+			Ok(module)
 		})
 	};
 
@@ -494,12 +503,40 @@ where
 	RuntimeApi: ConstructRuntimeApi<Block, ParachainClient<RuntimeApi>> + Send + Sync + 'static,
 	RuntimeApi::RuntimeApi: RuntimeApiExt<RuntimeApi>,
 {
-	start_node_impl::<RuntimeApi, _>(
+	start_node_impl::<RuntimeApi, _, _>(
 		parachain_config,
 		polkadot_config,
 		collator_options,
 		para_id,
 		start_consensus::<RuntimeApi>,
+		|_, _| Ok(()),
+		hwbench,
+	)
+	.await
+}
+
+/// Start a parachain node serving the messaging RPC methods, for runtimes which include the
+/// messaging pallet.
+#[cfg(feature = "ismp")]
+pub async fn start_parachain_node_with_messaging<RuntimeApi>(
+	parachain_config: Configuration,
+	polkadot_config: Configuration,
+	collator_options: CollatorOptions,
+	para_id: ParaId,
+	hwbench: Option<sc_sysinfo::HwBench>,
+) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient<RuntimeApi>>)>
+where
+	RuntimeApi: ConstructRuntimeApi<Block, ParachainClient<RuntimeApi>> + Send + Sync + 'static,
+	RuntimeApi::RuntimeApi: RuntimeApiExt<RuntimeApi>
+		+ pallet_api_vnext::messaging::runtime_api::MessagingApi<Block, pop_runtime_common::Balance>,
+{
+	start_node_impl::<RuntimeApi, _, _>(
+		parachain_config,
+		polkadot_config,
+		collator_options,
+		para_id,
+		start_consensus::<RuntimeApi>,
+		crate::rpc::extend_with_messaging,
 		hwbench,
 	)
 	.await
@@ -9,13 +9,14 @@ use frame_support::{
 		DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, WithPostDispatchInfo,
 	},
 	pallet_prelude::{CheckedSub, DispatchError, Zero},
-	sp_runtime::Saturating,
+	sp_runtime::{traits::MaybeEquivalence, Saturating, TokenError},
+	storage::with_storage_layer,
 	traits::fungibles::{approvals::Inspect as _, metadata::Inspect as _, Inspect as _},
 	weights::Weight,
 };
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 pub use pallet::*;
-use pallet_assets::NextAssetId;
+use pallet_assets::{Asset, AssetsCallback, NextAssetId};
 use weights::WeightInfo;
 use AddressMatcher::Fixed;
 
@@ -23,6 +24,8 @@ use super::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+/// The state of the native token, offered as an ERC20 token by the native precompile.
+pub mod native;
 /// The fungibles precompiles offer a streamlined interface for interacting with fungible tokens.
 pub mod precompiles;
 #[cfg(test)]
@@ -35,16 +38,109 @@ type WeightOf<T, I> = <T as Config<I>>::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use super::weights::WeightInfo;
+	use frame_support::pallet_prelude::*;
+
+	use super::{weights::WeightInfo, MaybeEquivalence, H160};
 
 	#[pallet::config]
-	pub trait Config<I: 'static = ()>: frame_system::Config {
+	pub trait Config<I: 'static = ()>: frame_system::Config + pallet_assets::Config<I> {
+		/// The maximum number of transfers of a batch.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+		/// Converts between the `u32` token identifiers used by the precompiles and the asset
+		/// identifiers of the assets instance.
+		type TokenIdConverter: MaybeEquivalence<u32, Self::AssetId>;
 		/// Weight information for precompiles in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T, I = ()>(_);
+
+	/// The next nonce of each owner for signature-based approvals (permits), as defined by
+	/// EIP-2612, by the address of the token's precompile and owner.
+	#[pallet::storage]
+	pub(super) type Nonces<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		H160,
+		Blake2_128Concat,
+		T::AccountId,
+		u64,
+		ValueQuery,
+	>;
+
+	/// The assets registered by [`super::HashedTokenId`], by token identifier.
+	#[pallet::storage]
+	pub(super) type TokenAssets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, T::AssetId>;
+}
+
+/// Uses the asset identifiers of the assets instance as token identifiers, for instances with
+/// numeric asset identifiers.
+pub struct NumericTokenId;
+impl<AssetId: Clone + From<u32> + Into<u32>> MaybeEquivalence<u32, AssetId> for NumericTokenId {
+	fn convert(token: &u32) -> Option<AssetId> {
+		Some((*token).into())
+	}
+
+	fn convert_back(asset: &AssetId) -> Option<u32> {
+		Some(asset.clone().into())
+	}
+}
+
+/// Derives token identifiers from the hash of the asset identifiers of the assets instance, for
+/// instances with non-numeric asset identifiers (e.g. XCM locations).
+///
+/// Assets are registered when created and unregistered when destroyed, so this must also be
+/// configured as the `CallbackHandle` of the assets instance. The creation of an asset whose
+/// token identifier collides with that of an existing asset is rejected.
+pub struct HashedTokenId<T, I = ()>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> HashedTokenId<T, I> {
+	/// The token identifier of an asset: the first four bytes of the hash of its identifier.
+	pub fn token_id(asset: &AssetIdOf<T, I>) -> u32 {
+		let hash = sp_io::hashing::blake2_256(&asset.encode());
+		u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+	}
+}
+
+impl<T: Config<I>, I: 'static> MaybeEquivalence<u32, AssetIdOf<T, I>> for HashedTokenId<T, I> {
+	fn convert(token: &u32) -> Option<AssetIdOf<T, I>> {
+		TokenAssets::<T, I>::get(token)
+	}
+
+	fn convert_back(asset: &AssetIdOf<T, I>) -> Option<u32> {
+		let token = Self::token_id(asset);
+		TokenAssets::<T, I>::get(token).filter(|a| a == asset).map(|_| token)
+	}
+}
+
+impl<T: Config<I>, I: 'static> AssetsCallback<AssetIdOf<T, I>, AccountIdOf<T>>
+	for HashedTokenId<T, I>
+{
+	fn created(asset: &AssetIdOf<T, I>, _: &AccountIdOf<T>) -> Result<(), ()> {
+		let token = Self::token_id(asset);
+		if TokenAssets::<T, I>::contains_key(token) {
+			return Err(());
+		}
+		TokenAssets::<T, I>::insert(token, asset);
+		Ok(())
+	}
+
+	fn destroyed(asset: &AssetIdOf<T, I>) -> Result<(), ()> {
+		TokenAssets::<T, I>::remove(Self::token_id(asset));
+		Ok(())
+	}
+}
+
+// Resolves the asset identified by a token identifier.
+fn asset_id<T: Config<I>, I: 'static>(token: u32) -> Result<AssetIdOf<T, I>, DispatchError> {
+	T::TokenIdConverter::convert(&token).ok_or(TokenError::UnknownAsset.into())
+}
+
+// Resolves the token identifier of an asset.
+fn token_id<T: Config<I>, I: 'static>(asset: &AssetIdOf<T, I>) -> Result<u32, DispatchError> {
+	T::TokenIdConverter::convert_back(asset).ok_or(TokenError::UnknownAsset.into())
 }
 
 fn approve<T: Config<I> + pallet_assets::Config<I>, I>(
@@ -108,6 +204,14 @@ fn balance<T: pallet_assets::Config<I>, I>(
 	<Assets<T, I>>::balance(asset, account)
 }
 
+fn block<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+	account: AccountIdOf<T>,
+) -> DispatchResult {
+	<Assets<T, I>>::block(origin, asset.into(), T::Lookup::unlookup(account))
+}
+
 fn burn<T: Config<I> + pallet_assets::Config<I>, I>(
 	origin: OriginFor<T>,
 	asset: AssetIdOf<T, I>,
@@ -174,10 +278,60 @@ fn decrease_allowance<T: Config<I> + pallet_assets::Config<I>, I>(
 	Ok((new_allowance, Some(weight)))
 }
 
+// Destroys up to `RemoveItemsLimit` accounts of a token being destroyed, returning the number of
+// accounts removed and remaining.
+fn destroy_accounts<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+) -> Result<(u32, u32), DispatchError> {
+	let accounts =
+		|asset: &AssetIdOf<T, I>| Asset::<T, I>::get(asset).map_or(0, |details| details.accounts);
+	let before = accounts(&asset);
+	<Assets<T, I>>::destroy_accounts(origin, asset.clone().into()).map_err(|e| e.error)?;
+	let remaining = accounts(&asset);
+	Ok((before.saturating_sub(remaining), remaining))
+}
+
+// Destroys up to `RemoveItemsLimit` approvals of a token being destroyed, returning the number of
+// approvals removed and remaining.
+fn destroy_approvals<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+) -> Result<(u32, u32), DispatchError> {
+	let approvals =
+		|asset: &AssetIdOf<T, I>| Asset::<T, I>::get(asset).map_or(0, |details| details.approvals);
+	let before = approvals(&asset);
+	<Assets<T, I>>::destroy_approvals(origin, asset.clone().into()).map_err(|e| e.error)?;
+	let remaining = approvals(&asset);
+	Ok((before.saturating_sub(remaining), remaining))
+}
+
 fn exists<T: pallet_assets::Config<I>, I>(asset: AssetIdOf<T, I>) -> bool {
 	<Assets<T, I>>::asset_exists(asset)
 }
 
+fn finish_destroy<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+) -> DispatchResult {
+	<Assets<T, I>>::finish_destroy(origin, asset.into())
+}
+
+fn freeze<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+	account: AccountIdOf<T>,
+) -> DispatchResult {
+	<Assets<T, I>>::freeze(origin, asset.into(), T::Lookup::unlookup(account))
+}
+
+fn freeze_asset<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+) -> DispatchResult {
+	<Assets<T, I>>::freeze_asset(origin, asset.into())
+}
+
 fn increase_allowance<T: Config<I> + pallet_assets::Config<I>, I>(
 	origin: OriginFor<T>,
 	asset: AssetIdOf<T, I>,
@@ -213,6 +367,14 @@ fn name<T: pallet_assets::Config<I>, I>(asset: AssetIdOf<T, I>) -> Vec<u8> {
 	<Assets<T, I>>::name(asset)
 }
 
+fn refund<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+	allow_burn: bool,
+) -> DispatchResult {
+	<Assets<T, I>>::refund(origin, asset.into(), allow_burn)
+}
+
 fn set_metadata<T: pallet_assets::Config<I>, I>(
 	origin: OriginFor<T>,
 	asset: AssetIdOf<T, I>,
@@ -223,6 +385,30 @@ fn set_metadata<T: pallet_assets::Config<I>, I>(
 	<Assets<T, I>>::set_metadata(origin, asset.into(), name, symbol, decimals)
 }
 
+fn set_min_balance<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+	min_balance: BalanceOf<T, I>,
+) -> DispatchResult {
+	<Assets<T, I>>::set_min_balance(origin, asset.into(), min_balance)
+}
+
+fn set_team<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+	issuer: AccountIdOf<T>,
+	admin: AccountIdOf<T>,
+	freezer: AccountIdOf<T>,
+) -> DispatchResult {
+	<Assets<T, I>>::set_team(
+		origin,
+		asset.into(),
+		T::Lookup::unlookup(issuer),
+		T::Lookup::unlookup(admin),
+		T::Lookup::unlookup(freezer),
+	)
+}
+
 fn start_destroy<T: pallet_assets::Config<I>, I>(
 	origin: OriginFor<T>,
 	asset: AssetIdOf<T, I>,
@@ -234,10 +420,32 @@ fn symbol<T: pallet_assets::Config<I>, I>(asset: AssetIdOf<T, I>) -> Vec<u8> {
 	<Assets<T, I>>::symbol(asset)
 }
 
+fn thaw<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+	account: AccountIdOf<T>,
+) -> DispatchResult {
+	<Assets<T, I>>::thaw(origin, asset.into(), T::Lookup::unlookup(account))
+}
+
+fn thaw_asset<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+) -> DispatchResult {
+	<Assets<T, I>>::thaw_asset(origin, asset.into())
+}
+
 fn total_supply<T: pallet_assets::Config<I>, I>(asset: AssetIdOf<T, I>) -> T::Balance {
 	<Assets<T, I>>::total_supply(asset)
 }
 
+fn touch<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+) -> DispatchResult {
+	<Assets<T, I>>::touch(origin, asset.into())
+}
+
 fn transfer<T: pallet_assets::Config<I>, I>(
 	origin: OriginFor<T>,
 	asset: AssetIdOf<T, I>,
@@ -252,6 +460,21 @@ fn transfer<T: pallet_assets::Config<I>, I>(
 	)
 }
 
+// Transfers to each recipient in turn, within a storage layer so that either all transfers succeed
+// or none are applied.
+fn transfer_batch<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+	transfers: Vec<(AccountIdOf<T>, BalanceOf<T, I>)>,
+) -> DispatchResult {
+	with_storage_layer(|| {
+		for (to, value) in transfers {
+			transfer::<T, I>(origin.clone(), asset.clone(), to, value)?;
+		}
+		Ok(())
+	})
+}
+
 fn transfer_from<T: pallet_assets::Config<I>, I>(
 	origin: OriginFor<T>,
 	asset: AssetIdOf<T, I>,
@@ -267,3 +490,44 @@ fn transfer_from<T: pallet_assets::Config<I>, I>(
 		value,
 	)
 }
+
+// Transfers from an owner to each recipient in turn, within a storage layer so that either all
+// transfers succeed or none are applied.
+fn transfer_from_batch<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+	from: AccountIdOf<T>,
+	transfers: Vec<(AccountIdOf<T>, BalanceOf<T, I>)>,
+) -> DispatchResult {
+	with_storage_layer(|| {
+		for (to, value) in transfers {
+			transfer_from::<T, I>(origin.clone(), asset.clone(), from.clone(), to, value)?;
+		}
+		Ok(())
+	})
+}
+
+fn transfer_ownership<T: pallet_assets::Config<I>, I>(
+	origin: OriginFor<T>,
+	asset: AssetIdOf<T, I>,
+	owner: AccountIdOf<T>,
+) -> DispatchResult {
+	<Assets<T, I>>::transfer_ownership(origin, asset.into(), T::Lookup::unlookup(owner))
+}
+
+fn nonce<T: Config<I>, I: 'static>(token: &H160, owner: &T::AccountId) -> u64 {
+	Nonces::<T, I>::get(token, owner)
+}
+
+// Consumes the current nonce of the owner for a token, returning it. Fails rather than wrapping,
+// which would allow previous permits to be replayed.
+fn use_nonce<T: Config<I>, I: 'static>(
+	token: &H160,
+	owner: &T::AccountId,
+) -> Result<u64, DispatchError> {
+	Nonces::<T, I>::try_mutate(token, owner, |nonce| {
+		let current = *nonce;
+		*nonce = nonce.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+		Ok(current)
+	})
+}
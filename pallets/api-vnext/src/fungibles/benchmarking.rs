@@ -3,12 +3,18 @@
 use alloc::{
 	string::{String, ToString},
 	vec,
+	vec::Vec,
 };
 
 use frame_benchmarking::v2::*;
 use frame_support::{
 	assert_ok,
 	pallet_prelude::{DispatchError, IsType},
+	sp_runtime::{
+		app_crypto::sp_core::ecdsa,
+		traits::{AtLeast32Bit, StaticLookup},
+		KeyTypeId,
+	},
 	traits::{
 		fungible::{Inspect, Mutate},
 		fungibles::{
@@ -19,33 +25,44 @@ use frame_support::{
 		Get, Time,
 	},
 };
-use pallet_assets::{Asset, AssetStatus};
+use frame_system::RawOrigin;
+use pallet_assets::{AccountStatus, Asset, AssetStatus};
 use pallet_revive::{
 	precompiles::{
-		alloy::primitives as alloy,
+		alloy::{primitives as alloy, sol_types::SolStruct},
 		run::{H256, U256},
 	},
 	test_utils::{ALICE_ADDR, BOB_ADDR, CHARLIE_ADDR},
-	AddressMapper as _, Origin,
+	AddressMapper as _, Origin, H160,
 };
 
 use super::{
-	precompiles::v0::{IFungibles, IFungiblesCalls},
+	precompiles::{
+		erc20::v0::{IERC20Calls, Permit, IERC20},
+		v0::{IFungibles, IFungiblesCalls},
+	},
 	set_up_call, Config, NextAssetId, Pallet, TryConvert,
 };
 #[cfg(test)]
 use crate::mock::{ExtBuilder, Test};
-use crate::{call_precompile, fixed_address};
+use crate::{call_precompile, fixed_address, prefixed_address};
 
 const FUNGIBLES: u16 = 100;
 const ADDRESS: [u8; 20] = fixed_address(FUNGIBLES);
+const ERC20: u16 = 102;
+const FUNGIBLES_V1: u16 = 103;
+const V1_ADDRESS: [u8; 20] = fixed_address(FUNGIBLES_V1);
+// The key type used to sign permits.
+const PERMIT: KeyTypeId = KeyTypeId(*b"perm");
 
 type AddressMapper<T> = <T as pallet_revive::Config>::AddressMapper;
 type Assets<T, I> = pallet_assets::Pallet<T, I>;
 type AssetsBalance<T, I> = <T as pallet_assets::Config<I>>::Balance;
 type AssetsStringLimit<T, I> = <T as pallet_assets::Config<I>>::StringLimit;
 type Balances<T> = <T as pallet_revive::Config>::Currency;
+type Erc20<T, I> = super::precompiles::erc20::v0::Erc20<ERC20, T, I>;
 type Fungibles<T, I> = super::precompiles::v0::Fungibles<FUNGIBLES, T, I>;
+type FungiblesV1<T, I> = v1::Fungibles<FUNGIBLES_V1, T, I>;
 type TokenId<T, I> = <T as pallet_assets::Config<I>>::AssetId;
 
 #[instance_benchmarks(
@@ -57,7 +74,7 @@ type TokenId<T, I> = <T as pallet_assets::Config<I>>::AssetId;
             Time: Time<Moment: Into<U256>>
         >,
         // Fungibles
-        T: pallet_assets::Config<I, AssetId: Default + From<u32> + Into<u32> + Copy, Balance: TryConvert<alloy::U256, Error = DispatchError>> + pallet_balances::Config,
+        T: pallet_assets::Config<I, AssetId: AtLeast32Bit + Default + From<u32> + Into<u32> + Copy, Balance: TryConvert<alloy::U256, Error = DispatchError>> + pallet_balances::Config,
         alloy::U256: TryConvert<AssetsBalance<T, I>, Error = DispatchError>
 )]
 mod benchmarks {
@@ -446,9 +463,529 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn domain_separator() {
+		let token = super::create::<T, I>(<AddressMapper<T>>::to_account_id(&ALICE_ADDR));
+		let mut call_setup = set_up_call();
+		let mut ext = call_setup.ext().0;
+		let input = IERC20Calls::DOMAIN_SEPARATOR(IERC20::DOMAIN_SEPARATORCall {});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T, I>, _, alloy::B256>(
+				&mut ext,
+				&prefixed_address(ERC20, token.into()),
+				&input
+			));
+		}
+	}
+
+	#[benchmark]
+	fn nonces() {
+		let token = super::create::<T, I>(<AddressMapper<T>>::to_account_id(&ALICE_ADDR));
+		let mut call_setup = set_up_call();
+		let mut ext = call_setup.ext().0;
+		let input = IERC20Calls::nonces(IERC20::noncesCall { owner: ALICE_ADDR.0.into() });
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T, I>, _, alloy::U256>(
+				&mut ext,
+				&prefixed_address(ERC20, token.into()),
+				&input
+			));
+		}
+	}
+
+	#[benchmark]
+	fn permit() {
+		let (key, owner) = key_pair();
+		let account = <AddressMapper<T>>::to_account_id(&owner);
+		let spender = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+		let token = super::create::<T, I>(<AddressMapper<T>>::to_account_id(&CHARLIE_ADDR));
+		let address = prefixed_address(ERC20, token.into());
+		// Worst case: the current allowance is cancelled and replaced with a smaller one.
+		<Balances<T>>::set_balance(&account, u32::MAX.into());
+		assert_ok!(<Assets<T, I> as approvals::Mutate<T::AccountId>>::approve(
+			token,
+			&account,
+			&spender,
+			<AssetsBalance<T, I>>::from(u32::MAX / 2),
+		));
+		let value = <AssetsBalance<T, I>>::from(u32::MAX / 4);
+
+		let mut call_setup = set_up_call();
+		let mut ext = call_setup.ext().0;
+		let domain_separator = call_precompile::<Erc20<T, I>, _, alloy::B256>(
+			&mut ext,
+			&address,
+			&IERC20Calls::DOMAIN_SEPARATOR(IERC20::DOMAIN_SEPARATORCall {}),
+		)
+		.unwrap();
+		let input = sign(
+			&key,
+			domain_separator,
+			Permit {
+				owner: owner.0.into(),
+				spender: BOB_ADDR.0.into(),
+				value: value.try_convert().unwrap(),
+				nonce: alloy::U256::ZERO,
+				deadline: alloy::U256::MAX,
+			},
+		);
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T, I>, _, ()>(&mut ext, &address, &input));
+		}
+
+		assert_eq!(<Assets<T, I>>::allowance(token, &account, &spender), value);
+	}
+
+	#[benchmark]
+	fn freeze() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create::<T, I>(owner.clone());
+		let account = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::freeze(v1::IFungibles::freezeCall {
+			token: token.into(),
+			account: <AddressMapper<T>>::to_address(&account).0.into(),
+		});
+
+		<Assets<T, I>>::set_balance(token, &account, u32::MAX.into());
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(super::account_status::<T, I>(token, &account), Some(AccountStatus::Frozen));
+	}
+
+	#[benchmark]
+	fn thaw() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create::<T, I>(owner.clone());
+		let account = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::thaw(v1::IFungibles::thawCall {
+			token: token.into(),
+			account: <AddressMapper<T>>::to_address(&account).0.into(),
+		});
+
+		<Assets<T, I>>::set_balance(token, &account, u32::MAX.into());
+		assert_ok!(<Assets<T, I>>::freeze(
+			RawOrigin::Signed(owner).into(),
+			token.into(),
+			T::Lookup::unlookup(account.clone())
+		));
+		assert_eq!(super::account_status::<T, I>(token, &account), Some(AccountStatus::Frozen));
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(super::account_status::<T, I>(token, &account), Some(AccountStatus::Liquid));
+	}
+
+	#[benchmark]
+	fn freeze_token() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create::<T, I>(owner.clone());
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::freezeToken(v1::IFungibles::freezeTokenCall {
+			token: token.into(),
+		});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(Asset::<T, I>::get(token).unwrap().status, AssetStatus::Frozen);
+	}
+
+	#[benchmark]
+	fn thaw_token() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create::<T, I>(owner.clone());
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner.clone()));
+		let mut ext = call_setup.ext().0;
+		let input =
+			v1::IFungiblesCalls::thawToken(v1::IFungibles::thawTokenCall { token: token.into() });
+
+		assert_ok!(<Assets<T, I>>::freeze_asset(RawOrigin::Signed(owner).into(), token.into()));
+		assert_eq!(Asset::<T, I>::get(token).unwrap().status, AssetStatus::Frozen);
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(Asset::<T, I>::get(token).unwrap().status, AssetStatus::Live);
+	}
+
+	#[benchmark]
+	fn block() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create::<T, I>(owner.clone());
+		let account = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::block(v1::IFungibles::blockCall {
+			token: token.into(),
+			account: <AddressMapper<T>>::to_address(&account).0.into(),
+		});
+
+		<Assets<T, I>>::set_balance(token, &account, u32::MAX.into());
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(super::account_status::<T, I>(token, &account), Some(AccountStatus::Blocked));
+	}
+
+	#[benchmark]
+	fn set_team() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create::<T, I>(owner.clone());
+		let issuer = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+		let admin = <AddressMapper<T>>::to_account_id(&CHARLIE_ADDR);
+		let freezer = owner.clone();
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::setTeam(v1::IFungibles::setTeamCall {
+			token: token.into(),
+			issuer: <AddressMapper<T>>::to_address(&issuer).0.into(),
+			admin: <AddressMapper<T>>::to_address(&admin).0.into(),
+			freezer: <AddressMapper<T>>::to_address(&freezer).0.into(),
+		});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		let details = Asset::<T, I>::get(token).unwrap();
+		assert_eq!(details.issuer, issuer);
+		assert_eq!(details.admin, admin);
+		assert_eq!(details.freezer, freezer);
+	}
+
+	#[benchmark]
+	fn transfer_ownership() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create::<T, I>(owner.clone());
+		let new_owner = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::transferOwnership(v1::IFungibles::transferOwnershipCall {
+			token: token.into(),
+			owner: <AddressMapper<T>>::to_address(&new_owner).0.into(),
+		});
+
+		// Ensure the new owner can receive the deposits of the token.
+		<Balances<T>>::set_balance(&new_owner, u32::MAX.into());
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(Asset::<T, I>::get(token).unwrap().owner, new_owner);
+	}
+
+	#[benchmark]
+	fn set_min_balance() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		// The minimum balance of a sufficient token cannot be changed.
+		let token = super::create_insufficient::<T, I>(owner.clone());
+		let min_balance: AssetsBalance<T, I> = u32::MAX.into();
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::setMinBalance(v1::IFungibles::setMinBalanceCall {
+			token: token.into(),
+			minBalance: min_balance.try_convert().unwrap(),
+		});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(<Assets<T, I>>::minimum_balance(token), min_balance);
+	}
+
+	#[benchmark]
+	fn touch() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		// Touching an insufficient token requires a deposit.
+		let token = super::create_insufficient::<T, I>(owner);
+		let account = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(account.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::touch(v1::IFungibles::touchCall { token: token.into() });
+
+		<Balances<T>>::set_balance(&account, u32::MAX.into());
+		assert_eq!(super::account_status::<T, I>(token, &account), None);
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(super::account_status::<T, I>(token, &account), Some(AccountStatus::Liquid));
+	}
+
+	#[benchmark]
+	fn refund() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create_insufficient::<T, I>(owner);
+		let account = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(account.clone()));
+		let mut ext = call_setup.ext().0;
+		// Burning any remaining balance is the most expensive path.
+		let input = v1::IFungiblesCalls::refund(v1::IFungibles::refundCall {
+			token: token.into(),
+			allowBurn: true,
+		});
+
+		<Balances<T>>::set_balance(&account, u32::MAX.into());
+		assert_ok!(<Assets<T, I>>::touch(RawOrigin::Signed(account.clone()).into(), token.into()));
+		<Assets<T, I>>::set_balance(token, &account, u32::MAX.into());
+		assert_eq!(super::account_status::<T, I>(token, &account), Some(AccountStatus::Liquid));
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(super::account_status::<T, I>(token, &account), None);
+	}
+
+	#[benchmark]
+	fn destroy_accounts(
+		c: Linear<0, { <T as pallet_assets::Config<I>>::RemoveItemsLimit::get() }>,
+	) {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create::<T, I>(owner.clone());
+		for i in 0..c {
+			let holder: T::AccountId = account("holder", i, 0);
+			<Assets<T, I>>::set_balance(token, &holder, u32::MAX.into());
+		}
+		assert_ok!(<Assets<T, I>>::start_destroy(
+			RawOrigin::Signed(owner.clone()).into(),
+			token.into()
+		));
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::destroyAccounts(v1::IFungibles::destroyAccountsCall {
+			token: token.into(),
+		});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, u32>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(Asset::<T, I>::get(token).unwrap().accounts, 0);
+	}
+
+	#[benchmark]
+	fn destroy_approvals(
+		a: Linear<0, { <T as pallet_assets::Config<I>>::RemoveItemsLimit::get() }>,
+	) {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create::<T, I>(owner.clone());
+		for i in 0..a {
+			let spender: T::AccountId = account("spender", i, 0);
+			assert_ok!(<Assets<T, I>>::approve(token, &owner, &spender, 1u32.into()));
+		}
+		assert_ok!(<Assets<T, I>>::start_destroy(
+			RawOrigin::Signed(owner.clone()).into(),
+			token.into()
+		));
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::destroyApprovals(v1::IFungibles::destroyApprovalsCall {
+			token: token.into(),
+		});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, u32>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(Asset::<T, I>::get(token).unwrap().approvals, 0);
+	}
+
+	#[benchmark]
+	fn finish_destroy() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		// Includes metadata, the deposit of which is returned to the owner.
+		let token = super::create::<T, I>(owner.clone());
+		assert_ok!(<Assets<T, I>>::start_destroy(
+			RawOrigin::Signed(owner.clone()).into(),
+			token.into()
+		));
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::finishDestroy(v1::IFungibles::finishDestroyCall {
+			token: token.into(),
+		});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert!(!<Assets<T, I>>::asset_exists(token));
+	}
+
+	// Parameter:
+	// - 'n': the number of transfers of the batch, each to a new account.
+	#[benchmark]
+	fn transfer_batch(n: Linear<1, { <T as Config<I>>::MaxBatchSize::get() }>) {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create::<T, I>(owner.clone());
+		let recipients = recipients(n);
+		let value: AssetsBalance<T, I> = u32::MAX.into();
+		let min_balance = <Assets<T, I>>::minimum_balance(token);
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::transferBatch(v1::IFungibles::transferBatchCall {
+			token: token.into(),
+			to: recipients.iter().map(|to| to.0.into()).collect(),
+			values: vec![value.try_convert().unwrap(); n as usize],
+		});
+
+		<Assets<T, I>>::set_balance(
+			token,
+			&owner,
+			value * AssetsBalance::<T, I>::from(n) + min_balance,
+		);
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(<Assets<T, I>>::balance(token, &owner), min_balance);
+		for to in &recipients {
+			assert_eq!(
+				<Assets<T, I>>::balance(token, &<AddressMapper<T>>::to_account_id(to)),
+				value
+			);
+		}
+	}
+
+	// Parameter:
+	// - 'n': the number of transfers of the batch, each to a new account.
+	#[benchmark]
+	fn transfer_from_batch(n: Linear<1, { <T as Config<I>>::MaxBatchSize::get() }>) {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let token = super::create::<T, I>(owner.clone());
+		let spender = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+		let recipients = recipients(n);
+		let value: AssetsBalance<T, I> = u32::MAX.into();
+		let total = value * AssetsBalance::<T, I>::from(n);
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(spender.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = v1::IFungiblesCalls::transferFromBatch(v1::IFungibles::transferFromBatchCall {
+			token: token.into(),
+			from: <AddressMapper<T>>::to_address(&owner).0.into(),
+			to: recipients.iter().map(|to| to.0.into()).collect(),
+			values: vec![value.try_convert().unwrap(); n as usize],
+		});
+
+		<Assets<T, I>>::set_balance(token, &owner, total);
+		assert_ok!(<Assets<T, I>>::approve(token, &owner, &spender, total));
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<FungiblesV1<T, I>, _, ()>(&mut ext, &V1_ADDRESS, &input));
+		}
+
+		assert_eq!(<Assets<T, I>>::balance(token, &owner), 0u8.into());
+		for to in &recipients {
+			assert_eq!(
+				<Assets<T, I>>::balance(token, &<AddressMapper<T>>::to_account_id(to)),
+				value
+			);
+		}
+		assert_eq!(<Assets<T, I>>::allowance(token, &owner, &spender), 0u8.into());
+	}
+
 	impl_benchmark_test_suite!(Pallet, ExtBuilder::new().build(), Test);
 }
 
+// Generates a key pair for signing permits, returning its public key and address.
+pub(super) fn key_pair() -> (ecdsa::Public, H160) {
+	let key = sp_io::crypto::ecdsa_generate(PERMIT, None);
+	let digest = [0; 32];
+	let signature = sp_io::crypto::ecdsa_sign_prehashed(PERMIT, &key, &digest).unwrap();
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &digest).unwrap();
+	(key, H160(alloy::Address::from_raw_public_key(&public).0 .0))
+}
+
+// Signs a permit for the signing domain with the specified separator, as defined by EIP-712.
+pub(super) fn sign(
+	key: &ecdsa::Public,
+	domain_separator: alloy::B256,
+	permit: Permit,
+) -> IERC20Calls {
+	let digest = alloy::keccak256(
+		[&[0x19, 0x01][..], &domain_separator[..], &permit.eip712_hash_struct()[..]].concat(),
+	);
+	let signature = sp_io::crypto::ecdsa_sign_prehashed(PERMIT, key, &digest.0).unwrap().0;
+	IERC20Calls::permit(IERC20::permitCall {
+		owner: permit.owner,
+		spender: permit.spender,
+		value: permit.value,
+		deadline: permit.deadline,
+		v: signature[64] + 27,
+		r: alloy::B256::from_slice(&signature[..32]),
+		s: alloy::B256::from_slice(&signature[32..64]),
+	})
+}
+
 // Ensure `event` has been emitted.
 fn assert_has_event<T: pallet_assets::Config<I>, I>(
 	event: <T as pallet_assets::Config<I>>::RuntimeEvent,
@@ -482,3 +1019,29 @@ fn create<
 
 	token
 }
+
+// Creates a token which is not sufficient, requiring a deposit for each account holding it.
+fn create_insufficient<
+	T: Config<I> + pallet_assets::Config<I, AssetId: Copy + Default> + pallet_revive::Config,
+	I,
+>(
+	owner: T::AccountId,
+) -> TokenId<T, I> {
+	let token = NextAssetId::<T, I>::get().unwrap_or_default();
+	<Balances<T>>::set_balance(&owner, u32::MAX.into());
+	assert_ok!(<Assets<T, I> as Create<T::AccountId>>::create(token, owner, false, 1u32.into()));
+	token
+}
+
+// Distinct addresses of accounts not yet holding any tokens, for batches of transfers.
+fn recipients(n: u32) -> Vec<H160> {
+	(0..n).map(|i| H160::from_low_u64_be(u64::MAX - u64::from(i))).collect()
+}
+
+// The status of an account holding a token, if any.
+fn account_status<T: pallet_assets::Config<I>, I>(
+	token: TokenId<T, I>,
+	account: &T::AccountId,
+) -> Option<AccountStatus> {
+	pallet_assets::Account::<T, I>::get(token, account).map(|a| a.status)
+}
@@ -0,0 +1,163 @@
+//! The state of the native token, as offered as an ERC20 token by the native precompile.
+//!
+//! The native currency has no notion of allowances, so approvals and the nonces of permits are
+//! stored by this pallet. Unlike the fungibles pallet, it is not instantiated per assets instance,
+//! as the state of the native token is independent of any assets instance.
+
+use frame_support::{
+	dispatch::DispatchResult,
+	pallet_prelude::{CheckedSub, DispatchError, Zero},
+	sp_runtime::ArithmeticError,
+	traits::{
+		fungible,
+		tokens::{Fortitude, Precision, Preservation},
+		Get,
+	},
+};
+pub use pallet::*;
+
+use crate::AccountIdOf;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub(super) type BalanceOf<T> =
+	<<T as Config>::Currency as fungible::Inspect<AccountIdOf<T>>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+
+	use super::{fungible, weights::WeightInfo, BalanceOf};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The native currency, offered as an ERC20 token by the native precompile.
+		type Currency: fungible::Mutate<Self::AccountId>
+			+ fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+		/// The deposit held from the owner of an approval, for as long as the approval is stored.
+		#[pallet::constant]
+		type ApprovalDeposit: Get<BalanceOf<Self>>;
+		/// The overarching hold reason for deposits.
+		type RuntimeHoldReason: From<HoldReason>;
+		/// Weight information for the native precompile.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Held for as long as an approval is stored.
+		#[codec(index = 0)]
+		Approval,
+	}
+
+	/// The amounts which spenders are approved to transfer on behalf of owners, by owner and
+	/// spender.
+	#[pallet::storage]
+	pub(crate) type Allowances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The next nonce of each owner for signature-based approvals (permits), as defined by
+	/// EIP-2612, by owner.
+	#[pallet::storage]
+	pub(crate) type Nonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+}
+
+pub(super) fn allowance<T: Config>(owner: &T::AccountId, spender: &T::AccountId) -> BalanceOf<T> {
+	Allowances::<T>::get(owner, spender)
+}
+
+// Approves a spender, holding a deposit from the owner when the approval is first stored and
+// releasing it once the approval is removed, as with approvals of assets.
+pub(super) fn approve<T: Config>(
+	owner: &T::AccountId,
+	spender: &T::AccountId,
+	value: BalanceOf<T>,
+) -> DispatchResult {
+	let reason = HoldReason::Approval.into();
+	let deposit = T::ApprovalDeposit::get();
+	let approved = Allowances::<T>::contains_key(owner, spender);
+	if value.is_zero() {
+		if approved {
+			Allowances::<T>::remove(owner, spender);
+			// Best effort, as the deposit may have been changed since the approval was stored.
+			<T::Currency as fungible::MutateHold<_>>::release(
+				&reason,
+				owner,
+				deposit,
+				Precision::BestEffort,
+			)?;
+		}
+	} else {
+		if !approved {
+			<T::Currency as fungible::MutateHold<_>>::hold(&reason, owner, deposit)?;
+		}
+		Allowances::<T>::insert(owner, spender, value);
+	}
+	Ok(())
+}
+
+pub(super) fn balance<T: Config>(account: &T::AccountId) -> BalanceOf<T> {
+	<T::Currency as fungible::Inspect<_>>::balance(account)
+}
+
+pub(super) fn reducible_balance<T: Config>(account: &T::AccountId) -> BalanceOf<T> {
+	<T::Currency as fungible::Inspect<_>>::reducible_balance(
+		account,
+		Preservation::Preserve,
+		Fortitude::Polite,
+	)
+}
+
+pub(super) fn total_supply<T: Config>() -> BalanceOf<T> {
+	<T::Currency as fungible::Inspect<_>>::total_issuance()
+}
+
+pub(super) fn transfer<T: Config>(
+	from: &T::AccountId,
+	to: &T::AccountId,
+	value: BalanceOf<T>,
+) -> DispatchResult {
+	<T::Currency as fungible::Mutate<_>>::transfer(from, to, value, Preservation::Preserve)?;
+	Ok(())
+}
+
+pub(super) fn transfer_from<T: Config>(
+	spender: &T::AccountId,
+	from: &T::AccountId,
+	to: &T::AccountId,
+	value: BalanceOf<T>,
+) -> DispatchResult {
+	let remaining = allowance::<T>(from, spender)
+		.checked_sub(&value)
+		.ok_or(ArithmeticError::Underflow)?;
+	transfer::<T>(from, to, value)?;
+	approve::<T>(from, spender, remaining)
+}
+
+pub(super) fn nonce<T: Config>(owner: &T::AccountId) -> u64 {
+	Nonces::<T>::get(owner)
+}
+
+// Consumes the current nonce of the owner, returning it. Fails rather than wrapping, which would
+// allow previous permits to be replayed.
+pub(super) fn use_nonce<T: Config>(owner: &T::AccountId) -> Result<u64, DispatchError> {
+	Nonces::<T>::try_mutate(owner, |nonce| {
+		let current = *nonce;
+		*nonce = nonce.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+		Ok(current)
+	})
+}
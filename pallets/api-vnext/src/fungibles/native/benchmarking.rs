@@ -0,0 +1,256 @@
+//! Benchmarking setup for pallet_api::fungibles::native
+
+use alloc::string::String;
+
+use frame_benchmarking::v2::*;
+use frame_support::{
+	assert_ok,
+	pallet_prelude::{DispatchError, IsType},
+	traits::{
+		fungible::{Inspect, Mutate},
+		Time,
+	},
+};
+use pallet_revive::{
+	precompiles::{
+		alloy::primitives as alloy,
+		run::{H256, U256},
+	},
+	test_utils::{ALICE_ADDR, BOB_ADDR, CHARLIE_ADDR},
+	AddressMapper as _, Origin,
+};
+
+use super::{
+	super::{
+		benchmarking::{key_pair, sign},
+		precompiles::erc20::v0::{
+			native::{Metadata, NativeErc20},
+			IERC20Calls, Permit, IERC20,
+		},
+	},
+	allowance, approve, BalanceOf, Config, Pallet,
+};
+#[cfg(test)]
+use crate::mock::{ExtBuilder, Test};
+use crate::{call_precompile, fixed_address, set_up_call, TryConvert};
+
+const NATIVE_ERC20: u16 = 101;
+const ADDRESS: [u8; 20] = fixed_address(NATIVE_ERC20);
+
+type AddressMapper<T> = <T as pallet_revive::Config>::AddressMapper;
+type Currency<T> = <T as Config>::Currency;
+type Erc20<T> = NativeErc20<NATIVE_ERC20, T, NativeMetadata>;
+
+#[benchmarks(
+    where
+        T: pallet_revive::Config<
+            Currency: Inspect<<T as frame_system::Config>::AccountId, Balance: Into<U256> + TryFrom<U256>>,
+            Hash: IsType<H256>,
+            Time: Time<Moment: Into<U256>>
+        >,
+        BalanceOf<T>: TryConvert<alloy::U256, Error = DispatchError>,
+        alloy::U256: TryConvert<BalanceOf<T>, Error = DispatchError>
+)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn allowance() {
+		let mut call_setup = set_up_call();
+		let mut ext = call_setup.ext().0;
+		let input = IERC20Calls::allowance(IERC20::allowanceCall {
+			owner: ALICE_ADDR.0.into(),
+			spender: BOB_ADDR.0.into(),
+		});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T>, _, alloy::U256>(&mut ext, &ADDRESS, &input));
+		}
+	}
+
+	#[benchmark]
+	fn approve() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let spender = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+		let value: BalanceOf<T> = u32::MAX.into();
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = IERC20Calls::approve(IERC20::approveCall {
+			spender: BOB_ADDR.0.into(),
+			value: value.try_convert().unwrap(),
+		});
+
+		// The approval is new, requiring a deposit to be held.
+		<Currency<T>>::set_balance(&owner, value);
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T>, _, bool>(&mut ext, &ADDRESS, &input));
+		}
+
+		assert_eq!(super::allowance::<T>(&owner, &spender), value);
+	}
+
+	#[benchmark]
+	fn balance_of() {
+		let mut call_setup = set_up_call();
+		let mut ext = call_setup.ext().0;
+		let input = IERC20Calls::balanceOf(IERC20::balanceOfCall { account: ALICE_ADDR.0.into() });
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T>, _, alloy::U256>(&mut ext, &ADDRESS, &input));
+		}
+	}
+
+	#[benchmark]
+	fn total_supply() {
+		let mut call_setup = set_up_call();
+		let mut ext = call_setup.ext().0;
+		let input = IERC20Calls::totalSupply(IERC20::totalSupplyCall {});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T>, _, alloy::U256>(&mut ext, &ADDRESS, &input));
+		}
+	}
+
+	#[benchmark]
+	fn transfer() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let to = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+		let value: BalanceOf<T> = u32::MAX.into();
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(owner.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = IERC20Calls::transfer(IERC20::transferCall {
+			to: BOB_ADDR.0.into(),
+			value: value.try_convert().unwrap(),
+		});
+
+		<Currency<T>>::set_balance(&owner, value + value);
+		let balance = <Currency<T>>::balance(&to);
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T>, _, bool>(&mut ext, &ADDRESS, &input));
+		}
+
+		assert_eq!(<Currency<T>>::balance(&to), balance + value);
+	}
+
+	#[benchmark]
+	fn transfer_from() {
+		let owner = <AddressMapper<T>>::to_account_id(&ALICE_ADDR);
+		let spender = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+		let to = <AddressMapper<T>>::to_account_id(&CHARLIE_ADDR);
+		let value: BalanceOf<T> = u32::MAX.into();
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Origin::Signed(spender.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = IERC20Calls::transferFrom(IERC20::transferFromCall {
+			from: ALICE_ADDR.0.into(),
+			to: CHARLIE_ADDR.0.into(),
+			value: value.try_convert().unwrap(),
+		});
+
+		<Currency<T>>::set_balance(&owner, value + value);
+		let balance = <Currency<T>>::balance(&to);
+		// The allowance is used in full, releasing the deposit.
+		assert_ok!(super::approve::<T>(&owner, &spender, value));
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T>, _, bool>(&mut ext, &ADDRESS, &input));
+		}
+
+		assert_eq!(<Currency<T>>::balance(&to), balance + value);
+		assert_eq!(super::allowance::<T>(&owner, &spender), 0u8.into());
+	}
+
+	#[benchmark]
+	fn domain_separator() {
+		let mut call_setup = set_up_call();
+		let mut ext = call_setup.ext().0;
+		let input = IERC20Calls::DOMAIN_SEPARATOR(IERC20::DOMAIN_SEPARATORCall {});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T>, _, alloy::B256>(&mut ext, &ADDRESS, &input));
+		}
+	}
+
+	#[benchmark]
+	fn nonces() {
+		let mut call_setup = set_up_call();
+		let mut ext = call_setup.ext().0;
+		let input = IERC20Calls::nonces(IERC20::noncesCall { owner: ALICE_ADDR.0.into() });
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T>, _, alloy::U256>(&mut ext, &ADDRESS, &input));
+		}
+	}
+
+	#[benchmark]
+	fn permit() {
+		let (key, owner) = key_pair();
+		let account = <AddressMapper<T>>::to_account_id(&owner);
+		let spender = <AddressMapper<T>>::to_account_id(&BOB_ADDR);
+		let value: BalanceOf<T> = u32::MAX.into();
+
+		let mut call_setup = set_up_call();
+		let mut ext = call_setup.ext().0;
+		let domain_separator = call_precompile::<Erc20<T>, _, alloy::B256>(
+			&mut ext,
+			&ADDRESS,
+			&IERC20Calls::DOMAIN_SEPARATOR(IERC20::DOMAIN_SEPARATORCall {}),
+		)
+		.unwrap();
+		let input = sign(
+			&key,
+			domain_separator,
+			Permit {
+				owner: owner.0.into(),
+				spender: BOB_ADDR.0.into(),
+				value: value.try_convert().unwrap(),
+				nonce: alloy::U256::ZERO,
+				deadline: alloy::U256::MAX,
+			},
+		);
+		<Currency<T>>::set_balance(&account, value);
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T>, _, ()>(&mut ext, &ADDRESS, &input));
+		}
+
+		assert_eq!(super::allowance::<T>(&account, &spender), value);
+	}
+
+	#[benchmark]
+	fn metadata() {
+		let mut call_setup = set_up_call();
+		let mut ext = call_setup.ext().0;
+		let input = IERC20Calls::name(IERC20::nameCall {});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Erc20<T>, _, String>(&mut ext, &ADDRESS, &input));
+		}
+	}
+
+	impl_benchmark_test_suite!(Pallet, ExtBuilder::new().build(), Test);
+}
+
+struct NativeMetadata;
+impl Metadata for NativeMetadata {
+	const DECIMALS: u8 = 10;
+	const NAME: &'static str = "Native";
+	const SYMBOL: &'static str = "NAT";
+}
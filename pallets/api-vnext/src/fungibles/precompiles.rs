@@ -17,3 +17,7 @@ pub mod erc20;
 /// The first version of the Fungibles API.
 #[allow(ambiguous_associated_items)]
 pub mod v0;
+
+/// The second version of the Fungibles API, adding the administration of tokens.
+#[allow(ambiguous_associated_items)]
+pub mod v1;
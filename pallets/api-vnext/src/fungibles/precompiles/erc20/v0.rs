@@ -1,28 +1,45 @@
 use alloc::{string::String, vec::Vec};
 
-use frame_support::{pallet_prelude as frame, sp_runtime::traits::AtLeast32Bit};
+use frame_support::{pallet_prelude as frame, traits::Get};
 use pallet_assets::precompiles::{AssetIdExtractor, InlineAssetIdExtractor};
-use pallet_revive::precompiles::RuntimeCosts;
+use pallet_revive::precompiles::{
+	alloy::{
+		primitives::{b256, B256},
+		sol_types::{Eip712Domain, SolStruct},
+	},
+	RuntimeCosts,
+};
 use AddressMatcher::Prefix;
 use IERC20::*;
 
 use super::{super::super::*, U256, *};
 
+/// Precompile providing an interface of the ERC-20 standard for the native token.
+pub mod native;
+
 sol!(
 	#![sol(extra_derives(Debug, PartialEq))]
 	"src/fungibles/precompiles/interfaces/v0/IERC20.sol"
 );
 
+sol! {
+	/// The typed data signed by an owner to approve a spender, as defined by EIP-2612.
+	struct Permit {
+		address owner;
+		address spender;
+		uint256 value;
+		uint256 nonce;
+		uint256 deadline;
+	}
+}
+
 /// Precompile providing an interface of the ERC-20 standard as defined in the ERC.
 pub struct Erc20<const PREFIX: u16, T, I = ()>(PhantomData<(T, I)>);
 impl<
 		const PREFIX: u16,
 		T: frame_system::Config
-			+ pallet_assets::Config<
-				I,
-				AssetId: AtLeast32Bit,
-				Balance: TryConvert<U256, Error = frame::DispatchError>,
-			> + pallet_revive::Config
+			+ pallet_assets::Config<I, Balance: TryConvert<U256, Error = frame::DispatchError>>
+			+ pallet_revive::Config
 			+ Config<I>,
 		I: 'static,
 	> Precompile for Erc20<PREFIX, T, I>
@@ -41,15 +58,15 @@ where
 		input: &Self::Interface,
 		env: &mut impl Ext<T = Self::T>,
 	) -> Result<Vec<u8>, Error> {
-		let token = InlineAssetIdExtractor::asset_id_from_address(address)?.into();
+		let token = InlineAssetIdExtractor::asset_id_from_address(address)?;
+		let token = asset_id::<T, I>(token).map_err(map_err)?;
 
 		match input {
 			// IERC20
 			IERC20Calls::totalSupply(_) => {
 				env.charge(<T as Config<I>>::WeightInfo::total_supply())?;
 
-				let total_supply =
-					total_supply::<T, I>(token).try_convert().map_err(Self::map_err)?;
+				let total_supply = total_supply::<T, I>(token).try_convert().map_err(map_err)?;
 
 				Ok(totalSupplyCall::abi_encode_returns(&total_supply))
 			},
@@ -57,8 +74,7 @@ where
 				env.charge(<T as Config<I>>::WeightInfo::balance_of())?;
 
 				let account = env.to_account_id(&(*account.0).into());
-				let balance =
-					balance::<T, I>(token, &account).try_convert().map_err(Self::map_err)?;
+				let balance = balance::<T, I>(token, &account).try_convert().map_err(map_err)?;
 
 				Ok(balanceOfCall::abi_encode_returns(&balance))
 			},
@@ -67,17 +83,17 @@ where
 				ensure!(!to.is_zero(), ERC20InvalidReceiver { receiver: *to });
 				ensure!(!value.is_zero(), ERC20InsufficientValue);
 
-				let origin = Origin::try_from(env.caller()).map_err(Self::map_err)?;
+				let origin = Origin::try_from(env.caller()).map_err(map_err)?;
 				let from = origin.address();
 				let balance = balance::<T, I>(token.clone(), &origin.account)
 					.try_convert()
-					.map_err(Self::map_err)?;
+					.map_err(map_err)?;
 
 				transfer::<T, I>(
 					origin.into(),
 					token,
 					env.to_account_id(&(*to.0).into()),
-					(*value).try_convert().map_err(Self::map_err)?,
+					(*value).try_convert().map_err(map_err)?,
 				)
 				.map_err(|e| Self::map_transfer_err(e, &from, value, &balance))?;
 
@@ -89,9 +105,8 @@ where
 
 				let owner = env.to_account_id(&(*owner.0).into());
 				let spender = env.to_account_id(&(*spender.0).into());
-				let remaining = allowance::<T, I>(token, &owner, &spender)
-					.try_convert()
-					.map_err(Self::map_err)?;
+				let remaining =
+					allowance::<T, I>(token, &owner, &spender).try_convert().map_err(map_err)?;
 
 				Ok(allowanceCall::abi_encode_returns(&remaining))
 			},
@@ -133,7 +148,7 @@ where
 
 					Ok(owner)
 				})()
-				.map_err(Self::map_err)?;
+				.map_err(map_err)?;
 
 				deposit_event(env, Approval { owner, spender: *spender, value: *value })?;
 				Ok(approveCall::abi_encode_returns(&true))
@@ -144,12 +159,12 @@ where
 				ensure!(!to.is_zero(), ERC20InvalidReceiver { receiver: *to });
 				ensure!(!value.is_zero(), ERC20InsufficientValue);
 
-				let origin = Origin::try_from(env.caller()).map_err(Self::map_err)?;
+				let origin = Origin::try_from(env.caller()).map_err(map_err)?;
 				let owner = env.to_account_id(&(*from.0).into());
 				let spender = origin.address();
 				let allowance = allowance::<T, I>(token.clone(), &owner, &origin.account)
 					.try_convert()
-					.map_err(Self::map_err)?;
+					.map_err(map_err)?;
 
 				transfer_from::<T, I>(
 					origin.into(),
@@ -187,6 +202,61 @@ where
 
 				Ok(decimalsCall::abi_encode_returns(&result))
 			},
+			// IERC20Permit
+			IERC20Calls::permit(permitCall { owner, spender, value, deadline, v, r, s }) => {
+				env.charge(<T as Config<I>>::WeightInfo::permit())?;
+				ensure!(!spender.is_zero(), ERC20InvalidSpender { spender: *spender });
+				ensure!(
+					U256::from_limbs(env.now().0) <= *deadline,
+					ERC2612ExpiredSignature { deadline: *deadline }
+				);
+
+				let account = env.to_account_id(&(*owner.0).into());
+				let permit = Permit {
+					owner: *owner,
+					spender: *spender,
+					value: *value,
+					nonce: U256::from(nonce::<T, I>(&(*address).into(), &account)),
+					deadline: *deadline,
+				};
+				let domain = domain::<T>(
+					String::from_utf8_lossy(&name::<T, I>(token.clone())).into(),
+					address,
+				);
+				let signer = permit_signer(&domain, &permit, *v, r, s);
+				ensure!(
+					signer == Some(*owner),
+					ERC2612InvalidSigner { signer: signer.unwrap_or_default(), owner: *owner }
+				);
+
+				use_nonce::<T, I>(&(*address).into(), &account).map_err(map_err)?;
+				approve::<T, I>(
+					RawOrigin::Signed(account).into(),
+					token,
+					env.to_account_id(&(*spender.0).into()),
+					(*value).try_convert()?,
+				)
+				.map_err(|e| map_err(e.error))?;
+
+				deposit_event(env, Approval { owner: *owner, spender: *spender, value: *value })?;
+				Ok(permitCall::abi_encode_returns(&permitReturn {}))
+			},
+			IERC20Calls::nonces(noncesCall { owner }) => {
+				env.charge(<T as Config<I>>::WeightInfo::nonces())?;
+
+				let owner = env.to_account_id(&(*owner.0).into());
+				let nonce = U256::from(nonce::<T, I>(&(*address).into(), &owner));
+
+				Ok(noncesCall::abi_encode_returns(&nonce))
+			},
+			IERC20Calls::DOMAIN_SEPARATOR(_) => {
+				env.charge(<T as Config<I>>::WeightInfo::domain_separator())?;
+
+				let domain =
+					domain::<T>(String::from_utf8_lossy(&name::<T, I>(token)).into(), address);
+
+				Ok(DOMAIN_SEPARATORCall::abi_encode_returns(&domain.separator()))
+			},
 		}
 	}
 }
@@ -197,20 +267,6 @@ impl<const PREFIX: u16, T: pallet_assets::Config<I>, I: 'static> Erc20<PREFIX, T
 		prefixed_address(PREFIX, id)
 	}
 
-	// Maps generic runtime errors.
-	fn map_err(e: frame::DispatchError) -> Error {
-		use frame::DispatchError::*;
-		match e {
-			Arithmetic(error) => self::Arithmetic::from(error).into(),
-			Module(ModuleError { index, error, .. }) =>
-				self::Module { index, error: error.into() }.into(),
-			Token(error) => self::Token::from(error).into(),
-			Transactional(error) => self::Transactional::from(error).into(),
-			Trie(error) => self::Trie::from(error).into(),
-			other => self::Dispatch::from(other).into(),
-		}
-	}
-
 	// Maps select, domain-specific dispatch errors to ERC20 errors. All others are mapped to
 	// more generic runtime errors.
 	fn map_transfer_err(
@@ -239,7 +295,7 @@ impl<const PREFIX: u16, T: pallet_assets::Config<I>, I: 'static> Erc20<PREFIX, T
 			},
 			_ => None,
 		}
-		.unwrap_or_else(|| Self::map_err(e))
+		.unwrap_or_else(|| map_err(e))
 	}
 
 	// Maps select, domain-specific dispatch errors to ERC20 errors. All others are mapped to
@@ -270,7 +326,57 @@ impl<const PREFIX: u16, T: pallet_assets::Config<I>, I: 'static> Erc20<PREFIX, T
 			},
 			_ => None,
 		}
-		.unwrap_or_else(|| Self::map_err(e))
+		.unwrap_or_else(|| map_err(e))
+	}
+}
+
+// The EIP-712 signing domain of a token, as used by permits.
+fn domain<T: pallet_revive::Config>(name: String, address: &[u8; 20]) -> Eip712Domain {
+	Eip712Domain::new(
+		Some(name.into()),
+		Some("1".into()),
+		Some(U256::from(T::ChainId::get())),
+		Some(Address::from(*address)),
+		None,
+	)
+}
+
+// Recovers the signer of a permit from its signature, as defined by EIP-2612. Malleable
+// signatures (i.e. with an `s` value in the upper range) are rejected, as per EIP-2.
+fn permit_signer(
+	domain: &Eip712Domain,
+	permit: &Permit,
+	v: u8,
+	r: &B256,
+	s: &B256,
+) -> Option<Address> {
+	// The order of the secp256k1 curve, halved.
+	const MAX_S: B256 = b256!("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0");
+	if !matches!(v, 27 | 28) || *s > MAX_S {
+		return None;
+	}
+
+	let mut signature = [0u8; 65];
+	signature[..32].copy_from_slice(r.as_slice());
+	signature[32..64].copy_from_slice(s.as_slice());
+	signature[64] = v - 27;
+	let digest = permit.eip712_signing_hash(domain);
+	sp_io::crypto::secp256k1_ecdsa_recover(&signature, &digest.0)
+		.ok()
+		.map(|public| Address::from_raw_public_key(&public))
+}
+
+// Maps generic runtime errors.
+fn map_err(e: frame::DispatchError) -> Error {
+	use frame::DispatchError::*;
+	match e {
+		Arithmetic(error) => self::Arithmetic::from(error).into(),
+		Module(ModuleError { index, error, .. }) =>
+			self::Module { index, error: error.into() }.into(),
+		Token(error) => self::Token::from(error).into(),
+		Transactional(error) => self::Transactional::from(error).into(),
+		Trie(error) => self::Trie::from(error).into(),
+		other => self::Dispatch::from(other).into(),
 	}
 }
 
@@ -381,12 +487,13 @@ impl From<frame_support::traits::TrieError> for Trie {
 #[cfg(test)]
 mod tests {
 	use frame_support::{
-		assert_ok, sp_runtime::app_crypto::sp_core::bytes::to_hex,
+		assert_ok,
+		sp_runtime::app_crypto::sp_core::{bytes::to_hex, ecdsa, Pair as _},
 		traits::fungibles::approvals::Inspect,
 	};
 	use pallet_revive::{
 		precompiles::alloy::{
-			primitives::Address,
+			primitives::{keccak256, Address},
 			sol_types::{SolInterface, SolType, SolValue},
 		},
 		test_utils::{ALICE, BOB, CHARLIE},
@@ -396,12 +503,13 @@ mod tests {
 	use super::*;
 	use crate::{
 		assert_last_event, bare_call,
-		fungibles::approve,
-		mock::{Assets, ExtBuilder, RuntimeOrigin, Test, ERC20, UNIT},
+		fungibles::{approve, Nonces},
+		mock::{Assets, ExtBuilder, RuntimeOrigin, Test, Timestamp, ERC20, UNIT},
 		to_address, DepositLimit, Weight,
 	};
 
 	type AccountId = <Test as frame_system::Config>::AccountId;
+	type AddressMapper = <Test as pallet_revive::Config>::AddressMapper;
 
 	#[test]
 	fn total_supply_works() {
@@ -806,11 +914,328 @@ mod tests {
 			});
 	}
 
+	#[test]
+	fn permit_reverts_with_invalid_spender() {
+		let token = 1;
+		let (key, owner) = key_pair(1);
+		ExtBuilder::new()
+			.with_assets(vec![(token, CHARLIE, false, 1)])
+			.build()
+			.execute_with(|| {
+				let permit = Permit {
+					owner,
+					spender: Address::ZERO,
+					value: U256::from(10_000_000),
+					nonce: U256::ZERO,
+					deadline: U256::MAX,
+				};
+				let call = sign(&key, &domain_of(token), permit);
+				assert_revert!(
+					call_precompile::<()>(&BOB, token, &IERC20Calls::permit(call)),
+					ERC20InvalidSpender { spender: Address::ZERO }
+				);
+			});
+	}
+
+	#[test]
+	fn permit_with_zero_value_revokes_allowance() {
+		let token = 1;
+		let (key, owner) = key_pair(1);
+		let account = AddressMapper::to_fallback_account_id(&owner.0 .0.into());
+		let spender = BOB;
+		ExtBuilder::new()
+			.with_balances(vec![(account.clone(), UNIT)])
+			.with_assets(vec![(token, CHARLIE, false, 1)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(approve::<Test, ()>(
+					RuntimeOrigin::signed(account.clone()),
+					token,
+					spender.clone(),
+					10_000_000
+				));
+
+				let permit = Permit {
+					owner,
+					spender: to_address(&spender).0.into(),
+					value: U256::ZERO,
+					nonce: U256::ZERO,
+					deadline: U256::MAX,
+				};
+				let call = sign(&key, &domain_of(token), permit);
+				assert_ok!(call_precompile::<()>(&CHARLIE, token, &IERC20Calls::permit(call)));
+
+				assert_eq!(Assets::allowance(token, &account, &spender), 0);
+				assert_eq!(nonce_of(token, &account), 1);
+				let spender = to_address(&spender).0.into();
+				let event = Approval { owner, spender, value: U256::ZERO };
+				assert_last_event(prefixed_address(ERC20, token), event);
+			});
+	}
+
+	#[test]
+	fn permit_reverts_with_expired_signature() {
+		let token = 1;
+		let (key, owner) = key_pair(1);
+		let deadline = U256::from(1);
+		ExtBuilder::new()
+			.with_assets(vec![(token, CHARLIE, false, 1)])
+			.build()
+			.execute_with(|| {
+				Timestamp::set_timestamp(10_000_000);
+				let permit = Permit {
+					owner,
+					spender: to_address(&BOB).0.into(),
+					value: U256::from(10_000_000),
+					nonce: U256::ZERO,
+					deadline,
+				};
+				let call = sign(&key, &domain_of(token), permit);
+				assert_revert!(
+					call_precompile::<()>(&BOB, token, &IERC20Calls::permit(call)),
+					ERC2612ExpiredSignature { deadline }
+				);
+			});
+	}
+
+	#[test]
+	fn permit_reverts_with_invalid_signer() {
+		let token = 1;
+		let (_, owner) = key_pair(1);
+		let (key, signer) = key_pair(2);
+		ExtBuilder::new()
+			.with_assets(vec![(token, CHARLIE, false, 1)])
+			.build()
+			.execute_with(|| {
+				let permit = Permit {
+					owner,
+					spender: to_address(&BOB).0.into(),
+					value: U256::from(10_000_000),
+					nonce: U256::ZERO,
+					deadline: U256::MAX,
+				};
+				let call = sign(&key, &domain_of(token), permit);
+				assert_revert!(
+					call_precompile::<()>(&BOB, token, &IERC20Calls::permit(call)),
+					ERC2612InvalidSigner { signer, owner }
+				);
+			});
+	}
+
+	#[test]
+	fn permit_reverts_with_invalid_signature() {
+		let token = 1;
+		let (key, owner) = key_pair(1);
+		ExtBuilder::new()
+			.with_assets(vec![(token, CHARLIE, false, 1)])
+			.build()
+			.execute_with(|| {
+				let permit = Permit {
+					owner,
+					spender: to_address(&BOB).0.into(),
+					value: U256::from(10_000_000),
+					nonce: U256::ZERO,
+					deadline: U256::MAX,
+				};
+				let call = sign(&key, &domain_of(token), permit);
+				// Invalid recovery identifier.
+				assert_revert!(
+					call_precompile::<()>(
+						&BOB,
+						token,
+						&IERC20Calls::permit(permitCall { v: 29, ..call.clone() })
+					),
+					ERC2612InvalidSigner { signer: Address::ZERO, owner }
+				);
+				// Malleable signature, using the complement of `s`.
+				let s = b256!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+				let s = B256::from(U256::from_be_bytes(s.0) - U256::from_be_bytes(call.s.0));
+				assert_revert!(
+					call_precompile::<()>(
+						&BOB,
+						token,
+						&IERC20Calls::permit(permitCall { v: call.v ^ 1, s, ..call })
+					),
+					ERC2612InvalidSigner { signer: Address::ZERO, owner }
+				);
+			});
+	}
+
+	#[test]
+	fn permit_cannot_be_replayed() {
+		let token = 1;
+		let (key, owner) = key_pair(1);
+		let account = AddressMapper::to_fallback_account_id(&owner.0 .0.into());
+		let spender = BOB;
+		let value = 10_000_000;
+		ExtBuilder::new()
+			.with_balances(vec![(account.clone(), UNIT)])
+			.with_assets(vec![(token, CHARLIE, false, 1)])
+			.build()
+			.execute_with(|| {
+				let permit = Permit {
+					owner,
+					spender: to_address(&spender).0.into(),
+					value: U256::from(value),
+					nonce: U256::ZERO,
+					deadline: U256::MAX,
+				};
+				let call = sign(&key, &domain_of(token), permit.clone());
+				assert_ok!(call_precompile::<()>(&BOB, token, &IERC20Calls::permit(call.clone())));
+				assert_ok!(approve::<Test, ()>(
+					RuntimeOrigin::signed(account.clone()),
+					token,
+					spender.clone(),
+					0
+				));
+
+				// The signature is now checked against the next nonce, recovering another signer.
+				let signer = permit_signer(
+					&domain_of(token),
+					&Permit { nonce: U256::from(1), ..permit },
+					call.v,
+					&call.r,
+					&call.s,
+				)
+				.unwrap();
+				assert_revert!(
+					call_precompile::<()>(&BOB, token, &IERC20Calls::permit(call)),
+					ERC2612InvalidSigner { signer, owner }
+				);
+				assert_eq!(Assets::allowance(token, &account, &spender), 0);
+				assert_eq!(nonce_of(token, &account), 1);
+			});
+	}
+
+	#[test]
+	fn permit_works() {
+		let token = 1;
+		let (key, owner) = key_pair(1);
+		let account = AddressMapper::to_fallback_account_id(&owner.0 .0.into());
+		let spender = BOB;
+		let value = 10_000_000;
+		ExtBuilder::new()
+			.with_balances(vec![(account.clone(), UNIT)])
+			.with_assets(vec![(token, CHARLIE, false, 1)])
+			.build()
+			.execute_with(|| {
+				assert_eq!(Assets::allowance(token, &account, &spender), 0);
+
+				let permit = Permit {
+					owner,
+					spender: to_address(&spender).0.into(),
+					value: U256::from(value),
+					nonce: U256::ZERO,
+					deadline: U256::MAX,
+				};
+				let call = sign(&key, &domain_of(token), permit);
+				// Submitted by a relayer on behalf of the owner.
+				assert_ok!(call_precompile::<()>(&CHARLIE, token, &IERC20Calls::permit(call)));
+
+				assert_eq!(Assets::allowance(token, &account, &spender), value);
+				assert_eq!(nonce_of(token, &account), 1);
+				let spender = to_address(&spender).0.into();
+				let event = Approval { owner, spender, value: U256::from(value) };
+				assert_last_event(prefixed_address(ERC20, token), event);
+			});
+	}
+
+	#[test]
+	fn nonces_works() {
+		let token = 1;
+		let owner = ALICE;
+		let nonce = 42;
+		ExtBuilder::new()
+			.with_assets(vec![(token, CHARLIE, false, 1)])
+			.build()
+			.execute_with(|| {
+				let call = IERC20Calls::nonces(noncesCall { owner: to_address(&owner).0.into() });
+				assert_eq!(call_precompile::<U256>(&BOB, token, &call).unwrap(), U256::ZERO);
+
+				Nonces::<Test>::insert(H160::from(prefixed_address(ERC20, token)), &owner, nonce);
+
+				assert_eq!(call_precompile::<U256>(&BOB, token, &call).unwrap(), U256::from(nonce));
+			});
+	}
+
+	#[test]
+	fn nonces_are_kept_per_token() {
+		let tokens = [1, 2];
+		let (key, owner) = key_pair(1);
+		let account = AddressMapper::to_fallback_account_id(&owner.0 .0.into());
+		let spender = BOB;
+		let value = 10_000_000;
+		ExtBuilder::new()
+			.with_balances(vec![(account.clone(), UNIT)])
+			.with_assets(tokens.iter().map(|token| (*token, CHARLIE, false, 1)).collect())
+			.build()
+			.execute_with(|| {
+				// Permits for different tokens are signed with the same nonce, with the use of one
+				// not affecting the other.
+				let permits: Vec<_> = tokens
+					.iter()
+					.map(|token| {
+						let permit = Permit {
+							owner,
+							spender: to_address(&spender).0.into(),
+							value: U256::from(value),
+							nonce: U256::ZERO,
+							deadline: U256::MAX,
+						};
+						(*token, sign(&key, &domain_of(*token), permit))
+					})
+					.collect();
+				for (token, call) in permits.into_iter().rev() {
+					assert_ok!(call_precompile::<()>(&CHARLIE, token, &IERC20Calls::permit(call)));
+
+					assert_eq!(Assets::allowance(token, &account, &spender), value);
+					assert_eq!(nonce_of(token, &account), 1);
+				}
+			});
+	}
+
+	#[test]
+	fn domain_separator_works() {
+		let token = 1;
+		let name = "name";
+		ExtBuilder::new()
+			.with_assets(vec![(token, CHARLIE, false, 1)])
+			.with_asset_metadata(vec![(token, name.as_bytes().to_vec(), b"symbol".to_vec(), 10)])
+			.build()
+			.execute_with(|| {
+				let expected = keccak256(
+					(
+						keccak256(
+							"EIP712Domain(string name,string version,uint256 chainId,address \
+							 verifyingContract)",
+						),
+						keccak256(name),
+						keccak256("1"),
+						U256::from(<<Test as pallet_revive::Config>::ChainId as Get<u64>>::get()),
+						Address::from(prefixed_address(ERC20, token)),
+					)
+						.abi_encode(),
+				);
+				assert_eq!(
+					call_precompile::<B256>(
+						&ALICE,
+						token,
+						&IERC20Calls::DOMAIN_SEPARATOR(DOMAIN_SEPARATORCall {})
+					)
+					.unwrap(),
+					expected
+				);
+			});
+	}
+
 	#[test]
 	fn selectors_match_standard() {
 		assert_eq!(to_hex(&decimalsCall::SELECTOR, false), "0x313ce567");
 		assert_eq!(to_hex(&nameCall::SELECTOR, false), "0x06fdde03");
 		assert_eq!(to_hex(&symbolCall::SELECTOR, false), "0x95d89b41");
+		assert_eq!(to_hex(&permitCall::SELECTOR, false), "0xd505accf");
+		assert_eq!(to_hex(&noncesCall::SELECTOR, false), "0x7ecebe00");
+		assert_eq!(to_hex(&DOMAIN_SEPARATORCall::SELECTOR, false), "0x3644e515");
 	}
 
 	fn call_precompile<Output: SolValue + From<<Output::SolType as SolType>::RustType>>(
@@ -828,4 +1253,39 @@ mod tests {
 			input.abi_encode(),
 		)
 	}
+
+	fn nonce_of(token: u32, owner: &AccountId) -> u64 {
+		Nonces::<Test>::get(H160::from(prefixed_address(ERC20, token)), owner)
+	}
+
+	fn domain_of(token: u32) -> Eip712Domain {
+		domain::<Test>(
+			String::from_utf8_lossy(&crate::fungibles::name::<Test, ()>(token)).into(),
+			&prefixed_address(ERC20, token),
+		)
+	}
+
+	// A key pair for signing permits, along with its address.
+	pub(super) fn key_pair(seed: u8) -> (ecdsa::Pair, Address) {
+		let key = ecdsa::Pair::from_seed(&[seed; 32]);
+		let digest = [0; 32];
+		let public =
+			sp_io::crypto::secp256k1_ecdsa_recover(&key.sign_prehashed(&digest).0, &digest)
+				.unwrap();
+		(key, Address::from_raw_public_key(&public))
+	}
+
+	// Signs a permit for the signing domain of a token.
+	pub(super) fn sign(key: &ecdsa::Pair, domain: &Eip712Domain, permit: Permit) -> permitCall {
+		let signature = key.sign_prehashed(&permit.eip712_signing_hash(domain).0).0;
+		permitCall {
+			owner: permit.owner,
+			spender: permit.spender,
+			value: permit.value,
+			deadline: permit.deadline,
+			v: signature[64] + 27,
+			r: B256::from_slice(&signature[..32]),
+			s: B256::from_slice(&signature[32..64]),
+		}
+	}
 }
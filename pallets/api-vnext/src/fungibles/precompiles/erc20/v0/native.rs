@@ -0,0 +1,755 @@
+use frame_support::sp_runtime::TokenError;
+use AddressMatcher::Fixed;
+
+use super::*;
+use crate::fungibles::native::{
+	allowance, approve, balance, nonce, reducible_balance, total_supply, transfer, transfer_from,
+	use_nonce, weights::WeightInfo as _, BalanceOf, Config,
+};
+
+/// The metadata of the native token.
+pub trait Metadata {
+	/// The name of the token.
+	const NAME: &'static str;
+	/// The symbol of the token.
+	const SYMBOL: &'static str;
+	/// The number of decimals used to represent the token.
+	const DECIMALS: u8;
+}
+
+/// Precompile providing an interface of the ERC-20 standard as defined in the ERC for the native
+/// token, backed by the configured [`Config::Currency`].
+///
+/// Approvals are stored by the native pallet, independently of any assets instance, with a deposit
+/// held from the owner for as long as an approval is stored and approving a zero `value` revoking
+/// an allowance. Transfers preserve the account of the sender.
+pub struct NativeErc20<const FIXED: u16, T, M>(PhantomData<(T, M)>);
+impl<const FIXED: u16, T: frame_system::Config + pallet_revive::Config + Config, M: Metadata>
+	Precompile for NativeErc20<FIXED, T, M>
+where
+	BalanceOf<T>: TryConvert<U256, Error = frame::DispatchError>,
+	U256: TryConvert<BalanceOf<T>, Error = frame::DispatchError>,
+{
+	type Interface = IERC20Calls;
+	type T = T;
+
+	const HAS_CONTRACT_INFO: bool = false;
+	const MATCHER: AddressMatcher =
+		Fixed(NonZero::new(FIXED).expect("expected non-zero precompile address"));
+
+	fn call(
+		address: &[u8; 20],
+		input: &Self::Interface,
+		env: &mut impl Ext<T = Self::T>,
+	) -> Result<Vec<u8>, Error> {
+		match input {
+			// IERC20
+			IERC20Calls::totalSupply(_) => {
+				env.charge(<T as Config>::WeightInfo::total_supply())?;
+
+				let total_supply = total_supply::<T>().try_convert().map_err(map_err)?;
+
+				Ok(totalSupplyCall::abi_encode_returns(&total_supply))
+			},
+			IERC20Calls::balanceOf(balanceOfCall { account }) => {
+				env.charge(<T as Config>::WeightInfo::balance_of())?;
+
+				let account = env.to_account_id(&(*account.0).into());
+				let balance = balance::<T>(&account).try_convert().map_err(map_err)?;
+
+				Ok(balanceOfCall::abi_encode_returns(&balance))
+			},
+			IERC20Calls::transfer(transferCall { to, value }) => {
+				env.charge(<T as Config>::WeightInfo::transfer())?;
+				ensure!(!to.is_zero(), ERC20InvalidReceiver { receiver: *to });
+				ensure!(!value.is_zero(), ERC20InsufficientValue);
+
+				let origin = Origin::try_from(env.caller()).map_err(map_err)?;
+				let from = origin.address();
+
+				transfer::<T>(
+					&origin.account,
+					&env.to_account_id(&(*to.0).into()),
+					(*value).try_convert().map_err(map_err)?,
+				)
+				.map_err(|e| Self::map_transfer_err(e, &origin.account, &from, value))?;
+
+				deposit_event(env, Transfer { from, to: *to, value: *value })?;
+				Ok(transferCall::abi_encode_returns(&true))
+			},
+			IERC20Calls::allowance(allowanceCall { owner, spender }) => {
+				env.charge(<T as Config>::WeightInfo::allowance())?;
+
+				let owner = env.to_account_id(&(*owner.0).into());
+				let spender = env.to_account_id(&(*spender.0).into());
+				let remaining = allowance::<T>(&owner, &spender).try_convert().map_err(map_err)?;
+
+				Ok(allowanceCall::abi_encode_returns(&remaining))
+			},
+			IERC20Calls::approve(approveCall { spender, value }) => {
+				env.charge(<T as Config>::WeightInfo::approve())?;
+				ensure!(!spender.is_zero(), ERC20InvalidSpender { spender: *spender });
+
+				let origin = Origin::try_from(env.caller()).map_err(map_err)?;
+				approve::<T>(
+					&origin.account,
+					&env.to_account_id(&(*spender.0).into()),
+					(*value).try_convert().map_err(map_err)?,
+				)
+				.map_err(map_err)?;
+
+				let owner = origin.address();
+				deposit_event(env, Approval { owner, spender: *spender, value: *value })?;
+				Ok(approveCall::abi_encode_returns(&true))
+			},
+			IERC20Calls::transferFrom(transferFromCall { from, to, value }) => {
+				env.charge(<T as Config>::WeightInfo::transfer_from())?;
+				ensure!(!from.is_zero(), ERC20InvalidSender { sender: *from });
+				ensure!(!to.is_zero(), ERC20InvalidReceiver { receiver: *to });
+				ensure!(!value.is_zero(), ERC20InsufficientValue);
+
+				let origin = Origin::try_from(env.caller()).map_err(map_err)?;
+				let owner = env.to_account_id(&(*from.0).into());
+				let allowance =
+					allowance::<T>(&owner, &origin.account).try_convert().map_err(map_err)?;
+				ensure!(
+					allowance >= *value,
+					ERC20InsufficientAllowance {
+						spender: origin.address(),
+						allowance,
+						needed: *value
+					}
+				);
+
+				transfer_from::<T>(
+					&origin.account,
+					&owner,
+					&env.to_account_id(&(*to.0).into()),
+					(*value).try_convert().map_err(map_err)?,
+				)
+				.map_err(|e| Self::map_transfer_err(e, &owner, from, value))?;
+
+				deposit_event(env, Transfer { from: *from, to: *to, value: *value })?;
+				Ok(transferFromCall::abi_encode_returns(&true))
+			},
+			// IERC20Metadata
+			IERC20Calls::name(_) => {
+				env.charge(<T as Config>::WeightInfo::metadata())?;
+
+				Ok(nameCall::abi_encode_returns(&M::NAME.into()))
+			},
+			IERC20Calls::symbol(_) => {
+				env.charge(<T as Config>::WeightInfo::metadata())?;
+
+				Ok(symbolCall::abi_encode_returns(&M::SYMBOL.into()))
+			},
+			IERC20Calls::decimals(_) => {
+				env.charge(<T as Config>::WeightInfo::metadata())?;
+
+				Ok(decimalsCall::abi_encode_returns(&M::DECIMALS))
+			},
+			// IERC20Permit
+			IERC20Calls::permit(permitCall { owner, spender, value, deadline, v, r, s }) => {
+				env.charge(<T as Config>::WeightInfo::permit())?;
+				ensure!(!spender.is_zero(), ERC20InvalidSpender { spender: *spender });
+				ensure!(
+					U256::from_limbs(env.now().0) <= *deadline,
+					ERC2612ExpiredSignature { deadline: *deadline }
+				);
+
+				let account = env.to_account_id(&(*owner.0).into());
+				let permit = Permit {
+					owner: *owner,
+					spender: *spender,
+					value: *value,
+					nonce: U256::from(nonce::<T>(&account)),
+					deadline: *deadline,
+				};
+				let signer =
+					permit_signer(&domain::<T>(M::NAME.into(), address), &permit, *v, r, s);
+				ensure!(
+					signer == Some(*owner),
+					ERC2612InvalidSigner { signer: signer.unwrap_or_default(), owner: *owner }
+				);
+
+				use_nonce::<T>(&account).map_err(map_err)?;
+				approve::<T>(
+					&account,
+					&env.to_account_id(&(*spender.0).into()),
+					(*value).try_convert().map_err(map_err)?,
+				)
+				.map_err(map_err)?;
+
+				deposit_event(env, Approval { owner: *owner, spender: *spender, value: *value })?;
+				Ok(permitCall::abi_encode_returns(&permitReturn {}))
+			},
+			IERC20Calls::nonces(noncesCall { owner }) => {
+				env.charge(<T as Config>::WeightInfo::nonces())?;
+
+				let owner = env.to_account_id(&(*owner.0).into());
+				let nonce = U256::from(nonce::<T>(&owner));
+
+				Ok(noncesCall::abi_encode_returns(&nonce))
+			},
+			IERC20Calls::DOMAIN_SEPARATOR(_) => {
+				env.charge(<T as Config>::WeightInfo::domain_separator())?;
+
+				let domain = domain::<T>(M::NAME.into(), address);
+
+				Ok(DOMAIN_SEPARATORCall::abi_encode_returns(&domain.separator()))
+			},
+		}
+	}
+}
+
+impl<const FIXED: u16, T: Config, M> NativeErc20<FIXED, T, M>
+where
+	BalanceOf<T>: TryConvert<U256, Error = frame::DispatchError>,
+{
+	/// The address of the precompile.
+	pub const fn address() -> [u8; 20] {
+		fixed_address(FIXED)
+	}
+
+	// Maps select, domain-specific dispatch errors to ERC20 errors. All others are mapped to
+	// more generic runtime errors.
+	fn map_transfer_err(
+		e: frame::DispatchError,
+		account: &T::AccountId,
+		from: &Address,
+		value: &U256,
+	) -> Error {
+		match e {
+			frame::DispatchError::Token(TokenError::FundsUnavailable) =>
+				reducible_balance::<T>(account).try_convert().ok().map(|balance| {
+					IERC20::ERC20InsufficientBalance { sender: *from, balance, needed: *value }
+						.into()
+				}),
+			_ => None,
+		}
+		.unwrap_or_else(|| map_err(e))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::{
+		assert_ok,
+		traits::{
+			fungible::{Inspect, InspectHold},
+			Get,
+		},
+	};
+	use pallet_revive::{
+		precompiles::alloy::{
+			primitives::{keccak256, Address},
+			sol_types::{SolInterface, SolType, SolValue},
+		},
+		test_utils::{ALICE, BOB, CHARLIE},
+	};
+	use IERC20::{Approval, Transfer};
+
+	use super::{
+		super::tests::{key_pair, sign},
+		*,
+	};
+	use crate::{
+		assert_last_event, bare_call,
+		fungibles::native::{Allowances, HoldReason, Nonces},
+		mock::{
+			Balance, Balances, ExistentialDeposit, ExtBuilder, NativeMetadata, RuntimeOrigin, Test,
+			NATIVE_ERC20, UNIT,
+		},
+		to_address, DepositLimit, Weight,
+	};
+
+	type AccountId = <Test as frame_system::Config>::AccountId;
+	type AddressMapper = <Test as pallet_revive::Config>::AddressMapper;
+
+	const ADDRESS: [u8; 20] = fixed_address(NATIVE_ERC20);
+
+	#[test]
+	fn total_supply_works() {
+		let endowment = 10_000_000;
+		ExtBuilder::new()
+			.with_balances(vec![(ALICE, endowment), (BOB, endowment)])
+			.build()
+			.execute_with(|| {
+				let call = IERC20Calls::totalSupply(totalSupplyCall {});
+				assert_eq!(
+					call_precompile::<U256>(&ALICE, &call).unwrap(),
+					U256::from(Balances::total_issuance())
+				);
+			});
+	}
+
+	#[test]
+	fn balance_of_works() {
+		let account = BOB;
+		let endowment = 10_000_000;
+		ExtBuilder::new()
+			.with_balances(vec![(ALICE, endowment), (account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let account = to_address(&account).0.into();
+				assert_eq!(
+					call_precompile::<U256>(
+						&ALICE,
+						&IERC20Calls::balanceOf(balanceOfCall { account })
+					)
+					.unwrap(),
+					U256::from(endowment)
+				);
+			});
+	}
+
+	#[test]
+	fn transfer_reverts_with_invalid_receiver() {
+		ExtBuilder::new().build().execute_with(|| {
+			let call = transferCall { to: Address::default(), value: U256::ZERO };
+			assert_revert!(
+				call_precompile::<()>(&ALICE, &IERC20Calls::transfer(call)),
+				ERC20InvalidReceiver { receiver: Address::default() }
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_reverts_with_zero_value() {
+		let to = [255; 20].into();
+		ExtBuilder::new().build().execute_with(|| {
+			let call = transferCall { to, value: U256::ZERO };
+			assert_revert!(
+				call_precompile::<()>(&ALICE, &IERC20Calls::transfer(call)),
+				ERC20InsufficientValue
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_reverts_with_insufficient_balance() {
+		let origin = ALICE;
+		let endowment = 10_000_000;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				// The account of the sender is preserved.
+				let value = U256::from(endowment);
+				let call = transferCall { to: to_address(&BOB).0.into(), value };
+				assert_revert!(
+					call_precompile::<()>(&origin, &IERC20Calls::transfer(call)),
+					ERC20InsufficientBalance {
+						sender: to_address(&origin).0.into(),
+						balance: U256::from(endowment - ExistentialDeposit::get()),
+						needed: value
+					}
+				);
+			});
+	}
+
+	#[test]
+	fn transfer_works() {
+		let origin = ALICE;
+		let to = BOB;
+		let endowment = 10_000_000;
+		let value = endowment / 2;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				assert!(call_precompile::<bool>(
+					&origin,
+					&IERC20Calls::transfer(transferCall {
+						to: to_address(&to).0.into(),
+						value: U256::from(value)
+					})
+				)
+				.unwrap());
+
+				assert_eq!(Balances::balance(&origin), endowment - value);
+				assert_eq!(Balances::balance(&to), value);
+				let from = to_address(&origin).0.into();
+				let to = to_address(&to).0.into();
+				let event = Transfer { from, to, value: U256::from(value) };
+				assert_last_event(ADDRESS, event);
+			});
+	}
+
+	#[test]
+	fn allowance_works() {
+		let owner = ALICE;
+		let spender = BOB;
+		let value = 10_000_000;
+		ExtBuilder::new()
+			.with_balances(vec![(owner.clone(), UNIT)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(approve::<Test>(&owner, &spender, value));
+
+				assert_eq!(
+					call_precompile::<U256>(
+						&CHARLIE,
+						&IERC20Calls::allowance(allowanceCall {
+							owner: to_address(&owner).0.into(),
+							spender: to_address(&spender).0.into(),
+						})
+					)
+					.unwrap(),
+					U256::from(value)
+				);
+			});
+	}
+
+	#[test]
+	fn approve_reverts_with_invalid_spender() {
+		ExtBuilder::new().build().execute_with(|| {
+			let call = approveCall { spender: Address::default(), value: U256::ZERO };
+			assert_revert!(
+				call_precompile::<()>(&ALICE, &IERC20Calls::approve(call)),
+				ERC20InvalidSpender { spender: Address::default() }
+			);
+		});
+	}
+
+	#[test]
+	fn approve_reverts_with_insufficient_deposit() {
+		let origin = ALICE;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.clone(), ExistentialDeposit::get())])
+			.build()
+			.execute_with(|| {
+				let call = approveCall { spender: to_address(&BOB).0.into(), value: U256::ONE };
+				assert_revert!(
+					call_precompile::<()>(&origin, &IERC20Calls::approve(call)),
+					Token::from(TokenError::FundsUnavailable)
+				);
+				assert!(!Allowances::<Test>::contains_key(&origin, &BOB));
+			});
+	}
+
+	#[test]
+	fn approve_works() {
+		let origin = ALICE;
+		let spender = BOB;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.clone(), UNIT)])
+			.build()
+			.execute_with(|| {
+				// Approving a zero value revokes the allowance, releasing the deposit.
+				for (value, deposit) in
+					[(10_000_000, approval_deposit()), (5_000_000, approval_deposit()), (0, 0)]
+				{
+					assert!(call_precompile::<bool>(
+						&origin,
+						&IERC20Calls::approve(approveCall {
+							spender: to_address(&spender).0.into(),
+							value: U256::from(value)
+						})
+					)
+					.unwrap());
+
+					assert_eq!(allowance::<Test>(&origin, &spender), value);
+					assert_eq!(approval_deposit_held(&origin), deposit);
+					let owner = to_address(&origin).0.into();
+					let spender = to_address(&spender).0.into();
+					let event = Approval { owner, spender, value: U256::from(value) };
+					assert_last_event(ADDRESS, event);
+				}
+				assert!(!Allowances::<Test>::contains_key(&origin, &spender));
+			});
+	}
+
+	#[test]
+	fn transfer_from_reverts_with_invalid_sender() {
+		ExtBuilder::new().build().execute_with(|| {
+			let call = transferFromCall {
+				from: Address::default(),
+				to: Address::default(),
+				value: U256::ZERO,
+			};
+			assert_revert!(
+				call_precompile::<()>(&ALICE, &IERC20Calls::transferFrom(call)),
+				ERC20InvalidSender { sender: Address::default() }
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_from_reverts_with_invalid_receiver() {
+		let from = [255; 20].into();
+		ExtBuilder::new().build().execute_with(|| {
+			let call = transferFromCall { from, to: Address::default(), value: U256::ZERO };
+			assert_revert!(
+				call_precompile::<()>(&ALICE, &IERC20Calls::transferFrom(call)),
+				ERC20InvalidReceiver { receiver: Address::default() }
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_from_reverts_with_zero_value() {
+		let from = [255; 20].into();
+		let to = [1; 20].into();
+		ExtBuilder::new().build().execute_with(|| {
+			let call = transferFromCall { from, to, value: U256::ZERO };
+			assert_revert!(
+				call_precompile::<()>(&ALICE, &IERC20Calls::transferFrom(call)),
+				ERC20InsufficientValue
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_from_reverts_with_insufficient_allowance() {
+		let origin = BOB;
+		let from = ALICE;
+		let to = CHARLIE;
+		let endowment = 10_000_000;
+		let allowance = endowment / 2;
+		ExtBuilder::new()
+			.with_balances(vec![(from.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(approve::<Test>(&from, &origin, allowance));
+
+				let value = U256::from(allowance + 1);
+				let call = transferFromCall {
+					from: to_address(&from).0.into(),
+					to: to_address(&to).0.into(),
+					value,
+				};
+				assert_revert!(
+					call_precompile::<()>(&origin, &IERC20Calls::transferFrom(call)),
+					ERC20InsufficientAllowance {
+						spender: to_address(&origin).0.into(),
+						allowance: U256::from(allowance),
+						needed: value
+					}
+				);
+			});
+	}
+
+	#[test]
+	fn transfer_from_reverts_with_insufficient_balance() {
+		let origin = BOB;
+		let from = ALICE;
+		let to = CHARLIE;
+		let endowment = 10_000_000;
+		ExtBuilder::new()
+			.with_balances(vec![(from.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(approve::<Test>(&from, &origin, endowment));
+
+				let value = U256::from(endowment);
+				let call = transferFromCall {
+					from: to_address(&from).0.into(),
+					to: to_address(&to).0.into(),
+					value,
+				};
+				assert_revert!(
+					call_precompile::<()>(&origin, &IERC20Calls::transferFrom(call)),
+					ERC20InsufficientBalance {
+						sender: to_address(&from).0.into(),
+						balance: U256::from(
+							endowment - approval_deposit() - ExistentialDeposit::get()
+						),
+						needed: value
+					}
+				);
+				assert_eq!(allowance::<Test>(&from, &origin), endowment);
+			});
+	}
+
+	#[test]
+	fn transfer_from_works() {
+		let origin = BOB;
+		let from = ALICE;
+		let to = CHARLIE;
+		let endowment = 10_000_000;
+		let allowance = endowment / 2;
+		let value = allowance / 2;
+		ExtBuilder::new()
+			.with_balances(vec![(from.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(approve::<Test>(&from, &origin, allowance));
+
+				assert!(call_precompile::<bool>(
+					&origin,
+					&IERC20Calls::transferFrom(transferFromCall {
+						from: to_address(&from).0.into(),
+						to: to_address(&to).0.into(),
+						value: U256::from(value),
+					})
+				)
+				.unwrap());
+
+				assert_eq!(Balances::balance(&from), endowment - approval_deposit() - value);
+				assert_eq!(Balances::balance(&to), value);
+				assert_eq!(allowance::<Test>(&from, &origin), allowance - value);
+				assert_eq!(approval_deposit_held(&from), approval_deposit());
+				let from = to_address(&from).0.into();
+				let to = to_address(&to).0.into();
+				let event = Transfer { from, to, value: U256::from(value) };
+				assert_last_event(ADDRESS, event);
+			});
+	}
+
+	#[test]
+	fn transfer_from_of_entire_allowance_releases_deposit() {
+		let origin = BOB;
+		let from = ALICE;
+		let to = CHARLIE;
+		let endowment = 10_000_000;
+		let allowance = endowment / 2;
+		ExtBuilder::new()
+			.with_balances(vec![(from.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(approve::<Test>(&from, &origin, allowance));
+
+				assert!(call_precompile::<bool>(
+					&origin,
+					&IERC20Calls::transferFrom(transferFromCall {
+						from: to_address(&from).0.into(),
+						to: to_address(&to).0.into(),
+						value: U256::from(allowance),
+					})
+				)
+				.unwrap());
+
+				assert_eq!(Balances::balance(&from), endowment - allowance);
+				assert!(!Allowances::<Test>::contains_key(&from, &origin));
+				assert_eq!(approval_deposit_held(&from), 0);
+			});
+	}
+
+	#[test]
+	fn permit_reverts_with_invalid_signer() {
+		let (_, owner) = key_pair(1);
+		let (key, signer) = key_pair(2);
+		ExtBuilder::new().build().execute_with(|| {
+			let permit = Permit {
+				owner,
+				spender: to_address(&BOB).0.into(),
+				value: U256::from(10_000_000),
+				nonce: U256::ZERO,
+				deadline: U256::MAX,
+			};
+			let call = sign(&key, &domain::<Test>(NativeMetadata::NAME.into(), &ADDRESS), permit);
+			assert_revert!(
+				call_precompile::<()>(&BOB, &IERC20Calls::permit(call)),
+				ERC2612InvalidSigner { signer, owner }
+			);
+		});
+	}
+
+	#[test]
+	fn permit_works() {
+		let (key, owner) = key_pair(1);
+		let account = AddressMapper::to_fallback_account_id(&owner.0 .0.into());
+		let spender = BOB;
+		ExtBuilder::new()
+			.with_balances(vec![(account.clone(), UNIT)])
+			.build()
+			.execute_with(|| {
+				let domain = domain::<Test>(NativeMetadata::NAME.into(), &ADDRESS);
+				// Permitting a zero value revokes the allowance, releasing the deposit.
+				for (nonce, (value, deposit)) in
+					[(10_000_000, approval_deposit()), (0, 0)].into_iter().enumerate()
+				{
+					let permit = Permit {
+						owner,
+						spender: to_address(&spender).0.into(),
+						value: U256::from(value),
+						nonce: U256::from(nonce),
+						deadline: U256::MAX,
+					};
+					let call = sign(&key, &domain, permit);
+					// Submitted by a relayer on behalf of the owner.
+					assert_ok!(call_precompile::<()>(&CHARLIE, &IERC20Calls::permit(call)));
+
+					assert_eq!(allowance::<Test>(&account, &spender), value);
+					assert_eq!(approval_deposit_held(&account), deposit);
+					assert_eq!(Nonces::<Test>::get(&account), nonce as u64 + 1);
+					let spender = to_address(&spender).0.into();
+					let event = Approval { owner, spender, value: U256::from(value) };
+					assert_last_event(ADDRESS, event);
+				}
+			});
+	}
+
+	#[test]
+	fn nonces_works() {
+		let owner = ALICE;
+		let nonce = 42;
+		ExtBuilder::new().build().execute_with(|| {
+			let call = IERC20Calls::nonces(noncesCall { owner: to_address(&owner).0.into() });
+			assert_eq!(call_precompile::<U256>(&BOB, &call).unwrap(), U256::ZERO);
+
+			Nonces::<Test>::insert(&owner, nonce);
+
+			assert_eq!(call_precompile::<U256>(&BOB, &call).unwrap(), U256::from(nonce));
+		});
+	}
+
+	#[test]
+	fn domain_separator_works() {
+		ExtBuilder::new().build().execute_with(|| {
+			let expected = keccak256(
+				(
+					keccak256(
+						"EIP712Domain(string name,string version,uint256 chainId,address \
+						 verifyingContract)",
+					),
+					keccak256(NativeMetadata::NAME),
+					keccak256("1"),
+					U256::from(<<Test as pallet_revive::Config>::ChainId as Get<u64>>::get()),
+					Address::from(ADDRESS),
+				)
+					.abi_encode(),
+			);
+			let call = IERC20Calls::DOMAIN_SEPARATOR(DOMAIN_SEPARATORCall {});
+			assert_eq!(call_precompile::<B256>(&ALICE, &call).unwrap(), expected);
+		});
+	}
+
+	#[test]
+	fn metadata_works() {
+		ExtBuilder::new().build().execute_with(|| {
+			let call = IERC20Calls::name(nameCall {});
+			assert_eq!(call_precompile::<String>(&ALICE, &call).unwrap(), NativeMetadata::NAME);
+			let call = IERC20Calls::symbol(symbolCall {});
+			assert_eq!(call_precompile::<String>(&ALICE, &call).unwrap(), NativeMetadata::SYMBOL);
+			let call = IERC20Calls::decimals(decimalsCall {});
+			assert_eq!(
+				call_precompile::<u16>(&ALICE, &call).unwrap() as u8,
+				NativeMetadata::DECIMALS
+			);
+		});
+	}
+
+	fn approval_deposit() -> Balance {
+		<<Test as Config>::ApprovalDeposit as Get<Balance>>::get()
+	}
+
+	fn approval_deposit_held(owner: &AccountId) -> Balance {
+		Balances::balance_on_hold(&HoldReason::Approval.into(), owner)
+	}
+
+	fn call_precompile<Output: SolValue + From<<Output::SolType as SolType>::RustType>>(
+		origin: &AccountId,
+		input: &IERC20Calls,
+	) -> Result<Output, Error> {
+		bare_call::<Test, Output>(
+			RuntimeOrigin::signed(origin.clone()),
+			ADDRESS.into(),
+			0,
+			Weight::MAX,
+			DepositLimit::Balance(u128::MAX),
+			input.abi_encode(),
+		)
+	}
+}
@@ -102,6 +102,45 @@ interface IERC20 {
      */
     function decimals() external view returns (uint8);
 
+    // IERC20Permit: Interface of the ERC-20 Permit extension allowing approvals to be made via signatures, as defined in
+    // https://eips.ethereum.org/EIPS/eip-2612.
+    // Source: https://github.com/OpenZeppelin/openzeppelin-contracts/blob/master/contracts/token/ERC20/extensions/IERC20Permit.sol
+
+    /**
+     * @notice Sets `value` as the allowance of `spender` over ``owner``'s tokens, given ``owner``'s signed approval.
+     *
+     * The signature must be a valid `secp256k1` signature from `owner` over the EIP712-formatted function arguments,
+     * using `owner`'s current nonce (see {nonces}). `deadline` must be a timestamp in the future.
+     *
+     * Nonces are kept per token, so permits for different tokens can be used independently of one another. A zero
+     * `value` revokes the allowance of `spender`.
+     *
+     * Emits an {Approval} event.
+     */
+    function permit(
+        address owner,
+        address spender,
+        uint256 value,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external;
+
+    /**
+     * @notice Returns the current nonce for `owner`. This value must be included whenever a signature is generated
+     * for {permit}.
+     *
+     * Every successful call to {permit} increases ``owner``'s nonce by one. This prevents a signature from being used
+     * multiple times.
+     */
+    function nonces(address owner) external view returns (uint256);
+
+    /**
+     * @notice Returns the domain separator used in the encoding of the signature for {permit}, as defined by EIP712.
+     */
+    function DOMAIN_SEPARATOR() external view returns (bytes32);
+
     /**
      * @notice Indicates a failure with the `spender`’s `allowance`. Used in transfers.
      * @param spender Address that may be allowed to operate on tokens without being their owner.
@@ -141,6 +180,17 @@ interface IERC20 {
      * @param spender Address that may be allowed to operate on tokens without being their owner.
      */
     error ERC20InvalidSpender(address spender);
+    /**
+     * @notice Permit deadline has expired.
+     * @param deadline The timestamp after which the signature is no longer valid.
+     */
+    error ERC2612ExpiredSignature(uint256 deadline);
+    /**
+     * @notice Mismatched signature.
+     * @param signer Address recovered from the signature, or the zero address if the signature is invalid.
+     * @param owner Address whose tokens were to be approved.
+     */
+    error ERC2612InvalidSigner(address signer, address owner);
 }
 
 
@@ -17,7 +17,7 @@ impl<
 		T: frame_system::Config
 			+ pallet_assets::Config<
 				I,
-				AssetId: Default + From<u32> + Into<u32>,
+				AssetId: Default,
 				Balance: TryConvert<U256, Error = frame::DispatchError>,
 			> + pallet_balances::Config
 			+ pallet_revive::Config
@@ -51,7 +51,7 @@ where
 
 					transfer::<T, I>(
 						origin.into(),
-						(*token).into(),
+						asset_id::<T, I>(*token)?,
 						env.to_account_id(&(*to.0).into()),
 						(*value).try_convert()?,
 					)?;
@@ -73,7 +73,7 @@ where
 				(|| {
 					transfer_from::<T, I>(
 						to_runtime_origin(env.caller()),
-						(*token).into(),
+						asset_id::<T, I>(*token)?,
 						env.to_account_id(&(*from.0).into()),
 						env.to_account_id(&(*to.0).into()),
 						(*value).try_convert()?,
@@ -95,7 +95,7 @@ where
 
 					match approve::<T, I>(
 						origin.into(),
-						(*token).into(),
+						asset_id::<T, I>(*token)?,
 						env.to_account_id(&(*spender.0).into()),
 						(*value).try_convert()?,
 					) {
@@ -139,7 +139,7 @@ where
 
 					let value = increase_allowance::<T, I>(
 						origin.into(),
-						(*token).into(),
+						asset_id::<T, I>(*token)?,
 						env.to_account_id(&(*spender.0).into()),
 						(*value).try_convert()?,
 					)
@@ -174,7 +174,7 @@ where
 
 					let value = match decrease_allowance::<T, I>(
 						origin.into(),
-						(*token).into(),
+						asset_id::<T, I>(*token)?,
 						env.to_account_id(&(*spender.0).into()),
 						(*value).try_convert()?,
 					) {
@@ -221,10 +221,9 @@ where
 						origin.into(),
 						env.to_account_id(&(*admin.0).into()),
 						(*minBalance).try_convert()?,
-					)?
-					.into();
+					)?;
 
-					Ok((creator, id))
+					Ok((creator, token_id::<T, I>(&id)?))
 				})()
 				.map_err(Self::map_err)?;
 
@@ -234,21 +233,28 @@ where
 			IFungiblesCalls::startDestroy(startDestroyCall { token }) => {
 				env.charge(<T as Config<I>>::WeightInfo::start_destroy())?;
 
-				start_destroy::<T, I>(to_runtime_origin(env.caller()), (*token).into())
-					.map_err(Self::map_err)?;
+				(|| {
+					start_destroy::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+					)
+				})()
+				.map_err(Self::map_err)?;
 
 				Ok(startDestroyCall::abi_encode_returns(&startDestroyReturn {}))
 			},
 			IFungiblesCalls::setMetadata(setMetadataCall { token, name, symbol, decimals }) => {
 				env.charge(<T as Config<I>>::WeightInfo::set_metadata())?;
 
-				set_metadata::<T, I>(
-					to_runtime_origin(env.caller()),
-					(*token).into(),
-					name.as_bytes().to_vec(),
-					symbol.as_bytes().to_vec(),
-					*decimals,
-				)
+				(|| {
+					set_metadata::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+						name.as_bytes().to_vec(),
+						symbol.as_bytes().to_vec(),
+						*decimals,
+					)
+				})()
 				.map_err(Self::map_err)?;
 
 				Ok(setMetadataCall::abi_encode_returns(&setMetadataReturn {}))
@@ -256,8 +262,13 @@ where
 			IFungiblesCalls::clearMetadata(clearMetadataCall { token }) => {
 				env.charge(<T as Config<I>>::WeightInfo::clear_metadata())?;
 
-				clear_metadata::<T, I>(to_runtime_origin(env.caller()), (*token).into())
-					.map_err(Self::map_err)?;
+				(|| {
+					clear_metadata::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+					)
+				})()
+				.map_err(Self::map_err)?;
 
 				Ok(clearMetadataCall::abi_encode_returns(&clearMetadataReturn {}))
 			},
@@ -269,7 +280,7 @@ where
 				(|| {
 					mint::<T, I>(
 						to_runtime_origin(env.caller()),
-						(*token).into(),
+						asset_id::<T, I>(*token)?,
 						env.to_account_id(&(*account.0).into()),
 						(*value).try_convert()?,
 					)
@@ -289,7 +300,7 @@ where
 				(|| {
 					burn::<T, I>(
 						to_runtime_origin(env.caller()),
-						(*token).into(),
+						asset_id::<T, I>(*token)?,
 						env.to_account_id(&(*account.0).into()),
 						(*value).try_convert()?,
 					)
@@ -314,8 +325,11 @@ where
 			IFungiblesCalls::totalSupply(totalSupplyCall { token }) => {
 				env.charge(<T as Config<I>>::WeightInfo::total_supply())?;
 
-				let total_supply =
-					total_supply::<T, I>((*token).into()).try_convert().map_err(Self::map_err)?;
+				let total_supply = asset_id::<T, I>(*token)
+					.map(total_supply::<T, I>)
+					.unwrap_or_default()
+					.try_convert()
+					.map_err(Self::map_err)?;
 
 				Ok(totalSupplyCall::abi_encode_returns(&total_supply))
 			},
@@ -323,7 +337,9 @@ where
 				env.charge(<T as Config<I>>::WeightInfo::balance_of())?;
 
 				let account = env.to_account_id(&(*owner.0).into());
-				let balance = balance::<T, I>((*token).into(), &account)
+				let balance = asset_id::<T, I>(*token)
+					.map(|token| balance::<T, I>(token, &account))
+					.unwrap_or_default()
 					.try_convert()
 					.map_err(Self::map_err)?;
 
@@ -334,7 +350,9 @@ where
 
 				let owner = env.to_account_id(&(*owner.0).into());
 				let spender = env.to_account_id(&(*spender.0).into());
-				let allowance = allowance::<T, I>((*token).into(), &owner, &spender);
+				let allowance = asset_id::<T, I>(*token)
+					.map(|token| allowance::<T, I>(token, &owner, &spender))
+					.unwrap_or_default();
 				let remaining = allowance.try_convert().map_err(Self::map_err)?;
 
 				Ok(allowanceCall::abi_encode_returns(&remaining))
@@ -342,7 +360,7 @@ where
 			IFungiblesCalls::name(nameCall { token }) => {
 				env.charge(<T as Config<I>>::WeightInfo::metadata_name())?;
 
-				let result = name::<T, I>((*token).into());
+				let result = asset_id::<T, I>(*token).map(name::<T, I>).unwrap_or_default();
 				let result = String::from_utf8_lossy(result.as_slice()).into();
 
 				Ok(nameCall::abi_encode_returns(&result))
@@ -350,7 +368,7 @@ where
 			IFungiblesCalls::symbol(symbolCall { token }) => {
 				env.charge(<T as Config<I>>::WeightInfo::metadata_symbol())?;
 
-				let result = symbol::<T, I>((*token).into());
+				let result = asset_id::<T, I>(*token).map(symbol::<T, I>).unwrap_or_default();
 				let result = String::from_utf8_lossy(result.as_slice()).into();
 
 				Ok(nameCall::abi_encode_returns(&result))
@@ -358,14 +376,14 @@ where
 			IFungiblesCalls::decimals(decimalsCall { token }) => {
 				env.charge(<T as Config<I>>::WeightInfo::metadata_decimals())?;
 
-				let result = decimals::<T, I>((*token).into());
+				let result = asset_id::<T, I>(*token).map(decimals::<T, I>).unwrap_or_default();
 
 				Ok(decimalsCall::abi_encode_returns(&result))
 			},
 			IFungiblesCalls::exists(existsCall { token }) => {
 				env.charge(<T as Config<I>>::WeightInfo::exists())?;
 
-				let result = exists::<T, I>((*token).into());
+				let result = asset_id::<T, I>(*token).map(exists::<T, I>).unwrap_or_default();
 
 				Ok(existsCall::abi_encode_returns(&result))
 			},
@@ -383,7 +401,7 @@ impl<const FIXED: u16, T: pallet_assets::Config<I> + pallet_balances::Config, I:
 
 	// Maps select, domain-specific dispatch errors to fungibles errors. All others are mapped to
 	// more generic runtime errors.
-	fn map_err(e: frame::DispatchError) -> Error {
+	pub(super) fn map_err(e: frame::DispatchError) -> Error {
 		use frame::DispatchError::*;
 		match e {
 			Arithmetic(error) => self::Arithmetic::from(error).into(),
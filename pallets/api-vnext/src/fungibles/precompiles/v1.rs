@@ -0,0 +1,1257 @@
+use frame_support::{pallet_prelude as frame, traits::Get};
+use pallet_revive::precompiles::alloy::sol_types::SolInterface;
+pub(crate) use IFungibles::*;
+
+use super::*;
+
+sol!(
+	#![sol(extra_derives(Debug, PartialEq))]
+	"src/fungibles/precompiles/interfaces/v1/IFungibles.sol"
+);
+
+/// The fungibles precompile offers a streamlined interface for interacting with fungible
+/// tokens. The goal is to provide a simplified, consistent API that adheres to standards in
+/// the smart contract space.
+///
+/// This version extends the first with the administration of tokens, such as freezing accounts
+/// and changing the management team of a token.
+pub struct Fungibles<const FIXED: u16, T, I = ()>(PhantomData<(T, I)>);
+impl<
+		const FIXED: u16,
+		T: frame_system::Config
+			+ pallet_assets::Config<
+				I,
+				AssetId: Default,
+				Balance: TryConvert<U256, Error = frame::DispatchError>,
+			> + pallet_balances::Config
+			+ pallet_revive::Config
+			+ Config<I>,
+		I: 'static,
+	> Precompile for Fungibles<FIXED, T, I>
+where
+	U256: TryConvert<<T as pallet_assets::Config<I>>::Balance, Error = frame::DispatchError>,
+{
+	type Interface = IFungiblesCalls;
+	type T = T;
+
+	const HAS_CONTRACT_INFO: bool = false;
+	const MATCHER: AddressMatcher =
+		Fixed(NonZero::new(FIXED).expect("expected non-zero precompile address"));
+
+	fn call(
+		address: &[u8; 20],
+		input: &Self::Interface,
+		env: &mut impl Ext<T = Self::T>,
+	) -> Result<Vec<u8>, Error> {
+		match input {
+			IFungiblesCalls::transferBatch(transferBatchCall { token, to, values }) => {
+				env.charge(<T as Config<I>>::WeightInfo::transfer_batch(to.len().try_convert()?))?;
+				ensure!(Self::is_valid_batch(to, values), InvalidBatch);
+				ensure!(to.iter().all(|to| !to.is_zero()), ZeroRecipientAddress);
+				ensure!(values.iter().all(|value| !value.is_zero()), ZeroValue);
+
+				let from = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let from = origin.address();
+
+					transfer_batch::<T, I>(
+						origin.into(),
+						asset_id::<T, I>(*token)?,
+						Self::transfers(&*env, to, values)?,
+					)?;
+
+					Ok(from)
+				})()
+				.map_err(Self::map_err)?;
+
+				for (to, value) in to.iter().zip(values) {
+					deposit_event(env, Transfer { token: *token, from, to: *to, value: *value })?;
+				}
+				Ok(transferBatchCall::abi_encode_returns(&transferBatchReturn {}))
+			},
+			IFungiblesCalls::transferFromBatch(transferFromBatchCall {
+				token,
+				from,
+				to,
+				values,
+			}) => {
+				env.charge(<T as Config<I>>::WeightInfo::transfer_from_batch(
+					to.len().try_convert()?,
+				))?;
+				ensure!(Self::is_valid_batch(to, values), InvalidBatch);
+				ensure!(!from.is_zero(), ZeroSenderAddress);
+				ensure!(to.iter().all(|to| !to.is_zero()), ZeroRecipientAddress);
+				ensure!(!to.contains(from), InvalidRecipient(*from));
+				ensure!(values.iter().all(|value| !value.is_zero()), ZeroValue);
+
+				(|| {
+					transfer_from_batch::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+						env.to_account_id(&(*from.0).into()),
+						Self::transfers(&*env, to, values)?,
+					)
+				})()
+				.map_err(Self::map_err)?;
+
+				for (to, value) in to.iter().zip(values) {
+					deposit_event(
+						env,
+						Transfer { token: *token, from: *from, to: *to, value: *value },
+					)?;
+				}
+				Ok(transferFromBatchCall::abi_encode_returns(&transferFromBatchReturn {}))
+			},
+			IFungiblesCalls::freeze(freezeCall { token, account }) => {
+				env.charge(<T as Config<I>>::WeightInfo::freeze())?;
+				ensure!(!account.is_zero(), ZeroAccountAddress);
+
+				(|| {
+					freeze::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+						env.to_account_id(&(*account.0).into()),
+					)
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(env, AccountFrozen { token: *token, account: *account })?;
+				Ok(freezeCall::abi_encode_returns(&freezeReturn {}))
+			},
+			IFungiblesCalls::thaw(thawCall { token, account }) => {
+				env.charge(<T as Config<I>>::WeightInfo::thaw())?;
+				ensure!(!account.is_zero(), ZeroAccountAddress);
+
+				(|| {
+					thaw::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+						env.to_account_id(&(*account.0).into()),
+					)
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(env, AccountThawed { token: *token, account: *account })?;
+				Ok(thawCall::abi_encode_returns(&thawReturn {}))
+			},
+			IFungiblesCalls::freezeToken(freezeTokenCall { token }) => {
+				env.charge(<T as Config<I>>::WeightInfo::freeze_token())?;
+
+				(|| {
+					freeze_asset::<T, I>(to_runtime_origin(env.caller()), asset_id::<T, I>(*token)?)
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(env, TokenFrozen { token: *token })?;
+				Ok(freezeTokenCall::abi_encode_returns(&freezeTokenReturn {}))
+			},
+			IFungiblesCalls::thawToken(thawTokenCall { token }) => {
+				env.charge(<T as Config<I>>::WeightInfo::thaw_token())?;
+
+				(|| thaw_asset::<T, I>(to_runtime_origin(env.caller()), asset_id::<T, I>(*token)?))()
+					.map_err(Self::map_err)?;
+
+				deposit_event(env, TokenThawed { token: *token })?;
+				Ok(thawTokenCall::abi_encode_returns(&thawTokenReturn {}))
+			},
+			IFungiblesCalls::block(blockCall { token, account }) => {
+				env.charge(<T as Config<I>>::WeightInfo::block())?;
+				ensure!(!account.is_zero(), ZeroAccountAddress);
+
+				(|| {
+					block::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+						env.to_account_id(&(*account.0).into()),
+					)
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(env, AccountBlocked { token: *token, account: *account })?;
+				Ok(blockCall::abi_encode_returns(&blockReturn {}))
+			},
+			IFungiblesCalls::setTeam(setTeamCall { token, issuer, admin, freezer }) => {
+				env.charge(<T as Config<I>>::WeightInfo::set_team())?;
+				ensure!(!issuer.is_zero(), ZeroAccountAddress);
+				ensure!(!admin.is_zero(), ZeroAdminAddress);
+				ensure!(!freezer.is_zero(), ZeroAccountAddress);
+
+				(|| {
+					set_team::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+						env.to_account_id(&(*issuer.0).into()),
+						env.to_account_id(&(*admin.0).into()),
+						env.to_account_id(&(*freezer.0).into()),
+					)
+				})()
+				.map_err(Self::map_err)?;
+
+				let event = TeamChanged {
+					token: *token,
+					issuer: *issuer,
+					admin: *admin,
+					freezer: *freezer,
+				};
+				deposit_event(env, event)?;
+				Ok(setTeamCall::abi_encode_returns(&setTeamReturn {}))
+			},
+			IFungiblesCalls::transferOwnership(transferOwnershipCall { token, owner }) => {
+				env.charge(<T as Config<I>>::WeightInfo::transfer_ownership())?;
+				ensure!(!owner.is_zero(), ZeroAccountAddress);
+
+				(|| {
+					transfer_ownership::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+						env.to_account_id(&(*owner.0).into()),
+					)
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(env, OwnerChanged { token: *token, owner: *owner })?;
+				Ok(transferOwnershipCall::abi_encode_returns(&transferOwnershipReturn {}))
+			},
+			IFungiblesCalls::setMinBalance(setMinBalanceCall { token, minBalance }) => {
+				env.charge(<T as Config<I>>::WeightInfo::set_min_balance())?;
+				ensure!(!minBalance.is_zero(), MinBalanceZero);
+
+				(|| {
+					set_min_balance::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+						(*minBalance).try_convert()?,
+					)
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(env, MinBalanceChanged { token: *token, minBalance: *minBalance })?;
+				Ok(setMinBalanceCall::abi_encode_returns(&setMinBalanceReturn {}))
+			},
+			IFungiblesCalls::touch(touchCall { token }) => {
+				env.charge(<T as Config<I>>::WeightInfo::touch())?;
+
+				let account = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let account = origin.address();
+
+					touch::<T, I>(origin.into(), asset_id::<T, I>(*token)?)?;
+
+					Ok(account)
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(env, AccountTouched { token: *token, account })?;
+				Ok(touchCall::abi_encode_returns(&touchReturn {}))
+			},
+			IFungiblesCalls::refund(refundCall { token, allowBurn }) => {
+				env.charge(<T as Config<I>>::WeightInfo::refund())?;
+
+				let account = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let account = origin.address();
+
+					refund::<T, I>(origin.into(), asset_id::<T, I>(*token)?, *allowBurn)?;
+
+					Ok(account)
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(env, AccountRefunded { token: *token, account })?;
+				Ok(refundCall::abi_encode_returns(&refundReturn {}))
+			},
+			IFungiblesCalls::destroyAccounts(destroyAccountsCall { token }) => {
+				let charged = env.charge(<T as Config<I>>::WeightInfo::destroy_accounts(
+					<T as pallet_assets::Config<I>>::RemoveItemsLimit::get(),
+				))?;
+
+				let (removed, remaining) = (|| {
+					destroy_accounts::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+					)
+				})()
+				.map_err(Self::map_destroy_err)?;
+
+				// Adjust weight to the number of accounts actually destroyed.
+				let actual_weight = <T as Config<I>>::WeightInfo::destroy_accounts(removed);
+				// TODO: replace with `env.adjust_gas(charged, result.weight);` once #8693 lands
+				env.gas_meter_mut().adjust_gas(charged, RuntimeCosts::Precompile(actual_weight));
+
+				deposit_event(env, AccountsDestroyed { token: *token, removed, remaining })?;
+				Ok(destroyAccountsCall::abi_encode_returns(&remaining))
+			},
+			IFungiblesCalls::destroyApprovals(destroyApprovalsCall { token }) => {
+				let charged = env.charge(<T as Config<I>>::WeightInfo::destroy_approvals(
+					<T as pallet_assets::Config<I>>::RemoveItemsLimit::get(),
+				))?;
+
+				let (removed, remaining) = (|| {
+					destroy_approvals::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+					)
+				})()
+				.map_err(Self::map_destroy_err)?;
+
+				// Adjust weight to the number of approvals actually destroyed.
+				let actual_weight = <T as Config<I>>::WeightInfo::destroy_approvals(removed);
+				// TODO: replace with `env.adjust_gas(charged, result.weight);` once #8693 lands
+				env.gas_meter_mut().adjust_gas(charged, RuntimeCosts::Precompile(actual_weight));
+
+				deposit_event(env, ApprovalsDestroyed { token: *token, removed, remaining })?;
+				Ok(destroyApprovalsCall::abi_encode_returns(&remaining))
+			},
+			IFungiblesCalls::finishDestroy(finishDestroyCall { token }) => {
+				env.charge(<T as Config<I>>::WeightInfo::finish_destroy())?;
+
+				(|| {
+					finish_destroy::<T, I>(
+						to_runtime_origin(env.caller()),
+						asset_id::<T, I>(*token)?,
+					)
+				})()
+				.map_err(Self::map_destroy_err)?;
+
+				deposit_event(env, Destroyed { token: *token })?;
+				Ok(finishDestroyCall::abi_encode_returns(&finishDestroyReturn {}))
+			},
+			// The remaining calls are unchanged from the first version, with identical
+			// signatures and therefore encodings, so are handled by it.
+			_ => {
+				let input = v0::IFungiblesCalls::abi_decode(&input.abi_encode())
+					.map_err(|_| Error::Error(frame::DispatchError::Corruption.into()))?;
+				v0::Fungibles::<FIXED, T, I>::call(address, &input, env)
+			},
+		}
+	}
+}
+
+impl<const FIXED: u16, T: pallet_assets::Config<I> + pallet_balances::Config, I: 'static>
+	Fungibles<FIXED, T, I>
+{
+	/// The address of the precompile.
+	pub const fn address() -> [u8; 20] {
+		fixed_address(FIXED)
+	}
+
+	// Whether a batch has transfers, within the maximum number of transfers, with a value for each
+	// recipient.
+	fn is_valid_batch(to: &[Address], values: &[U256]) -> bool
+	where
+		T: Config<I>,
+	{
+		!to.is_empty() &&
+			to.len() <= <T as Config<I>>::MaxBatchSize::get() as usize &&
+			to.len() == values.len()
+	}
+
+	// Converts the recipients and values of a batch to transfers.
+	fn transfers(
+		env: &impl Ext<T = T>,
+		to: &[Address],
+		values: &[U256],
+	) -> Result<Vec<(AccountIdOf<T>, BalanceOf<T, I>)>, frame::DispatchError>
+	where
+		T: pallet_revive::Config,
+		U256: TryConvert<BalanceOf<T, I>, Error = frame::DispatchError>,
+	{
+		to.iter()
+			.zip(values)
+			.map(|(to, value)| {
+				Ok::<_, frame::DispatchError>((
+					env.to_account_id(&(*to.0).into()),
+					(*value).try_convert()?,
+				))
+			})
+			.collect()
+	}
+
+	// Maps the dispatch errors of token administration to fungibles errors, deferring to the first
+	// version for all others.
+	fn map_err(e: frame::DispatchError) -> Error {
+		if let frame::DispatchError::Module(ModuleError { index, error, .. }) = e {
+			if Some(index as usize) == T::PalletInfo::index::<pallet_assets::Pallet<T, I>>() {
+				use pallet_assets::{Error, Error::*};
+
+				match Error::<T, I>::decode(&mut error.as_slice()) {
+					Ok(AlreadyExists) => return IFungibles::AlreadyExists.into(),
+					Ok(Frozen) => return IFungibles::Frozen.into(),
+					Ok(InUse) => return IFungibles::InUse.into(),
+					Ok(MinBalanceZero) => return IFungibles::MinBalanceZero.into(),
+					Ok(NoAccount) => return IFungibles::NoAccount.into(),
+					Ok(NoDeposit) => return IFungibles::NoDeposit.into(),
+					Ok(NotFrozen) => return IFungibles::NotFrozen.into(),
+					Ok(WouldBurn) => return IFungibles::WouldBurn.into(),
+					_ => {},
+				}
+			}
+		}
+		v0::Fungibles::<FIXED, T, I>::map_err(e)
+	}
+
+	// Maps the dispatch errors of token destruction, where an incorrect status indicates that the
+	// token is not being destroyed.
+	fn map_destroy_err(e: frame::DispatchError) -> Error {
+		if let frame::DispatchError::Module(ModuleError { index, error, .. }) = e {
+			if Some(index as usize) == T::PalletInfo::index::<pallet_assets::Pallet<T, I>>() &&
+				matches!(
+					pallet_assets::Error::<T, I>::decode(&mut error.as_slice()),
+					Ok(pallet_assets::Error::IncorrectStatus)
+				) {
+				return IFungibles::NotDestroying.into();
+			}
+		}
+		Self::map_err(e)
+	}
+}
+
+// Encoding of custom errors via `Error(String)`.
+impl_from_sol_error! {
+	IFungibles::AlreadyExists,
+	IFungibles::Frozen,
+	IFungibles::InUse,
+	IFungibles::MinBalanceZero,
+	IFungibles::NoAccount,
+	IFungibles::NoDeposit,
+	IFungibles::NotDestroying,
+	IFungibles::NotFrozen,
+	IFungibles::WouldBurn,
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::{
+		assert_ok,
+		traits::{
+			fungibles::{approvals::Mutate, Inspect},
+			ReservableCurrency,
+		},
+		weights::Weight,
+	};
+	use mock::{Assets, ExtBuilder, *};
+	use pallet_assets::{AccountStatus, AssetStatus};
+	use pallet_revive::{
+		precompiles::alloy::sol_types::{SolInterface, SolType},
+		test_utils::{ALICE, BOB, CHARLIE},
+	};
+
+	use super::*;
+
+	const ADDRESS: [u8; 20] = fixed_address(FUNGIBLES_V1);
+
+	type AccountId = <Test as frame_system::Config>::AccountId;
+	type AddressMapper = <Test as pallet_revive::Config>::AddressMapper;
+	type Asset = pallet_assets::Asset<Test>;
+
+	#[test]
+	fn freeze_reverts_with_zero_account_address() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			let call = IFungiblesCalls::freeze(freezeCall { token, account: Address::default() });
+			assert_revert!(call_precompile::<()>(&origin, &call), ZeroAccountAddress);
+		});
+	}
+
+	#[test]
+	fn freeze_reverts_with_no_account() {
+		let token = 1;
+		let origin = ALICE;
+		let account = to_address(&BOB).0.into();
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.build()
+			.execute_with(|| {
+				let call = IFungiblesCalls::freeze(freezeCall { token, account });
+				assert_revert!(call_precompile::<()>(&origin, &call), NoAccount);
+			});
+	}
+
+	#[test]
+	fn freeze_works() {
+		let token = 1;
+		let origin = ALICE;
+		let account = BOB;
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.with_asset_balances(vec![(token, account.clone(), 1)])
+			.build()
+			.execute_with(|| {
+				assert_eq!(status(token, &account), Some(AccountStatus::Liquid));
+
+				let account_address = to_address(&account).0.into();
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::freeze(freezeCall { token, account: account_address })
+				));
+
+				assert_eq!(status(token, &account), Some(AccountStatus::Frozen));
+				assert_last_event(ADDRESS, AccountFrozen { token, account: account_address });
+			});
+	}
+
+	#[test]
+	fn thaw_reverts_with_zero_account_address() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			let call = IFungiblesCalls::thaw(thawCall { token, account: Address::default() });
+			assert_revert!(call_precompile::<()>(&origin, &call), ZeroAccountAddress);
+		});
+	}
+
+	#[test]
+	fn thaw_works() {
+		let token = 1;
+		let origin = ALICE;
+		let account = BOB;
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.with_asset_balances(vec![(token, account.clone(), 1)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(super::freeze::<Test, ()>(
+					RuntimeOrigin::signed(origin.clone()),
+					token,
+					account.clone()
+				));
+				assert_eq!(status(token, &account), Some(AccountStatus::Frozen));
+
+				let account_address = to_address(&account).0.into();
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::thaw(thawCall { token, account: account_address })
+				));
+
+				assert_eq!(status(token, &account), Some(AccountStatus::Liquid));
+				assert_last_event(ADDRESS, AccountThawed { token, account: account_address });
+			});
+	}
+
+	#[test]
+	fn freeze_token_works() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.build()
+			.execute_with(|| {
+				assert_eq!(Asset::get(token).unwrap().status, AssetStatus::Live);
+
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::freezeToken(freezeTokenCall { token })
+				));
+
+				assert_eq!(Asset::get(token).unwrap().status, AssetStatus::Frozen);
+				assert_last_event(ADDRESS, TokenFrozen { token });
+			});
+	}
+
+	#[test]
+	fn thaw_token_works() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(super::freeze_asset::<Test, ()>(
+					RuntimeOrigin::signed(origin.clone()),
+					token
+				));
+				assert_eq!(Asset::get(token).unwrap().status, AssetStatus::Frozen);
+
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::thawToken(thawTokenCall { token })
+				));
+
+				assert_eq!(Asset::get(token).unwrap().status, AssetStatus::Live);
+				assert_last_event(ADDRESS, TokenThawed { token });
+			});
+	}
+
+	#[test]
+	fn block_reverts_with_zero_account_address() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			let call = IFungiblesCalls::block(blockCall { token, account: Address::default() });
+			assert_revert!(call_precompile::<()>(&origin, &call), ZeroAccountAddress);
+		});
+	}
+
+	#[test]
+	fn block_works() {
+		let token = 1;
+		let origin = ALICE;
+		let account = BOB;
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.with_asset_balances(vec![(token, account.clone(), 1)])
+			.build()
+			.execute_with(|| {
+				let account_address = to_address(&account).0.into();
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::block(blockCall { token, account: account_address })
+				));
+
+				assert_eq!(status(token, &account), Some(AccountStatus::Blocked));
+				assert_last_event(ADDRESS, AccountBlocked { token, account: account_address });
+			});
+	}
+
+	#[test]
+	fn set_team_reverts_with_zero_account_address() {
+		let token = 1;
+		let origin = ALICE;
+		let account = [255; 20].into();
+		ExtBuilder::new().build().execute_with(|| {
+			for (issuer, freezer) in [(Address::default(), account), (account, Address::default())]
+			{
+				let call = IFungiblesCalls::setTeam(setTeamCall {
+					token,
+					issuer,
+					admin: account,
+					freezer,
+				});
+				assert_revert!(call_precompile::<()>(&origin, &call), ZeroAccountAddress);
+			}
+		});
+	}
+
+	#[test]
+	fn set_team_reverts_with_zero_admin_address() {
+		let token = 1;
+		let origin = ALICE;
+		let account = [255; 20].into();
+		ExtBuilder::new().build().execute_with(|| {
+			let call = IFungiblesCalls::setTeam(setTeamCall {
+				token,
+				issuer: account,
+				admin: Address::default(),
+				freezer: account,
+			});
+			assert_revert!(call_precompile::<()>(&origin, &call), ZeroAdminAddress);
+		});
+	}
+
+	#[test]
+	fn set_team_works() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.build()
+			.execute_with(|| {
+				let issuer = to_address(&BOB).0.into();
+				let admin = to_address(&CHARLIE).0.into();
+				let freezer = to_address(&origin).0.into();
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::setTeam(setTeamCall { token, issuer, admin, freezer })
+				));
+
+				let details = Asset::get(token).unwrap();
+				assert_eq!(details.issuer, BOB);
+				assert_eq!(details.admin, CHARLIE);
+				assert_eq!(details.freezer, origin);
+				assert_last_event(ADDRESS, TeamChanged { token, issuer, admin, freezer });
+			});
+	}
+
+	#[test]
+	fn transfer_ownership_reverts_with_zero_account_address() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			let call = IFungiblesCalls::transferOwnership(transferOwnershipCall {
+				token,
+				owner: Address::default(),
+			});
+			assert_revert!(call_precompile::<()>(&origin, &call), ZeroAccountAddress);
+		});
+	}
+
+	#[test]
+	fn transfer_ownership_works() {
+		let token = 1;
+		let origin = ALICE;
+		let owner = BOB;
+		ExtBuilder::new()
+			.with_balances(vec![(owner.clone(), UNIT)])
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.build()
+			.execute_with(|| {
+				let owner_address = to_address(&owner).0.into();
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::transferOwnership(transferOwnershipCall {
+						token,
+						owner: owner_address
+					})
+				));
+
+				assert_eq!(Asset::get(token).unwrap().owner, owner);
+				assert_last_event(ADDRESS, OwnerChanged { token, owner: owner_address });
+			});
+	}
+
+	#[test]
+	fn set_min_balance_reverts_with_min_balance_zero() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			let call =
+				IFungiblesCalls::setMinBalance(setMinBalanceCall { token, minBalance: U256::ZERO });
+			assert_revert!(call_precompile::<()>(&origin, &call), MinBalanceZero);
+		});
+	}
+
+	#[test]
+	fn set_min_balance_works() {
+		let token = 1;
+		let origin = ALICE;
+		let min_balance = 10;
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.build()
+			.execute_with(|| {
+				assert_eq!(Assets::minimum_balance(token), 1);
+
+				let min_balance = U256::from(min_balance);
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::setMinBalance(setMinBalanceCall {
+						token,
+						minBalance: min_balance
+					})
+				));
+
+				assert_eq!(Assets::minimum_balance(token), 10);
+				assert_last_event(ADDRESS, MinBalanceChanged { token, minBalance: min_balance });
+			});
+	}
+
+	#[test]
+	fn touch_works() {
+		let token = 1;
+		let origin = BOB;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.clone(), UNIT)])
+			.with_assets(vec![(token, ALICE, false, 1)])
+			.build()
+			.execute_with(|| {
+				assert_eq!(status(token, &origin), None);
+
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::touch(touchCall { token })
+				));
+
+				assert_eq!(status(token, &origin), Some(AccountStatus::Liquid));
+				let account = to_address(&origin).0.into();
+				assert_last_event(ADDRESS, AccountTouched { token, account });
+			});
+	}
+
+	#[test]
+	fn refund_reverts_with_no_deposit() {
+		let token = 1;
+		let origin = BOB;
+		ExtBuilder::new()
+			.with_assets(vec![(token, ALICE, false, 1)])
+			.build()
+			.execute_with(|| {
+				let call = IFungiblesCalls::refund(refundCall { token, allowBurn: false });
+				assert_revert!(call_precompile::<()>(&origin, &call), NoDeposit);
+			});
+	}
+
+	#[test]
+	fn refund_works() {
+		let token = 1;
+		let origin = BOB;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.clone(), UNIT)])
+			.with_assets(vec![(token, ALICE, false, 1)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(super::touch::<Test, ()>(RuntimeOrigin::signed(origin.clone()), token));
+				assert_eq!(status(token, &origin), Some(AccountStatus::Liquid));
+
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::refund(refundCall { token, allowBurn: false })
+				));
+
+				assert_eq!(status(token, &origin), None);
+				let account = to_address(&origin).0.into();
+				assert_last_event(ADDRESS, AccountRefunded { token, account });
+			});
+	}
+
+	#[test]
+	fn destroy_accounts_reverts_with_not_destroying() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.build()
+			.execute_with(|| {
+				let call = IFungiblesCalls::destroyAccounts(destroyAccountsCall { token });
+				assert_revert!(call_precompile::<u32>(&origin, &call), NotDestroying);
+			});
+	}
+
+	#[test]
+	fn destroy_accounts_works() {
+		let token = 1;
+		let origin = BOB;
+		let holders = holders(12);
+		ExtBuilder::new()
+			.with_assets(vec![(token, ALICE, true, 1)])
+			.with_asset_balances(holders.iter().map(|h| (token, h.clone(), 1)).collect())
+			.build()
+			.execute_with(|| {
+				assert_ok!(super::start_destroy::<Test, ()>(RuntimeOrigin::signed(ALICE), token));
+
+				// Accounts are destroyed in batches, until none remain.
+				let limit = <Test as pallet_assets::Config>::RemoveItemsLimit::get();
+				let mut remaining = holders.len() as u32;
+				while remaining > 0 {
+					let removed = remaining.min(limit);
+					remaining = call_precompile::<u32>(
+						&origin,
+						&IFungiblesCalls::destroyAccounts(destroyAccountsCall { token }),
+					)
+					.unwrap();
+
+					assert_eq!(Asset::get(token).unwrap().accounts, remaining);
+					assert_last_event(ADDRESS, AccountsDestroyed { token, removed, remaining });
+				}
+				assert!(holders.iter().all(|h| status(token, h).is_none()));
+			});
+	}
+
+	#[test]
+	fn destroy_approvals_reverts_with_not_destroying() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.build()
+			.execute_with(|| {
+				let call = IFungiblesCalls::destroyApprovals(destroyApprovalsCall { token });
+				assert_revert!(call_precompile::<u32>(&origin, &call), NotDestroying);
+			});
+	}
+
+	#[test]
+	fn destroy_approvals_works() {
+		let token = 1;
+		let origin = BOB;
+		let owner = ALICE;
+		let spenders = holders(7);
+		ExtBuilder::new()
+			.with_balances(vec![(owner.clone(), UNIT)])
+			.with_assets(vec![(token, owner.clone(), false, 1)])
+			.build()
+			.execute_with(|| {
+				for spender in &spenders {
+					assert_ok!(Assets::approve(token, &owner, spender, 1));
+				}
+				assert_ok!(super::start_destroy::<Test, ()>(
+					RuntimeOrigin::signed(owner.clone()),
+					token
+				));
+
+				// Approvals are destroyed in batches, until none remain.
+				let limit = <Test as pallet_assets::Config>::RemoveItemsLimit::get();
+				let mut remaining = spenders.len() as u32;
+				while remaining > 0 {
+					let removed = remaining.min(limit);
+					remaining = call_precompile::<u32>(
+						&origin,
+						&IFungiblesCalls::destroyApprovals(destroyApprovalsCall { token }),
+					)
+					.unwrap();
+
+					assert_eq!(Asset::get(token).unwrap().approvals, remaining);
+					assert_last_event(ADDRESS, ApprovalsDestroyed { token, removed, remaining });
+				}
+				assert_eq!(Balances::reserved_balance(&owner), 0);
+			});
+	}
+
+	#[test]
+	fn finish_destroy_reverts_with_not_destroying() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), false, 1)])
+			.build()
+			.execute_with(|| {
+				let call = IFungiblesCalls::finishDestroy(finishDestroyCall { token });
+				assert_revert!(call_precompile::<()>(&origin, &call), NotDestroying);
+			});
+	}
+
+	#[test]
+	fn finish_destroy_reverts_with_in_use() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new()
+			.with_assets(vec![(token, origin.clone(), true, 1)])
+			.with_asset_balances(vec![(token, BOB, 1)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(super::start_destroy::<Test, ()>(
+					RuntimeOrigin::signed(origin.clone()),
+					token
+				));
+
+				let call = IFungiblesCalls::finishDestroy(finishDestroyCall { token });
+				assert_revert!(call_precompile::<()>(&origin, &call), InUse);
+			});
+	}
+
+	#[test]
+	fn finish_destroy_works() {
+		let token = 1;
+		let origin = ALICE;
+		let holders = holders(12);
+		ExtBuilder::new()
+			.with_balances(vec![(origin.clone(), UNIT)])
+			.with_assets(vec![(token, origin.clone(), true, 1)])
+			.with_asset_balances(holders.iter().map(|h| (token, h.clone(), 1)).collect())
+			.build()
+			.execute_with(|| {
+				for spender in &holders {
+					assert_ok!(Assets::approve(token, &origin, spender, 1));
+				}
+
+				// Destroys the token from a contract, starting with the first version.
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::startDestroy(startDestroyCall { token })
+				));
+				let destroy_accounts =
+					IFungiblesCalls::destroyAccounts(destroyAccountsCall { token });
+				while call_precompile::<u32>(&origin, &destroy_accounts).unwrap() > 0 {}
+				let destroy_approvals =
+					IFungiblesCalls::destroyApprovals(destroyApprovalsCall { token });
+				while call_precompile::<u32>(&origin, &destroy_approvals).unwrap() > 0 {}
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::finishDestroy(finishDestroyCall { token })
+				));
+
+				assert!(!Assets::asset_exists(token));
+				assert_eq!(Balances::reserved_balance(&origin), 0);
+				assert_last_event(ADDRESS, Destroyed { token });
+			});
+	}
+
+	#[test]
+	fn transfer_works() {
+		let token = 1;
+		let origin = ALICE;
+		let endowment = 10_000_000;
+		let to = BOB;
+		ExtBuilder::new()
+			.with_assets(vec![(token, CHARLIE, true, 1)])
+			.with_asset_balances(vec![(token, origin.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let value = endowment / 2;
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::transfer(transferCall {
+						token,
+						to: to_address(&to).0.into(),
+						value: U256::from(value)
+					})
+				));
+
+				assert_eq!(Assets::balance(token, &origin), endowment - value);
+				assert_eq!(Assets::balance(token, &to), value);
+				let from = to_address(&origin).0.into();
+				let to = to_address(&to).0.into();
+				let event = Transfer { token, from, to, value: U256::from(value) };
+				assert_last_event(ADDRESS, event);
+			});
+	}
+
+	#[test]
+	fn transfer_batch_reverts_with_invalid_batch() {
+		let token = 1;
+		let origin = ALICE;
+		let max = <Test as Config>::MaxBatchSize::get() as usize;
+		ExtBuilder::new().build().execute_with(|| {
+			for (recipients, values) in [(0, 0), (max + 1, max + 1), (2, 1), (1, 2)] {
+				let call = IFungiblesCalls::transferBatch(transferBatchCall {
+					token,
+					to: vec![to_address(&BOB).0.into(); recipients],
+					values: vec![U256::from(1); values],
+				});
+				assert_revert!(call_precompile::<()>(&origin, &call), InvalidBatch);
+			}
+		});
+	}
+
+	#[test]
+	fn transfer_batch_reverts_with_zero_recipient_address() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			let call = IFungiblesCalls::transferBatch(transferBatchCall {
+				token,
+				to: vec![to_address(&BOB).0.into(), Address::default()],
+				values: vec![U256::from(1); 2],
+			});
+			assert_revert!(call_precompile::<()>(&origin, &call), ZeroRecipientAddress);
+		});
+	}
+
+	#[test]
+	fn transfer_batch_reverts_with_zero_value() {
+		let token = 1;
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			let call = IFungiblesCalls::transferBatch(transferBatchCall {
+				token,
+				to: vec![to_address(&BOB).0.into(), to_address(&CHARLIE).0.into()],
+				values: vec![U256::from(1), U256::ZERO],
+			});
+			assert_revert!(call_precompile::<()>(&origin, &call), ZeroValue);
+		});
+	}
+
+	#[test]
+	fn transfer_batch_reverts_all_with_insufficient_balance() {
+		let token = 1;
+		let origin = ALICE;
+		let endowment = 10_000_000;
+		let recipients = recipients(3);
+		ExtBuilder::new()
+			.with_assets(vec![(token, CHARLIE, true, 1)])
+			.with_asset_balances(vec![(token, origin.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				// The last transfer exceeds the remaining balance.
+				let call = IFungiblesCalls::transferBatch(transferBatchCall {
+					token,
+					to: recipients.iter().map(|to| to_address(to).0.into()).collect(),
+					values: vec![U256::from(endowment / 3); 3],
+				});
+				assert_revert!(call_precompile::<()>(&origin, &call), InsufficientBalance);
+
+				assert_eq!(Assets::balance(token, &origin), endowment);
+				assert!(recipients.iter().all(|to| Assets::balance(token, to) == 0));
+			});
+	}
+
+	#[test]
+	fn transfer_batch_works() {
+		let token = 1;
+		let origin = ALICE;
+		let endowment = 10_000_000;
+		let recipients = recipients(3);
+		let values = [1, 2, 3].map(|i| i * endowment / 10);
+		ExtBuilder::new()
+			.with_assets(vec![(token, CHARLIE, true, 1)])
+			.with_asset_balances(vec![(token, origin.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::transferBatch(transferBatchCall {
+						token,
+						to: recipients.iter().map(|to| to_address(to).0.into()).collect(),
+						values: values.iter().copied().map(U256::from).collect(),
+					})
+				));
+
+				assert_eq!(
+					Assets::balance(token, &origin),
+					endowment - values.iter().sum::<u128>()
+				);
+				let from = to_address(&origin).0.into();
+				for (to, value) in recipients.iter().zip(values) {
+					assert_eq!(Assets::balance(token, to), value);
+					let to = to_address(to).0.into();
+					assert_has_event(Transfer { token, from, to, value: U256::from(value) });
+				}
+			});
+	}
+
+	#[test]
+	fn transfer_from_batch_reverts_with_invalid_batch() {
+		let token = 1;
+		let origin = BOB;
+		let from = to_address(&ALICE).0.into();
+		let max = <Test as Config>::MaxBatchSize::get() as usize;
+		ExtBuilder::new().build().execute_with(|| {
+			for (recipients, values) in [(0, 0), (max + 1, max + 1), (2, 1), (1, 2)] {
+				let call = IFungiblesCalls::transferFromBatch(transferFromBatchCall {
+					token,
+					from,
+					to: vec![to_address(&CHARLIE).0.into(); recipients],
+					values: vec![U256::from(1); values],
+				});
+				assert_revert!(call_precompile::<()>(&origin, &call), InvalidBatch);
+			}
+		});
+	}
+
+	#[test]
+	fn transfer_from_batch_reverts_with_zero_sender_address() {
+		let token = 1;
+		let origin = BOB;
+		ExtBuilder::new().build().execute_with(|| {
+			let call = IFungiblesCalls::transferFromBatch(transferFromBatchCall {
+				token,
+				from: Address::default(),
+				to: vec![to_address(&CHARLIE).0.into()],
+				values: vec![U256::from(1)],
+			});
+			assert_revert!(call_precompile::<()>(&origin, &call), ZeroSenderAddress);
+		});
+	}
+
+	#[test]
+	fn transfer_from_batch_reverts_with_invalid_recipient() {
+		let token = 1;
+		let origin = BOB;
+		let from = to_address(&ALICE).0.into();
+		ExtBuilder::new().build().execute_with(|| {
+			let call = IFungiblesCalls::transferFromBatch(transferFromBatchCall {
+				token,
+				from,
+				to: vec![to_address(&CHARLIE).0.into(), from],
+				values: vec![U256::from(1); 2],
+			});
+			assert_revert!(call_precompile::<()>(&origin, &call), InvalidRecipient(from));
+		});
+	}
+
+	#[test]
+	fn transfer_from_batch_reverts_all_with_unapproved() {
+		let token = 1;
+		let origin = BOB;
+		let owner = ALICE;
+		let endowment = 10_000_000;
+		let recipients = recipients(3);
+		ExtBuilder::new()
+			.with_balances(vec![(owner.clone(), UNIT)])
+			.with_assets(vec![(token, CHARLIE, true, 1)])
+			.with_asset_balances(vec![(token, owner.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				// The last transfer exceeds the remaining allowance.
+				let allowance = endowment / 2;
+				assert_ok!(Assets::approve(token, &owner, &origin, allowance));
+				let call = IFungiblesCalls::transferFromBatch(transferFromBatchCall {
+					token,
+					from: to_address(&owner).0.into(),
+					to: recipients.iter().map(|to| to_address(to).0.into()).collect(),
+					values: vec![U256::from(allowance / 2); 3],
+				});
+				assert_revert!(call_precompile::<()>(&origin, &call), Unapproved);
+
+				assert_eq!(Assets::balance(token, &owner), endowment);
+				assert!(recipients.iter().all(|to| Assets::balance(token, to) == 0));
+				assert_eq!(Assets::allowance(token, &owner, &origin), allowance);
+			});
+	}
+
+	#[test]
+	fn transfer_from_batch_works() {
+		let token = 1;
+		let origin = BOB;
+		let owner = ALICE;
+		let endowment = 10_000_000;
+		let recipients = recipients(3);
+		let values = [1, 2, 3].map(|i| i * endowment / 10);
+		ExtBuilder::new()
+			.with_balances(vec![(owner.clone(), UNIT)])
+			.with_assets(vec![(token, CHARLIE, true, 1)])
+			.with_asset_balances(vec![(token, owner.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(Assets::approve(token, &owner, &origin, endowment));
+				assert_ok!(call_precompile::<()>(
+					&origin,
+					&IFungiblesCalls::transferFromBatch(transferFromBatchCall {
+						token,
+						from: to_address(&owner).0.into(),
+						to: recipients.iter().map(|to| to_address(to).0.into()).collect(),
+						values: values.iter().copied().map(U256::from).collect(),
+					})
+				));
+
+				let total = values.iter().sum::<u128>();
+				assert_eq!(Assets::balance(token, &owner), endowment - total);
+				assert_eq!(Assets::allowance(token, &owner, &origin), endowment - total);
+				let from = to_address(&owner).0.into();
+				for (to, value) in recipients.iter().zip(values) {
+					assert_eq!(Assets::balance(token, to), value);
+					let to = to_address(to).0.into();
+					assert_has_event(Transfer { token, from, to, value: U256::from(value) });
+				}
+			});
+	}
+
+	#[test]
+	fn selectors_match_first_version() {
+		for selector in v0::IFungiblesCalls::SELECTORS {
+			assert!(IFungiblesCalls::valid_selector(*selector));
+		}
+	}
+
+	fn call_precompile<Output: SolValue + From<<Output::SolType as SolType>::RustType>>(
+		origin: &AccountId,
+		input: &IFungiblesCalls,
+	) -> Result<Output, Error> {
+		bare_call::<Test, Output>(
+			RuntimeOrigin::signed(origin.clone()),
+			ADDRESS.into(),
+			0,
+			Weight::MAX,
+			DepositLimit::Balance(u128::MAX),
+			input.abi_encode(),
+		)
+	}
+
+	// Asserts that the precompile emitted an event, for calls emitting several events.
+	fn assert_has_event(event: impl SolEvent) {
+		System::assert_has_event(
+			pallet_revive::Event::ContractEmitted {
+				contract: ADDRESS.into(),
+				data: event.encode_data(),
+				topics: topics(&event),
+			}
+			.into(),
+		)
+	}
+
+	fn status(token: TokenId, account: &AccountId) -> Option<AccountStatus> {
+		pallet_assets::Account::<Test>::get(token, account).map(|a| a.status)
+	}
+
+	// Distinct accounts, for tokens with many holders.
+	fn holders(n: u8) -> Vec<AccountId> {
+		(0..n).map(|i| AccountId::from([u8::MAX - i; 32])).collect()
+	}
+
+	// Distinct accounts derived from addresses, for batches of transfers.
+	fn recipients(n: u8) -> Vec<AccountId> {
+		(0..n)
+			.map(|i| AddressMapper::to_fallback_account_id(&H160::repeat_byte(u8::MAX - i)))
+			.collect()
+	}
+}
@@ -6,7 +6,7 @@ use frame_support::{
 		Get,
 	},
 };
-use pallet_assets::WeightInfo as _;
+use pallet_assets::{AccountStatus, Asset, AssetStatus, WeightInfo as _};
 
 use super::{weights::WeightInfo as _, *};
 use crate::mock::{Assets, *};
@@ -93,6 +93,82 @@ fn transfer_from_works() {
 		});
 }
 
+#[test]
+fn transfer_batch_works() {
+	let token = 1;
+	let value = 100 * UNIT;
+	let from = ALICE;
+	let recipients = holders(3);
+	ExtBuilder::new()
+		.with_assets(vec![(token, from.clone(), true, 1)])
+		.with_asset_balances(vec![(token, from.clone(), value * 3)])
+		.build()
+		.execute_with(|| {
+			let transfers = |value: Balance| -> Vec<_> {
+				recipients.iter().map(|to| (to.clone(), value)).collect()
+			};
+			for origin in vec![root(), none()] {
+				assert_noop!(
+					transfer_batch::<Test, ()>(origin, token, transfers(value)),
+					BadOrigin
+				);
+			}
+			// The batch is rolled back when any transfer fails, here the last one.
+			assert_noop!(
+				transfer_batch::<Test, ()>(signed(from.clone()), token, transfers(value)),
+				AssetsError::BalanceLow
+			);
+			assert_ok!(transfer_batch::<Test, ()>(signed(from.clone()), token, transfers(UNIT)));
+			assert_eq!(Assets::balance(token, &from), value * 3 - UNIT * 3);
+			assert!(recipients.iter().all(|to| Assets::balance(token, to) == UNIT));
+		});
+}
+
+#[test]
+fn transfer_from_batch_works() {
+	let token = 1;
+	let value = 100 * UNIT;
+	let from = ALICE;
+	let spender = CHARLIE;
+	let recipients = holders(3);
+	ExtBuilder::new()
+		.with_balances(vec![(from.clone(), UNIT)])
+		.with_assets(vec![(token, from.clone(), true, 1)])
+		.with_asset_balances(vec![(token, from.clone(), value * 3)])
+		.build()
+		.execute_with(|| {
+			let transfers = |value: Balance| -> Vec<_> {
+				recipients.iter().map(|to| (to.clone(), value)).collect()
+			};
+			for origin in vec![root(), none()] {
+				assert_noop!(
+					transfer_from_batch::<Test, ()>(origin, token, from.clone(), transfers(value)),
+					BadOrigin
+				);
+			}
+			// Approve `spender` to transfer up to `value`, which is exceeded by the last transfer.
+			assert_ok!(Assets::approve(token, &from, &spender, value));
+			assert_noop!(
+				transfer_from_batch::<Test, ()>(
+					signed(spender.clone()),
+					token,
+					from.clone(),
+					transfers(value / 2)
+				),
+				AssetsError::Unapproved
+			);
+			assert_ok!(transfer_from_batch::<Test, ()>(
+				signed(spender.clone()),
+				token,
+				from.clone(),
+				transfers(UNIT)
+			));
+			assert_eq!(Assets::balance(token, &from), value * 3 - UNIT * 3);
+			assert!(recipients.iter().all(|to| Assets::balance(token, to) == UNIT));
+			assert_eq!(Assets::allowance(token, &from, &spender), value - UNIT * 3);
+		});
+}
+
 mod approve {
 	use super::*;
 	use crate::fungibles::approve;
@@ -458,6 +534,300 @@ fn burn_works() {
 		});
 }
 
+#[test]
+fn freeze_works() {
+	let token = 1;
+	let owner = ALICE;
+	let account = BOB;
+	ExtBuilder::new()
+		.with_assets(vec![(token, owner.clone(), false, 1)])
+		.with_asset_balances(vec![(token, account.clone(), UNIT)])
+		.build()
+		.execute_with(|| {
+			// Check error works for `Assets::freeze()`.
+			assert_noop!(
+				freeze::<Test, ()>(signed(account.clone()), token, account.clone()),
+				AssetsError::NoPermission
+			);
+			assert_ok!(freeze::<Test, ()>(signed(owner), token, account.clone()));
+			assert_eq!(account_status(token, &account), AccountStatus::Frozen);
+		});
+}
+
+#[test]
+fn thaw_works() {
+	let token = 1;
+	let owner = ALICE;
+	let account = BOB;
+	ExtBuilder::new()
+		.with_assets(vec![(token, owner.clone(), false, 1)])
+		.with_asset_balances(vec![(token, account.clone(), UNIT)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Assets::freeze(signed(owner.clone()), token.into(), account.clone().into()));
+			// Check error works for `Assets::thaw()`.
+			assert_noop!(
+				thaw::<Test, ()>(signed(account.clone()), token, account.clone()),
+				AssetsError::NoPermission
+			);
+			assert_ok!(thaw::<Test, ()>(signed(owner), token, account.clone()));
+			assert_eq!(account_status(token, &account), AccountStatus::Liquid);
+		});
+}
+
+#[test]
+fn freeze_asset_works() {
+	let token = 1;
+	let owner = ALICE;
+	ExtBuilder::new()
+		.with_assets(vec![(token, owner.clone(), false, 1)])
+		.build()
+		.execute_with(|| {
+			// Check error works for `Assets::freeze_asset()`.
+			assert_noop!(
+				freeze_asset::<Test, ()>(signed(owner.clone()), TokenId::MAX),
+				AssetsError::Unknown
+			);
+			assert_ok!(freeze_asset::<Test, ()>(signed(owner), token));
+			assert_eq!(Asset::<Test>::get(token).unwrap().status, AssetStatus::Frozen);
+		});
+}
+
+#[test]
+fn thaw_asset_works() {
+	let token = 1;
+	let owner = ALICE;
+	ExtBuilder::new()
+		.with_assets(vec![(token, owner.clone(), false, 1)])
+		.build()
+		.execute_with(|| {
+			// Check error works for `Assets::thaw_asset()`.
+			assert_noop!(
+				thaw_asset::<Test, ()>(signed(owner.clone()), token),
+				AssetsError::NotFrozen
+			);
+			assert_ok!(Assets::freeze_asset(signed(owner.clone()), token.into()));
+			assert_ok!(thaw_asset::<Test, ()>(signed(owner), token));
+			assert_eq!(Asset::<Test>::get(token).unwrap().status, AssetStatus::Live);
+		});
+}
+
+#[test]
+fn block_works() {
+	let token = 1;
+	let owner = ALICE;
+	let account = BOB;
+	ExtBuilder::new()
+		.with_assets(vec![(token, owner.clone(), false, 1)])
+		.with_asset_balances(vec![(token, account.clone(), UNIT)])
+		.build()
+		.execute_with(|| {
+			// Check error works for `Assets::block()`.
+			assert_noop!(
+				block::<Test, ()>(signed(owner.clone()), token, CHARLIE),
+				AssetsError::NoAccount
+			);
+			assert_ok!(block::<Test, ()>(signed(owner), token, account.clone()));
+			assert_eq!(account_status(token, &account), AccountStatus::Blocked);
+		});
+}
+
+#[test]
+fn set_team_works() {
+	let token = 1;
+	let owner = ALICE;
+	let (issuer, admin, freezer) = (BOB, CHARLIE, ALICE);
+	ExtBuilder::new()
+		.with_assets(vec![(token, owner.clone(), false, 1)])
+		.build()
+		.execute_with(|| {
+			// Check error works for `Assets::set_team()`.
+			assert_noop!(
+				set_team::<Test, ()>(
+					signed(issuer.clone()),
+					token,
+					issuer.clone(),
+					admin.clone(),
+					freezer.clone()
+				),
+				AssetsError::NoPermission
+			);
+			assert_ok!(set_team::<Test, ()>(
+				signed(owner),
+				token,
+				issuer.clone(),
+				admin.clone(),
+				freezer.clone()
+			));
+			let details = Asset::<Test>::get(token).unwrap();
+			assert_eq!((details.issuer, details.admin, details.freezer), (issuer, admin, freezer));
+		});
+}
+
+#[test]
+fn transfer_ownership_works() {
+	let token = 1;
+	let owner = ALICE;
+	let new_owner = BOB;
+	ExtBuilder::new()
+		.with_assets(vec![(token, owner.clone(), false, 1)])
+		.build()
+		.execute_with(|| {
+			// Check error works for `Assets::transfer_ownership()`.
+			assert_noop!(
+				transfer_ownership::<Test, ()>(signed(new_owner.clone()), token, new_owner.clone()),
+				AssetsError::NoPermission
+			);
+			assert_ok!(transfer_ownership::<Test, ()>(signed(owner), token, new_owner.clone()));
+			assert_eq!(Asset::<Test>::get(token).unwrap().owner, new_owner);
+		});
+}
+
+#[test]
+fn set_min_balance_works() {
+	let token = 1;
+	let owner = ALICE;
+	let min_balance = 10;
+	ExtBuilder::new()
+		.with_assets(vec![(token, owner.clone(), false, 1)])
+		.build()
+		.execute_with(|| {
+			// Check error works for `Assets::set_min_balance()`.
+			assert_noop!(
+				set_min_balance::<Test, ()>(signed(BOB), token, min_balance),
+				AssetsError::NoPermission
+			);
+			assert_ok!(set_min_balance::<Test, ()>(signed(owner), token, min_balance));
+			assert_eq!(Assets::minimum_balance(token), min_balance);
+		});
+}
+
+#[test]
+fn touch_works() {
+	let token = 1;
+	let owner = ALICE;
+	let account = BOB;
+	ExtBuilder::new()
+		.with_balances(vec![(account.clone(), UNIT)])
+		.with_assets(vec![(token, owner, false, 1)])
+		.build()
+		.execute_with(|| {
+			// Check error works for `Assets::touch()`.
+			assert_noop!(
+				touch::<Test, ()>(signed(account.clone()), TokenId::MAX),
+				AssetsError::Unknown
+			);
+			assert_ok!(touch::<Test, ()>(signed(account.clone()), token));
+			assert_eq!(account_status(token, &account), AccountStatus::Liquid);
+		});
+}
+
+#[test]
+fn refund_works() {
+	let token = 1;
+	let owner = ALICE;
+	let account = BOB;
+	ExtBuilder::new()
+		.with_balances(vec![(account.clone(), UNIT)])
+		.with_assets(vec![(token, owner, false, 1)])
+		.build()
+		.execute_with(|| {
+			// Check error works for `Assets::refund()`.
+			assert_noop!(
+				refund::<Test, ()>(signed(account.clone()), token, false),
+				AssetsError::NoDeposit
+			);
+			assert_ok!(Assets::touch(signed(account.clone()), token.into()));
+			assert_ok!(refund::<Test, ()>(signed(account.clone()), token, false));
+			assert!(pallet_assets::Account::<Test>::get(token, &account).is_none());
+		});
+}
+
+#[test]
+fn destroy_accounts_works() {
+	let token = 1;
+	let owner = ALICE;
+	let holders = holders(12);
+	ExtBuilder::new()
+		.with_assets(vec![(token, owner.clone(), true, 1)])
+		.with_asset_balances(holders.iter().map(|h| (token, h.clone(), 1)).collect())
+		.build()
+		.execute_with(|| {
+			// Check error works for `Assets::destroy_accounts()`.
+			assert_noop!(
+				destroy_accounts::<Test, ()>(signed(owner.clone()), token),
+				AssetsError::IncorrectStatus
+			);
+			assert_ok!(Assets::start_destroy(signed(owner.clone()), token.into()));
+			// Accounts are destroyed in batches of at most `RemoveItemsLimit`.
+			let limit = <Test as pallet_assets::Config>::RemoveItemsLimit::get();
+			assert_eq!(destroy_accounts::<Test, ()>(signed(BOB), token), Ok((limit, 12 - limit)));
+			assert_eq!(
+				destroy_accounts::<Test, ()>(signed(BOB), token),
+				Ok((limit, 12 - 2 * limit))
+			);
+			assert_eq!(destroy_accounts::<Test, ()>(signed(BOB), token), Ok((12 - 2 * limit, 0)));
+			assert_eq!(destroy_accounts::<Test, ()>(signed(BOB), token), Ok((0, 0)));
+			assert!(holders
+				.iter()
+				.all(|h| pallet_assets::Account::<Test>::get(token, h).is_none()));
+		});
+}
+
+#[test]
+fn destroy_approvals_works() {
+	let token = 1;
+	let owner = ALICE;
+	let spenders = holders(7);
+	ExtBuilder::new()
+		.with_balances(vec![(owner.clone(), UNIT)])
+		.with_assets(vec![(token, owner.clone(), false, 1)])
+		.build()
+		.execute_with(|| {
+			for spender in &spenders {
+				assert_ok!(Assets::approve(token, &owner, spender, 1));
+			}
+			// Check error works for `Assets::destroy_approvals()`.
+			assert_noop!(
+				destroy_approvals::<Test, ()>(signed(owner.clone()), token),
+				AssetsError::IncorrectStatus
+			);
+			assert_ok!(Assets::start_destroy(signed(owner.clone()), token.into()));
+			// Approvals are destroyed in batches of at most `RemoveItemsLimit`.
+			let limit = <Test as pallet_assets::Config>::RemoveItemsLimit::get();
+			assert_eq!(destroy_approvals::<Test, ()>(signed(BOB), token), Ok((limit, 7 - limit)));
+			assert_eq!(destroy_approvals::<Test, ()>(signed(BOB), token), Ok((7 - limit, 0)));
+			assert!(spenders
+				.iter()
+				.all(|s| pallet_assets::Approvals::<Test>::get((token, &owner, s)).is_none()));
+		});
+}
+
+#[test]
+fn finish_destroy_works() {
+	let token = 1;
+	let owner = ALICE;
+	ExtBuilder::new()
+		.with_assets(vec![(token, owner.clone(), true, 1)])
+		.with_asset_balances(vec![(token, BOB, 1)])
+		.build()
+		.execute_with(|| {
+			// Check error works for `Assets::finish_destroy()`.
+			assert_noop!(
+				finish_destroy::<Test, ()>(signed(owner.clone()), token),
+				AssetsError::IncorrectStatus
+			);
+			assert_ok!(Assets::start_destroy(signed(owner.clone()), token.into()));
+			assert_noop!(
+				finish_destroy::<Test, ()>(signed(owner.clone()), token),
+				AssetsError::InUse
+			);
+			assert_ok!(destroy_accounts::<Test, ()>(signed(owner.clone()), token));
+			assert_ok!(finish_destroy::<Test, ()>(signed(BOB), token));
+			assert!(!Asset::<Test>::contains_key(token));
+		});
+}
+
 #[test]
 fn total_supply_works() {
 	let token = 1;
@@ -539,3 +909,83 @@ fn exists_works() {
 			assert_eq!(exists::<Test, ()>(token), true);
 		});
 }
+
+#[test]
+fn numeric_token_id_works() {
+	let token = 1;
+	assert_eq!(<NumericTokenId as MaybeEquivalence<u32, TokenId>>::convert(&token), Some(token));
+	assert_eq!(
+		<NumericTokenId as MaybeEquivalence<u32, TokenId>>::convert_back(&token),
+		Some(token)
+	);
+}
+
+mod hashed_token_id {
+	use super::*;
+
+	type HashedTokenId = super::HashedTokenId<Test>;
+
+	#[test]
+	fn token_id_works() {
+		let asset = 1;
+		let hash = sp_io::hashing::blake2_256(&asset.encode());
+		assert_eq!(
+			HashedTokenId::token_id(&asset),
+			u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+		);
+		assert_ne!(HashedTokenId::token_id(&asset), HashedTokenId::token_id(&2));
+	}
+
+	#[test]
+	fn created_registers_asset() {
+		let asset = 1;
+		let token = HashedTokenId::token_id(&asset);
+		ExtBuilder::new().build().execute_with(|| {
+			assert_eq!(HashedTokenId::convert(&token), None);
+			assert_eq!(HashedTokenId::convert_back(&asset), None);
+			assert_eq!(asset_id::<Test, ()>(token), Err(TokenError::UnknownAsset.into()));
+
+			assert_ok!(HashedTokenId::created(&asset, &ALICE));
+			assert_eq!(HashedTokenId::convert(&token), Some(asset));
+			assert_eq!(HashedTokenId::convert_back(&asset), Some(token));
+		});
+	}
+
+	#[test]
+	fn created_rejects_colliding_asset() {
+		let asset = 1;
+		let token = HashedTokenId::token_id(&asset);
+		let other = 2;
+		ExtBuilder::new().build().execute_with(|| {
+			// Simulate another asset registered under the same token identifier.
+			TokenAssets::<Test>::insert(token, other);
+
+			assert_eq!(HashedTokenId::created(&asset, &ALICE), Err(()));
+			assert_eq!(HashedTokenId::convert(&token), Some(other));
+			assert_eq!(HashedTokenId::convert_back(&asset), None);
+		});
+	}
+
+	#[test]
+	fn destroyed_unregisters_asset() {
+		let asset = 1;
+		let token = HashedTokenId::token_id(&asset);
+		ExtBuilder::new().build().execute_with(|| {
+			assert_ok!(HashedTokenId::created(&asset, &ALICE));
+
+			assert_ok!(HashedTokenId::destroyed(&asset));
+			assert_eq!(HashedTokenId::convert(&token), None);
+			assert_eq!(HashedTokenId::convert_back(&asset), None);
+		});
+	}
+}
+
+// The status of an account holding a token.
+fn account_status(token: TokenId, account: &AccountId) -> AccountStatus {
+	pallet_assets::Account::<Test>::get(token, account).unwrap().status
+}
+
+// Distinct accounts, for tokens with many holders.
+fn holders(n: u8) -> Vec<AccountId> {
+	(0..n).map(|i| AccountId::from([u8::MAX - i; 32])).collect()
+}
@@ -3,29 +3,38 @@
 
 use deposits::*;
 use frame_support::{
-	dispatch::{DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo},
+	dispatch::{
+		DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo,
+	},
 	pallet_prelude::{DispatchError::BadOrigin, *},
 	storage::KeyLenOf,
 	traits::{
 		tokens::{
-			fungible::{hold::Mutate as HoldMutate, Balanced, Credit, Inspect, Mutate},
-			Fortitude, Precision, Preservation,
+			fungible::{
+				hold::{Inspect as HoldInspect, Mutate as HoldMutate},
+				Balanced, Credit, Inspect, Mutate,
+			},
+			Fortitude, Precision, Preservation, Restriction,
 		},
 		Get, OnUnbalanced,
 	},
-	weights::WeightToFee,
+	weights::{WeightMeter, WeightToFee},
 	BoundedSlice,
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::{Error, *};
 use pallet_revive::{
-	sp_runtime::traits::{SaturatedConversion, Saturating, TryConvert},
+	sp_runtime::traits::{Convert, SaturatedConversion, Saturating, TryConvert},
 	H160,
 };
 use sp_runtime::ArithmeticError;
 use transports::{
-	ismp::IsmpDispatcher,
-	xcm::{Location, NotifyQueryHandler, QueryId, Response},
+	ismp::{
+		FundRequest, GetBatch, IsmpBatchTransport, IsmpDispatcher, IsmpTransport,
+		StateMachineHeight, Subscription,
+	},
+	xcm::{Location, NotifyQueryHandler, QueryId, Response, XcmApi, XcmTransport},
+	MessageTransport, Transport, TransportIndex,
 };
 use weights::WeightInfo;
 
@@ -34,8 +43,12 @@ use super::*;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 mod deposits;
+/// Storage migrations.
+pub mod migrations;
 /// The messaging precompiles offer a streamlined interface for cross-chain messaging.
 pub mod precompiles;
+/// The runtime API for inspecting messages.
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
 /// Messaging transports.
@@ -46,6 +59,7 @@ pub(crate) type BalanceOf<T> = <<T as Config>::Fungibles as Inspect<AccountIdOf<
 type BlockNumberOf<T> = BlockNumberFor<T>;
 type DbWeightOf<T> = <T as frame_system::Config>::DbWeight;
 pub type MessageId = u64;
+pub type SubscriptionId = u64;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -53,36 +67,68 @@ pub mod pallet {
 
 	use super::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		/// A converter for conversion of the address of a message origin to an account.
+		type AddressConverter: Convert<H160, Self::AccountId>;
 		/// The type responsible for executing callbacks.
 		type CallbackExecutor: CallbackExecutor<Self>;
+		/// The maximum weight which may be used to retry deferred callbacks per block.
+		#[pallet::constant]
+		type DeferredCallbackWeightLimit: Get<Weight>;
 		/// Where the callback fees or response fees are charged to.
 		type FeeHandler: OnUnbalanced<Credit<Self::AccountId, Self::Fungibles>>;
 		/// The deposit + fee mechanism.
 		type Fungibles: HoldMutate<Self::AccountId, Reason = Self::RuntimeHoldReason>
 			+ Mutate<Self::AccountId>
 			+ Balanced<Self::AccountId>;
-		/// The ISMP message dispatcher.
-		type IsmpDispatcher: IsmpDispatcher<Account = Self::AccountId, Balance = BalanceOf<Self>>;
+		/// The ISMP message dispatcher, also used to fund outstanding requests and to determine the
+		/// latest heights of the state machines read by subscriptions.
+		type IsmpDispatcher: IsmpDispatcher<Account = Self::AccountId, Balance = BalanceOf<Self>>
+			+ FundRequest<Self::AccountId, BalanceOf<Self>>
+			+ StateMachineHeight;
+		/// The gas limit used when delivering an inbound ISMP `Post` request to a contract.
+		#[pallet::constant]
+		type IsmpPostRequestGasLimit: Get<Weight>;
+		/// The storage deposit limit used when delivering an inbound ISMP `Post` request to a
+		/// contract.
+		#[pallet::constant]
+		type IsmpPostRequestStorageDepositLimit: Get<BalanceOf<Self>>;
 		/// The implementation of Keccak used for commitment hashes.
 		type Keccak256: ::ismp::messaging::Keccak256;
+		/// The maximum number of ISMP `Get` requests of a batch.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+		/// The maximum number of times a deferred callback is retried before being abandoned.
+		#[pallet::constant]
+		type MaxCallbackRetries: Get<u32>;
 		/// The maximum length of any additional application-specific metadata relating to a
 		/// request.
 		#[pallet::constant]
 		type MaxContextLen: Get<u32>;
-		/// The maximum length of outbound (posted) data.
+		/// The maximum length of posted data, both outbound and inbound.
 		#[pallet::constant]
 		type MaxDataLen: Get<u32>;
+		/// The maximum number of callbacks which can be deferred due to a lack of blockspace.
+		#[pallet::constant]
+		type MaxDeferredCallbacks: Get<u32>;
 		/// The maximum byte length for a single key of an ismp request.
 		#[pallet::constant]
 		type MaxKeyLen: Get<u32>;
 		/// The maximum number of keys for an outbound request.
 		#[pallet::constant]
 		type MaxKeys: Get<u32>;
+		/// The maximum number of pending messages of an origin, unless overridden by
+		/// `QuotaOrigin`.
+		#[pallet::constant]
+		type MaxPendingMessages: Get<u32>;
 		/// The maximum length of the receiving module identifier/address on a destination chain.
 		#[pallet::constant]
 		type MaxRecipientLen: Get<u32>;
@@ -93,9 +139,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxResponseLen: Get<u32>;
 		/// SAFETY: Recommended this is small as is used to updated a message status in the hooks.
-		/// The maximum number of xcm timeout updates that can be processed per block.
+		/// The maximum number of xcm timeout updates that can be processed per block, with any
+		/// further timeouts spilling over to be processed in `on_idle`.
 		#[pallet::constant]
 		type MaxXcmQueryTimeoutsPerBlock: Get<u32>;
+		/// The minimum number of blocks between the executions of a subscription.
+		#[pallet::constant]
+		type MinSubscriptionInterval: Get<BlockNumberFor<Self>>;
+		/// The number of blocks for which a completed or timed out message is retained, after
+		/// which it expires and is removed, with its deposits released to its origin.
+		#[pallet::constant]
+		type MessageRetentionPeriod: Get<BlockNumberFor<Self>>;
 		/// The base byte fee for data stored offchain.
 		#[pallet::constant]
 		type OffChainByteFee: Get<BalanceOf<Self>>;
@@ -104,6 +158,9 @@ pub mod pallet {
 		type OnChainByteFee: Get<BalanceOf<Self>>;
 		/// A converter for conversion of a call origin to a location.
 		type OriginConverter: TryConvert<Self::RuntimeOrigin, Location>;
+		/// The origin permitted to override the maximum number of pending messages of an origin,
+		/// such as for trusted contracts.
+		type QuotaOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// The overarching hold reason for deposits.
 		type RuntimeHoldReason: From<HoldReason>;
 		/// The type responsible for converting between weight and balance, commonly transaction
@@ -113,6 +170,8 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 		/// A handler for the creation of a XCM query notification.
 		type Xcm: NotifyQueryHandler<Self>;
+		/// Dry-running and fee estimation of XCM messages, as offered by the XCM runtime APIs.
+		type XcmApi: XcmApi<Self>;
 		/// The origin of the response for xcm.
 		type XcmResponseOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Location>;
 	}
@@ -137,8 +196,6 @@ pub mod pallet {
 		RequestTimedOut,
 		/// Timeouts must be in the future.
 		FutureTimeoutMandatory,
-		/// Message block limit has been reached for this expiry block. Try a different timeout.
-		MaxMessageTimeoutPerBlockReached,
 		/// This callback cannot be processed due to lack of blockspace. Please poll the response.
 		BlockspaceAllowanceReached,
 		/// This is not possible as the message has completed.
@@ -149,6 +206,16 @@ pub mod pallet {
 		ZeroWeight,
 		/// The number of messages exceeds the limit.
 		TooManyMessages,
+		/// The subscription has no executions or its interval is below the minimum.
+		InvalidSubscription,
+		/// The subscription was not found.
+		SubscriptionNotFound,
+		/// The origin has reached its maximum number of pending messages.
+		TooManyPendingMessages,
+		/// The batch has no requests or exceeds the maximum number of requests.
+		InvalidBatch,
+		/// The storage of the pallet is being migrated.
+		MigrationOngoing,
 	}
 
 	/// A reason for the pallet placing a hold on funds.
@@ -159,6 +226,13 @@ pub mod pallet {
 		Messaging,
 		#[codec(index = 1)]
 		CallbackGas,
+		/// Prepaid for the remaining executions of a subscription.
+		#[codec(index = 2)]
+		Subscription,
+		/// Prepaid for handling the response to a XCM query, with any fee unused by the response
+		/// refunded once it is received.
+		#[codec(index = 3)]
+		ResponseFee,
 	}
 
 	#[pallet::hooks]
@@ -174,28 +248,43 @@ pub mod pallet {
 		}
 
 		fn on_initialize(n: BlockNumberOf<T>) -> Weight {
+			// Messages are not processed whilst the storage of the pallet is being migrated.
+			if migrating::<T>() {
+				return DbWeightOf::<T>::get().reads(1);
+			}
+
 			// As of polkadot-2412 XCM timeouts are not handled by the implementation of OnResponse
 			// in pallet-xcm. As a result, we must handle timeouts in the pallet.
 			// Iterate through the queries that have expired and update their status.
 			let mut weight: Weight = Zero::zero();
 			let mut query_ids = Vec::new();
 			for message_id in XcmQueryTimeouts::<T>::get(n) {
-				weight = weight.saturating_add(DbWeightOf::<T>::get().reads_writes(2, 1));
-				Messages::<T>::mutate(message_id, |maybe_message| {
-					if let Some(Message::XcmQuery { origin, query_id, message_deposit, callback }) =
-						maybe_message.as_mut()
-					{
-						let callback_deposit =
-							callback.map(|cb| T::WeightToFee::weight_to_fee(&cb.gas_limit));
-						query_ids.push(*query_id);
-						*maybe_message = Some(Message::XcmTimeout {
-							origin: origin.address,
-							query_id: *query_id,
-							message_deposit: *message_deposit,
-							callback_deposit,
-						});
-					}
-				})
+				weight = weight.saturating_add(DbWeightOf::<T>::get().reads_writes(3, 3));
+				if let Some(query_id) = timeout_xcm_query::<T>(message_id) {
+					query_ids.push(query_id);
+				}
+			}
+
+			// Any queries which exceeded the timeout limit of this block are timed out in
+			// `on_idle`, keeping the work done here bounded.
+			weight = weight.saturating_add(DbWeightOf::<T>::get().reads(1));
+			if XcmQueryTimeoutOverflow::<T>::contains_prefix(n) {
+				weight = weight.saturating_add(DbWeightOf::<T>::get().reads_writes(1, 2));
+				XcmQueryTimeoutOverflowQueue::<T>::mutate(|(_, tail)| {
+					XcmQueryTimeoutOverflowBlocks::<T>::insert(*tail, n);
+					tail.saturating_inc();
+				});
+			}
+
+			// Subscriptions which are due are dispatched in `on_idle`, in the order in which their
+			// blocks became due.
+			weight = weight.saturating_add(DbWeightOf::<T>::get().reads(1));
+			if SubscriptionSchedule::<T>::contains_prefix(n) {
+				weight = weight.saturating_add(DbWeightOf::<T>::get().reads_writes(1, 2));
+				SubscriptionScheduleQueue::<T>::mutate(|(_, tail)| {
+					SubscriptionScheduleBlocks::<T>::insert(*tail, n);
+					tail.saturating_inc();
+				});
 			}
 
 			if !query_ids.is_empty() {
@@ -203,25 +292,93 @@ pub mod pallet {
 			}
 			weight
 		}
+
+		fn on_idle(n: BlockNumberOf<T>, remaining_weight: Weight) -> Weight {
+			// Messages are not processed whilst the storage of the pallet is being migrated.
+			if migrating::<T>() {
+				return DbWeightOf::<T>::get().reads(1);
+			}
+
+			// Retry any callbacks deferred due to a lack of blockspace.
+			let weight = retry_deferred_callbacks::<T>(
+				remaining_weight.min(T::DeferredCallbackWeightLimit::get()),
+			);
+			// Time out any queries which exceeded the timeout limit of their block.
+			let weight = weight.saturating_add(timeout_overflowing_xcm_queries::<T>(
+				remaining_weight.saturating_sub(weight),
+			));
+			// Dispatch the requests of any subscriptions which are due.
+			let weight = weight.saturating_add(dispatch_subscriptions::<T>(
+				remaining_weight.saturating_sub(weight),
+			));
+			// Remove any expired messages with the remaining weight.
+			weight.saturating_add(expire_messages::<T>(n, remaining_weight.saturating_sub(weight)))
+		}
 	}
 
 	/// The message queue.
 	#[pallet::storage]
 	pub(crate) type Messages<T: Config> = StorageMap<_, Twox64Concat, MessageId, Message<T>>;
 
+	/// The identifiers of the messages of each origin.
+	#[pallet::storage]
+	pub(crate) type OriginMessages<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, H160, Twox64Concat, MessageId, ()>;
+
+	/// The pending transfers of the ownership of messages, as the owner offering the ownership and
+	/// the address offered it, by message identifier.
+	#[pallet::storage]
+	pub(super) type MessageTransfers<T: Config> =
+		StorageMap<_, Twox64Concat, MessageId, (Origin<T>, H160)>;
+
 	/// The next message identifier.
 	///
 	/// Also serves as a count of the total number of messages sent.
 	#[pallet::storage]
 	pub(crate) type NextMessageId<T: Config> = StorageValue<_, MessageId, ValueQuery>;
 
-	/// The active ISMP requests, mapped to the originating message identifier.
+	/// The messages of each transport, by their key on the transport.
 	#[pallet::storage]
-	pub(super) type IsmpRequests<T: Config> = StorageMap<_, Identity, H256, MessageId>;
+	pub(crate) type TransportMessages<T: Config> =
+		StorageMap<_, Blake2_128Concat, MessageTransport, MessageId>;
 
-	/// The active XCM queries, mapped to the originating message identifier.
+	/// The number of pending messages of each origin.
 	#[pallet::storage]
-	pub(super) type XcmQueries<T: Config> = StorageMap<_, Twox64Concat, QueryId, MessageId>;
+	pub(super) type PendingMessages<T: Config> =
+		StorageMap<_, Blake2_128Concat, H160, u32, ValueQuery>;
+
+	/// Overrides of the maximum number of pending messages, by origin.
+	#[pallet::storage]
+	pub(super) type PendingMessageQuotas<T: Config> = StorageMap<_, Blake2_128Concat, H160, u32>;
+
+	/// The cumulative fees paid to relayers for pending ISMP requests, by commitment.
+	#[pallet::storage]
+	pub(super) type IsmpFees<T: Config> = StorageMap<_, Identity, H256, BalanceOf<T>>;
+
+	/// The pending batches of ISMP `Get` requests, by message identifier.
+	#[pallet::storage]
+	pub(super) type IsmpBatches<T: Config> = StorageMap<_, Twox64Concat, MessageId, GetBatch<T>>;
+
+	/// The pending ISMP `Get` requests of batches, with the message identifier of the batch and
+	/// the position of the request within it, by commitment.
+	#[pallet::storage]
+	pub(super) type IsmpBatchRequests<T: Config> = StorageMap<_, Identity, H256, (MessageId, u32)>;
+
+	/// The sources from which each contract accepts inbound ISMP `Post` requests, with the deposit
+	/// held for each registration. A source is keyed by the hash of its state machine and the
+	/// identifier of the sending module.
+	#[pallet::storage]
+	pub(super) type IsmpPostRequestSources<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, H160, Identity, H256, BalanceOf<T>>;
+
+	/// The pending XCM queries, with the block at which each is to time out.
+	#[pallet::storage]
+	pub(super) type PendingXcmQueries<T: Config> =
+		StorageMap<_, Twox64Concat, QueryId, BlockNumberOf<T>>;
+
+	/// The fees held for handling the responses to pending XCM queries, by query identifier.
+	#[pallet::storage]
+	pub(super) type XcmResponseFees<T: Config> = StorageMap<_, Twox64Concat, QueryId, BalanceOf<T>>;
 
 	/// The timeouts of XCM queries, by block number.
 	#[pallet::storage]
@@ -233,10 +390,92 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The timeouts of XCM queries which exceeded the maximum number of timeouts of their block,
+	/// by block number.
+	#[pallet::storage]
+	pub(super) type XcmQueryTimeoutOverflow<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberOf<T>, Twox64Concat, MessageId, ()>;
+
+	/// The blocks with overflowing XCM query timeouts which are due, by their position in the
+	/// overflow queue.
+	#[pallet::storage]
+	pub(super) type XcmQueryTimeoutOverflowBlocks<T: Config> =
+		StorageMap<_, Twox64Concat, u64, BlockNumberOf<T>>;
+
+	/// The overflow queue, as the position of the next block of overflowing XCM query timeouts to
+	/// be processed and the position at which the next block is to be scheduled.
+	#[pallet::storage]
+	pub(super) type XcmQueryTimeoutOverflowQueue<T: Config> =
+		StorageValue<_, (u64, u64), ValueQuery>;
+
+	/// Callbacks deferred due to a lack of blockspace, by message identifier.
+	#[pallet::storage]
+	pub(super) type DeferredCallbacks<T: Config> =
+		StorageMap<_, Twox64Concat, MessageId, DeferredCallback<T>>;
+
+	/// The message identifiers of deferred callbacks, in the order in which they are retried.
+	#[pallet::storage]
+	pub(super) type DeferredCallbackQueue<T: Config> =
+		StorageValue<_, BoundedVec<MessageId, T::MaxDeferredCallbacks>, ValueQuery>;
+
+	/// Completed and timed out messages, with the block at which they expire, by their position in
+	/// the expiry queue.
+	#[pallet::storage]
+	pub(super) type MessageExpiries<T: Config> =
+		StorageMap<_, Twox64Concat, u64, (MessageId, BlockNumberOf<T>)>;
+
+	/// The expiry queue, as the position of the next message expiry to be processed and the
+	/// position at which the next message expiry is to be scheduled.
+	#[pallet::storage]
+	pub(super) type MessageExpiryQueue<T: Config> = StorageValue<_, (u64, u64), ValueQuery>;
+
+	/// The subscriptions to recurring ISMP `Get` requests.
+	#[pallet::storage]
+	pub(super) type Subscriptions<T: Config> =
+		StorageMap<_, Twox64Concat, SubscriptionId, Subscription<T>>;
+
+	/// The next subscription identifier.
+	#[pallet::storage]
+	pub(super) type NextSubscriptionId<T: Config> = StorageValue<_, SubscriptionId, ValueQuery>;
+
+	/// The next executions of subscriptions, by block number.
+	#[pallet::storage]
+	pub(super) type SubscriptionSchedule<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberOf<T>, Twox64Concat, SubscriptionId, ()>;
+
+	/// The blocks with subscription executions which are due, by their position in the schedule
+	/// queue.
+	#[pallet::storage]
+	pub(super) type SubscriptionScheduleBlocks<T: Config> =
+		StorageMap<_, Twox64Concat, u64, BlockNumberOf<T>>;
+
+	/// The schedule queue, as the position of the next block of subscription executions to be
+	/// processed and the position at which the next block is to be scheduled.
+	#[pallet::storage]
+	pub(super) type SubscriptionScheduleQueue<T: Config> = StorageValue<_, (u64, u64), ValueQuery>;
+
 	/// The events that can be emitted.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
+		/// A callback has been abandoned, with the deposit held for its execution released.
+		CallbackAbandoned {
+			/// The origin of the callback.
+			origin: T::AccountId,
+			/// The identifier specified for the request.
+			id: MessageId,
+			/// The abandoned callback.
+			callback: Callback<BalanceOf<T>>,
+		},
+		/// A callback has been deferred due to a lack of blockspace.
+		CallbackDeferred {
+			/// The origin of the callback.
+			origin: T::AccountId,
+			/// The identifier specified for the request.
+			id: MessageId,
+			/// The deferred callback.
+			callback: Callback<BalanceOf<T>>,
+		},
 		/// A callback has been executed successfully.
 		CallbackExecuted {
 			/// The origin of the callback.
@@ -257,6 +496,22 @@ pub mod pallet {
 			/// The error which occurred.
 			error: DispatchErrorWithPostInfo,
 		},
+		/// A deferred callback is being retried.
+		CallbackRetried {
+			/// The origin of the callback.
+			origin: T::AccountId,
+			/// The identifier specified for the request.
+			id: MessageId,
+			/// The number of times the callback has been retried.
+			retries: u32,
+		},
+		/// Every GET of a batch has been answered or has timed out.
+		IsmpGetBatchCompleted {
+			/// The destination of the responses.
+			dest: H160,
+			/// The identifier specified for the batch.
+			id: MessageId,
+		},
 		/// A response to a GET has been received via ISMP.
 		IsmpGetResponseReceived {
 			/// The destination of the response.
@@ -266,6 +521,29 @@ pub mod pallet {
 			/// The ISMP request commitment.
 			commitment: H256,
 		},
+		/// The delivery of a POST received via ISMP to a contract has failed.
+		IsmpPostRequestFailed {
+			/// The contract to which the request was delivered.
+			dest: H160,
+			/// The ISMP request commitment.
+			commitment: H256,
+			/// The error which occurred.
+			error: DispatchErrorWithPostInfo,
+		},
+		/// A POST has been received via ISMP.
+		IsmpPostRequestReceived {
+			/// The contract to which the request is delivered.
+			dest: H160,
+			/// The ISMP request commitment.
+			commitment: H256,
+		},
+		/// A response to a POST received via ISMP has been dispatched.
+		IsmpPostResponseDispatched {
+			/// The contract which handled the request.
+			origin: H160,
+			/// The ISMP request commitment.
+			commitment: H256,
+		},
 		/// A response to a POST has been received via ISMP.
 		IsmpPostResponseReceived {
 			/// The destination of the response.
@@ -277,6 +555,45 @@ pub mod pallet {
 		},
 		/// An ISMP message has timed out.
 		IsmpTimedOut { commitment: H256 },
+		/// A collection of completed or timed out messages have expired and been removed, with
+		/// their deposits released.
+		MessagesExpired { messages: Vec<MessageId> },
+		/// The maximum number of pending messages of an origin has been overridden.
+		PendingMessageQuotaSet {
+			/// The origin.
+			origin: H160,
+			/// The maximum number of pending messages of the origin, with `None` restoring the
+			/// default.
+			quota: Option<u32>,
+		},
+		/// The `Get` request of a subscription has been dispatched via ISMP.
+		SubscriptionDispatched {
+			/// The origin of the subscription.
+			origin: H160,
+			/// The identifier of the subscription.
+			subscription: SubscriptionId,
+			/// The identifier of the message of the request.
+			id: MessageId,
+			/// The ISMP request commitment.
+			commitment: H256,
+		},
+		/// The `Get` request of a subscription could not be dispatched, with the execution being
+		/// retried at the next interval.
+		SubscriptionDispatchFailed {
+			/// The origin of the subscription.
+			origin: H160,
+			/// The identifier of the subscription.
+			subscription: SubscriptionId,
+			/// The error which occurred.
+			error: DispatchError,
+		},
+		/// A subscription has been removed after its final execution, with its deposit released.
+		SubscriptionExhausted {
+			/// The origin of the subscription.
+			origin: H160,
+			/// The identifier of the subscription.
+			subscription: SubscriptionId,
+		},
 		/// An error has occured while attempting to refund weight.
 		WeightRefundErrored { message_id: MessageId, error: DispatchError },
 		/// A collection of xcm queries have timed out.
@@ -306,8 +623,9 @@ pub mod pallet {
 		/// - `xcm_response`: The response data.
 		#[pallet::call_index(0)]
 		#[pallet::weight({
-				// This is only used to check against max_weight field in the OnResponse implementation in pallet-xcm.
-				T::WeightInfo::xcm_response()
+				// This is only used to check against max_weight field in the OnResponse implementation in pallet-xcm,
+				// so covers a response of the maximum length.
+				T::WeightInfo::xcm_response(T::MaxResponseLen::get())
 			})]
 		pub fn xcm_response(
 			origin: OriginFor<T>,
@@ -315,8 +633,12 @@ pub mod pallet {
 			xcm_response: Response,
 		) -> DispatchResult {
 			T::XcmResponseOrigin::ensure_origin(origin)?;
+			ensure!(!migrating::<T>(), Error::<T>::MigrationOngoing);
 
-			let extrinsic_weight = T::WeightInfo::xcm_response()
+			// Responses exceeding the maximum length are rejected, so are charged at most the
+			// weight of a response of the maximum length, as covered by the prepaid fee.
+			let len = (xcm_response.encoded_size() as u32).min(T::MaxResponseLen::get());
+			let extrinsic_weight = T::WeightInfo::xcm_response(len)
 				.saturating_add(T::CallbackExecutor::execution_weight());
 
 			ensure!(
@@ -329,59 +651,44 @@ pub mod pallet {
 			// Manually adjust weight ahead of fallible execution.
 			// The fees of which should have been paid.
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::WeightInfo::xcm_response()
-					.saturating_add(T::CallbackExecutor::execution_weight()),
+				extrinsic_weight,
 				DispatchClass::Normal,
 			);
 
-			let id = XcmQueries::<T>::get(query_id).ok_or(Error::<T>::MessageNotFound)?;
-			let xcm_query_message = Messages::<T>::get(id).ok_or(Error::<T>::MessageNotFound)?;
-
-			let (origin, query_id, callback, message_deposit) = match &xcm_query_message {
-				Message::XcmQuery { origin, query_id, callback, message_deposit } =>
-					(origin, query_id, callback, message_deposit),
-				Message::XcmTimeout { .. } => return Err(Error::<T>::RequestTimedOut.into()),
-				_ => return Err(Error::<T>::InvalidMessage.into()),
-			};
-
-			// Emit event before possible callback execution.
-			Self::deposit_event(Event::<T>::XcmResponseReceived {
-				dest: origin.address,
-				id,
-				query_id: *query_id,
-				response: xcm_response.clone(),
-			});
-
-			if let Some(callback) = callback {
-				// Attempt callback with response if specified.
-				log::debug!(target: "pop-api::extension", "xcm callback={:?}, response={:?}", callback, xcm_response);
-				// Never roll back state if call fails.
-				// Ensure that the response can be polled.
-				if call::<T>(&origin.account, *callback, &id, &xcm_response).is_ok() {
-					Messages::<T>::remove(id);
-					XcmQueries::<T>::remove(query_id);
-					T::Fungibles::release(
-						&HoldReason::Messaging.into(),
-						&origin.account,
-						*message_deposit,
-						Precision::Exact,
-					)?;
-
-					return Ok(());
+			let id = transports::message_id::<T, XcmTransport>(&query_id)
+				.ok_or(Error::<T>::MessageNotFound)?;
+			let (origin, ..) = transports::pending::<T, XcmTransport>(id)?;
+			transports::respond::<T, XcmTransport>(&query_id, &xcm_response, |dest, id| {
+				Event::<T>::XcmResponseReceived {
+					dest,
+					id,
+					query_id,
+					response: xcm_response.clone(),
 				}
-			}
-			// No callback is executed,
-			Messages::<T>::insert(
-				id,
-				Message::XcmResponse {
-					origin: origin.address,
-					query_id: *query_id,
-					message_deposit: *message_deposit,
-					response: xcm_response,
-				},
-			);
+			})?;
+			transports::xcm::settle_response_fee::<T>(&query_id, &origin.account, Some(len))?;
 			Ok(().into())
 		}
+
+		/// Override the maximum number of pending messages of an origin.
+		///
+		/// # Parameters
+		/// - `origin`: The quota origin.
+		/// - `address`: The address of the origin whose maximum is overridden.
+		/// - `quota`: The maximum number of pending messages of the origin, with `None` restoring
+		///   the default of `MaxPendingMessages`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_pending_message_quota())]
+		pub fn set_pending_message_quota(
+			origin: OriginFor<T>,
+			address: H160,
+			quota: Option<u32>,
+		) -> DispatchResult {
+			T::QuotaOrigin::ensure_origin(origin)?;
+			PendingMessageQuotas::<T>::set(address, quota);
+			Self::deposit_event(Event::<T>::PendingMessageQuotaSet { origin: address, quota });
+			Ok(())
+		}
 	}
 }
 
@@ -393,6 +700,121 @@ impl<T: Config> Pallet<T> {
 	pub fn get(id: MessageId) -> Option<Message<T>> {
 		<Messages<T>>::get(id)
 	}
+
+	/// Get the details of a message by its identifier.
+	///
+	/// # Parameters
+	/// - `id`: The message identifier.
+	pub fn message(id: MessageId) -> Option<MessageDetails<BalanceOf<T>>> {
+		<Messages<T>>::get(id).map(|message| message.details(id))
+	}
+
+	/// Get the messages of an origin.
+	///
+	/// Messages are returned in storage order, with `start_after` used as a cursor to continue
+	/// from the last message of a previous page. Only the messages of the origin are read.
+	///
+	/// # Parameters
+	/// - `origin`: The address of the origin of the messages.
+	/// - `start_after`: The identifier of the message after which to start, if any.
+	/// - `limit`: The maximum number of messages to return.
+	pub fn messages(
+		origin: H160,
+		start_after: Option<MessageId>,
+		limit: u32,
+	) -> Vec<MessageDetails<BalanceOf<T>>> {
+		let ids = match start_after {
+			Some(id) => <OriginMessages<T>>::iter_key_prefix_from(
+				origin,
+				<OriginMessages<T>>::hashed_key_for(origin, id),
+			),
+			None => <OriginMessages<T>>::iter_key_prefix(origin),
+		};
+		ids.take(limit as usize)
+			.filter_map(|id| <Messages<T>>::get(id).map(|message| message.details(id)))
+			.collect()
+	}
+
+	/// Get a message by the commitment of its ISMP request.
+	///
+	/// # Parameters
+	/// - `commitment`: The commitment of the ISMP request.
+	pub fn ismp_message(commitment: H256) -> Option<MessageDetails<BalanceOf<T>>> {
+		let id = transports::message_id::<T, IsmpTransport>(&commitment)?;
+		<Messages<T>>::get(id).map(|message| message.details(id))
+	}
+
+	/// Get a message by the identifier of its XCM query.
+	///
+	/// # Parameters
+	/// - `query_id`: The identifier of the XCM query.
+	pub fn xcm_message(query_id: QueryId) -> Option<MessageDetails<BalanceOf<T>>> {
+		let id = transports::message_id::<T, XcmTransport>(&query_id)?;
+		<Messages<T>>::get(id).map(|message| message.details(id))
+	}
+
+	/// The total deposit held for the messages of an account, including any deposits held for
+	/// callback execution, the amounts prepaid for the remaining executions of subscriptions and
+	/// the fees held for handling the responses to XCM queries.
+	///
+	/// # Parameters
+	/// - `account`: The account of the origin of the messages.
+	pub fn held_deposit(account: &AccountIdOf<T>) -> BalanceOf<T> {
+		T::Fungibles::balance_on_hold(&HoldReason::Messaging.into(), account)
+			.saturating_add(T::Fungibles::balance_on_hold(&HoldReason::CallbackGas.into(), account))
+			.saturating_add(T::Fungibles::balance_on_hold(
+				&HoldReason::Subscription.into(),
+				account,
+			))
+			.saturating_add(T::Fungibles::balance_on_hold(&HoldReason::ResponseFee.into(), account))
+	}
+
+	/// The maximum number of pending messages of an origin.
+	///
+	/// # Parameters
+	/// - `origin`: The address of the origin.
+	pub fn pending_message_quota(origin: H160) -> u32 {
+		PendingMessageQuotas::<T>::get(origin).unwrap_or_else(T::MaxPendingMessages::get)
+	}
+
+	/// Quote the amounts taken for an ISMP `Get` request, excluding the fee paid to relayers.
+	///
+	/// # Parameters
+	/// - `callback_gas_limit`: The gas limit of the callback, if any.
+	pub fn quote_get(callback_gas_limit: Option<Weight>) -> MessageQuote<BalanceOf<T>> {
+		transports::ismp::quote_get::<T>(callback_gas_limit)
+	}
+
+	/// Quote the amounts taken for an ISMP `Post` request, excluding the fee paid to relayers.
+	///
+	/// # Parameters
+	/// - `callback_gas_limit`: The gas limit of the callback, if any.
+	pub fn quote_post(callback_gas_limit: Option<Weight>) -> MessageQuote<BalanceOf<T>> {
+		transports::ismp::quote_post::<T>(callback_gas_limit)
+	}
+
+	/// Quote the amounts taken for a subscription to recurring ISMP `Get` requests, including the
+	/// fees paid to relayers for all executions.
+	///
+	/// # Parameters
+	/// - `executions`: The number of executions of the subscription.
+	/// - `fee`: The fee to be paid to relayers per execution.
+	/// - `callback_gas_limit`: The gas limit of the callback.
+	pub fn quote_subscribe(
+		executions: u32,
+		fee: BalanceOf<T>,
+		callback_gas_limit: Weight,
+	) -> MessageQuote<BalanceOf<T>> {
+		transports::ismp::quote_subscribe::<T>(executions, fee, callback_gas_limit)
+	}
+
+	/// Quote the amounts taken for a XCM query.
+	///
+	/// # Parameters
+	/// - `callback_gas_limit`: The gas limit of the callback, if any.
+	pub fn quote_new_query(callback_gas_limit: Option<Weight>) -> MessageQuote<BalanceOf<T>> {
+		transports::xcm::quote_new_query::<T>(callback_gas_limit)
+	}
 }
 
 /// Executes a registered callback with the given input data and manually charges block
@@ -432,18 +854,247 @@ pub(crate) fn call<T: Config>(
 	let max_weight = callback.gas_limit;
 
 	// Dont mutate state if blockspace will be saturated.
+	ensure_blockspace::<T>(max_weight)?;
+
+	let data = data.encode(callback.encoding, callback.selector, *id);
+	let weight_used = execute_callback::<T>(initiating_origin, callback, id, data);
+
+	// Manually adjust callback weight.
+	frame_system::Pallet::<T>::register_extra_weight_unchecked(weight_used, DispatchClass::Normal);
+	Ok(())
+}
+
+/// Executes a callback, deferring it to be retried in a later block if there is insufficient
+/// blockspace.
+///
+/// Returns whether the callback was executed.
+///
+/// # Parameters
+///
+/// - `initiating_origin`: The account that triggered the callback.
+/// - `callback`: The callback definition.
+/// - `id`: The message ID associated with this callback's message.
+/// - `data`: The encoded payload to send to the callback.
+pub(crate) fn call_or_defer<T: Config>(
+	initiating_origin: &AccountIdOf<T>,
+	callback: Callback<BalanceOf<T>>,
+	id: &MessageId,
+	data: &impl EncodeCallback,
+) -> bool {
+	// The only error returned is a lack of blockspace.
+	if call::<T>(initiating_origin, callback, id, data).is_ok() {
+		return true;
+	}
+	let input = data.encode(callback.encoding, callback.selector, *id);
+	defer::<T>(initiating_origin, callback, id, input);
+	false
+}
+
+/// Defers a callback which could not be executed due to a lack of blockspace, to be retried in
+/// `on_idle` of a later block. The `CallbackGas` hold remains in place until the callback is
+/// resolved.
+///
+/// The callback is abandoned if it cannot be deferred, either due to the deferral queue being
+/// full or its input exceeding the maximum length.
+///
+/// # Parameters
+///
+/// - `initiating_origin`: The account that triggered the callback.
+/// - `callback`: The callback definition.
+/// - `id`: The message ID associated with this callback's message.
+/// - `input`: The encoded input of the callback.
+fn defer<T: Config>(
+	initiating_origin: &AccountIdOf<T>,
+	callback: Callback<BalanceOf<T>>,
+	id: &MessageId,
+	input: Vec<u8>,
+) {
+	let Ok(input) = BoundedVec::try_from(input) else {
+		return abandon::<T>(initiating_origin, callback, id);
+	};
+	if DeferredCallbackQueue::<T>::try_mutate(|queue| queue.try_push(*id)).is_err() {
+		return abandon::<T>(initiating_origin, callback, id);
+	}
+	DeferredCallbacks::<T>::insert(
+		id,
+		DeferredCallback { origin: initiating_origin.clone(), callback, input, retries: 0 },
+	);
+	<Pallet<T>>::deposit_event(Event::<T>::CallbackDeferred {
+		origin: initiating_origin.clone(),
+		id: *id,
+		callback,
+	});
+}
+
+/// Abandons a callback, releasing the deposit held for its execution.
+///
+/// # Parameters
+///
+/// - `initiating_origin`: The account that triggered the callback.
+/// - `callback`: The callback definition.
+/// - `id`: The message ID associated with this callback's message.
+fn abandon<T: Config>(
+	initiating_origin: &AccountIdOf<T>,
+	callback: Callback<BalanceOf<T>>,
+	id: &MessageId,
+) {
+	if let Err(error) = T::Fungibles::release(
+		&HoldReason::CallbackGas.into(),
+		initiating_origin,
+		T::WeightToFee::weight_to_fee(&callback.gas_limit),
+		Precision::BestEffort,
+	) {
+		log::error!(target: "pop-api::extension", "failed to release callback deposit: id={id}, error={error:?}");
+	}
+	<Pallet<T>>::deposit_event(Event::<T>::CallbackAbandoned {
+		origin: initiating_origin.clone(),
+		id: *id,
+		callback,
+	});
+}
+
+/// Retries deferred callbacks in the order in which they were deferred, within the weight limit.
+///
+/// A callback which again cannot be executed due to a lack of blockspace stops processing for
+/// the current block, retaining its position at the front of the queue, unless it has reached
+/// the maximum number of retries, in which case it is abandoned.
+///
+/// Returns the weight used, including the weight used by any callback executions.
+///
+/// # Parameters
+///
+/// - `limit`: The maximum weight to be used, including callback executions.
+pub(crate) fn retry_deferred_callbacks<T: Config>(limit: Weight) -> Weight {
+	let mut meter = WeightMeter::with_limit(limit);
+	let mut weight = DbWeightOf::<T>::get().reads(1);
+	if meter.try_consume(weight).is_err() {
+		return Weight::zero();
+	}
+
+	let queue = DeferredCallbackQueue::<T>::get();
+	let retry_weight = T::WeightInfo::retry_deferred_callback()
+		.saturating_add(T::CallbackExecutor::execution_weight());
+	let mut resolved = 0;
+	for id in queue.iter() {
+		let Some(mut deferred) = DeferredCallbacks::<T>::get(id) else {
+			// The message has since been removed.
+			weight.saturating_accrue(DbWeightOf::<T>::get().reads(1));
+			resolved += 1;
+			continue;
+		};
+		if meter
+			.try_consume(retry_weight.saturating_add(deferred.callback.gas_limit))
+			.is_err()
+		{
+			break;
+		}
+		weight.saturating_accrue(retry_weight);
+
+		deferred.retries.saturating_inc();
+		<Pallet<T>>::deposit_event(Event::<T>::CallbackRetried {
+			origin: deferred.origin.clone(),
+			id: *id,
+			retries: deferred.retries,
+		});
+
+		if ensure_blockspace::<T>(deferred.callback.gas_limit).is_err() {
+			if deferred.retries < T::MaxCallbackRetries::get() {
+				DeferredCallbacks::<T>::insert(id, deferred);
+			} else {
+				DeferredCallbacks::<T>::remove(id);
+				abandon::<T>(&deferred.origin, deferred.callback, id);
+				resolved += 1;
+			}
+			break;
+		}
+
+		DeferredCallbacks::<T>::remove(id);
+		weight.saturating_accrue(execute_callback::<T>(
+			&deferred.origin,
+			deferred.callback,
+			id,
+			deferred.input.into_inner(),
+		));
+		complete::<T>(&deferred.origin, id);
+		resolved += 1;
+	}
+
+	if resolved > 0 {
+		DeferredCallbackQueue::<T>::mutate(|queue| {
+			*queue = BoundedVec::truncate_from(queue.iter().skip(resolved).copied().collect());
+		});
+		weight.saturating_accrue(DbWeightOf::<T>::get().writes(1));
+	}
+	weight
+}
+
+/// Completes a message for which a deferred callback has been executed, removing it and
+/// releasing its deposit.
+///
+/// # Parameters
+///
+/// - `initiating_origin`: The account that initiated the message.
+/// - `id`: The message identifier.
+fn complete<T: Config>(initiating_origin: &AccountIdOf<T>, id: &MessageId) {
+	let Some(Message::Complete { origin, transport, message_deposit, .. }) = Messages::<T>::get(id)
+	else {
+		return;
+	};
+	Messages::<T>::remove(id);
+	MessageTransfers::<T>::remove(id);
+	OriginMessages::<T>::remove(origin, id);
+	TransportMessages::<T>::remove(transport);
+	if let Err(error) = T::Fungibles::release(
+		&HoldReason::Messaging.into(),
+		initiating_origin,
+		message_deposit,
+		Precision::Exact,
+	) {
+		log::error!(target: "pop-api::extension", "failed to release message deposit: id={id}, error={error:?}");
+	}
+}
+
+/// Ensures that there is sufficient blockspace remaining for the given weight.
+///
+/// # Parameters
+///
+/// - `weight`: The weight to be accommodated in the current block.
+fn ensure_blockspace<T: Config>(weight: Weight) -> DispatchResult {
 	frame_support::ensure!(
 		frame_system::BlockWeight::<T>::get()
-			.checked_accrue(max_weight, DispatchClass::Normal)
+			.checked_accrue(weight, DispatchClass::Normal)
 			.is_ok(),
 		Error::<T>::BlockspaceAllowanceReached
 	);
+	Ok(())
+}
 
-	// Execute callback.
-	// Its important to note that we must still ensure that the weight used is accounted for
-	// in frame_system. Hence all calls after this must not return an err and state
-	// should not be rolled back.
-	let data = data.encode(callback.encoding, callback.selector, *id);
+/// Whether the storage of the pallet is yet to be migrated to the current storage version, during
+/// which inbound messages are rejected and the hooks of the pallet are suspended.
+///
+/// Runtimes adding the pallet after genesis must therefore migrate its storage version (see
+/// [`migrations::v1::MigrateToV1`]).
+pub(crate) fn migrating<T: Config>() -> bool {
+	Pallet::<T>::on_chain_storage_version() < Pallet::<T>::in_code_storage_version()
+}
+
+/// Executes a callback with the given input, managing the callback fees.
+///
+/// Returns the weight used by the callback, which the caller must ensure is accounted for in
+/// frame_system. Hence this function is infallible and state should not be rolled back.
+///
+/// # Parameters
+///
+/// - `initiating_origin`: The account that triggered the callback.
+/// - `callback`: The callback definition.
+/// - `id`: The message ID associated with this callback's message.
+/// - `data`: The encoded input of the callback.
+fn execute_callback<T: Config>(
+	initiating_origin: &AccountIdOf<T>,
+	callback: Callback<BalanceOf<T>>,
+	id: &MessageId,
+	data: Vec<u8>,
+) -> Weight {
 	let result = T::CallbackExecutor::execute(
 		&initiating_origin,
 		callback.destination,
@@ -456,19 +1107,13 @@ pub(crate) fn call<T: Config>(
 	deposit_callback_event::<T>(initiating_origin.clone(), *id, &callback, &result);
 	let callback_weight_used = process_callback_weight(&result, callback.gas_limit);
 
-	// Manually adjust callback weight.
-	frame_system::Pallet::<T>::register_extra_weight_unchecked(
-		callback_weight_used,
-		DispatchClass::Normal,
-	);
-
 	match manage_fees::<T>(&initiating_origin, callback_weight_used, callback.gas_limit) {
 		Ok(_) => (),
-		// Dont return early, we must register the weight used by the callback.
+		// Dont return early, we must return the weight used by the callback.
 		Err(error) =>
 			<Pallet<T>>::deposit_event(Event::WeightRefundErrored { message_id: *id, error }),
 	}
-	Ok(())
+	callback_weight_used
 }
 
 /// Deposits an event indicating the outcome of a callback execution.
@@ -508,14 +1153,10 @@ pub(crate) fn deposit_callback_event<T: Config>(
 }
 
 fn get<T: Config>(id: &MessageId) -> Vec<u8> {
-	use Message::*;
-	Messages::<T>::get(id)
-		.and_then(|m| match m {
-			Ismp { .. } | IsmpTimeout { .. } | XcmQuery { .. } | XcmTimeout { .. } => None,
-			IsmpResponse { response, .. } => Some(response.into_inner()),
-			XcmResponse { response, .. } => Some(codec::Encode::encode(&response)),
-		})
-		.unwrap_or_default()
+	match Messages::<T>::get(id) {
+		Some(Message::Complete { response, .. }) => response.into_inner(),
+		_ => Vec::default(),
+	}
 }
 
 fn id<T: parachain_info::Config>() -> u32 {
@@ -622,7 +1263,8 @@ pub(crate) fn process_callback_weight(
 
 /// Remove a batch of completed or timed-out messages.
 ///
-/// Allows users to clean up storage and reclaim deposits for messages that have concluded.
+/// Allows users to clean up storage and reclaim deposits for messages that have concluded. Any
+/// deferred callback of a removed message is cancelled.
 ///
 /// # Parameters
 /// - `origin`: The account removing its messages.
@@ -636,41 +1278,26 @@ fn remove<T: Config>(origin: Origin<T>, messages: &[MessageId]) -> DispatchResul
 		};
 
 		let (message_deposit, maybe_callback_deposit) = match message {
-			Message::Ismp { .. } | Message::XcmQuery { .. } => Err(Error::<T>::RequestPending),
-			Message::IsmpResponse { origin: initiator, message_deposit, commitment, .. } => {
+			Message::Pending { .. } => Err(Error::<T>::RequestPending),
+			Message::Complete { origin: initiator, transport, message_deposit, .. } => {
 				frame_support::ensure!(origin.address == initiator, BadOrigin);
 				Messages::<T>::remove(id);
-				IsmpRequests::<T>::remove(commitment);
-				Ok((message_deposit, None))
+				MessageTransfers::<T>::remove(id);
+				OriginMessages::<T>::remove(initiator, id);
+				TransportMessages::<T>::remove(transport);
+				Ok((message_deposit, cancel_deferred_callback::<T>(id)))
 			},
-			Message::IsmpTimeout {
+			Message::Timeout {
 				origin: initiator,
+				transport,
 				message_deposit,
-				commitment,
 				callback_deposit,
-				..
 			} => {
 				frame_support::ensure!(origin.address == initiator, BadOrigin);
 				Messages::<T>::remove(id);
-				IsmpRequests::<T>::remove(commitment);
-				Ok((message_deposit, callback_deposit))
-			},
-			Message::XcmResponse { origin: initiator, message_deposit, query_id, .. } => {
-				frame_support::ensure!(origin.address == initiator, BadOrigin);
-				Messages::<T>::remove(id);
-				XcmQueries::<T>::remove(query_id);
-				Ok((message_deposit, None))
-			},
-			Message::XcmTimeout {
-				origin: initiator,
-				query_id,
-				message_deposit,
-				callback_deposit,
-				..
-			} => {
-				frame_support::ensure!(origin.address == initiator, BadOrigin);
-				Messages::<T>::remove(id);
-				XcmQueries::<T>::remove(query_id);
+				MessageTransfers::<T>::remove(id);
+				OriginMessages::<T>::remove(initiator, id);
+				TransportMessages::<T>::remove(transport);
 				Ok((message_deposit, callback_deposit))
 			},
 		}?;
@@ -693,6 +1320,393 @@ fn remove<T: Config>(origin: Origin<T>, messages: &[MessageId]) -> DispatchResul
 	Ok(())
 }
 
+/// Offer the ownership of a message to another account.
+///
+/// The ownership is only transferred once accepted by the new owner, replacing any previous offer.
+/// Offering a message to its origin withdraws any pending offer.
+///
+/// # Parameters
+/// - `origin`: The account offering the message, which must be the origin of the message.
+/// - `id`: The message identifier.
+/// - `new_owner`: The address of the new owner of the message.
+fn transfer_message<T: Config>(
+	origin: Origin<T>,
+	id: MessageId,
+	new_owner: H160,
+) -> DispatchResult {
+	let message = Messages::<T>::get(id).ok_or(Error::<T>::MessageNotFound)?;
+	ensure!(origin.address == *message.origin(), BadOrigin);
+	if origin.address == new_owner {
+		MessageTransfers::<T>::remove(id);
+	} else {
+		MessageTransfers::<T>::insert(id, (origin, new_owner));
+	}
+	Ok(())
+}
+
+/// Accept the ownership of a message offered to the caller.
+///
+/// The new owner becomes the origin of the message, with the deposits held for the message and the
+/// execution of any callback being moved to it. A pending message counts towards the pending
+/// messages of the new owner.
+///
+/// Returns the address of the previous owner of the message.
+///
+/// # Parameters
+/// - `origin`: The account accepting the message, which must have been offered its ownership.
+/// - `id`: The message identifier.
+fn accept_message<T: Config>(origin: Origin<T>, id: MessageId) -> Result<H160, DispatchError> {
+	let mut message = Messages::<T>::get(id).ok_or(Error::<T>::MessageNotFound)?;
+	let (previous_owner, new_owner) = MessageTransfers::<T>::get(id).ok_or(BadOrigin)?;
+	ensure!(origin.address == new_owner, BadOrigin);
+
+	let (message_deposit, callback_deposit, response_fee) = match &mut message {
+		Message::Pending { origin: owner, transport, callback, message_deposit } => {
+			transports::add_pending::<T>(&origin.address)?;
+			transports::conclude_pending::<T>(&owner.address);
+			*owner = origin.clone();
+			(
+				*message_deposit,
+				callback.as_ref().map(|cb| T::WeightToFee::weight_to_fee(&cb.gas_limit)),
+				transport
+					.key::<T, XcmTransport>()
+					.and_then(|query_id| XcmResponseFees::<T>::get(query_id)),
+			)
+		},
+		Message::Complete { origin: owner, message_deposit, .. } => {
+			*owner = origin.address;
+			// Any deferred callback is executed on behalf of the new owner.
+			let callback_deposit = DeferredCallbacks::<T>::mutate(id, |deferred| {
+				deferred.as_mut().map(|deferred| {
+					deferred.origin = origin.account.clone();
+					T::WeightToFee::weight_to_fee(&deferred.callback.gas_limit)
+				})
+			});
+			(*message_deposit, callback_deposit, None)
+		},
+		Message::Timeout { origin: owner, message_deposit, callback_deposit, .. } => {
+			*owner = origin.address;
+			(*message_deposit, *callback_deposit, None)
+		},
+	};
+	Messages::<T>::insert(id, message);
+	MessageTransfers::<T>::remove(id);
+	OriginMessages::<T>::remove(previous_owner.address, id);
+	OriginMessages::<T>::insert(origin.address, id, ());
+
+	for (reason, amount) in [
+		(HoldReason::Messaging, Some(message_deposit)),
+		(HoldReason::CallbackGas, callback_deposit),
+		(HoldReason::ResponseFee, response_fee),
+	] {
+		let Some(amount) = amount.filter(|amount| !amount.is_zero()) else { continue };
+		T::Fungibles::transfer_on_hold(
+			&reason.into(),
+			&previous_owner.account,
+			&origin.account,
+			amount,
+			Precision::Exact,
+			Restriction::OnHold,
+			Fortitude::Polite,
+		)?;
+	}
+	Ok(previous_owner.address)
+}
+
+/// Update the callback of a pending message.
+///
+/// The deposit held for the execution of the callback is adjusted to that of the new callback.
+///
+/// # Parameters
+/// - `origin`: The account updating the callback, which must be the origin of the message.
+/// - `id`: The message identifier.
+/// - `callback`: The callback to invoke upon receiving a response.
+fn update_callback<T: Config>(
+	origin: Origin<T>,
+	id: MessageId,
+	callback: Callback<BalanceOf<T>>,
+) -> DispatchResult {
+	Messages::<T>::try_mutate(id, |message| {
+		let message = message.as_mut().ok_or(Error::<T>::MessageNotFound)?;
+		ensure!(origin.address == *message.origin(), BadOrigin);
+		let current = match message {
+			Message::Pending { callback, .. } => Ok(callback),
+			Message::Complete { .. } => Err(Error::<T>::MessageCompleted),
+			Message::Timeout { .. } => Err(Error::<T>::RequestTimedOut),
+		}?;
+
+		let held = current
+			.as_ref()
+			.map_or(Zero::zero(), |cb| T::WeightToFee::weight_to_fee(&cb.gas_limit));
+		let required = T::WeightToFee::weight_to_fee(&callback.gas_limit);
+		if required > held {
+			T::Fungibles::hold(
+				&HoldReason::CallbackGas.into(),
+				&origin.account,
+				required.saturating_sub(held),
+			)?;
+		} else if held > required {
+			T::Fungibles::release(
+				&HoldReason::CallbackGas.into(),
+				&origin.account,
+				held.saturating_sub(required),
+				Precision::Exact,
+			)?;
+		}
+		*current = Some(callback);
+		Ok(())
+	})
+}
+
+/// Cancels the deferred callback of a message, if any, returning the deposit held for its
+/// execution.
+///
+/// # Parameters
+/// - `id`: The message identifier.
+fn cancel_deferred_callback<T: Config>(id: &MessageId) -> Option<BalanceOf<T>> {
+	let deferred = DeferredCallbacks::<T>::take(id)?;
+	DeferredCallbackQueue::<T>::mutate(|queue| queue.retain(|i| i != id));
+	Some(T::WeightToFee::weight_to_fee(&deferred.callback.gas_limit))
+}
+
+/// Times out a pending XCM query.
+///
+/// Returns the identifier of the XCM query if the message was timed out, with messages which
+/// have since completed or been removed being ignored.
+///
+/// # Parameters
+/// - `id`: The message identifier.
+fn timeout_xcm_query<T: Config>(id: MessageId) -> Option<QueryId> {
+	let Some(Message::Pending { origin, transport, .. }) = Messages::<T>::get(id) else {
+		return None;
+	};
+	let query_id = transport.key::<T, XcmTransport>()?;
+	transports::time_out::<T, XcmTransport>(&query_id).ok()?;
+	if let Err(error) = transports::xcm::settle_response_fee::<T>(&query_id, &origin.account, None)
+	{
+		log::error!(target: "pop-api::extension", "failed to release response fee: query_id={query_id}, error={error:?}");
+	}
+	Some(query_id)
+}
+
+/// Times out XCM queries which exceeded the maximum number of timeouts of their block, in the
+/// order in which their blocks became due, within the weight limit.
+///
+/// Returns the weight used.
+///
+/// # Parameters
+/// - `limit`: The maximum weight to be used.
+pub(crate) fn timeout_overflowing_xcm_queries<T: Config>(limit: Weight) -> Weight {
+	let mut meter = WeightMeter::with_limit(limit);
+	if meter.try_consume(DbWeightOf::<T>::get().reads(1)).is_err() {
+		return Weight::zero();
+	}
+
+	let (start, tail) = XcmQueryTimeoutOverflowQueue::<T>::get();
+	let mut head = start;
+	let timeout_weight = T::WeightInfo::xcm_timeout_query();
+	// A block is removed from the queue once all of its timeouts have been processed.
+	let block_weight = DbWeightOf::<T>::get().reads_writes(2, 1);
+	// The overflow queue is updated if any blocks are processed.
+	let update_weight = DbWeightOf::<T>::get().writes(1);
+	let mut query_ids = Vec::new();
+	'blocks: while head < tail {
+		if !meter.can_consume(block_weight.saturating_add(update_weight)) {
+			break;
+		}
+		let Some(block) = XcmQueryTimeoutOverflowBlocks::<T>::get(head) else {
+			// Should not be possible, but ensures the queue cannot become blocked.
+			meter.consume(DbWeightOf::<T>::get().reads(1));
+			head.saturating_inc();
+			continue;
+		};
+		// Entries are only removed from storage as the iterator advances.
+		let mut timeouts = XcmQueryTimeoutOverflow::<T>::drain_prefix(block);
+		loop {
+			if !meter.can_consume(timeout_weight.saturating_add(block_weight)) {
+				break 'blocks;
+			}
+			let Some((id, ())) = timeouts.next() else { break };
+			meter.consume(timeout_weight);
+			if let Some(query_id) = timeout_xcm_query::<T>(id) {
+				query_ids.push(query_id);
+			}
+		}
+		meter.consume(block_weight);
+		XcmQueryTimeoutOverflowBlocks::<T>::remove(head);
+		head.saturating_inc();
+	}
+
+	if head != start {
+		XcmQueryTimeoutOverflowQueue::<T>::put((head, tail));
+		meter.consume(update_weight);
+	}
+	if !query_ids.is_empty() {
+		<Pallet<T>>::deposit_event(Event::<T>::XcmQueriesTimedOut { query_ids });
+	}
+	meter.consumed()
+}
+
+/// Dispatches the requests of subscriptions which are due, in the order in which their blocks
+/// became due, within the weight limit.
+///
+/// Returns the weight used.
+///
+/// # Parameters
+/// - `limit`: The maximum weight to be used.
+pub(crate) fn dispatch_subscriptions<T: Config>(limit: Weight) -> Weight {
+	let mut meter = WeightMeter::with_limit(limit);
+	if meter.try_consume(DbWeightOf::<T>::get().reads(1)).is_err() {
+		return Weight::zero();
+	}
+
+	let (start, tail) = SubscriptionScheduleQueue::<T>::get();
+	let mut head = start;
+	let dispatch_weight = T::WeightInfo::ismp_dispatch_subscription();
+	// A block is removed from the queue once all of its executions have been processed.
+	let block_weight = DbWeightOf::<T>::get().reads_writes(2, 1);
+	// The schedule queue is updated if any blocks are processed.
+	let update_weight = DbWeightOf::<T>::get().writes(1);
+	'blocks: while head < tail {
+		if !meter.can_consume(block_weight.saturating_add(update_weight)) {
+			break;
+		}
+		let Some(block) = SubscriptionScheduleBlocks::<T>::get(head) else {
+			// Should not be possible, but ensures the queue cannot become blocked.
+			meter.consume(DbWeightOf::<T>::get().reads(1));
+			head.saturating_inc();
+			continue;
+		};
+		// Entries are only removed from storage as the iterator advances. Executions are
+		// rescheduled at a later block, so cannot be added to the block being drained.
+		let mut executions = SubscriptionSchedule::<T>::drain_prefix(block);
+		loop {
+			if !meter.can_consume(dispatch_weight.saturating_add(block_weight)) {
+				break 'blocks;
+			}
+			let Some((id, ())) = executions.next() else { break };
+			meter.consume(dispatch_weight);
+			transports::ismp::execute_subscription::<T>(id);
+		}
+		meter.consume(block_weight);
+		SubscriptionScheduleBlocks::<T>::remove(head);
+		head.saturating_inc();
+	}
+
+	if head != start {
+		SubscriptionScheduleQueue::<T>::put((head, tail));
+		meter.consume(update_weight);
+	}
+	meter.consumed()
+}
+
+/// Schedules the expiry of a completed or timed out message, after the retention period.
+///
+/// # Parameters
+/// - `id`: The message identifier.
+pub(crate) fn schedule_expiry<T: Config>(id: MessageId) {
+	let expires_at =
+		frame_system::Pallet::<T>::block_number().saturating_add(T::MessageRetentionPeriod::get());
+	MessageExpiryQueue::<T>::mutate(|(_, tail)| {
+		MessageExpiries::<T>::insert(*tail, (id, expires_at));
+		tail.saturating_inc();
+	});
+}
+
+/// Removes messages which have expired, in the order in which they were scheduled to expire,
+/// within the weight limit.
+///
+/// Returns the weight used.
+///
+/// # Parameters
+/// - `now`: The current block number.
+/// - `limit`: The maximum weight to be used.
+pub(crate) fn expire_messages<T: Config>(now: BlockNumberOf<T>, limit: Weight) -> Weight {
+	let mut meter = WeightMeter::with_limit(limit);
+	if meter.try_consume(DbWeightOf::<T>::get().reads(1)).is_err() {
+		return Weight::zero();
+	}
+
+	let (start, tail) = MessageExpiryQueue::<T>::get();
+	let mut head = start;
+	let expire_weight = T::WeightInfo::expire_message();
+	// The expiry queue is updated if any messages are processed.
+	let update_weight = DbWeightOf::<T>::get().writes(1);
+	let mut expired = Vec::new();
+	while head < tail {
+		if !meter.can_consume(expire_weight.saturating_add(update_weight)) {
+			break;
+		}
+		let Some((id, expires_at)) = MessageExpiries::<T>::get(head) else {
+			// Should not be possible, but ensures the queue cannot become blocked.
+			meter.consume(DbWeightOf::<T>::get().reads(1));
+			head.saturating_inc();
+			continue;
+		};
+		if expires_at > now {
+			meter.consume(DbWeightOf::<T>::get().reads(1));
+			break;
+		}
+		meter.consume(expire_weight);
+		MessageExpiries::<T>::remove(head);
+		head.saturating_inc();
+		if expire::<T>(&id) {
+			expired.push(id);
+		}
+	}
+
+	if head != start {
+		MessageExpiryQueue::<T>::put((head, tail));
+		meter.consume(update_weight);
+	}
+	if !expired.is_empty() {
+		<Pallet<T>>::deposit_event(Event::<T>::MessagesExpired { messages: expired });
+	}
+	meter.consumed()
+}
+
+/// Removes an expired message, releasing its deposits to its origin.
+///
+/// Returns whether the message was removed, with messages which have already been removed by
+/// their origin or are still pending being ignored.
+///
+/// # Parameters
+/// - `id`: The message identifier.
+fn expire<T: Config>(id: &MessageId) -> bool {
+	let (origin, transport, message_deposit, callback_deposit) = match Messages::<T>::get(id) {
+		Some(Message::Complete { origin, transport, message_deposit, .. }) =>
+			(origin, transport, message_deposit, cancel_deferred_callback::<T>(id)),
+		Some(Message::Timeout { origin, transport, message_deposit, callback_deposit }) =>
+			(origin, transport, message_deposit, callback_deposit),
+		Some(Message::Pending { .. }) | None => return false,
+	};
+	TransportMessages::<T>::remove(transport);
+	Messages::<T>::remove(id);
+	MessageTransfers::<T>::remove(id);
+	OriginMessages::<T>::remove(origin, id);
+
+	let account = T::AddressConverter::convert(origin);
+	if let Err(error) = T::Fungibles::release(
+		&HoldReason::Messaging.into(),
+		&account,
+		message_deposit,
+		Precision::BestEffort,
+	) {
+		log::error!(target: "pop-api::extension", "failed to release message deposit: id={id}, error={error:?}");
+	}
+	if let Some(callback_deposit) = callback_deposit {
+		if let Err(error) = T::Fungibles::release(
+			&HoldReason::CallbackGas.into(),
+			&account,
+			callback_deposit,
+			Precision::BestEffort,
+		) {
+			log::error!(target: "pop-api::extension", "failed to release callback deposit: id={id}, error={error:?}");
+		}
+	}
+	true
+}
+
 /// A message callback.
 #[derive(
 	Copy,
@@ -740,6 +1754,39 @@ impl<Balance> Callback<Balance> {
 	}
 }
 
+/// A callback deferred due to a lack of blockspace, to be retried in a later block.
+#[derive(Clone, Debug, Encode, Eq, Decode, MaxEncodedLen, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct DeferredCallback<T: Config> {
+	/// The account which initiated the message.
+	pub(crate) origin: T::AccountId,
+	/// The callback definition.
+	pub(crate) callback: Callback<BalanceOf<T>>,
+	/// The encoded input of the callback.
+	pub(crate) input: BoundedVec<u8, MaxCallbackInputLen<T>>,
+	/// The number of times the callback has been retried.
+	pub(crate) retries: u32,
+}
+
+/// The maximum length of the encoded input of a deferred callback: the maximum length of a
+/// response, along with an allowance for the selector, message identifier and any encoding
+/// overhead.
+pub struct MaxCallbackInputLen<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for MaxCallbackInputLen<T> {
+	fn get() -> u32 {
+		T::MaxResponseLen::get().saturating_add(256)
+	}
+}
+
+/// Converts the address of a message origin to its account, using the address mapper of
+/// `pallet-revive`.
+pub struct AddressToAccountId<T>(PhantomData<T>);
+impl<T: pallet_revive::Config> Convert<H160, T::AccountId> for AddressToAccountId<T> {
+	fn convert(address: H160) -> T::AccountId {
+		<T::AddressMapper as pallet_revive::AddressMapper<T>>::to_account_id(&address)
+	}
+}
+
 /// The trait responsible for executing callbacks in response to cross-chain messages.
 ///
 /// Implementors of this trait define the mechanism by which callback data is executed
@@ -766,6 +1813,24 @@ pub trait CallbackExecutor<T: Config> {
 		storage_deposit_limit: BalanceOf<T>,
 	) -> DispatchResultWithPostInfo;
 
+	/// Execute a call to a contract with the given encoded payload, returning the output of the
+	/// call.
+	///
+	/// # Parameters
+	/// - `account`: The account on whose behalf the call is made.
+	/// - `destination`: The contract address to which the call should be sent.
+	/// - `data`: Encoded call data, typically ABI-encoded input including selector and parameters.
+	/// - `gas_limit`: The gas limit allowed for executing this call.
+	/// - `storage_deposit_limit`: The maximum amount of balance that can be charged from the caller
+	///   to pay for the storage consumed for this call.
+	fn execute_with_output(
+		account: &T::AccountId,
+		destination: H160,
+		data: Vec<u8>,
+		gas_limit: Weight,
+		storage_deposit_limit: BalanceOf<T>,
+	) -> Result<(PostDispatchInfo, Vec<u8>), DispatchErrorWithPostInfo>;
+
 	/// Returns the baseline weight required for a single callback execution.
 	///
 	/// This serves as an overhead estimate, useful for pallet-level weight calculations.
@@ -793,7 +1858,7 @@ pub enum Encoding {
 }
 
 /// Trait for encoding a response callback.
-pub(crate) trait EncodeCallback {
+pub trait EncodeCallback {
 	/// Encodes the data using the specified encoding.
 	///
 	/// # Parameters
@@ -801,11 +1866,18 @@ pub(crate) trait EncodeCallback {
 	/// - `selector`: The message selector to be used for the callback.
 	/// - `id`: The originating message identifier.
 	fn encode(&self, encoding: Encoding, selector: [u8; 4], id: MessageId) -> Vec<u8>;
+
+	/// The exact length of the response once encoded using the specified encoding, excluding the
+	/// selector and message identifier which precede it in the input of a callback.
+	///
+	/// # Parameters
+	/// - `encoding`: The encoding to use.
+	fn encoded_len(&self, encoding: Encoding) -> usize;
 }
 
-/// Represents a cross-chain message in the system.
+/// Represents a cross-chain message in the system, sent via any of the transports.
 ///
-/// Each variant of this enum captures a different state or type of message lifecycle:
+/// Each variant of this enum captures a different state of the message lifecycle:
 /// - A request in progress.
 /// - A response received.
 /// - A timeout occurred.
@@ -815,90 +1887,92 @@ pub(crate) trait EncodeCallback {
 #[derive(Clone, Debug, Encode, Eq, Decode, MaxEncodedLen, PartialEq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub enum Message<T: Config> {
-	/// Represents a pending ISMP request.
+	/// Represents a pending request.
 	///
 	/// # Fields
 	/// - `origin`: The origin of the request.
-	/// - `commitment`: The cryptographic commitment of the request payload.
+	/// - `transport`: The transport of the request and its key on the transport.
 	/// - `callback`: An optional callback to invoke upon receiving a response.
-	/// - `deposit`: The total deposit held to cover message and callback fees.
-	Ismp {
-		origin: Origin<T>,
-		commitment: H256,
-		callback: Option<Callback<BalanceOf<T>>>,
-		message_deposit: BalanceOf<T>,
-	},
-
-	/// Represents a pending XCM query request.
-	///
-	/// # Fields
-	/// - `origin`: The origin of the request.
-	/// - `query_id`: Unique identifier for the XCM query.
-	/// - `callback`: An optional callback for handling the response.
-	/// - `deposit`: The deposit held to cover fees for query execution and callback.
-	XcmQuery {
+	/// - `message_deposit`: The deposit held for the message.
+	Pending {
 		origin: Origin<T>,
-		query_id: QueryId,
+		transport: MessageTransport,
 		callback: Option<Callback<BalanceOf<T>>>,
 		message_deposit: BalanceOf<T>,
 	},
 
-	/// Represents a received ISMP response.
+	/// Represents a received response.
 	///
 	/// # Fields
 	/// - `origin`: The origin of the request.
-	/// - `commitment`: The original commitment for the request.
-	/// - `deposit`: The held deposit for the message, which may be released or burned.
+	/// - `transport`: The transport of the request and its key on the transport.
+	/// - `message_deposit`: The deposit held for the message, which may be reclaimed.
 	/// - `response`: The encoded response payload, size-bounded by `T::MaxResponseLen`.
-	IsmpResponse {
+	Complete {
 		origin: H160,
-		commitment: H256,
+		transport: MessageTransport,
 		message_deposit: BalanceOf<T>,
 		response: BoundedVec<u8, T::MaxResponseLen>,
 	},
 
-	/// Represents a received XCM response.
-	///
-	/// # Fields
-	/// - `origin`: The origin of the request.
-	/// - `query_id`: Identifier that matches a previously sent XCM query.
-	/// - `deposit`: The deposit originally held for this message.
-	/// - `response`: The deserialized response payload.
-	XcmResponse {
-		origin: H160,
-		query_id: QueryId,
-		message_deposit: BalanceOf<T>,
-		response: Response,
-	},
-
-	/// Represents an ISMP request that timed out before a response was received.
+	/// Represents a request that timed out before a response was received.
 	///
 	/// # Fields
 	/// - `origin`: The origin of the request.
-	/// - `commitment`: The original commitment of the request.
-	/// - `deposit`: The deposit held for the request, which may be reclaimed.
-	IsmpTimeout {
+	/// - `transport`: The transport of the request and its key on the transport.
+	/// - `message_deposit`: The deposit held for the message, which may be reclaimed.
+	/// - `callback_deposit`: The deposit held for the execution of any callback, which may be
+	///   reclaimed.
+	Timeout {
 		origin: H160,
-		commitment: H256,
-		message_deposit: BalanceOf<T>,
-		callback_deposit: Option<BalanceOf<T>>,
-	},
-
-	/// Represents an XCM query that timed out before a response was received.
-	///
-	/// # Fields
-	/// - `origin`: The origin of the request.
-	/// - `query_id`: The original query ID that timed out.
-	/// - `deposit`: The deposit held for the query, which may be reclaimed.
-	XcmTimeout {
-		origin: H160,
-		query_id: QueryId,
+		transport: MessageTransport,
 		message_deposit: BalanceOf<T>,
 		callback_deposit: Option<BalanceOf<T>>,
 	},
 }
 
 impl<T: Config> Message<T> {
+	/// The address of the origin of the message.
+	pub(crate) fn origin(&self) -> &H160 {
+		match self {
+			Message::Pending { origin, .. } => &origin.address,
+			Message::Complete { origin, .. } | Message::Timeout { origin, .. } => origin,
+		}
+	}
+
+	/// The transport of the message and its key on the transport.
+	pub(crate) fn transport(&self) -> &MessageTransport {
+		match self {
+			Message::Pending { transport, .. } |
+			Message::Complete { transport, .. } |
+			Message::Timeout { transport, .. } => transport,
+		}
+	}
+
+	/// The details of the message.
+	///
+	/// # Parameters
+	/// - `id`: The message identifier.
+	fn details(&self, id: MessageId) -> MessageDetails<BalanceOf<T>> {
+		let (message_deposit, callback_deposit) = match self {
+			Message::Pending { callback, message_deposit, .. } => (
+				*message_deposit,
+				callback.as_ref().map(|cb| T::WeightToFee::weight_to_fee(&cb.gas_limit)),
+			),
+			Message::Complete { message_deposit, .. } => (*message_deposit, None),
+			Message::Timeout { message_deposit, callback_deposit, .. } =>
+				(*message_deposit, *callback_deposit),
+		};
+		MessageDetails {
+			id,
+			origin: *self.origin(),
+			transport: TransportId::of::<T>(self.transport()),
+			status: self.into(),
+			message_deposit,
+			callback_deposit,
+		}
+	}
+
 	#[cfg(test)]
 	fn ismp(
 		origin: Origin<T>,
@@ -906,7 +1980,8 @@ impl<T: Config> Message<T> {
 		callback: Option<Callback<BalanceOf<T>>>,
 		message_deposit: BalanceOf<T>,
 	) -> Self {
-		Self::Ismp { origin, commitment, callback, message_deposit }
+		let transport = MessageTransport::ismp::<T>(commitment);
+		Self::Pending { origin, transport, callback, message_deposit }
 	}
 
 	#[cfg(any(test, feature = "runtime-benchmarks"))]
@@ -916,7 +1991,8 @@ impl<T: Config> Message<T> {
 		message_deposit: BalanceOf<T>,
 		response: BoundedVec<u8, T::MaxResponseLen>,
 	) -> Self {
-		Self::IsmpResponse { origin, commitment, message_deposit, response }
+		let transport = MessageTransport::ismp::<T>(commitment);
+		Self::Complete { origin, transport, message_deposit, response }
 	}
 
 	#[cfg(any(test, feature = "runtime-benchmarks"))]
@@ -926,17 +2002,19 @@ impl<T: Config> Message<T> {
 		message_deposit: BalanceOf<T>,
 		callback_deposit: Option<BalanceOf<T>>,
 	) -> Self {
-		Self::IsmpTimeout { origin, commitment, message_deposit, callback_deposit }
+		let transport = MessageTransport::ismp::<T>(commitment);
+		Self::Timeout { origin, transport, message_deposit, callback_deposit }
 	}
 
-	#[cfg(test)]
+	#[cfg(any(test, feature = "runtime-benchmarks"))]
 	fn xcm_query(
 		origin: Origin<T>,
 		query_id: QueryId,
 		callback: Option<Callback<BalanceOf<T>>>,
 		message_deposit: BalanceOf<T>,
 	) -> Self {
-		Self::XcmQuery { origin, query_id, callback, message_deposit }
+		let transport = MessageTransport::xcm::<T>(query_id);
+		Self::Pending { origin, transport, callback, message_deposit }
 	}
 
 	#[cfg(test)]
@@ -946,7 +2024,9 @@ impl<T: Config> Message<T> {
 		message_deposit: BalanceOf<T>,
 		response: Response,
 	) -> Self {
-		Self::XcmResponse { origin, query_id, message_deposit, response }
+		let transport = MessageTransport::xcm::<T>(query_id);
+		let response = BoundedVec::truncate_from(response.encode());
+		Self::Complete { origin, transport, message_deposit, response }
 	}
 
 	#[cfg(test)]
@@ -956,28 +2036,103 @@ impl<T: Config> Message<T> {
 		message_deposit: BalanceOf<T>,
 		callback_deposit: Option<BalanceOf<T>>,
 	) -> Self {
-		Self::XcmTimeout { origin, query_id, message_deposit, callback_deposit }
+		let transport = MessageTransport::xcm::<T>(query_id);
+		Self::Timeout { origin, transport, message_deposit, callback_deposit }
 	}
 }
 
 /// The related message status of a Message.
 #[derive(Clone, Debug, Encode, Eq, Decode, MaxEncodedLen, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub enum MessageStatus {
 	NotFound,
 	Pending,
 	Complete,
 	Timeout,
+	/// A request of a batch whose response could not be included in the response to the batch.
+	Failed,
 }
 
 impl<T: Config> From<&Message<T>> for MessageStatus {
 	fn from(value: &Message<T>) -> Self {
 		match *value {
-			Message::Ismp { .. } => MessageStatus::Pending,
-			Message::XcmQuery { .. } => MessageStatus::Pending,
-			Message::IsmpResponse { .. } => MessageStatus::Complete,
-			Message::XcmResponse { .. } => MessageStatus::Complete,
-			Message::IsmpTimeout { .. } => MessageStatus::Timeout,
-			Message::XcmTimeout { .. } => MessageStatus::Timeout,
+			Message::Pending { .. } => MessageStatus::Pending,
+			Message::Complete { .. } => MessageStatus::Complete,
+			Message::Timeout { .. } => MessageStatus::Timeout,
+		}
+	}
+}
+
+/// The details of a message, as exposed via the [`runtime_api::MessagingApi`].
+#[derive(Clone, Debug, Encode, Eq, Decode, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct MessageDetails<Balance> {
+	/// The message identifier.
+	pub id: MessageId,
+	/// The address of the origin of the message.
+	pub origin: H160,
+	/// The identifier of the message on its transport.
+	pub transport: TransportId,
+	/// The status of the message. Completed and timed out messages can be removed by their
+	/// origin to reclaim deposits.
+	pub status: MessageStatus,
+	/// The deposit held for the message.
+	pub message_deposit: Balance,
+	/// The deposit held for the execution of the callback, if any.
+	pub callback_deposit: Option<Balance>,
+}
+
+/// The amounts taken for a new message, as exposed via the precompiles and the
+/// [`runtime_api::MessagingApi`].
+#[derive(Clone, Debug, Default, Encode, Eq, Decode, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct MessageQuote<Balance> {
+	/// The deposit held for the message, which is released once the message is removed.
+	pub message_deposit: Balance,
+	/// The fee prepaid for the handling of the response, which is not refunded.
+	pub prepaid_fee: Balance,
+	/// The deposit held for the execution of the callback, which is zero without a callback.
+	pub callback_deposit: Balance,
+}
+
+impl<Balance: Saturating> MessageQuote<Balance> {
+	/// The total amount taken for the message.
+	pub fn total(self) -> Balance {
+		self.message_deposit
+			.saturating_add(self.prepaid_fee)
+			.saturating_add(self.callback_deposit)
+	}
+}
+
+/// The identifier of a message on its transport.
+#[derive(Clone, Debug, Encode, Eq, Decode, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub enum TransportId {
+	/// The commitment of an ISMP request.
+	Ismp(H256),
+	/// The identifier of an XCM query.
+	Xcm(QueryId),
+	/// The key of a message sent via a transport registered by the runtime.
+	Other {
+		/// The index of the transport.
+		index: TransportIndex,
+		/// The encoded key of the message on the transport.
+		key: Vec<u8>,
+	},
+}
+
+impl TransportId {
+	/// The identifier of a message on its transport.
+	///
+	/// # Parameters
+	/// - `transport`: The transport of the message and its key on the transport.
+	fn of<T: Config>(transport: &MessageTransport) -> Self {
+		if let Some(commitment) = transport.key::<T, IsmpTransport>() {
+			return TransportId::Ismp(commitment);
+		}
+		if let Some(query_id) = transport.key::<T, XcmTransport>() {
+			return TransportId::Xcm(query_id);
 		}
+		TransportId::Other { index: transport.index, key: transport.key.to_vec() }
 	}
 }
@@ -1,18 +1,22 @@
 //! Benchmarking setup for pallet_api::messaging
 
-use alloc::{vec, vec::Vec};
+use alloc::{string::ToString, vec, vec::Vec};
 use core::u64;
 
 use ::ismp::{
+	consensus::StateMachineId,
 	dispatcher::{DispatchGet, DispatchPost},
-	host::StateMachine,
+	host::{IsmpHost, StateMachine},
+	messaging::hash_request,
 	module::IsmpModule,
 	router::{
 		GetRequest, GetResponse, PostRequest, PostResponse, Request, Response, StorageValue,
 		Timeout,
 	},
 };
-use ::xcm::latest::{Junctions, Location, Response::ExecutionResult};
+use ::xcm::latest::{
+	Junctions, Location, PalletInfo, Response as XcmResponse, MAX_PALLET_NAME_LEN,
+};
 use codec::Encode;
 use frame_benchmarking::v2::*;
 use frame_support::{
@@ -22,32 +26,42 @@ use frame_support::{
 		fungible::{Inspect, Mutate, MutateHold},
 		EnsureOrigin, Get, Time,
 	},
+	weights::WeightToFee,
+	BoundedVec,
 };
 use pallet_revive::{
 	precompiles::{
 		alloy::primitives as alloy,
 		run::{H256, U256},
 	},
-	test_utils::ALICE_ADDR,
+	test_utils::{ALICE_ADDR, BOB_ADDR},
 	Origin::Signed,
 };
 use sp_io::hashing::blake2_256;
 use sp_runtime::traits::Bounded;
 
 use super::{
-	call_precompile,
+	call_precompile, dispatch_subscriptions, expire_messages,
 	precompiles::{
 		ismp::v0::{self as ismp, IISMPCalls, IISMP},
 		v0::{IMessaging, IMessagingCalls},
 		xcm::v0::{self as xcm, BlockNumberOf, IXCMCalls, IXCM},
 	},
-	set_up_call,
+	retry_deferred_callbacks, schedule_expiry, set_up_call, timeout_overflowing_xcm_queries,
 	transports::{
-		ismp::{get, post, Module, ID},
+		ismp::{
+			allow_source, get, get_batch, post, source_key, subscribe, GetResult, GetTemplate,
+			IsmpBatchTransport, Module, Subscription, ID,
+		},
 		xcm::new_query,
 	},
-	Call, Callback, Config, Encoding, Event, HoldReason, IsmpRequests, Message, MessageId,
-	Messages, Origin, Pallet,
+	Call, Callback, Config, DeferredCallback, DeferredCallbackQueue, DeferredCallbacks, Encoding,
+	Event, HoldReason, IsmpPostRequestSources, MaxCallbackInputLen, Message, MessageExpiryQueue,
+	MessageId, MessageStatus, MessageTransfers, MessageTransport, Messages, Origin, Pallet,
+	PendingMessageQuotas, PendingMessages, PendingXcmQueries, SubscriptionId,
+	SubscriptionScheduleBlocks, SubscriptionScheduleQueue, Subscriptions, TransportMessages,
+	XcmQueryTimeoutOverflow, XcmQueryTimeoutOverflowBlocks, XcmQueryTimeoutOverflowQueue,
+	XcmQueryTimeouts,
 };
 #[cfg(test)]
 use crate::mock::{ExtBuilder, Test};
@@ -78,6 +92,65 @@ type Xcm<T> = super::precompiles::xcm::v0::Xcm<5, T>;
 mod benchmarks {
 	use super::*;
 
+	/// Accepts the ownership of a message.
+	///
+	/// No benchmark input parameters. A pending message with a callback is accepted, as the most
+	/// expensive case: its deposits for both the message and the callback are moved and it is
+	/// accounted towards the pending messages of the new owner.
+	#[benchmark]
+	fn accept_message() {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let new_owner = Origin::<T>::from_address(BOB_ADDR);
+		let message = 1;
+		let message_deposit = 50_000u32.into();
+		let callback = Callback {
+			destination: [255; 20].into(),
+			encoding: Encoding::Scale,
+			selector: [0; 4],
+			gas_limit: Weight::from_parts(100, 100),
+			storage_deposit_limit: 100u8.into(),
+		};
+
+		<Balances<T>>::set_balance(&origin.account, u32::MAX.into());
+		<Balances<T>>::set_balance(&new_owner.account, u32::MAX.into());
+		assert_ok!(T::Fungibles::hold(
+			&HoldReason::Messaging.into(),
+			&origin.account,
+			message_deposit
+		));
+		assert_ok!(T::Fungibles::hold(
+			&HoldReason::CallbackGas.into(),
+			&origin.account,
+			T::WeightToFee::weight_to_fee(&callback.gas_limit)
+		));
+		Messages::<T>::insert(
+			message,
+			Message::xcm_query(origin.clone(), 0, Some(callback), message_deposit),
+		);
+		TransportMessages::<T>::insert(MessageTransport::xcm::<T>(0), message);
+		PendingMessages::<T>::insert(origin.address, 1);
+		MessageTransfers::<T>::insert(message, (origin, new_owner.address));
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Signed(new_owner.account.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = IMessagingCalls::acceptMessage(IMessaging::acceptMessageCall { message });
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Messaging<T>, _, ()>(
+				&mut ext,
+				&Messaging::<T>::address(),
+				&input
+			));
+		}
+
+		assert!(matches!(
+			Messages::<T>::get(message),
+			Some(Message::Pending { origin, .. }) if origin == new_owner
+		));
+	}
+
 	#[benchmark]
 	fn block_number() {
 		let mut call_setup = set_up_call();
@@ -90,6 +163,67 @@ mod benchmarks {
 		}
 	}
 
+	/// No benchmark input parameters. A response with a deferred callback of the maximum input
+	/// length is expired, cancelling the callback.
+	#[benchmark]
+	fn expire_message() {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let message = 1;
+		let commitment: H256 = [255; 32].into();
+		let message_deposit = 50_000u32.into();
+		let callback = Callback {
+			destination: [255; 20].into(),
+			encoding: Encoding::Scale,
+			selector: [0; 4],
+			gas_limit: Weight::from_parts(100, 100),
+			storage_deposit_limit: 100u8.into(),
+		};
+
+		<Balances<T>>::set_balance(&origin.account, u32::MAX.into());
+		assert_ok!(T::Fungibles::hold(
+			&HoldReason::Messaging.into(),
+			&origin.account,
+			message_deposit
+		));
+		assert_ok!(T::Fungibles::hold(
+			&HoldReason::CallbackGas.into(),
+			&origin.account,
+			T::WeightToFee::weight_to_fee(&callback.gas_limit)
+		));
+		Messages::<T>::insert(
+			message,
+			Message::ismp_response(
+				origin.address,
+				commitment,
+				message_deposit,
+				vec![255; T::MaxResponseLen::get() as usize].try_into().unwrap(),
+			),
+		);
+		TransportMessages::<T>::insert(MessageTransport::ismp::<T>(commitment), message);
+		DeferredCallbackQueue::<T>::put(BoundedVec::truncate_from(vec![message]));
+		DeferredCallbacks::<T>::insert(
+			message,
+			DeferredCallback {
+				origin: origin.account.clone(),
+				callback,
+				input: vec![255; MaxCallbackInputLen::<T>::get() as usize].try_into().unwrap(),
+				retries: 0,
+			},
+		);
+		schedule_expiry::<T>(message);
+		let now = frame_system::Pallet::<T>::block_number() + T::MessageRetentionPeriod::get();
+
+		#[block]
+		{
+			expire_messages::<T>(now, Weight::MAX);
+		}
+
+		assert!(Messages::<T>::get(message).is_none());
+		assert!(DeferredCallbackQueue::<T>::get().is_empty());
+		assert_eq!(MessageExpiryQueue::<T>::get(), (1, 1));
+		assert_has_event::<T>(Event::MessagesExpired { messages: vec![message] }.into());
+	}
+
 	#[benchmark]
 	fn get_response() {
 		let origin = Origin::<T>::from_address(ALICE_ADDR);
@@ -136,6 +270,136 @@ mod benchmarks {
 		}
 	}
 
+	/// Allows a contract to receive inbound ISMP `Post` requests from a module on a source state
+	/// machine.
+	#[benchmark]
+	fn ismp_allow_source() {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let source = StateMachine::Polkadot(u32::MAX);
+		let from = [255u8; 32].to_vec();
+
+		<Balances<T>>::set_balance(&origin.account, <Balances<T>>::total_issuance() / 2u32.into());
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Signed(origin.account));
+		let mut ext = call_setup.ext().0;
+		let input = IISMPCalls::allowSource(IISMP::allowSourceCall {
+			source: source.to_string().into_bytes().into(),
+			from: from.clone().into(),
+		});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Ismp<T>, _, ()>(&mut ext, &Ismp::<T>::address(), &input));
+		}
+
+		assert!(IsmpPostRequestSources::<T>::contains_key(
+			origin.address,
+			source_key::<T>(&source, &from)
+		));
+	}
+
+	/// Cancels a subscription to recurring ISMP `Get` requests.
+	///
+	/// No benchmark input parameters. A subscription with the maximum request size is created and
+	/// then cancelled.
+	#[benchmark]
+	fn ismp_cancel_subscription() -> Result<(), BenchmarkError> {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+
+		<Balances<T>>::set_balance(&origin.account, <Balances<T>>::total_issuance() / 2u32.into());
+		let subscription = ismp_subscription::<T>(origin.clone())?;
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Signed(origin.account));
+		let mut ext = call_setup.ext().0;
+		let input = IISMPCalls::cancelSubscription(IISMP::cancelSubscriptionCall { subscription });
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Ismp<T>, _, ()>(&mut ext, &Ismp::<T>::address(), &input));
+		}
+
+		assert!(!Subscriptions::<T>::contains_key(subscription));
+		Ok(())
+	}
+
+	/// Dispatches the `Get` request of a subscription which is due.
+	///
+	/// No benchmark input parameters. A subscription with the maximum request size is created and
+	/// the block of its next execution queued, which is then processed.
+	// IMPORTANT NOTE: `skip_meta` and `pov_mode = Measured` currently used due to the usage of the
+	// `RequestCommitments` storage item within `pallet_ismp`'s child trie. See `ismp_get`.
+	#[benchmark(skip_meta, pov_mode = Measured)]
+	fn ismp_dispatch_subscription() -> Result<(), BenchmarkError> {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+
+		silence_timestamp_genesis_warnings::<T>();
+		<Balances<T>>::set_balance(&origin.account, <Balances<T>>::total_issuance() / 2u32.into());
+		let subscription = ismp_subscription::<T>(origin)?;
+		let Some(Subscription { next, remaining, .. }) = Subscriptions::<T>::get(subscription)
+		else {
+			return Err(BenchmarkError::Stop("subscription not found"));
+		};
+		pallet_ismp::LatestStateMachineHeight::<T>::insert(
+			StateMachineId {
+				state_id: StateMachine::Polkadot(u32::MAX),
+				consensus_state_id: *b"PARA",
+			},
+			u64::MAX,
+		);
+		SubscriptionScheduleBlocks::<T>::insert(0, next);
+		SubscriptionScheduleQueue::<T>::put((0, 1));
+
+		#[block]
+		{
+			dispatch_subscriptions::<T>(Weight::MAX);
+		}
+
+		assert_eq!(SubscriptionScheduleQueue::<T>::get(), (1, 1));
+		assert_eq!(Subscriptions::<T>::get(subscription).map(|s| s.remaining), Some(remaining - 1));
+		Ok(())
+	}
+
+	/// Adds to the fee paid to relayers for an outstanding ISMP request.
+	///
+	/// No benchmark input parameters. A `Get` request is dispatched and then funded.
+	// IMPORTANT NOTE: `skip_meta` and `pov_mode = Measured` currently used due to the usage of the
+	// `RequestCommitments` storage item within `pallet_ismp`'s child trie. See `ismp_get`.
+	#[benchmark(skip_meta, pov_mode = Measured)]
+	fn ismp_fund_request() -> Result<(), BenchmarkError> {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let request = DispatchGet {
+			dest: StateMachine::Polkadot(u32::MAX),
+			from: ID.to_vec(),
+			keys: vec![vec![255u8; T::MaxKeyLen::get() as usize]; T::MaxKeys::get() as usize],
+			height: u64::MAX,
+			context: vec![255u8; T::MaxContextLen::get() as usize],
+			timeout: u64::MAX,
+		};
+		let fee = <Balances<T>>::minimum_balance();
+		let amount = fee
+			.try_convert()
+			.map_err(|_| BenchmarkError::Stop("failed to convert minimum balance to fee"))?;
+
+		silence_timestamp_genesis_warnings::<T>();
+		<Balances<T>>::set_balance(&origin.account, <Balances<T>>::total_issuance() / 2u32.into());
+		let (message, _) = get::<T>(origin.clone(), request, fee, None)
+			.map_err(|_| BenchmarkError::Stop("failed to dispatch request"))?;
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Signed(origin.account));
+		let mut ext = call_setup.ext().0;
+		let input = IISMPCalls::fundRequest(IISMP::fundRequestCall { message, amount });
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Ismp<T>, _, ()>(&mut ext, &Ismp::<T>::address(), &input));
+		}
+
+		Ok(())
+	}
+
 	/// Sends a `Get` request using ISMP with varying context and key sizes.
 	///
 	/// # Parameters
@@ -195,26 +459,139 @@ mod benchmarks {
 		Ok(())
 	}
 
-	/// Handles acceptance of an ISMP POST.
+	/// Sends a batch of `Get` requests using ISMP, each of the maximum size.
+	///
+	/// # Parameters
+	/// - `x`: `Linear<1, { T::MaxBatchSize::get() }>`   Number of requests of the batch.
+	/// - `a`: `Linear<0, 1>`   Whether a callback is attached:
+	///   - `0`: No callback
+	///   - `1`: Callback attached
+	// IMPORTANT NOTE: `skip_meta` and `pov_mode = Measured` used for the same reasons as
+	// `ismp_get`.
+	#[benchmark(skip_meta, pov_mode = Measured)]
+	fn ismp_get_batch(
+		x: Linear<1, { T::MaxBatchSize::get() }>,
+		a: Linear<0, 1>,
+	) -> Result<(), BenchmarkError> {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let request = ismp::Get {
+			destination: u32::MAX,
+			height: u64::MAX,
+			timeout: u64::MAX,
+			context: vec![255; T::MaxContextLen::get() as usize].into(),
+			keys: vec![
+				vec![255u8; T::MaxKeyLen::get() as usize].into();
+				T::MaxKeys::get() as usize
+			],
+		};
+		let requests = vec![request; x as usize];
+		let fee = <Balances<T>>::minimum_balance()
+			.try_convert()
+			.map_err(|_| BenchmarkError::Stop("failed to convert minimum balance to fee"))?;
+
+		silence_timestamp_genesis_warnings::<T>();
+		<Balances<T>>::set_balance(&origin.account, <Balances<T>>::total_issuance() / 2u32.into());
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Signed(origin.account));
+		let mut ext = call_setup.ext().0;
+		let input = if a == 0 {
+			IISMPCalls::getBatch_0(IISMP::getBatch_0Call { requests, fee })
+		} else {
+			let callback = ismp::Callback {
+				destination: [255; 20].into(),
+				encoding: ismp::Encoding::SolidityAbi,
+				selector: [255; 4].into(),
+				gasLimit: ismp::Weight { refTime: 100_000, proofSize: 100_000 },
+				storageDepositLimit: alloy::U256::from(100_000),
+			};
+			IISMPCalls::getBatch_1(IISMP::getBatch_1Call { requests, fee, callback })
+		};
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Ismp<T>, _, ()>(&mut ext, &Ismp::<T>::address(), &input));
+		}
+
+		Ok(())
+	}
+
+	/// Handles the conclusion of the final request of a batch of the maximum size, completing the
+	/// batch with a response of the maximum length.
+	///
+	/// # Parameters
+	/// - `x`: `Linear<0, 1>`   How the request concluded:
+	///   - `0`: `GetResponse`
+	///   - `1`: Timeout
+	#[benchmark]
+	fn ismp_conclude_batch_request(x: Linear<0, 1>) {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let callback = Callback::new(
+			[255; 20].into(),
+			Encoding::Scale,
+			[255; 4],
+			Weight::from_parts(100_000, 100_000),
+			100_000u32.into(),
+		);
+		let module = Module::<T>::new();
+
+		silence_timestamp_genesis_warnings::<T>();
+		<Balances<T>>::set_balance(&origin.account, u32::MAX.into());
+
+		let (id, mut requests) = ismp_batch::<T>(origin.clone(), callback);
+		// Conclude all but the final request of the batch.
+		let request = requests.pop().unwrap();
+		for request in requests {
+			module.on_timeout(Timeout::Request(Request::Get(request))).unwrap();
+		}
+
+		#[block]
+		{
+			match x {
+				0 => {
+					let values = batch_values::<T>(T::MaxBatchSize::get());
+					module.on_response(Response::Get(GetResponse { get: request, values })).unwrap()
+				},
+				_ => module.on_timeout(Timeout::Request(Request::Get(request))).unwrap(),
+			}
+		}
+
+		assert_has_event::<T>(Event::IsmpGetBatchCompleted { dest: origin.address, id }.into());
+	}
+
+	/// Handles acceptance of an ISMP POST, delivering it to a contract.
 	#[benchmark]
 	fn ismp_on_accept() {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
 		let module = Module::<T>::new();
 		let request = PostRequest {
 			source: StateMachine::Polkadot(u32::MAX),
-			dest: StateMachine::Polkadot(u32::MAX),
+			dest: HostStateMachine::<T>::get(),
 			nonce: u64::MAX,
 			from: [255u8; 32].to_vec(),
-			to: [255u8; 32].to_vec(),
+			to: [ID.to_vec(), (origin.address, Encoding::Scale).encode()].concat(),
 			timeout_timestamp: u64::MAX,
 			body: vec![255u8; T::MaxDataLen::get() as usize],
 		};
+		let commitment = hash_request::<T::Keccak256>(&Request::Post(request.clone()));
 
 		silence_timestamp_genesis_warnings::<T>();
+		<Balances<T>>::set_balance(&origin.account, u32::MAX.into());
+		assert_ok!(allow_source::<T>(origin.clone(), request.source, &request.from));
+		// Request receipt stored by the ISMP handler before the request is accepted, allowing a
+		// response to be dispatched.
+		pallet_ismp::Pallet::<T>::default()
+			.store_request_receipt(&Request::Post(request.clone()), &Vec::default())
+			.unwrap();
 
 		#[block]
 		{
 			module.on_accept(request).unwrap()
 		}
+
+		assert_has_event::<T>(
+			Event::IsmpPostRequestReceived { dest: origin.address, commitment }.into(),
+		);
 	}
 
 	/// Handles a response to a previously submitted ISMP request.
@@ -223,8 +600,9 @@ mod benchmarks {
 	/// - `x`: `Linear<0, 1>`   The type of ISMP response:
 	///   - `0`: `GetResponse`
 	///   - `1`: `PostResponse`
+	/// - `y`: `Linear<0, { T::MaxResponseLen::get() }>`   Length of the response (in bytes).
 	#[benchmark]
-	fn ismp_on_response(x: Linear<0, 1>) {
+	fn ismp_on_response(x: Linear<0, 1>, y: Linear<0, { T::MaxResponseLen::get() }>) {
 		let origin = Origin::<T>::from_address(ALICE_ADDR);
 		let fee = <Balances<T>>::minimum_balance();
 		let callback = Callback::new(
@@ -240,7 +618,7 @@ mod benchmarks {
 		<Balances<T>>::set_balance(&origin.account, u32::MAX.into());
 
 		let (message_id, commitment, response) =
-			ismp_request::<T>(x, origin.clone(), fee, callback);
+			ismp_request::<T>(x, y, origin.clone(), fee, callback);
 
 		#[block]
 		{
@@ -279,7 +657,8 @@ mod benchmarks {
 		silence_timestamp_genesis_warnings::<T>();
 		<Balances<T>>::set_balance(&origin.account, u32::MAX.into());
 
-		let (_, commitment, response) = ismp_request::<T>(x, origin, fee, callback);
+		let (_, commitment, response) =
+			ismp_request::<T>(x, T::MaxResponseLen::get(), origin, fee, callback);
 		let timeout = match x {
 			0 => {
 				let Response::Get(response) = response else {
@@ -365,6 +744,117 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// Revokes the allowance of a contract to receive inbound ISMP `Post` requests from a module on
+	/// a source state machine.
+	#[benchmark]
+	fn ismp_revoke_source() {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let source = StateMachine::Polkadot(u32::MAX);
+		let from = [255u8; 32].to_vec();
+
+		<Balances<T>>::set_balance(&origin.account, <Balances<T>>::total_issuance() / 2u32.into());
+		assert_ok!(allow_source::<T>(origin.clone(), source, &from));
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Signed(origin.account));
+		let mut ext = call_setup.ext().0;
+		let input = IISMPCalls::revokeSource(IISMP::revokeSourceCall {
+			source: source.to_string().into_bytes().into(),
+			from: from.clone().into(),
+		});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Ismp<T>, _, ()>(&mut ext, &Ismp::<T>::address(), &input));
+		}
+
+		assert!(!IsmpPostRequestSources::<T>::contains_key(
+			origin.address,
+			source_key::<T>(&source, &from)
+		));
+	}
+
+	/// Subscribes to recurring ISMP `Get` requests with varying context and key sizes.
+	///
+	/// # Parameters
+	/// - `x`: `Linear<0, { T::MaxContextLen::get() }>`   Length of the context field (in bytes).
+	/// - `y`: `Linear<0, { T::MaxKeys::get() }>`   Number of keys in the outer keys array.
+	#[benchmark]
+	fn ismp_subscribe(
+		x: Linear<0, { T::MaxContextLen::get() }>,
+		y: Linear<0, { T::MaxKeys::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let subscription = ismp::Subscription {
+			destination: u32::MAX,
+			timeout: u64::MAX,
+			context: vec![255; x as usize].into(),
+			keys: vec![vec![255u8; T::MaxKeyLen::get() as usize].into(); y as usize].into(),
+			interval: T::MinSubscriptionInterval::get().into(),
+			executions: 10,
+		};
+		let fee = <Balances<T>>::minimum_balance()
+			.try_convert()
+			.map_err(|_| BenchmarkError::Stop("failed to convert minimum balance to fee"))?;
+		let callback = ismp::Callback {
+			destination: [255; 20].into(),
+			encoding: ismp::Encoding::SolidityAbi,
+			selector: [255; 4].into(),
+			gasLimit: ismp::Weight { refTime: 100_000, proofSize: 100_000 },
+			storageDepositLimit: alloy::U256::from(100_000),
+		};
+
+		<Balances<T>>::set_balance(&origin.account, <Balances<T>>::total_issuance() / 2u32.into());
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Signed(origin.account));
+		let mut ext = call_setup.ext().0;
+		let input = IISMPCalls::subscribe(IISMP::subscribeCall { subscription, fee, callback });
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Ismp<T>, _, u64>(&mut ext, &Ismp::<T>::address(), &input));
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn poll_batch() {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let message = 1;
+		let mut response = vec![
+			GetResult { status: MessageStatus::Timeout, values: Vec::new() };
+			T::MaxBatchSize::get() as usize
+		];
+		if let Some(result) = response.last_mut() {
+			*result = GetResult {
+				status: MessageStatus::Complete,
+				values: batch_values::<T>(T::MaxBatchSize::get()),
+			};
+		}
+
+		Messages::<T>::insert(
+			message,
+			Message::Complete {
+				origin: origin.address,
+				transport: MessageTransport::of::<T, IsmpBatchTransport>(&message).unwrap(),
+				message_deposit: BalanceOf::<T>::max_value(),
+				response: response.encode().try_into().unwrap(),
+			},
+		);
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Signed(origin.account));
+		let mut ext = call_setup.ext().0;
+		let input = IISMPCalls::pollBatch(IISMP::pollBatchCall { message });
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Ismp<T>, _, ()>(&mut ext, &Ismp::<T>::address(), &input));
+		}
+	}
+
 	#[benchmark]
 	fn poll_status() {
 		let origin = Origin::<T>::from_address(ALICE_ADDR);
@@ -398,6 +888,33 @@ mod benchmarks {
 	/// # Parameters
 	/// - `x`: `Linear<1, { T::MaxRemovals::get() }>`   The number of message removals to perform
 	///   (bounded by `MaxRemovals`).
+	/// Quotes the amounts taken for a new message.
+	///
+	/// No benchmark input parameters. A XCM query with a callback is quoted, which additionally
+	/// prepays for the execution of the callback.
+	#[benchmark]
+	fn quote() {
+		let mut call_setup = set_up_call();
+		let mut ext = call_setup.ext().0;
+		let callback = xcm::Callback {
+			destination: [255; 20].into(),
+			encoding: xcm::Encoding::Scale,
+			selector: [255; 4].into(),
+			gasLimit: xcm::Weight { refTime: u64::MAX, proofSize: u64::MAX },
+			storageDepositLimit: alloy::U256::MAX,
+		};
+		let input = IXCMCalls::quoteNewQuery_1(IXCM::quoteNewQuery_1Call { callback });
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Xcm<T>, _, xcm::Quote>(
+				&mut ext,
+				&Xcm::<T>::address(),
+				&input
+			));
+		}
+	}
+
 	#[benchmark]
 	fn remove(x: Linear<1, { T::MaxRemovals::get() }>) {
 		let message_deposit = 50_000u32.into();
@@ -433,7 +950,7 @@ mod benchmarks {
 			);
 
 			Messages::<T>::insert(&i, &good_message);
-			IsmpRequests::<T>::insert(&commitment, i);
+			TransportMessages::<T>::insert(MessageTransport::ismp::<T>(commitment), i);
 		}
 
 		let input =
@@ -449,6 +966,155 @@ mod benchmarks {
 		}
 	}
 
+	/// Retries a deferred callback, completing its message.
+	///
+	/// No benchmark input parameters. A response with a deferred callback of the maximum input
+	/// length is created and processed.
+	#[benchmark]
+	fn retry_deferred_callback() {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let message = 1;
+		let commitment: H256 = [255; 32].into();
+		let message_deposit = 50_000u32.into();
+		let callback = Callback {
+			destination: [255; 20].into(),
+			encoding: Encoding::Scale,
+			selector: [0; 4],
+			gas_limit: Weight::from_parts(100, 100),
+			storage_deposit_limit: 100u8.into(),
+		};
+
+		<Balances<T>>::set_balance(&origin.account, u32::MAX.into());
+		assert_ok!(T::Fungibles::hold(
+			&HoldReason::Messaging.into(),
+			&origin.account,
+			message_deposit
+		));
+		assert_ok!(T::Fungibles::hold(
+			&HoldReason::CallbackGas.into(),
+			&origin.account,
+			T::WeightToFee::weight_to_fee(&callback.gas_limit)
+		));
+		Messages::<T>::insert(
+			message,
+			Message::ismp_response(
+				origin.address,
+				commitment,
+				message_deposit,
+				vec![255; T::MaxResponseLen::get() as usize].try_into().unwrap(),
+			),
+		);
+		TransportMessages::<T>::insert(MessageTransport::ismp::<T>(commitment), message);
+		DeferredCallbackQueue::<T>::put(BoundedVec::truncate_from(vec![message]));
+		DeferredCallbacks::<T>::insert(
+			message,
+			DeferredCallback {
+				origin: origin.account.clone(),
+				callback,
+				input: vec![255; MaxCallbackInputLen::<T>::get() as usize].try_into().unwrap(),
+				retries: 0,
+			},
+		);
+
+		#[block]
+		{
+			retry_deferred_callbacks::<T>(Weight::MAX);
+		}
+
+		assert!(Messages::<T>::get(message).is_none());
+		assert!(DeferredCallbackQueue::<T>::get().is_empty());
+	}
+
+	/// Overrides the maximum number of pending messages of an origin.
+	#[benchmark]
+	fn set_pending_message_quota() {
+		let origin = T::QuotaOrigin::try_successful_origin().unwrap();
+
+		#[extrinsic_call]
+		Pallet::<T>::set_pending_message_quota(origin, ALICE_ADDR, Some(u32::MAX));
+
+		assert_eq!(PendingMessageQuotas::<T>::get(ALICE_ADDR), Some(u32::MAX));
+	}
+
+	/// Offers the ownership of a message to another account.
+	///
+	/// No benchmark input parameters.
+	#[benchmark]
+	fn transfer_message() {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let message = 1;
+		Messages::<T>::insert(message, Message::xcm_query(origin.clone(), 0, None, 0u32.into()));
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Signed(origin.account.clone()));
+		let mut ext = call_setup.ext().0;
+		let input = IMessagingCalls::transferMessage(IMessaging::transferMessageCall {
+			message,
+			newOwner: BOB_ADDR.0.into(),
+		});
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Messaging<T>, _, ()>(
+				&mut ext,
+				&Messaging::<T>::address(),
+				&input
+			));
+		}
+
+		assert_eq!(MessageTransfers::<T>::get(message), Some((origin, BOB_ADDR)));
+	}
+
+	/// Updates the callback of a pending message.
+	///
+	/// No benchmark input parameters. A callback is added to a pending message without one, with
+	/// the deposit for its execution being held.
+	#[benchmark]
+	fn update_callback() {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let message = 1;
+		let message_deposit = 50_000u32.into();
+
+		<Balances<T>>::set_balance(&origin.account, u32::MAX.into());
+		assert_ok!(T::Fungibles::hold(
+			&HoldReason::Messaging.into(),
+			&origin.account,
+			message_deposit
+		));
+		Messages::<T>::insert(
+			message,
+			Message::xcm_query(origin.clone(), 0, None, message_deposit),
+		);
+		TransportMessages::<T>::insert(MessageTransport::xcm::<T>(0), message);
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Signed(origin.account));
+		let mut ext = call_setup.ext().0;
+		let callback = IMessaging::Callback {
+			destination: [255; 20].into(),
+			encoding: IMessaging::Encoding::Scale,
+			selector: [0; 4].into(),
+			gasLimit: IMessaging::Weight { refTime: 100, proofSize: 100 },
+			storageDepositLimit: alloy::U256::from(100),
+		};
+		let input =
+			IMessagingCalls::updateCallback(IMessaging::updateCallbackCall { message, callback });
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Messaging<T>, _, ()>(
+				&mut ext,
+				&Messaging::<T>::address(),
+				&input
+			));
+		}
+
+		assert!(matches!(
+			Messages::<T>::get(message),
+			Some(Message::Pending { callback: Some(_), .. })
+		));
+	}
+
 	/// Submits a new XCM query message with an optional callback.
 	///
 	/// # Parameters
@@ -485,11 +1151,45 @@ mod benchmarks {
 		}
 	}
 
+	/// Cancels a pending XCM query, releasing the deposits held for the message.
+	///
+	/// No benchmark input parameters. A query with a callback is created and then cancelled.
+	#[benchmark]
+	fn xcm_cancel_query() {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let responder = Location { parents: 1, interior: Junctions::Here };
+		let timeout = frame_system::Pallet::<T>::block_number() + 1_000u32.into();
+		let callback = Some(Callback {
+			destination: [255; 20].into(),
+			encoding: Encoding::Scale,
+			selector: [0; 4],
+			gas_limit: Weight::from_parts(100, 100),
+			storage_deposit_limit: 100u8.into(),
+		});
+
+		<Balances<T>>::set_balance(&origin.account, u32::MAX.into());
+
+		let (message, _) = new_query::<T>(origin.clone(), responder, timeout, callback).unwrap();
+
+		let mut call_setup = set_up_call();
+		call_setup.set_origin(Signed(origin.account));
+		let mut ext = call_setup.ext().0;
+		let input = IXCMCalls::cancel(IXCM::cancelCall { message });
+
+		#[block]
+		{
+			assert_ok!(call_precompile::<Xcm<T>, _, ()>(&mut ext, &Xcm::<T>::address(), &input));
+		}
+
+		assert!(Messages::<T>::get(message).is_none());
+	}
+
 	/// Handles a response from an XCM query and executes a callback if present.
 	///
-	/// No benchmark input parameters. A mock response is created and processed.
+	/// # Parameters
+	/// - `x`: `Linear<0, { T::MaxResponseLen::get() }>`   Length of the response (in bytes).
 	#[benchmark]
-	fn xcm_response() {
+	fn xcm_response(x: Linear<0, { T::MaxResponseLen::get() }>) {
 		let origin = Origin::<T>::from_address(ALICE_ADDR);
 		let responder = Location { parents: 1, interior: Junctions::Here };
 		let timeout = frame_system::Pallet::<T>::block_number() + 1u8.into();
@@ -501,7 +1201,7 @@ mod benchmarks {
 			storage_deposit_limit: 100u8.into(),
 		});
 		let response_origin = T::XcmResponseOrigin::try_successful_origin().unwrap();
-		let response = ExecutionResult(None);
+		let response = xcm_response_of_len(x);
 
 		<Balances<T>>::set_balance(&origin.account, u32::MAX.into());
 
@@ -517,6 +1217,45 @@ mod benchmarks {
 		);
 	}
 
+	/// Times out a XCM query which exceeded the maximum number of timeouts of its block.
+	///
+	/// No benchmark input parameters. A query with a callback is spilled over into the overflow of
+	/// its block, which is then processed.
+	#[benchmark]
+	fn xcm_timeout_query() {
+		let origin = Origin::<T>::from_address(ALICE_ADDR);
+		let responder = Location { parents: 1, interior: Junctions::Here };
+		let timeout = frame_system::Pallet::<T>::block_number() + 1u8.into();
+		let callback = Some(Callback {
+			destination: [255; 20].into(),
+			encoding: Encoding::Scale,
+			selector: [0; 4],
+			gas_limit: Weight::from_parts(100, 100),
+			storage_deposit_limit: 100u8.into(),
+		});
+
+		<Balances<T>>::set_balance(&origin.account, u32::MAX.into());
+
+		let (message, query_id) =
+			new_query::<T>(origin.clone(), responder, timeout, callback).unwrap();
+		let Some(timeout) = PendingXcmQueries::<T>::get(query_id) else {
+			panic!("query should be pending")
+		};
+		XcmQueryTimeouts::<T>::remove(timeout);
+		XcmQueryTimeoutOverflow::<T>::insert(timeout, message, ());
+		XcmQueryTimeoutOverflowBlocks::<T>::insert(0, timeout);
+		XcmQueryTimeoutOverflowQueue::<T>::put((0, 1));
+
+		#[block]
+		{
+			timeout_overflowing_xcm_queries::<T>(Weight::MAX);
+		}
+
+		assert!(matches!(Messages::<T>::get(message), Some(Message::Timeout { .. })));
+		assert_eq!(XcmQueryTimeoutOverflowQueue::<T>::get(), (1, 1));
+		assert_has_event::<T>(Event::XcmQueriesTimedOut { query_ids: vec![query_id] }.into());
+	}
+
 	impl_benchmark_test_suite!(Pallet, ExtBuilder::new().build(), Test);
 }
 
@@ -527,6 +1266,7 @@ fn assert_has_event<T: Config>(generic_event: <T as frame_system::Config>::Runti
 
 fn ismp_request<T: Config + pallet_ismp::Config>(
 	x: u32,
+	len: u32,
 	origin: Origin<T>,
 	fee: BalanceOf<T>,
 	callback: Callback<BalanceOf<T>>,
@@ -554,10 +1294,7 @@ fn ismp_request<T: Config + pallet_ismp::Config>(
 				context: request.context.clone(),
 				timeout_timestamp: request.timeout,
 			},
-			values: (0..(T::MaxResponseLen::get() as usize / value.encoded_size())
-				.saturating_sub(1))
-				.map(|_| value.clone())
-				.collect::<Vec<_>>(),
+			values: vec![value.clone(); (len as usize / value.encoded_size()).saturating_sub(1)],
 		};
 
 		let (id, commitment) = get::<T>(origin, request, fee, Some(callback)).unwrap();
@@ -582,7 +1319,7 @@ fn ismp_request<T: Config + pallet_ismp::Config>(
 				timeout_timestamp: request.timeout,
 				body: request.body.clone(),
 			},
-			response: vec![255u8; T::MaxResponseLen::get() as usize - 2],
+			response: vec![255u8; (len as usize).saturating_sub(2)],
 			timeout_timestamp: request.timeout,
 		};
 
@@ -591,6 +1328,91 @@ fn ismp_request<T: Config + pallet_ismp::Config>(
 	}
 }
 
+// Submits a batch of the maximum number of ISMP `Get` requests, each of the maximum size, with a
+// callback. Returns the identifier of the batch along with the requests, as dispatched.
+fn ismp_batch<T: Config + pallet_ismp::Config>(
+	origin: Origin<T>,
+	callback: Callback<BalanceOf<T>>,
+) -> (MessageId, Vec<GetRequest>) {
+	let request = DispatchGet {
+		dest: StateMachine::Polkadot(u32::MAX),
+		from: ID.to_vec(),
+		keys: vec![vec![255u8; T::MaxKeyLen::get() as usize]; T::MaxKeys::get() as usize],
+		height: u64::MAX,
+		context: vec![255u8; T::MaxContextLen::get() as usize],
+		timeout: u64::MAX,
+	};
+	let requests = (0..T::MaxBatchSize::get() as u64)
+		.map(|nonce| GetRequest {
+			source: HostStateMachine::<T>::get(),
+			dest: request.dest,
+			nonce,
+			from: request.from.clone(),
+			keys: request.keys.clone(),
+			height: request.height,
+			context: request.context.clone(),
+			timeout_timestamp: request.timeout,
+		})
+		.collect();
+
+	let batch = vec![request; T::MaxBatchSize::get() as usize];
+	let (id, _) =
+		get_batch::<T>(origin, batch, <Balances<T>>::minimum_balance(), Some(callback)).unwrap();
+	(id, requests)
+}
+
+// The values read by the final request of a batch of `n` requests, where all others have timed
+// out, such that the response to the batch is of the maximum length.
+fn batch_values<T: Config>(n: u32) -> Vec<StorageValue> {
+	let value = StorageValue { key: vec![255u8; 1], value: Some(vec![255u8; 1]) };
+	// Each result prior to the final one is encoded as a status and an empty list of values.
+	let available = (T::MaxResponseLen::get() as usize).saturating_sub(2 * n as usize + 4);
+	vec![value.clone(); available / value.encoded_size()]
+}
+
+// A response to an XCM query of approximately, but not exceeding, `len` bytes once encoded,
+// including the prefix of its length when provided to a callback.
+fn xcm_response_of_len(len: u32) -> XcmResponse {
+	let name = vec![255u8; MAX_PALLET_NAME_LEN as usize];
+	let pallet = PalletInfo::new(u32::MAX, name.clone(), name, u32::MAX, u32::MAX, u32::MAX)
+		.expect("name within bounds");
+	let pallets = (len as usize).saturating_sub(6) / pallet.encoded_size();
+	XcmResponse::PalletsInfo(BoundedVec::truncate_from(vec![pallet; pallets]))
+}
+
+// Subscribes to recurring ISMP `Get` requests of the maximum size, with a callback.
+fn ismp_subscription<T: Config>(origin: Origin<T>) -> Result<SubscriptionId, BenchmarkError> {
+	let request = GetTemplate::<T> {
+		dest: u32::MAX,
+		timeout: u64::MAX,
+		context: BoundedVec::truncate_from(vec![255u8; T::MaxContextLen::get() as usize]),
+		keys: BoundedVec::truncate_from(vec![
+			BoundedVec::truncate_from(vec![
+				255u8;
+				T::MaxKeyLen::get()
+					as usize
+			]);
+			T::MaxKeys::get() as usize
+		]),
+	};
+	let callback = Callback {
+		destination: [255; 20].into(),
+		encoding: Encoding::SolidityAbi,
+		selector: [255; 4],
+		gas_limit: Weight::from_parts(100_000, 100_000),
+		storage_deposit_limit: 100_000u32.into(),
+	};
+	subscribe::<T>(
+		origin,
+		request,
+		T::MinSubscriptionInterval::get(),
+		10,
+		<Balances<T>>::minimum_balance(),
+		callback,
+	)
+	.map_err(|_| BenchmarkError::Stop("failed to subscribe"))
+}
+
 // Silence ``pallet_timestamp::UnixTime::now` is called at genesis, invalid value returned: 0`
 // warnings
 fn silence_timestamp_genesis_warnings<T: pallet_timestamp::Config>() {
@@ -2,8 +2,8 @@ use super::*;
 
 #[derive(Clone, Debug, Encode, Eq, Decode, MaxEncodedLen, PartialEq, TypeInfo)]
 pub enum ProtocolStorageDeposit {
-	XcmQueries,
-	IsmpRequests,
+	IsmpPostRequestSources,
+	IsmpSubscriptions,
 }
 
 /// Calculate the deposit required for the space used for a specific protocol.
@@ -11,22 +11,38 @@ pub fn calculate_protocol_deposit<T: Config, ByteFee: Get<BalanceOf<T>>>(
 	p: ProtocolStorageDeposit,
 ) -> BalanceOf<T> {
 	let base: usize = match p {
-		ProtocolStorageDeposit::XcmQueries => (KeyLenOf::<XcmQueries<T>>::get() as usize)
-			.saturating_add(AccountIdOf::<T>::max_encoded_len())
-			.saturating_add(MessageId::max_encoded_len())
-			.saturating_add(Option::<Callback<BalanceOf<T>>>::max_encoded_len()),
-
-		ProtocolStorageDeposit::IsmpRequests => (KeyLenOf::<IsmpRequests<T>>::get() as usize)
-			.saturating_add(AccountIdOf::<T>::max_encoded_len())
-			.saturating_add(MessageId::max_encoded_len()),
+		ProtocolStorageDeposit::IsmpPostRequestSources =>
+			(KeyLenOf::<IsmpPostRequestSources<T>>::get() as usize)
+				.saturating_add(BalanceOf::<T>::max_encoded_len()),
+		ProtocolStorageDeposit::IsmpSubscriptions => (KeyLenOf::<Subscriptions<T>>::get() as usize)
+			.saturating_add(Subscription::<T>::max_encoded_len())
+			.saturating_add(KeyLenOf::<SubscriptionSchedule<T>>::get() as usize),
 	};
 	ByteFee::get().saturating_mul(base.saturated_into())
 }
 
-/// Calculate the deposit for the storage used for the Message enum.
+/// Calculate the deposit required for the space used by a transport for a message, including the
+/// lookup of the message by its key on the transport.
+pub fn calculate_transport_deposit<
+	T: Config,
+	Tr: transports::Transport<T>,
+	ByteFee: Get<BalanceOf<T>>,
+>() -> BalanceOf<T> {
+	ByteFee::get().saturating_mul(
+		(KeyLenOf::<TransportMessages<T>>::get() as usize)
+			.saturating_add(MessageId::max_encoded_len())
+			.saturating_add(Tr::footprint() as usize)
+			.saturated_into(),
+	)
+}
+
+/// Calculate the deposit for the storage used for the Message enum, including the lookup of the
+/// message by its origin.
 pub fn calculate_message_deposit<T: Config, ByteFee: Get<BalanceOf<T>>>() -> BalanceOf<T> {
 	ByteFee::get().saturating_mul(
-		(KeyLenOf::<Messages<T>>::get() as usize + Message::<T>::max_encoded_len())
+		(KeyLenOf::<Messages<T>>::get() as usize +
+			Message::<T>::max_encoded_len() +
+			KeyLenOf::<OriginMessages<T>>::get() as usize)
 			.saturated_into(),
 	)
 }
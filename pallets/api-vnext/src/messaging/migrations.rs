@@ -0,0 +1,721 @@
+use frame_support::{
+	migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+	storage::with_storage_layer,
+	traits::PalletInfoAccess,
+};
+use pallet_revive::AddressMapper as _;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+use super::*;
+use crate::Origin;
+
+/// The migration of the storage of the pallet to version 1.
+pub mod v1;
+
+type AddressMapper<T> = <T as pallet_revive::Config>::AddressMapper;
+
+const LOG_TARGET: &str = "pop-api::messaging::migration";
+
+/// The layout of the storage of `pallet_api::messaging`, as used with `pallet-contracts`.
+pub mod v0 {
+	use super::*;
+
+	/// A message of `pallet_api::messaging`.
+	#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+	pub enum Message<Balance> {
+		/// A pending ISMP request.
+		Ismp { commitment: H256, callback: Option<Callback>, deposit: Balance },
+		/// An ISMP request which timed out.
+		IsmpTimedOut { commitment: H256, deposit: Balance },
+		/// A response to an ISMP request, as provided to its callback.
+		IsmpResponse { commitment: H256, deposit: Balance, response: Vec<u8> },
+		/// A pending XCM query.
+		XcmQuery { query_id: QueryId, callback: Option<Callback>, deposit: Balance },
+		/// A response to a XCM query.
+		XcmResponse { query_id: QueryId, deposit: Balance, response: Response },
+	}
+
+	impl<Balance: Copy> Message<Balance> {
+		/// The deposit held for the message.
+		pub(super) fn deposit(&self) -> Balance {
+			match self {
+				Message::Ismp { deposit, .. } |
+				Message::IsmpTimedOut { deposit, .. } |
+				Message::IsmpResponse { deposit, .. } |
+				Message::XcmQuery { deposit, .. } |
+				Message::XcmResponse { deposit, .. } => *deposit,
+			}
+		}
+	}
+
+	/// A callback of `pallet_api::messaging`, executed on the origin of the message.
+	#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+	pub struct Callback {
+		/// The selector of the message of the contract.
+		pub selector: [u8; 4],
+		/// The prepaid weight, used as the gas limit.
+		pub weight: Weight,
+	}
+
+	/// The messages, by origin and the identifier specified by the origin.
+	#[frame_support::storage_alias(dynamic)]
+	pub type Messages<P: Get<&'static str>, T: Config> = StorageDoubleMap<
+		P,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		MessageId,
+		Message<BalanceOf<T>>,
+	>;
+
+	/// The origin and message identifier of ISMP requests, by commitment.
+	#[frame_support::storage_alias(dynamic)]
+	pub type IsmpRequests<P: Get<&'static str>, T: Config> =
+		StorageMap<P, Identity, H256, (AccountIdOf<T>, MessageId)>;
+
+	/// The origin and message identifier of XCM queries, by query identifier.
+	#[frame_support::storage_alias(dynamic)]
+	pub type XcmQueries<P: Get<&'static str>, T: Config> =
+		StorageMap<P, Identity, QueryId, (AccountIdOf<T>, MessageId)>;
+}
+
+/// The configuration of `pallet_api::messaging` within the runtime, from which messages are
+/// migrated.
+pub trait PalletApiMessaging<T: Config> {
+	/// The name of the pallet within the runtime, which must differ from that of this pallet.
+	type PalletName: Get<&'static str>;
+	/// The reason of the holds placed on the deposits of messages by the pallet.
+	type HoldReason: Get<T::RuntimeHoldReason>;
+}
+
+/// The stage of the migration.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub enum Stage {
+	/// Migrating messages.
+	Messages,
+	/// Removing the lookups of messages by their ISMP commitments and XCM query identifiers.
+	Lookups,
+}
+
+/// A multi-block migration of the messages of `pallet_api::messaging` to this pallet, for runtimes
+/// moving from `pallet-contracts` to `pallet-revive`.
+///
+/// Each message is assigned a new identifier, with its origin addressed by the address of its
+/// account, which is mapped by `pallet-revive` if not already. The deposit held for the message is
+/// re-held under the hold reason of this pallet. Callbacks are dropped, as they target contracts of
+/// `pallet-contracts` which do not exist on `pallet-revive`, with any response stored for polling
+/// instead. As the weight of a callback was prepaid within the fee of the dispatching call, no
+/// callback deposit is held by either pallet. Messages which cannot be migrated, such as those
+/// whose response exceeds [`Config::MaxResponseLen`], are dropped with their deposit released.
+///
+/// Pending ISMP requests remain correlated by their commitment, so responses and timeouts continue
+/// to be handled once the module identifier of `pallet_api::messaging` is routed to
+/// [`transports::ismp::Module`]. The notifications of pending XCM queries are redirected to
+/// [`Pallet::xcm_response`].
+pub struct MigrateFromPalletApi<T, Old>(PhantomData<(T, Old)>);
+
+impl<T, Old> SteppedMigration for MigrateFromPalletApi<T, Old>
+where
+	T: Config + pallet_revive::Config + pallet_xcm::Config,
+	<T as pallet_xcm::Config>::RuntimeCall: From<Call<T>>,
+	Old: PalletApiMessaging<T>,
+{
+	type Cursor = Stage;
+	type Identifier = MigrationId<18>;
+
+	fn id() -> Self::Identifier {
+		// Distinct from the migrations of the storage of this pallet, as the messages are migrated
+		// from the storage of `pallet_api::messaging`.
+		MigrationId { pallet_id: *b"pop-api::messaging", version_from: 0, version_to: 1 }
+	}
+
+	fn step(
+		mut cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		// The storage of both pallets would otherwise collide.
+		if Old::PalletName::get() == <Pallet<T>>::name() {
+			return Err(SteppedMigrationError::Failed);
+		}
+		let required = Self::step_weight();
+		if meter.remaining().any_lt(required) {
+			return Err(SteppedMigrationError::InsufficientWeight { required });
+		}
+
+		while meter.try_consume(required).is_ok() {
+			cursor = match cursor.unwrap_or(Stage::Messages) {
+				Stage::Messages => match v0::Messages::<Old::PalletName, T>::drain().next() {
+					Some(((account, id), message)) => {
+						migrate::<T, Old>(account, id, message);
+						Some(Stage::Messages)
+					},
+					None => Some(Stage::Lookups),
+				},
+				Stage::Lookups =>
+					(v0::IsmpRequests::<Old::PalletName, T>::drain().next().is_some() ||
+						v0::XcmQueries::<Old::PalletName, T>::drain().next().is_some())
+					.then_some(Stage::Lookups),
+			};
+			if cursor.is_none() {
+				break;
+			}
+		}
+		Ok(cursor)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(
+			Old::PalletName::get() != <Pallet<T>>::name(),
+			"the pallets must have different names"
+		);
+		let mut accounts: Vec<AccountIdOf<T>> = v0::Messages::<Old::PalletName, T>::iter_keys()
+			.map(|(account, _)| account)
+			.collect();
+		accounts.dedup();
+		let messages = v0::Messages::<Old::PalletName, T>::iter().count() as u32;
+		Ok((accounts, messages, Messages::<T>::iter().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let (accounts, migrating, existing): (Vec<AccountIdOf<T>>, u32, u32) =
+			Decode::decode(&mut state.as_slice())
+				.map_err(|_| "the state should have been generated by pre_upgrade")?;
+		ensure!(
+			v0::Messages::<Old::PalletName, T>::iter().next().is_none() &&
+				v0::IsmpRequests::<Old::PalletName, T>::iter().next().is_none() &&
+				v0::XcmQueries::<Old::PalletName, T>::iter().next().is_none(),
+			"the storage of the previous pallet should be empty"
+		);
+		let migrated = (Messages::<T>::iter().count() as u32).saturating_sub(existing);
+		ensure!(migrated <= migrating, "no more messages should be migrated than existed");
+		for account in accounts {
+			ensure!(
+				T::Fungibles::balance_on_hold(&Old::HoldReason::get(), &account).is_zero(),
+				"the deposits of the previous pallet should have been released"
+			);
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config, Old> MigrateFromPalletApi<T, Old> {
+	// The weight of a single step, as an upper bound of the storage accessed when migrating a
+	// message: the message and its lookup, the holds and balance of its origin, the address
+	// mapping of its origin, the message identifier, transport lookup and pending count of the
+	// migrated message, the fee of an ISMP request, the pending query of `pallet-xcm` along with
+	// the timeout or expiry of the migrated message.
+	fn step_weight() -> Weight {
+		DbWeightOf::<T>::get().reads_writes(11, 15)
+	}
+}
+
+// Migrates a message of `pallet_api::messaging`. Any message which cannot be migrated is dropped,
+// with its deposit released.
+fn migrate<T, Old>(account: AccountIdOf<T>, old_id: MessageId, message: v0::Message<BalanceOf<T>>)
+where
+	T: Config + pallet_revive::Config + pallet_xcm::Config,
+	<T as pallet_xcm::Config>::RuntimeCall: From<Call<T>>,
+	Old: PalletApiMessaging<T>,
+{
+	// Remove the lookup of the message and release the deposit held by the previous pallet.
+	match &message {
+		v0::Message::Ismp { commitment, .. } |
+		v0::Message::IsmpTimedOut { commitment, .. } |
+		v0::Message::IsmpResponse { commitment, .. } =>
+			v0::IsmpRequests::<Old::PalletName, T>::remove(commitment),
+		v0::Message::XcmQuery { query_id, .. } | v0::Message::XcmResponse { query_id, .. } =>
+			v0::XcmQueries::<Old::PalletName, T>::remove(query_id),
+	}
+	let deposit = T::Fungibles::release(
+		&Old::HoldReason::get(),
+		&account,
+		message.deposit(),
+		Precision::BestEffort,
+	)
+	.unwrap_or_default();
+
+	let result = with_storage_layer(|| -> DispatchResult {
+		// Messages are owned by the address of their origin, which must therefore be mapped to
+		// its account.
+		if !AddressMapper::<T>::is_mapped(&account) {
+			AddressMapper::<T>::map(&account)?;
+		}
+		let address = AddressMapper::<T>::to_address(&account);
+		let origin = Origin::<T> { address, account: account.clone() };
+		T::Fungibles::hold(&HoldReason::Messaging.into(), &account, deposit)?;
+
+		let id = next_message_id::<T>()?;
+		let (message, timeout) = translate::<T>(&origin, message, deposit)?;
+		match &message {
+			Message::Pending { transport, .. } => {
+				PendingMessages::<T>::mutate(address, |pending| {
+					*pending = pending.saturating_add(1)
+				});
+				if let (Some(query_id), Some(timeout)) =
+					(transport.key::<T, XcmTransport>(), timeout)
+				{
+					// Timeouts are only processed from the next block.
+					let next_block =
+						frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+					transports::xcm::schedule_timeout::<T>(query_id, id, timeout.max(next_block));
+				}
+			},
+			Message::Complete { .. } | Message::Timeout { .. } => schedule_expiry::<T>(id),
+		}
+		TransportMessages::<T>::insert(message.transport(), id);
+		OriginMessages::<T>::insert(message.origin(), id, ());
+		Messages::<T>::insert(id, message);
+		log::debug!(target: LOG_TARGET, "migrated message: id={old_id} => {id}, account={account:?}");
+		Ok(())
+	});
+	if let Err(error) = result {
+		log::error!(
+			target: LOG_TARGET,
+			"dropped message: id={old_id}, account={account:?}, error={error:?}"
+		);
+	}
+}
+
+// Translates a message of `pallet_api::messaging` into a message of this pallet, along with the
+// block at which a pending XCM query times out.
+fn translate<T>(
+	origin: &Origin<T>,
+	message: v0::Message<BalanceOf<T>>,
+	message_deposit: BalanceOf<T>,
+) -> Result<(Message<T>, Option<BlockNumberOf<T>>), DispatchError>
+where
+	T: Config + pallet_xcm::Config,
+	<T as pallet_xcm::Config>::RuntimeCall: From<Call<T>>,
+{
+	use v0::Message::*;
+	let address = origin.address;
+	Ok(match message {
+		Ismp { commitment, callback, .. } => {
+			let transport = MessageTransport::of::<T, IsmpTransport>(&commitment)?;
+			record_fee::<T>(commitment);
+			(
+				Message::Pending {
+					origin: origin.clone(),
+					transport,
+					callback: drop_callback(origin, callback),
+					message_deposit,
+				},
+				None,
+			)
+		},
+		IsmpTimedOut { commitment, .. } => (
+			Message::Timeout {
+				origin: address,
+				transport: MessageTransport::of::<T, IsmpTransport>(&commitment)?,
+				message_deposit,
+				callback_deposit: None,
+			},
+			None,
+		),
+		IsmpResponse { commitment, response, .. } => (
+			Message::Complete {
+				origin: address,
+				transport: MessageTransport::of::<T, IsmpTransport>(&commitment)?,
+				message_deposit,
+				response: response.try_into().map_err(|_| Error::<T>::InvalidMessage)?,
+			},
+			None,
+		),
+		XcmQuery { query_id, callback, .. } => {
+			let transport = MessageTransport::of::<T, XcmTransport>(&query_id)?;
+			// Queries which are no longer pending on `pallet-xcm` will never receive a response.
+			match transports::xcm::renotify_pallet_xcm_query::<T>(query_id) {
+				Some(timeout) => (
+					Message::Pending {
+						origin: origin.clone(),
+						transport,
+						callback: drop_callback(origin, callback),
+						message_deposit,
+					},
+					Some(timeout),
+				),
+				None => (
+					Message::Timeout {
+						origin: address,
+						transport,
+						message_deposit,
+						callback_deposit: None,
+					},
+					None,
+				),
+			}
+		},
+		XcmResponse { query_id, response, .. } => (
+			Message::Complete {
+				origin: address,
+				transport: MessageTransport::of::<T, XcmTransport>(&query_id)?,
+				message_deposit,
+				response: response.encode().try_into().map_err(|_| Error::<T>::InvalidMessage)?,
+			},
+			None,
+		),
+	})
+}
+
+// Records the fee paid to relayers for a pending ISMP request, being the fee with which it was
+// dispatched along with any since added, as recorded by the ISMP dispatcher.
+fn record_fee<T: Config>(commitment: H256) {
+	if let Some(fee) = T::IsmpDispatcher::request_fee(commitment) {
+		IsmpFees::<T>::insert(commitment, fee);
+	}
+}
+
+// Drops any callback of `pallet_api::messaging`, which targets a contract of `pallet-contracts` on
+// the origin and so cannot be executed by `pallet-revive`. Any response is stored for polling.
+fn drop_callback<T: Config>(
+	origin: &Origin<T>,
+	callback: Option<v0::Callback>,
+) -> Option<Callback<BalanceOf<T>>> {
+	if let Some(callback) = callback {
+		log::warn!(
+			target: LOG_TARGET,
+			"dropped callback: account={:?}, selector={:?}",
+			origin.account,
+			callback.selector
+		);
+	}
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::{assert_ok, parameter_types, traits::fungible::InspectHold};
+	use pallet_revive::HoldReason::StorageDepositReserve;
+
+	use super::*;
+	use crate::{mock::*, to_address};
+
+	type Fungibles = <Test as Config>::Fungibles;
+	type Message = super::Message<Test>;
+	type Messages = super::Messages<Test>;
+	type Migration = MigrateFromPalletApi<Test, PalletApi>;
+	type OldMessage = v0::Message<Balance>;
+	type OldMessages = v0::Messages<OldPalletName, Test>;
+	type PendingMessages = super::PendingMessages<Test>;
+	type PendingXcmQueries = super::PendingXcmQueries<Test>;
+	type TransportMessages = super::TransportMessages<Test>;
+
+	const DEPOSIT: Balance = UNIT;
+
+	parameter_types! {
+		pub const OldPalletName: &'static str = "PalletApiMessaging";
+		// A stand-in for the hold reason of the previous pallet, which is not part of the mock.
+		pub OldHoldReason: RuntimeHoldReason = StorageDepositReserve.into();
+	}
+
+	struct PalletApi;
+	impl PalletApiMessaging<Test> for PalletApi {
+		type HoldReason = OldHoldReason;
+		type PalletName = OldPalletName;
+	}
+
+	#[test]
+	fn migrates_ismp_messages() {
+		let account = ALICE;
+		let address = to_address(&account);
+		let callback = v0::Callback { selector: [1; 4], weight: Weight::from_parts(100, 100) };
+		let [request, timed_out, responded] = [1, 2, 3].map(H256::repeat_byte);
+		ExtBuilder::new()
+			.with_balances(vec![(account.clone(), 100 * UNIT)])
+			.build()
+			.execute_with(|| {
+				insert(
+					&account,
+					1,
+					OldMessage::Ismp {
+						commitment: request,
+						callback: Some(callback.clone()),
+						deposit: DEPOSIT,
+					},
+				);
+				insert(
+					&account,
+					2,
+					OldMessage::IsmpTimedOut { commitment: timed_out, deposit: DEPOSIT },
+				);
+				insert(
+					&account,
+					3,
+					OldMessage::IsmpResponse {
+						commitment: responded,
+						deposit: DEPOSIT,
+						response: vec![1, 2, 3],
+					},
+				);
+
+				migrate();
+
+				let origin = Origin::from((address, account.clone()));
+				for (commitment, message) in [
+					(request, Message::ismp(origin, request, None, DEPOSIT)),
+					(timed_out, Message::ismp_timeout(address, timed_out, DEPOSIT, None)),
+					(
+						responded,
+						Message::ismp_response(
+							address,
+							responded,
+							DEPOSIT,
+							BoundedVec::truncate_from(vec![1, 2, 3]),
+						),
+					),
+				] {
+					let id =
+						TransportMessages::get(MessageTransport::ismp::<Test>(commitment)).unwrap();
+					assert_eq!(Messages::get(id), Some(message));
+				}
+				assert_eq!(PendingMessages::get(address), 1);
+				assert_eq!(Fungibles::balance_on_hold(&OldHoldReason::get(), &account), 0);
+				assert_eq!(
+					Fungibles::balance_on_hold(&HoldReason::Messaging.into(), &account),
+					3 * DEPOSIT
+				);
+				assert_eq!(
+					Fungibles::balance_on_hold(&HoldReason::CallbackGas.into(), &account),
+					0
+				);
+				assert!(v0::IsmpRequests::<OldPalletName, Test>::iter().next().is_none());
+			})
+	}
+
+	#[test]
+	fn migrates_xcm_messages() {
+		let account = ALICE;
+		let address = to_address(&account);
+		let callback = v0::Callback { selector: [1; 4], weight: Weight::from_parts(100, 100) };
+		ExtBuilder::new()
+			.with_balances(vec![(account.clone(), 100 * UNIT)])
+			.build()
+			.execute_with(|| {
+				// A query notifying the previous pallet, for which any call will suffice.
+				let notify = frame_system::Call::<Test>::remark { remark: vec![] };
+				let timeout = System::block_number() + 10;
+				let pending = pallet_xcm::Pallet::<Test>::new_notify_query(
+					RESPONSE_LOCATION,
+					notify,
+					timeout,
+					Location::here(),
+				);
+				let (expired, responded) = (pending + 1, pending + 2);
+				insert(
+					&account,
+					1,
+					OldMessage::XcmQuery {
+						query_id: pending,
+						callback: Some(callback.clone()),
+						deposit: DEPOSIT,
+					},
+				);
+				insert(
+					&account,
+					2,
+					OldMessage::XcmQuery { query_id: expired, callback: None, deposit: DEPOSIT },
+				);
+				insert(
+					&account,
+					3,
+					OldMessage::XcmResponse {
+						query_id: responded,
+						deposit: DEPOSIT,
+						response: Response::Null,
+					},
+				);
+
+				migrate();
+
+				let origin = Origin::from((address, account.clone()));
+				for (query_id, message) in [
+					(pending, Message::xcm_query(origin, pending, None, DEPOSIT)),
+					(expired, Message::xcm_timeout(address, expired, DEPOSIT, None)),
+					(responded, Message::xcm_response(address, responded, DEPOSIT, Response::Null)),
+				] {
+					let id =
+						TransportMessages::get(MessageTransport::xcm::<Test>(query_id)).unwrap();
+					assert_eq!(Messages::get(id), Some(message));
+				}
+				assert_eq!(PendingXcmQueries::get(pending), Some(timeout));
+				assert_eq!(PendingMessages::get(address), 1);
+				assert_eq!(Fungibles::balance_on_hold(&OldHoldReason::get(), &account), 0);
+				assert_eq!(
+					Fungibles::balance_on_hold(&HoldReason::Messaging.into(), &account),
+					3 * DEPOSIT
+				);
+				assert!(v0::XcmQueries::<OldPalletName, Test>::iter().next().is_none());
+				// The response to the pending query is now delivered to this pallet.
+				let notify: RuntimeCall =
+					Call::<Test>::xcm_response { query_id: 0, xcm_response: Response::Null }.into();
+				let Some(pallet_xcm::QueryStatus::Pending { maybe_notify, .. }) =
+					pallet_xcm::Pallet::<Test>::query(&pending)
+				else {
+					panic!("query should be pending")
+				};
+				assert_eq!(maybe_notify, notify.using_encoded(|call| Some((call[0], call[1]))));
+			})
+	}
+
+	#[test]
+	fn drops_messages_which_cannot_be_migrated() {
+		let account = ALICE;
+		let response = vec![0; <Test as Config>::MaxResponseLen::get() as usize + 1];
+		ExtBuilder::new()
+			.with_balances(vec![(account.clone(), 100 * UNIT)])
+			.build()
+			.execute_with(|| {
+				insert(
+					&account,
+					1,
+					OldMessage::IsmpResponse {
+						commitment: H256::zero(),
+						deposit: DEPOSIT,
+						response,
+					},
+				);
+
+				migrate();
+
+				assert!(Messages::iter().next().is_none());
+				assert!(TransportMessages::iter().next().is_none());
+				assert_eq!(Fungibles::balance_on_hold(&OldHoldReason::get(), &account), 0);
+				assert_eq!(Fungibles::balance_on_hold(&HoldReason::Messaging.into(), &account), 0);
+			})
+	}
+
+	#[test]
+	fn drops_callbacks_and_stores_responses_for_polling() {
+		let account = ALICE;
+		let address = to_address(&account);
+		let callback = v0::Callback { selector: [1; 4], weight: Weight::from_parts(100, 100) };
+		ExtBuilder::new()
+			.with_balances(vec![(account.clone(), 100 * UNIT)])
+			.build()
+			.execute_with(|| {
+				let notify = frame_system::Call::<Test>::remark { remark: vec![] };
+				let query_id = pallet_xcm::Pallet::<Test>::new_notify_query(
+					RESPONSE_LOCATION,
+					notify,
+					System::block_number() + 10,
+					Location::here(),
+				);
+				insert(
+					&account,
+					1,
+					OldMessage::XcmQuery { query_id, callback: Some(callback), deposit: DEPOSIT },
+				);
+				let balance = Fungibles::balance(&account);
+
+				migrate();
+
+				// No callback deposit is held, as the callback weight was prepaid.
+				assert_eq!(Fungibles::balance(&account), balance);
+				assert_eq!(
+					Fungibles::balance_on_hold(&HoldReason::CallbackGas.into(), &account),
+					0
+				);
+
+				assert_ok!(Pallet::<Test>::xcm_response(root(), query_id, Response::Null));
+
+				let id = TransportMessages::get(MessageTransport::xcm::<Test>(query_id)).unwrap();
+				assert_eq!(
+					Messages::get(id),
+					Some(Message::xcm_response(address, query_id, DEPOSIT, Response::Null))
+				);
+				assert_eq!(
+					Fungibles::balance_on_hold(&HoldReason::Messaging.into(), &account),
+					DEPOSIT
+				);
+			})
+	}
+
+	#[test]
+	fn step_requires_weight_for_a_message() {
+		ExtBuilder::new().build().execute_with(|| {
+			let required = Migration::step_weight();
+			let mut meter =
+				WeightMeter::with_limit(required.saturating_sub(Weight::from_parts(1, 0)));
+			assert_eq!(
+				Migration::step(None, &mut meter),
+				Err(SteppedMigrationError::InsufficientWeight { required })
+			);
+		})
+	}
+
+	#[test]
+	fn step_migrates_messages_within_weight_limit() {
+		let account = ALICE;
+		ExtBuilder::new()
+			.with_balances(vec![(account.clone(), 100 * UNIT)])
+			.build()
+			.execute_with(|| {
+				for id in 0..3 {
+					insert(
+						&account,
+						id,
+						OldMessage::IsmpTimedOut {
+							commitment: H256::repeat_byte(id as u8),
+							deposit: DEPOSIT,
+						},
+					);
+				}
+				let mut meter = WeightMeter::with_limit(Migration::step_weight() * 2);
+
+				assert_eq!(Migration::step(None, &mut meter), Ok(Some(Stage::Messages)));
+				assert_eq!(OldMessages::iter().count(), 1);
+				assert_eq!(Messages::iter().count(), 2);
+			})
+	}
+
+	#[test]
+	fn pallets_must_have_different_names() {
+		struct SamePallet;
+		impl PalletApiMessaging<Test> for SamePallet {
+			type HoldReason = OldHoldReason;
+			type PalletName = MessagingPalletName;
+		}
+		parameter_types! {
+			pub const MessagingPalletName: &'static str = "Messaging";
+		}
+
+		ExtBuilder::new().build().execute_with(|| {
+			assert_eq!(
+				MigrateFromPalletApi::<Test, SamePallet>::step(None, &mut WeightMeter::new()),
+				Err(SteppedMigrationError::Failed)
+			);
+		})
+	}
+
+	// Inserts a message into the storage of the previous pallet, holding its deposit.
+	fn insert(account: &AccountId, id: MessageId, message: OldMessage) {
+		match &message {
+			OldMessage::Ismp { commitment, .. } |
+			OldMessage::IsmpTimedOut { commitment, .. } |
+			OldMessage::IsmpResponse { commitment, .. } =>
+				v0::IsmpRequests::<OldPalletName, Test>::insert(commitment, (account, id)),
+			OldMessage::XcmQuery { query_id, .. } | OldMessage::XcmResponse { query_id, .. } =>
+				v0::XcmQueries::<OldPalletName, Test>::insert(query_id, (account, id)),
+		}
+		assert_ok!(Fungibles::hold(&OldHoldReason::get(), account, message.deposit()));
+		OldMessages::insert(account, id, message);
+	}
+
+	// Runs the migration to completion, including its try-runtime checks.
+	fn migrate() {
+		#[cfg(feature = "try-runtime")]
+		let state = Migration::pre_upgrade().unwrap();
+		let mut cursor = None;
+		loop {
+			cursor = Migration::step(cursor, &mut WeightMeter::new()).unwrap();
+			if cursor.is_none() {
+				break;
+			}
+		}
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(Migration::post_upgrade(state));
+	}
+}
@@ -0,0 +1,624 @@
+use frame_support::{
+	migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+	traits::{GetStorageVersion, StorageVersion},
+};
+
+use super::*;
+
+/// The layout of the storage of the pallet prior to version 1, where messages were stored by the
+/// kind of transport over which they were sent and correlated via transport-specific lookups.
+pub mod v0 {
+	use super::*;
+
+	/// A message of the pallet.
+	#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub enum Message<T: Config> {
+		/// A pending ISMP request.
+		Ismp {
+			origin: Origin<T>,
+			commitment: H256,
+			callback: Option<Callback<BalanceOf<T>>>,
+			message_deposit: BalanceOf<T>,
+		},
+		/// A pending XCM query.
+		XcmQuery {
+			origin: Origin<T>,
+			query_id: QueryId,
+			callback: Option<Callback<BalanceOf<T>>>,
+			message_deposit: BalanceOf<T>,
+		},
+		/// A response to an ISMP request.
+		IsmpResponse {
+			origin: H160,
+			commitment: H256,
+			message_deposit: BalanceOf<T>,
+			response: BoundedVec<u8, T::MaxResponseLen>,
+		},
+		/// A response to a XCM query.
+		XcmResponse {
+			origin: H160,
+			query_id: QueryId,
+			message_deposit: BalanceOf<T>,
+			response: Response,
+		},
+		/// An ISMP request which timed out.
+		IsmpTimeout {
+			origin: H160,
+			commitment: H256,
+			message_deposit: BalanceOf<T>,
+			callback_deposit: Option<BalanceOf<T>>,
+		},
+		/// A XCM query which timed out.
+		XcmTimeout {
+			origin: H160,
+			query_id: QueryId,
+			message_deposit: BalanceOf<T>,
+			callback_deposit: Option<BalanceOf<T>>,
+		},
+	}
+
+	/// The messages, by identifier.
+	#[frame_support::storage_alias]
+	pub type Messages<T: Config> = StorageMap<Pallet<T>, Twox64Concat, MessageId, Message<T>>;
+
+	/// The identifiers of the messages of ISMP requests, by commitment.
+	#[frame_support::storage_alias]
+	pub type IsmpRequests<T: Config> = StorageMap<Pallet<T>, Identity, H256, MessageId>;
+
+	/// The identifiers of the messages of XCM queries, by query identifier.
+	#[frame_support::storage_alias]
+	pub type XcmQueries<T: Config> = StorageMap<Pallet<T>, Twox64Concat, QueryId, MessageId>;
+}
+
+/// The stage of the migration.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub enum Stage<BlockNumber> {
+	/// Recording the timeouts of pending XCM queries, continuing after the block of the last
+	/// timeouts recorded.
+	Timeouts(Option<BlockNumber>),
+	/// Migrating messages, continuing after the last message migrated.
+	Messages(Option<MessageId>),
+	/// Removing the lookups of messages by their ISMP commitments and XCM query identifiers.
+	Lookups,
+}
+
+/// A multi-block migration of the storage of the pallet to version 1.
+///
+/// Each message is translated in place, keeping its identifier, with its transport and origin
+/// recorded in the lookups of messages by transport and by origin. Pending messages are counted
+/// against the pending messages of their origin, regardless of any maximum. Completed and timed out
+/// messages expire after the `MessageRetentionPeriod`, starting from the migration. The timeouts of
+/// pending XCM queries are retained, with any which passed during the migration occurring in the
+/// block after the message is migrated. The deposits held for messages are unchanged.
+///
+/// Messages which cannot be migrated are dropped, with their deposits released.
+///
+/// Whilst the migration is ongoing, the hooks of the pallet are suspended and inbound ISMP
+/// responses and timeouts are rejected, allowing their later redelivery. As a rejected XCM response
+/// cannot be redelivered, runtimes should also pause the processing of inbound XCM messages until
+/// the migration completes.
+pub struct MigrateToV1<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for MigrateToV1<T> {
+	type Cursor = Stage<BlockNumberOf<T>>;
+	type Identifier = MigrationId<24>;
+
+	fn id() -> Self::Identifier {
+		MigrationId { pallet_id: *b"pop-api-vnext::messaging", version_from: 0, version_to: 1 }
+	}
+
+	fn step(
+		mut cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+			return Ok(None);
+		}
+		let required = Self::step_weight();
+		if meter.remaining().any_lt(required) {
+			return Err(SteppedMigrationError::InsufficientWeight { required });
+		}
+
+		while meter.try_consume(required).is_ok() {
+			cursor = match cursor.unwrap_or(Stage::Timeouts(None)) {
+				Stage::Timeouts(last) => {
+					let mut timeouts = match last {
+						Some(last) => XcmQueryTimeouts::<T>::iter_from(
+							XcmQueryTimeouts::<T>::hashed_key_for(last),
+						),
+						None => XcmQueryTimeouts::<T>::iter(),
+					};
+					match timeouts.next() {
+						Some((block, ids)) => {
+							record_timeouts::<T>(block, ids.into_inner());
+							Some(Stage::Timeouts(Some(block)))
+						},
+						None => Some(Stage::Messages(None)),
+					}
+				},
+				Stage::Messages(last) => {
+					let mut messages = match last {
+						Some(last) =>
+							v0::Messages::<T>::iter_from(v0::Messages::<T>::hashed_key_for(last)),
+						None => v0::Messages::<T>::iter(),
+					};
+					match messages.next() {
+						Some((id, message)) => {
+							migrate::<T>(id, message);
+							Some(Stage::Messages(Some(id)))
+						},
+						None => Some(Stage::Lookups),
+					}
+				},
+				Stage::Lookups => (v0::IsmpRequests::<T>::drain().next().is_some() ||
+					v0::XcmQueries::<T>::drain().next().is_some())
+				.then_some(Stage::Lookups),
+			};
+			if cursor.is_none() {
+				StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T>>();
+				break;
+			}
+		}
+		Ok(cursor)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(
+			Pallet::<T>::on_chain_storage_version() == Self::id().version_from as u16,
+			"the storage version should be 0"
+		);
+		let mut messages = 0u32;
+		let mut pending = 0u32;
+		for message in v0::Messages::<T>::iter_values() {
+			messages.saturating_inc();
+			if matches!(message, v0::Message::Ismp { .. } | v0::Message::XcmQuery { .. }) {
+				pending.saturating_inc();
+			}
+		}
+		Ok((messages, pending).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let (messages, pending): (u32, u32) = Decode::decode(&mut state.as_slice())
+			.map_err(|_| "the state should have been generated by pre_upgrade")?;
+		ensure!(
+			Pallet::<T>::on_chain_storage_version() == Self::id().version_to as u16,
+			"the storage version should be 1"
+		);
+		ensure!(
+			v0::IsmpRequests::<T>::iter().next().is_none() &&
+				v0::XcmQueries::<T>::iter().next().is_none(),
+			"the lookups of the previous layout should have been removed"
+		);
+		let mut migrated = 0u32;
+		for (id, message) in Messages::<T>::iter() {
+			ensure!(
+				TransportMessages::<T>::get(message.transport()) == Some(id) &&
+					OriginMessages::<T>::contains_key(message.origin(), id),
+				"each message should be looked up by its transport and origin"
+			);
+			migrated.saturating_inc();
+		}
+		ensure!(migrated == messages, "every message should have been migrated");
+		ensure!(
+			PendingMessages::<T>::iter_values().fold(0u32, |total, n| total.saturating_add(n)) ==
+				pending,
+			"each pending message should be counted against its origin"
+		);
+		Ok(())
+	}
+}
+
+impl<T: Config> MigrateToV1<T> {
+	// The weight of a single step, as an upper bound of the storage accessed when recording the
+	// timeouts of a block, or when migrating a message: the message, the timeout and pending
+	// count of a pending message, or the expiry queue of a concluded message, along with the
+	// transport and origin lookups, the fee of an ISMP request and the holds of the origin should
+	// the message be dropped.
+	fn step_weight() -> Weight {
+		let timeouts = T::MaxXcmQueryTimeoutsPerBlock::get() as u64;
+		DbWeightOf::<T>::get()
+			.reads_writes(timeouts.saturating_add(1), timeouts)
+			.max(DbWeightOf::<T>::get().reads_writes(7, 10))
+	}
+}
+
+// Records the timeouts of the pending XCM queries of a block which is yet to be reached. Those of
+// blocks which have been reached were not processed, as the messages could not be read.
+fn record_timeouts<T: Config>(block: BlockNumberOf<T>, ids: Vec<MessageId>) {
+	if block <= frame_system::Pallet::<T>::block_number() {
+		return;
+	}
+	for id in ids {
+		if let Some(v0::Message::XcmQuery { query_id, .. }) = v0::Messages::<T>::get(id) {
+			PendingXcmQueries::<T>::insert(query_id, block);
+		}
+	}
+}
+
+// Migrates a message in place. Any message which cannot be migrated is dropped, with its deposits
+// released.
+fn migrate<T: Config>(id: MessageId, message: v0::Message<T>) {
+	let message = match translate::<T>(id, message.clone()) {
+		Ok(message) => message,
+		Err(error) => {
+			log::error!(target: LOG_TARGET, "dropped message: id={id}, error={error:?}");
+			v0::Messages::<T>::remove(id);
+			release::<T>(message);
+			return;
+		},
+	};
+	match &message {
+		Message::Pending { origin, .. } =>
+			PendingMessages::<T>::mutate(origin.address, |pending| {
+				*pending = pending.saturating_add(1)
+			}),
+		Message::Complete { .. } | Message::Timeout { .. } => schedule_expiry::<T>(id),
+	}
+	TransportMessages::<T>::insert(message.transport(), id);
+	OriginMessages::<T>::insert(message.origin(), id, ());
+	Messages::<T>::insert(id, message);
+	log::debug!(target: LOG_TARGET, "migrated message: id={id}");
+}
+
+// Translates a message into the layout of version 1, scheduling the timeout of a pending XCM query
+// whose timeout was not recorded.
+fn translate<T: Config>(
+	id: MessageId,
+	message: v0::Message<T>,
+) -> Result<Message<T>, DispatchError> {
+	use v0::Message::*;
+	Ok(match message {
+		Ismp { origin, commitment, callback, message_deposit } => {
+			let transport = MessageTransport::of::<T, IsmpTransport>(&commitment)?;
+			record_fee::<T>(commitment);
+			Message::Pending { origin, transport, callback, message_deposit }
+		},
+		XcmQuery { origin, query_id, callback, message_deposit } => {
+			let transport = MessageTransport::of::<T, XcmTransport>(&query_id)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			if !PendingXcmQueries::<T>::get(query_id).is_some_and(|timeout| timeout > now) {
+				transports::xcm::schedule_timeout::<T>(
+					query_id,
+					id,
+					now.saturating_add(One::one()),
+				);
+			}
+			Message::Pending { origin, transport, callback, message_deposit }
+		},
+		IsmpResponse { origin, commitment, message_deposit, response } => Message::Complete {
+			origin,
+			transport: MessageTransport::of::<T, IsmpTransport>(&commitment)?,
+			message_deposit,
+			response,
+		},
+		XcmResponse { origin, query_id, message_deposit, response } => Message::Complete {
+			origin,
+			transport: MessageTransport::of::<T, XcmTransport>(&query_id)?,
+			message_deposit,
+			response: response.encode().try_into().map_err(|_| Error::<T>::InvalidMessage)?,
+		},
+		IsmpTimeout { origin, commitment, message_deposit, callback_deposit } => Message::Timeout {
+			origin,
+			transport: MessageTransport::of::<T, IsmpTransport>(&commitment)?,
+			message_deposit,
+			callback_deposit,
+		},
+		XcmTimeout { origin, query_id, message_deposit, callback_deposit } => Message::Timeout {
+			origin,
+			transport: MessageTransport::of::<T, XcmTransport>(&query_id)?,
+			message_deposit,
+			callback_deposit,
+		},
+	})
+}
+
+// Releases the deposits held for a message which is dropped.
+fn release<T: Config>(message: v0::Message<T>) {
+	use v0::Message::*;
+	let (account, message_deposit, callback_deposit) = match message {
+		Ismp { origin, callback, message_deposit, .. } |
+		XcmQuery { origin, callback, message_deposit, .. } => (
+			origin.account,
+			message_deposit,
+			callback.map(|cb| T::WeightToFee::weight_to_fee(&cb.gas_limit)),
+		),
+		IsmpResponse { origin, message_deposit, .. } |
+		XcmResponse { origin, message_deposit, .. } =>
+			(T::AddressConverter::convert(origin), message_deposit, None),
+		IsmpTimeout { origin, message_deposit, callback_deposit, .. } |
+		XcmTimeout { origin, message_deposit, callback_deposit, .. } =>
+			(T::AddressConverter::convert(origin), message_deposit, callback_deposit),
+	};
+	for (reason, deposit) in [
+		(HoldReason::Messaging, Some(message_deposit)),
+		(HoldReason::CallbackGas, callback_deposit),
+	] {
+		let Some(deposit) = deposit else { continue };
+		if let Err(error) =
+			T::Fungibles::release(&reason.into(), &account, deposit, Precision::BestEffort)
+		{
+			log::error!(target: LOG_TARGET, "failed to release deposit: account={account:?}, error={error:?}");
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ::ismp::{
+		dispatcher::{DispatchPost, DispatchRequest, FeeMetadata},
+		host::StateMachine,
+	};
+	#[cfg(feature = "try-runtime")]
+	use frame_support::assert_ok;
+
+	use super::*;
+	use crate::mock::*;
+
+	type Message = super::Message<Test>;
+	type Messages = super::Messages<Test>;
+	type Migration = MigrateToV1<Test>;
+	type OldMessage = v0::Message<Test>;
+	type OldMessages = v0::Messages<Test>;
+	type PendingMessages = super::PendingMessages<Test>;
+	type PendingXcmQueries = super::PendingXcmQueries<Test>;
+	type TransportMessages = super::TransportMessages<Test>;
+	type XcmQueryTimeouts = super::XcmQueryTimeouts<Test>;
+
+	const DEPOSIT: Balance = UNIT;
+
+	#[test]
+	fn migrates_messages() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let address = origin.address;
+		let callback = Some(callback());
+		let commitments = [1, 2, 3].map(H256::repeat_byte);
+		let query_ids = [1, 2, 3];
+		at_version_0(ExtBuilder::new().build()).execute_with(|| {
+			let timeout = System::block_number() + 10;
+			let old_messages = [
+				(
+					0,
+					OldMessage::Ismp {
+						origin: origin.clone(),
+						commitment: commitments[0],
+						callback: callback.clone(),
+						message_deposit: DEPOSIT,
+					},
+					Message::ismp(origin.clone(), commitments[0], callback.clone(), DEPOSIT),
+				),
+				(
+					1,
+					OldMessage::IsmpResponse {
+						origin: address,
+						commitment: commitments[1],
+						message_deposit: DEPOSIT,
+						response: BoundedVec::truncate_from(vec![1, 2, 3]),
+					},
+					Message::ismp_response(
+						address,
+						commitments[1],
+						DEPOSIT,
+						BoundedVec::truncate_from(vec![1, 2, 3]),
+					),
+				),
+				(
+					2,
+					OldMessage::IsmpTimeout {
+						origin: address,
+						commitment: commitments[2],
+						message_deposit: DEPOSIT,
+						callback_deposit: Some(DEPOSIT),
+					},
+					Message::ismp_timeout(address, commitments[2], DEPOSIT, Some(DEPOSIT)),
+				),
+				(
+					3,
+					OldMessage::XcmQuery {
+						origin: origin.clone(),
+						query_id: query_ids[0],
+						callback: callback.clone(),
+						message_deposit: DEPOSIT,
+					},
+					Message::xcm_query(origin.clone(), query_ids[0], callback.clone(), DEPOSIT),
+				),
+				(
+					4,
+					OldMessage::XcmResponse {
+						origin: address,
+						query_id: query_ids[1],
+						message_deposit: DEPOSIT,
+						response: Response::Null,
+					},
+					Message::xcm_response(address, query_ids[1], DEPOSIT, Response::Null),
+				),
+				(
+					5,
+					OldMessage::XcmTimeout {
+						origin: address,
+						query_id: query_ids[2],
+						message_deposit: DEPOSIT,
+						callback_deposit: None,
+					},
+					Message::xcm_timeout(address, query_ids[2], DEPOSIT, None),
+				),
+			];
+			for (id, message, _) in &old_messages {
+				insert(*id, message.clone());
+			}
+			XcmQueryTimeouts::insert(timeout, BoundedVec::truncate_from(vec![3]));
+
+			migrate();
+
+			for (id, _, message) in old_messages {
+				assert_eq!(TransportMessages::get(message.transport()), Some(id));
+				assert!(OriginMessages::<Test>::contains_key(address, id));
+				assert_eq!(Messages::get(id), Some(message));
+			}
+			assert_eq!(PendingMessages::get(address), 2);
+			assert_eq!(PendingXcmQueries::get(query_ids[0]), Some(timeout));
+			// Completed and timed out messages are scheduled to expire.
+			assert_eq!(MessageExpiryQueue::<Test>::get(), (0, 4));
+			assert!(v0::IsmpRequests::<Test>::iter().next().is_none());
+			assert!(v0::XcmQueries::<Test>::iter().next().is_none());
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+		})
+	}
+
+	#[test]
+	fn records_the_fees_of_ismp_requests() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let fee = UNIT;
+		at_version_0(
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 100 * UNIT)])
+				.build(),
+		)
+		.execute_with(|| {
+			let request = DispatchPost {
+				dest: StateMachine::Polkadot(u32::MAX),
+				from: transports::ismp::ID.to_vec(),
+				to: transports::ismp::ID.to_vec(),
+				timeout: u64::MAX,
+				body: vec![],
+			};
+			let commitment = <Test as Config>::IsmpDispatcher::default()
+				.dispatch_request(
+					DispatchRequest::Post(request),
+					FeeMetadata { payer: origin.account.clone(), fee },
+				)
+				.unwrap();
+			insert(
+				0,
+				OldMessage::Ismp { origin, commitment, callback: None, message_deposit: DEPOSIT },
+			);
+
+			migrate();
+
+			assert_eq!(IsmpFees::<Test>::get(commitment), Some(fee));
+		})
+	}
+
+	#[test]
+	fn times_out_queries_whose_timeout_passed() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let query_id = 0;
+		at_version_0(ExtBuilder::new().build()).execute_with(|| {
+			let passed = System::block_number();
+			insert(
+				0,
+				OldMessage::XcmQuery {
+					origin: origin.clone(),
+					query_id,
+					callback: None,
+					message_deposit: DEPOSIT,
+				},
+			);
+			XcmQueryTimeouts::insert(passed, BoundedVec::truncate_from(vec![0]));
+
+			migrate();
+
+			let next_block = passed + 1;
+			assert_eq!(PendingXcmQueries::get(query_id), Some(next_block));
+			assert_eq!(XcmQueryTimeouts::get(next_block).into_inner(), vec![0]);
+		})
+	}
+
+	#[test]
+	fn skips_when_migrated() {
+		at_version_0(ExtBuilder::new().build()).execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<Test>>();
+			insert(
+				0,
+				OldMessage::IsmpTimeout {
+					origin: ALICE_ADDR,
+					commitment: H256::zero(),
+					message_deposit: DEPOSIT,
+					callback_deposit: None,
+				},
+			);
+
+			assert_eq!(Migration::step(None, &mut WeightMeter::new()), Ok(None));
+			assert!(OldMessages::get(0).is_some());
+		})
+	}
+
+	#[test]
+	fn step_requires_weight_for_a_message() {
+		at_version_0(ExtBuilder::new().build()).execute_with(|| {
+			let required = Migration::step_weight();
+			let mut meter =
+				WeightMeter::with_limit(required.saturating_sub(Weight::from_parts(1, 0)));
+			assert_eq!(
+				Migration::step(None, &mut meter),
+				Err(SteppedMigrationError::InsufficientWeight { required })
+			);
+		})
+	}
+
+	#[test]
+	fn step_migrates_messages_within_weight_limit() {
+		at_version_0(ExtBuilder::new().build()).execute_with(|| {
+			for id in 0..3 {
+				insert(
+					id,
+					OldMessage::IsmpTimeout {
+						origin: ALICE_ADDR,
+						commitment: H256::repeat_byte(id as u8),
+						message_deposit: DEPOSIT,
+						callback_deposit: None,
+					},
+				);
+			}
+			// Sufficient for the absence of timeouts and two messages.
+			let mut meter = WeightMeter::with_limit(Migration::step_weight() * 3);
+
+			let Ok(Some(Stage::Messages(Some(_)))) = Migration::step(None, &mut meter) else {
+				panic!("messages should be being migrated")
+			};
+			assert_eq!(Messages::iter().count(), 2);
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 0);
+		})
+	}
+
+	// Sets the storage of the pallet to the version prior to the migration.
+	fn at_version_0(mut ext: sp_io::TestExternalities) -> sp_io::TestExternalities {
+		ext.execute_with(|| StorageVersion::new(0).put::<Pallet<Test>>());
+		ext
+	}
+
+	fn callback() -> Callback<Balance> {
+		Callback::new(ALICE_ADDR, Encoding::Scale, [1; 4], Weight::from_parts(100, 100), 0)
+	}
+
+	// Inserts a message using the previous layout, along with its lookup.
+	fn insert(id: MessageId, message: OldMessage) {
+		match &message {
+			OldMessage::Ismp { commitment, .. } |
+			OldMessage::IsmpResponse { commitment, .. } |
+			OldMessage::IsmpTimeout { commitment, .. } => v0::IsmpRequests::<Test>::insert(commitment, id),
+			OldMessage::XcmQuery { query_id, .. } |
+			OldMessage::XcmResponse { query_id, .. } |
+			OldMessage::XcmTimeout { query_id, .. } => v0::XcmQueries::<Test>::insert(query_id, id),
+		}
+		OldMessages::insert(id, message);
+	}
+
+	// Runs the migration to completion, including its try-runtime checks.
+	fn migrate() {
+		#[cfg(feature = "try-runtime")]
+		let state = Migration::pre_upgrade().unwrap();
+		let mut cursor = None;
+		loop {
+			cursor = Migration::step(cursor, &mut WeightMeter::new()).unwrap();
+			if cursor.is_none() {
+				break;
+			}
+		}
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(Migration::post_upgrade(state));
+	}
+}
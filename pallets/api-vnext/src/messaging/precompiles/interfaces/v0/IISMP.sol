@@ -5,6 +5,29 @@ pragma solidity ^0.8.30;
  * @title The ISMP precompile offers a streamlined interface for messaging using the Interoperable State Machine Protocol.
  */
 interface IISMP {
+    /**
+     * @notice Allow inbound ISMP `Post` requests from a module on a source state machine to be delivered to the calling contract.
+     * @dev Inbound requests are only delivered to a contract, at the expense of the contract, once it has allowed their source state machine and sending module. A deposit is held for the registration, released once it is revoked. Allowing a source which is already allowed has no effect.
+     * @param source The identifier of the source state machine, as provided to `onPostRequest` (e.g. `POLKADOT-2000`).
+     * @param from The module on the source state machine which sends the requests.
+     */
+    function allowSource(bytes calldata source, bytes calldata from) external;
+
+    /**
+     * @notice Cancel a subscription to recurring `Get` requests.
+     * @dev Releases the deposit of the subscription and the amounts prepaid for its remaining executions. Requests which have already been dispatched are unaffected. Only the origin of the subscription can cancel it.
+     * @param subscription The subscription identifier.
+     */
+    function cancelSubscription(uint64 subscription) external;
+
+    /**
+     * @notice Add to the fee paid to relayers for an outstanding request.
+     * @dev Allows the fee of a request which is yet to be relayed to be topped up, using the fee-funding mechanism of ISMP. Only the origin of the request can fund it.
+     * @param message The message identifier of the request to fund.
+     * @param amount The amount to be added to the fee paid to relayers.
+     */
+    function fundRequest(uint64 message, uint256 amount) external;
+
     /**
      * @notice Submit a new ISMP `Get` request.
      * @dev Sends a `Get` request through ISMP.
@@ -30,6 +53,32 @@ interface IISMP {
         Callback calldata callback
     ) external returns (uint64 id);
 
+    /**
+     * @notice Submit a new batch of ISMP `Get` requests.
+     * @dev Sends each `Get` request through ISMP, with the batch completing once every request has been answered or has timed out. A single deposit is held for the batch.
+     * @param requests The ISMP `Get` messages containing query details.
+     * @param fee The fee to be paid to relayers for each request.
+     * @return id A unique message identifier.
+     */
+    function getBatch(
+        Get[] calldata requests,
+        uint256 fee
+    ) external returns (uint64 id);
+
+    /**
+     * @notice Submit a new batch of ISMP `Get` requests.
+     * @dev Sends each `Get` request through ISMP, with the batch completing once every request has been answered or has timed out. The outcome of every request is delivered via a single execution of the callback. A single deposit is held for the batch.
+     * @param requests The ISMP `Get` messages containing query details.
+     * @param fee The fee to be paid to relayers for each request.
+     * @param callback The callback to execute once the batch completes.
+     * @return id A unique message identifier.
+     */
+    function getBatch(
+        Get[] calldata requests,
+        uint256 fee,
+        Callback calldata callback
+    ) external returns (uint64 id);
+
     /**
      * @notice Returns the response to a message (if any).
      * @dev A non-existent message identifier will return an empty response, which could also be a valid response depending on the source message.
@@ -44,6 +93,15 @@ interface IISMP {
      */
     function id() external returns (uint32 id);
 
+    /**
+     * @notice Polls the status of a batch of `Get` requests, along with the status of each of its requests.
+     * @dev The statuses of the requests are available whilst the batch is pending and once it has completed, until its response is removed. The statuses of the requests of any other message are empty.
+     * @param message The message identifier of the batch to poll.
+     * @return status The status of the batch.
+     * @return requests The status of each request, in the order in which they were specified.
+     */
+    function pollBatch(uint64 message) external returns (MessageStatus status, MessageStatus[] memory requests);
+
     /**
      * @notice Polls the status of a message.
      * @param message The message identifier to poll.
@@ -77,6 +135,67 @@ interface IISMP {
         Callback calldata callback
     ) external returns (uint64 id);
 
+    /**
+     * @notice Quote the amounts taken for a new `Get` request.
+     * @dev Excludes the fee paid to relayers, which is specified with the request.
+     * @return quote The amounts taken for the request.
+     */
+    function quoteGet() external view returns (Quote memory quote);
+
+    /**
+     * @notice Quote the amounts taken for a new `Get` request with a callback.
+     * @dev Excludes the fee paid to relayers, which is specified with the request.
+     * @param callback The callback to execute upon receiving a response.
+     * @return quote The amounts taken for the request.
+     */
+    function quoteGet(Callback calldata callback) external view returns (Quote memory quote);
+
+    /**
+     * @notice Quote the amounts taken for a new batch of `Get` requests.
+     * @dev Excludes the fees paid to relayers, which are specified with the batch.
+     * @param requests The number of requests of the batch.
+     * @return quote The amounts taken for the batch.
+     */
+    function quoteGetBatch(uint32 requests) external view returns (Quote memory quote);
+
+    /**
+     * @notice Quote the amounts taken for a new batch of `Get` requests with a callback.
+     * @dev Excludes the fees paid to relayers, which are specified with the batch.
+     * @param requests The number of requests of the batch.
+     * @param callback The callback to execute once the batch completes.
+     * @return quote The amounts taken for the batch.
+     */
+    function quoteGetBatch(uint32 requests, Callback calldata callback) external view returns (Quote memory quote);
+
+    /**
+     * @notice Quote the amounts taken for a new `Post` request.
+     * @dev Excludes the fee paid to relayers, which is specified with the request.
+     * @return quote The amounts taken for the request.
+     */
+    function quotePost() external view returns (Quote memory quote);
+
+    /**
+     * @notice Quote the amounts taken for a new `Post` request with a callback.
+     * @dev Excludes the fee paid to relayers, which is specified with the request.
+     * @param callback The callback to execute upon receiving a response.
+     * @return quote The amounts taken for the request.
+     */
+    function quotePost(Callback calldata callback) external view returns (Quote memory quote);
+
+    /**
+     * @notice Quote the amounts taken for a new subscription to recurring `Get` requests.
+     * @dev The amounts for all executions are taken upfront, including the fees paid to relayers, with those of any remaining executions being released when the subscription is cancelled.
+     * @param executions The number of executions of the subscription.
+     * @param fee The fee to be paid to relayers per execution.
+     * @param callback The callback to execute upon receiving each response.
+     * @return quote The amounts taken for the subscription.
+     */
+    function quoteSubscribe(
+        uint32 executions,
+        uint256 fee,
+        Callback calldata callback
+    ) external view returns (Quote memory quote);
+
     /**
      * @notice Remove a completed or timed-out message.
      * @dev Allows users to clean up storage and reclaim deposits for messages that have concluded.
@@ -91,6 +210,28 @@ interface IISMP {
      */
     function remove(uint64[] calldata messages) external;
 
+    /**
+     * @notice Revoke the allowance of inbound ISMP `Post` requests from a module on a source state machine to be delivered to the calling contract.
+     * @dev Releases the deposit held for the registration. Revoking a source which is not allowed has no effect.
+     * @param source The identifier of the source state machine, as provided to `onPostRequest` (e.g. `POLKADOT-2000`).
+     * @param from The module on the source state machine which sends the requests.
+     */
+    function revokeSource(bytes calldata source, bytes calldata from) external;
+
+    /**
+     * @notice Subscribe to recurring ISMP `Get` requests.
+     * @dev A `Get` request is dispatched at each interval, reading the destination at the latest height known to this chain, until the number of executions is reached. Each response is delivered via the callback, with the identifier of the message of the request. The deposit of the subscription and the amounts for all executions are taken upfront, with the amounts for each execution being drawn down as it is dispatched.
+     * @param subscription The template of the requests, along with their interval and number of executions.
+     * @param fee The fee to be paid to relayers per execution.
+     * @param callback The callback to execute upon receiving each response.
+     * @return id A unique subscription identifier.
+     */
+    function subscribe(
+        Subscription calldata subscription,
+        uint256 fee,
+        Callback calldata callback
+    ) external returns (uint64 id);
+
     /// @notice A GET request, intended to be used for sending outgoing requests
     struct Get {
         /// @custom:property The destination state machine of this request.
@@ -105,6 +246,14 @@ interface IISMP {
         bytes[] keys;
     }
 
+    /// @notice The outcome of a GET request of a batch.
+    struct GetResult {
+        /// @custom:property The status of the request.
+        MessageStatus status;
+        /// @custom:property The values read by the request, which are empty unless the request has completed.
+        StorageValue[] values;
+    }
+
     /// @notice A POST request, intended to be used for sending outgoing requests.
     struct Post {
         /// @custom:property The destination state machine of this request.
@@ -117,6 +266,32 @@ interface IISMP {
         bytes data;
     }
 
+    /// @notice The amounts taken for a new message.
+    struct Quote {
+        /// @custom:property The deposit held for the message, released once the message is removed.
+        uint256 deposit;
+        /// @custom:property The fee prepaid for the handling of the response, which is not refunded.
+        uint256 prepaidFee;
+        /// @custom:property The deposit held for the execution of the callback, if any.
+        uint256 callbackDeposit;
+    }
+
+    /// @notice A subscription to recurring GET requests.
+    struct Subscription {
+        /// @custom:property The destination state machine of the requests.
+        uint32 destination;
+        /// @custom:property Relative from the current timestamp at which each request expires in seconds.
+        uint64 timeout;
+        /// @custom:property Some application-specific metadata relating to the requests.
+        bytes context;
+        /// @custom:property Raw Storage keys that would be used to fetch the values from the counterparty.
+        bytes[] keys;
+        /// @custom:property The number of blocks between executions.
+        uint32 interval;
+        /// @custom:property The number of executions.
+        uint32 executions;
+    }
+
     /// @notice A verified storage value.
     struct StorageValue{
         /// @custom:property The request storage key.
@@ -133,6 +308,23 @@ interface IISMP {
         bytes value;
     }
 
+    /**
+     * @notice A batch of GETs has been dispatched via ISMP.
+     * @param origin The origin of the batch.
+     * @param id The identifier of the message.
+     * @param commitments The ISMP request commitments.
+     */
+    event GetBatchDispatched(address origin, uint64 id, bytes32[] commitments);
+
+    /**
+     * @notice A batch of GETs has been dispatched via ISMP.
+     * @param origin The origin of the batch.
+     * @param id The identifier of the message.
+     * @param commitments The ISMP request commitments.
+     * @param callback The callback to be used to return the responses.
+     */
+    event GetBatchDispatched(address origin, uint64 id, bytes32[] commitments, Callback callback);
+
     /**
      * @notice A GET has been dispatched via ISMP.
      * @param origin The origin of the request.
@@ -167,6 +359,55 @@ interface IISMP {
      */
     event PostDispatched(address origin, uint64 id, bytes32 commitment, Callback callback);
 
+    /**
+     * @notice The fee paid to relayers for a request has been topped up.
+     * @param origin The origin of the request.
+     * @param id The identifier of the message.
+     * @param commitment The ISMP request commitment.
+     * @param amount The amount added to the fee.
+     * @param fee The cumulative fee paid to relayers for the request.
+     */
+    event RequestFunded(address origin, uint64 id, bytes32 commitment, uint256 amount, uint256 fee);
+
+    /**
+     * @notice Inbound POST requests from a module on a source state machine are now delivered to a contract.
+     * @param origin The contract receiving the requests.
+     * @param source The identifier of the source state machine.
+     * @param from The module on the source state machine which sends the requests.
+     */
+    event SourceAllowed(address origin, bytes source, bytes from);
+
+    /**
+     * @notice Inbound POST requests from a module on a source state machine are no longer delivered to a contract.
+     * @param origin The contract receiving the requests.
+     * @param source The identifier of the source state machine.
+     * @param from The module on the source state machine which sends the requests.
+     */
+    event SourceRevoked(address origin, bytes source, bytes from);
+
+    /**
+     * @notice A subscription to recurring GET requests has been created.
+     * @param origin The origin of the subscription.
+     * @param id The identifier of the subscription.
+     * @param callback The callback to be used to return each response.
+     */
+    event Subscribed(address origin, uint64 id, Callback callback);
+
+    /**
+     * @notice A subscription to recurring GET requests has been cancelled.
+     * @param origin The origin of the subscription.
+     * @param id The identifier of the subscription.
+     */
+    event SubscriptionCancelled(address origin, uint64 id);
+
+    /// @dev The batch has no requests or exceeds the maximum number of requests.
+    error InvalidBatch();
+    /// @dev The message is invalid.
+    error InvalidMessage();
+    /// @dev The identifier of the source state machine is invalid.
+    error InvalidSource();
+    /// @dev The subscription has no executions or its interval is below the minimum.
+    error InvalidSubscription();
     /// @dev The context exceeds the maximum allowed size.
     error MaxContextExceeded();
     /// @dev The data exceeds the maximum allowed size.
@@ -177,6 +418,12 @@ interface IISMP {
     error MaxKeysExceeded();
     /// @dev The length of the receiving module identifier/address exceeds the maximum allowed size.
     error MaxRecipientExceeded();
+    /// @dev This is not possible as the message has completed.
+    error MessageCompleted();
+    /// @dev The request has timed out.
+    error RequestTimedOut();
+    /// @dev The subscription was not found.
+    error SubscriptionNotFound();
 }
 
 /**
@@ -191,6 +438,18 @@ interface IGetResponse {
     function onGetResponse(uint64 id, IISMP.StorageValue[] memory response) external;
 }
 
+/**
+ * @title A callback for handling the responses to a batch of ISMP `Get` requests.
+ */
+interface IGetBatchResponse {
+    /**
+     * @notice Handles the responses to a batch of ISMP `Get` requests.
+     * @param id The identifier of the originating message.
+     * @param response The outcome of each request, in the order in which they were specified.
+     */
+    function onGetBatchResponse(uint64 id, IISMP.GetResult[] memory response) external;
+}
+
 /**
  * @title A callback for handling responses to ISMP `Post` requests.
  */
@@ -203,6 +462,22 @@ interface IPostResponse {
     function onPostResponse(uint64 id, bytes memory response) external;
 }
 
+/**
+ * @title A handler for ISMP `Post` requests received from other chains.
+ * @dev Requests are routed to a contract when the `to` field of the request is the module identifier (`pop`), followed by the contract address and the encoding to be used (`0`: SCALE, `1`: Solidity ABI).
+ */
+interface IPostRequest {
+    /**
+     * @notice Handles a `Post` request received via ISMP.
+     * @param source The source state machine of the request.
+     * @param nonce The nonce of the request on the source state machine.
+     * @param from The module on the source state machine which sent the request.
+     * @param body The request body.
+     * @return response The response to be returned to the source state machine. An empty response results in no response being dispatched.
+     */
+    function onPostRequest(bytes memory source, uint64 nonce, bytes memory from, bytes memory body) external returns (bytes memory response);
+}
+
 /// @notice A message callback.
 struct Callback {
     /// @custom:property The contract address to which the callback should be sent.
@@ -232,7 +507,8 @@ enum MessageStatus {
     NotFound,
     Pending,
     Complete,
-    Timeout
+    Timeout,
+    Failed
 }
 
 /**
@@ -258,6 +534,8 @@ error MessageNotFound();
 error RequestPending();
 /// @dev The number of messages exceeds the limit.
 error TooManyMessages();
+/// @dev The origin has reached its maximum number of pending messages.
+error TooManyPendingMessages();
 
 /// @notice An arithmetic error.
 error Arithmetic(ArithmeticError);
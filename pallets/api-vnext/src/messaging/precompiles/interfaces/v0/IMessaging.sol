@@ -5,6 +5,14 @@ pragma solidity ^0.8.30;
  * @title The messaging precompile offers a general interface for cross-chain messaging operations.
  */
 interface IMessaging {
+    /**
+     * @notice Accept the ownership of a message which has been offered to the caller.
+     * @dev The deposits held for the message and the execution of any callback are moved to the caller, which becomes
+     * the origin of the message. A pending message counts towards the pending messages of the caller.
+     * @param message The identifier of the message to accept.
+     */
+    function acceptMessage(uint64 message) external;
+
     /**
      * @notice Returns the response to a message (if any).
      * @dev A non-existent message identifier will return an empty response, which could also be a valid response depending on the source message.
@@ -42,6 +50,54 @@ interface IMessaging {
      */
     function remove(uint64[] calldata messages) external;
 
+    /**
+     * @notice Offer the ownership of a message to another account.
+     * @dev The ownership is only transferred once the new owner accepts the message, replacing any previous offer.
+     * Offering a message to the caller withdraws any pending offer. Allows contracts to hand messages over to a new
+     * address, such as when upgrading.
+     * @param message The identifier of the message to transfer.
+     * @param newOwner The address of the new owner of the message.
+     */
+    function transferMessage(uint64 message, address newOwner) external;
+
+    /**
+     * @notice Update the callback of a pending message.
+     * @dev The deposit held for the execution of the callback is adjusted to that of the new callback.
+     * @param message The identifier of the message.
+     * @param callback The callback to invoke upon receiving a response.
+     */
+    function updateCallback(uint64 message, Callback calldata callback) external;
+
+    /// @notice A message callback.
+    struct Callback {
+        /// @custom:property The contract address to which the callback should be sent.
+        address destination;
+        /// @custom:property The encoding used for the data going to the contract.
+        Encoding encoding;
+        /// @custom:property The message selector to be used for the callback.
+        bytes4 selector;
+        /// @custom:property The pre-paid weight used as a gas limit for the callback.
+        Weight gasLimit;
+        /// @custom:property The storage deposit limit for the callback.
+        uint256 storageDepositLimit;
+    }
+
+    /**
+     * @notice The specificiation of how data must be encoded before being sent to a contract.
+     */
+    enum Encoding {
+        Scale,
+        SolidityAbi
+    }
+
+    /// @notice The weight of/for a transaction.
+    struct Weight {
+        /// @custom:property The weight of computational time used based on some reference hardware.
+        uint64 refTime;
+        /// @custom:property The weight of storage space used by proof of validity.
+        uint64 proofSize;
+    }
+
     /**
      * @notice The status of a message.
      */
@@ -49,7 +105,8 @@ interface IMessaging {
         NotFound,
         Pending,
         Complete,
-        Timeout
+        Timeout,
+        Failed
     }
 
     /**
@@ -59,12 +116,44 @@ interface IMessaging {
      */
     event Removed(address account, uint64[] messages);
 
+    /**
+     * @notice The callback of a message has been updated.
+     * @param account The origin of the message.
+     * @param message The identifier of the message.
+     * @param callback The new callback of the message.
+     */
+    event CallbackUpdated(address account, uint64 message, Callback callback);
+
+    /**
+     * @notice The ownership of a message has been offered to another account.
+     * @param account The owner of the message.
+     * @param message The identifier of the message.
+     * @param newOwner The address offered the ownership of the message.
+     */
+    event MessageTransferOffered(address account, uint64 message, address newOwner);
+
+    /**
+     * @notice The ownership of a message has been transferred.
+     * @param account The previous owner of the message.
+     * @param message The identifier of the message.
+     * @param newOwner The new owner of the message.
+     */
+    event MessageTransferred(address account, uint64 message, address newOwner);
+
+    /// @dev The specified encoding is invalid.
+    error InvalidEncoding();
+    /// @dev This is not possible as the message has completed.
+    error MessageCompleted();
     /// @dev The message was not found.
     error MessageNotFound();
     /// @dev The request is pending.
     error RequestPending();
+    /// @dev The request has timed out.
+    error RequestTimedOut();
     /// @dev The number of messages exceeds the limit.
     error TooManyMessages();
+    /// @dev The origin has reached its maximum number of pending messages.
+    error TooManyPendingMessages();
 }
 
 /// @notice An arithmetic error.
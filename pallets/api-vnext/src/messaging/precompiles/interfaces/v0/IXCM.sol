@@ -12,6 +12,21 @@ interface IXCM {
      */
     function blockNumber() external view returns (uint32 result);
 
+    /**
+     * @notice Cancel a pending XCM query.
+     * @dev Deregisters the query, such that any subsequent response is ignored, and releases the deposits held for the message. Only the origin of the query can cancel it.
+     * @param message The message identifier of the query to cancel.
+     */
+    function cancel(uint64 message) external;
+
+    /**
+     * @notice Dry-run an XCM message from a local, signed, origin.
+     * @dev Backed by the same logic as the `DryRunApi` runtime API. Any changes made by the dry run are discarded. The weight required to execute the message is charged.
+     * @param message A SCALE-encoded versioned XCM message.
+     * @return effects The effects of the dry run.
+     */
+    function dryRunXcm(bytes calldata message) external returns (DryRunEffects memory effects);
+
     /**
      * @notice Execute an XCM message from a local, signed, origin.
      * @param message A SCALE-encoded versioned XCM message.
@@ -24,12 +39,12 @@ interface IXCM {
     ) external returns (bytes memory result);
 
     /**
-     * @notice Returns the response to a message (if any).
-     * @dev A non-existent message identifier will return an empty response, which could also be a valid response depending on the source message.
+     * @notice Returns the response to a query (if any).
+     * @dev A non-existent message identifier, or one without a response, will return a `Null` response, which could also be a valid response depending on the query.
      * @param message The message identifier.
-     * @return response The response to a message.
+     * @return response The response to the query.
      */
-    function getResponse(uint64 message) external returns (bytes memory response);
+    function getResponse(uint64 message) external returns (QueryResponse memory response);
 
     /**
      * @notice The identifier of this chain.
@@ -72,6 +87,43 @@ interface IXCM {
      */
     function pollStatus(uint64 message) external returns (MessageStatus status);
 
+    /**
+     * @notice Query the fees for delivering a message to a destination.
+     * @dev Backed by the same logic as the `XcmPaymentApi` runtime API. The fees do not include those for executing the message on the destination.
+     * @param destination The SCALE-encoded versioned location for the destination of the message.
+     * @param message A SCALE-encoded versioned XCM message.
+     * @return fees The delivery fees.
+     */
+    function queryDeliveryFees(
+        bytes calldata destination,
+        bytes calldata message
+    ) external view returns (Asset[] memory fees);
+
+    /**
+     * @notice Query the fee for a weight of execution on this chain, payable in a given asset.
+     * @dev Backed by the same logic as the `XcmPaymentApi` runtime API.
+     * @param weight The weight of execution.
+     * @param asset The location of the asset in which the fee is to be paid, relative to this chain.
+     * @return fee The fee, in units of the asset.
+     */
+    function queryWeightToAssetFee(
+        Weight calldata weight,
+        Location calldata asset
+    ) external view returns (uint128 fee);
+
+    /**
+     * @notice Quote the amounts taken for a new XCM query.
+     * @return quote The amounts taken for the query.
+     */
+    function quoteNewQuery() external view returns (Quote memory quote);
+
+    /**
+     * @notice Quote the amounts taken for a new XCM query with a callback.
+     * @param callback The callback to execute upon receiving a response.
+     * @return quote The amounts taken for the query.
+     */
+    function quoteNewQuery(Callback calldata callback) external view returns (Quote memory quote);
+
     /**
      * @notice Remove a completed or timed-out message.
      * @dev Allows users to clean up storage and reclaim deposits for messages that have concluded.
@@ -86,6 +138,19 @@ interface IXCM {
      */
     function remove(uint64[] calldata messages) external;
 
+    /**
+     * @notice Transfer assets to a beneficiary on Asset Hub, using Asset Hub as the reserve.
+     * @dev Built on `pallet_xcm::limited_reserve_transfer_assets`, with an unlimited weight limit for execution on Asset Hub.
+     * @param beneficiary The location of the beneficiary on Asset Hub, relative to Asset Hub.
+     * @param assets The assets to be transferred, relative to this chain.
+     * @param feeAssetIndex The index of the asset within `assets` to be used to pay fees.
+     */
+    function reserveTransferToAssetHub(
+        Location calldata beneficiary,
+        Asset[] calldata assets,
+        uint32 feeAssetIndex
+    ) external;
+
     /**
      * @notice Send an XCM from a given origin.
      * @param destination The SCALE-encoded versioned location for the destination of the message.
@@ -97,6 +162,218 @@ interface IXCM {
         bytes calldata message
     ) external returns (bytes memory result);
 
+    /**
+     * @notice Teleport assets to a beneficiary on another chain.
+     * @dev Built on `pallet_xcm::limited_teleport_assets`, with an unlimited weight limit for execution on the destination.
+     * @param destination The location of the destination chain, relative to this chain.
+     * @param beneficiary The location of the beneficiary, relative to the destination.
+     * @param assets The assets to be teleported, relative to this chain.
+     * @param feeAssetIndex The index of the asset within `assets` to be used to pay fees.
+     */
+    function teleportAssets(
+        Location calldata destination,
+        Location calldata beneficiary,
+        Asset[] calldata assets,
+        uint32 feeAssetIndex
+    ) external;
+
+    /**
+     * @notice Dispatch a call on another chain, from the sovereign account of the caller on that chain.
+     * @dev Sends a message which withdraws `feeAsset` to buy execution, dispatches `call` and then deposits any surplus back to the caller's account on the destination.
+     * @param destination The location of the destination chain, relative to this chain.
+     * @param call The SCALE-encoded call to be dispatched on the destination.
+     * @param weight The maximum weight the call may consume on the destination.
+     * @param feeAsset The asset used to pay for execution on the destination, relative to this chain.
+     */
+    function transact(
+        Location calldata destination,
+        bytes calldata call,
+        Weight calldata weight,
+        Asset calldata feeAsset
+    ) external;
+
+    /**
+     * @notice Transfer assets to a beneficiary on another chain.
+     * @dev Built on `pallet_xcm::transfer_assets`, which determines whether assets are teleported or reserve transferred. An unlimited weight limit is used for execution on the destination.
+     * @param destination The location of the destination chain, relative to this chain.
+     * @param beneficiary The location of the beneficiary, relative to the destination.
+     * @param assets The assets to be transferred, relative to this chain.
+     * @param feeAssetIndex The index of the asset within `assets` to be used to pay fees.
+     */
+    function transferAssets(
+        Location calldata destination,
+        Location calldata beneficiary,
+        Asset[] calldata assets,
+        uint32 feeAssetIndex
+    ) external;
+
+    /// @notice A fungible asset.
+    struct Asset {
+        /// @custom:property The location of the asset, relative to this chain.
+        Location id;
+        /// @custom:property The amount of the asset.
+        uint128 amount;
+    }
+
+    /// @notice The type of the instance of a non-fungible asset.
+    enum AssetInstanceType {
+        Undefined,
+        Index,
+        Array4,
+        Array8,
+        Array16,
+        Array32
+    }
+
+    /// @notice The effects of dry-running an XCM message.
+    struct DryRunEffects {
+        /// @custom:property The result of the execution.
+        ExecutionResult executionResult;
+        /// @custom:property The weight used by the execution.
+        Weight weightUsed;
+        /// @custom:property The SCALE-encoded runtime events emitted by the execution.
+        bytes[] emittedEvents;
+        /// @custom:property The messages forwarded by the execution.
+        ForwardedMessages[] forwardedMessages;
+    }
+
+    /// @notice The result of the execution of an XCM message.
+    struct ExecutionResult {
+        /// @custom:property Whether the execution succeeded.
+        bool success;
+        /// @custom:property The index of the instruction which failed, if any.
+        uint32 index;
+        /// @custom:property The SCALE index of the XCM error, if any.
+        uint8 error;
+        /// @custom:property The code of a `Trap` error, if any.
+        uint64 trapCode;
+    }
+
+    /// @notice The messages forwarded to a destination.
+    struct ForwardedMessages {
+        /// @custom:property The SCALE-encoded versioned location of the destination.
+        bytes destination;
+        /// @custom:property The SCALE-encoded versioned XCM messages.
+        bytes[] messages;
+    }
+
+    /// @notice A single item within the interior of a location.
+    struct Junction {
+        /// @custom:property The type of the junction.
+        JunctionType junctionType;
+        /// @custom:property The parachain identifier, pallet instance, general index, general key length or network index, depending on the type of the junction.
+        uint128 index;
+        /// @custom:property The 32-byte account identifier, the 20-byte account key or general key in the leading bytes, or the network data, depending on the type of the junction.
+        bytes32 key;
+    }
+
+    /// @notice The supported types of junction.
+    /// @dev A `GlobalConsensus` junction uses the SCALE index of the network as its `index` and, where applicable, the genesis hash or big-endian chain identifier of the network as its `key`.
+    enum JunctionType {
+        Parachain,
+        AccountId32,
+        AccountKey20,
+        PalletInstance,
+        GeneralIndex,
+        GeneralKey,
+        GlobalConsensus,
+        /// @dev A junction which has no Solidity representation, which may only be received.
+        Unsupported
+    }
+
+    /// @notice A relative location within the consensus universe.
+    struct Location {
+        /// @custom:property The number of parent junctions at the beginning of the location.
+        uint8 parents;
+        /// @custom:property The interior junctions of the location, up to a maximum of eight.
+        Junction[] interior;
+    }
+
+    /// @notice The result of a dispatch on another chain.
+    struct MaybeErrorCode {
+        /// @custom:property Whether the dispatch succeeded.
+        bool success;
+        /// @custom:property The SCALE-encoded dispatch error, if any.
+        bytes error;
+        /// @custom:property Whether the dispatch error was truncated.
+        bool truncated;
+    }
+
+    /// @notice A non-fungible asset.
+    struct NonFungibleAsset {
+        /// @custom:property The location of the asset class.
+        Location id;
+        /// @custom:property The type of the instance.
+        AssetInstanceType instanceType;
+        /// @custom:property The index of an `Index` instance.
+        uint128 index;
+        /// @custom:property The data of an array instance, in the leading bytes.
+        bytes32 data;
+    }
+
+    /// @notice Information about a pallet on another chain.
+    struct PalletInfo {
+        /// @custom:property The index of the pallet within the runtime.
+        uint32 index;
+        /// @custom:property The name of the pallet within the runtime.
+        string name;
+        /// @custom:property The name of the module of the pallet.
+        string moduleName;
+        /// @custom:property The major version of the pallet.
+        uint32 major;
+        /// @custom:property The minor version of the pallet.
+        uint32 minor;
+        /// @custom:property The patch version of the pallet.
+        uint32 patch;
+    }
+
+    /// @notice The amounts taken for a new message.
+    struct Quote {
+        /// @custom:property The deposit held for the message, released once the message is removed.
+        uint256 deposit;
+        /// @custom:property The fee prepaid for the handling of the response, which is not refunded.
+        uint256 prepaidFee;
+        /// @custom:property The deposit held for the execution of the callback, if any.
+        uint256 callbackDeposit;
+    }
+
+    /// @notice A response to an XCM query.
+    /// @dev Only the fields corresponding to the type of the response are populated.
+    struct QueryResponse {
+        /// @custom:property The type of the response.
+        ResponseType responseType;
+        /// @custom:property The fungible assets of an `Assets` response.
+        Asset[] assets;
+        /// @custom:property The non-fungible assets of an `Assets` response.
+        NonFungibleAsset[] nonFungibleAssets;
+        /// @custom:property The result of an `ExecutionResult` response.
+        ExecutionResult executionResult;
+        /// @custom:property The XCM version of a `Version` response.
+        uint32 version;
+        /// @custom:property The pallet information of a `PalletsInfo` response.
+        PalletInfo[] palletsInfo;
+        /// @custom:property The result of a `DispatchResult` response.
+        MaybeErrorCode dispatchResult;
+    }
+
+    /// @notice The type of a response to an XCM query.
+    enum ResponseType {
+        Null,
+        Assets,
+        ExecutionResult,
+        Version,
+        PalletsInfo,
+        DispatchResult
+    }
+
+    /**
+     * @notice A XCM query has been cancelled.
+     * @param account The origin of the request.
+     * @param id The identifier of the message.
+     * @param queryId The identifier of the cancelled XCM query.
+     */
+    event QueryCancelled(address account, uint64 id, uint64 queryId);
+
     /**
      * @notice A XCM query has been created.
      * @param account The origin of the request.
@@ -114,16 +391,32 @@ interface IXCM {
      */
     event QueryCreated(address account, uint64 id, uint64 queryId, Callback callback);
 
+    /// @dev The asset is not accepted for the payment of fees.
+    error AssetNotFound();
     /// @dev The input failed to decode.
     error DecodingFailed();
     /// @dev Timeouts must be in the future.
     error FutureTimeoutMandatory();
     /// @dev Timeouts must be in the future.
     error FundsUnavailable();
-    /// @dev Message block limit has been reached for this expiry block. Try a different timeout.
-    error MaxMessageTimeoutPerBlockReached();
+    /// @dev The asset is invalid or not supported.
+    error InvalidAsset();
+    /// @dev The location is invalid or not supported.
+    error InvalidLocation();
+    /// @dev The message is invalid.
+    error InvalidMessage();
+    /// @dev This is not possible as the message has completed.
+    error MessageCompleted();
     /// @dev Failed to convert origin.
     error OriginConversionFailed();
+    /// @dev The request has timed out.
+    error RequestTimedOut();
+    /// @dev The destination is not reachable.
+    error Unroutable();
+    /// @dev The operation is not supported by this chain.
+    error Unsupported();
+    /// @dev The weight of the message could not be computed.
+    error WeightNotComputable();
 }
 
 /**
@@ -131,11 +424,11 @@ interface IXCM {
  */
 interface IQueryResponse {
     /**
-     * @notice Handles a response to an ISMP `Post` request.
+     * @notice Handles a response to an XCM query.
      * @param id The identifier of the originating message.
-     * @param response The response message.
+     * @param response The response to the query.
      */
-    function onQueryResponse(uint64 id, bytes memory response) external;
+    function onQueryResponse(uint64 id, IXCM.QueryResponse memory response) external;
 }
 
 /// @notice A message callback.
@@ -174,7 +467,8 @@ enum MessageStatus {
     NotFound,
     Pending,
     Complete,
-    Timeout
+    Timeout,
+    Failed
 }
 
 /// @notice The weight of/for a transaction.
@@ -193,6 +487,8 @@ error MessageNotFound();
 error RequestPending();
 /// @dev The number of messages exceeds the limit.
 error TooManyMessages();
+/// @dev The origin has reached its maximum number of pending messages.
+error TooManyPendingMessages();
 
 /// @notice An arithmetic error.
 error Arithmetic(ArithmeticError);
@@ -1,16 +1,27 @@
+use alloc::string::ToString;
+use core::str::FromStr;
+
 use ::ismp::{
 	dispatcher::{DispatchGet, DispatchPost},
 	host::StateMachine,
+	router::PostRequest,
 };
 use frame_support::{
 	ensure,
 	traits::{tokens::fungible::Inspect, Get as _},
 };
-pub(crate) use IISMP::{Get, *};
+pub(crate) use IISMP::{Get, Subscription, *};
 
 use super::*;
 use crate::{
-	messaging::transports::ismp::{get, post, ID},
+	messaging::{
+		transports::ismp::{
+			allow_source, cancel_subscription, fund, get, get_batch, poll_batch, post, quote_get,
+			quote_get_batch, quote_post, quote_subscribe, revoke_source, subscribe, GetResult,
+			GetTemplate, ID,
+		},
+		MessageQuote,
+	},
 	TryConvert,
 };
 
@@ -53,6 +64,62 @@ where
 		env: &mut impl Ext<T = Self::T>,
 	) -> Result<Vec<u8>, pallet_revive::precompiles::Error> {
 		match input {
+			IISMPCalls::allowSource(allowSourceCall { source, from }) => {
+				env.charge(<T as Config>::WeightInfo::ismp_allow_source())?;
+				let state_machine = try_source(source)?;
+
+				let origin = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let address = origin.address();
+
+					allow_source::<T>(origin, state_machine, from)?;
+
+					Ok(address)
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(
+					env,
+					SourceAllowed { origin, source: source.clone(), from: from.clone() },
+				)?;
+				Ok(allowSourceCall::abi_encode_returns(&allowSourceReturn {}))
+			},
+			IISMPCalls::cancelSubscription(cancelSubscriptionCall { subscription }) => {
+				env.charge(<T as Config>::WeightInfo::ismp_cancel_subscription())?;
+
+				let origin = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let address = origin.address();
+
+					cancel_subscription::<T>(origin, *subscription)?;
+
+					Ok(address)
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(env, SubscriptionCancelled { origin, id: *subscription })?;
+				Ok(cancelSubscriptionCall::abi_encode_returns(&cancelSubscriptionReturn {}))
+			},
+			IISMPCalls::fundRequest(fundRequestCall { message, amount }) => {
+				env.charge(<T as Config>::WeightInfo::ismp_fund_request())?;
+
+				let (origin, commitment, fee) = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let amount = (*amount).try_convert()?;
+					let address = origin.address();
+
+					let (commitment, fee) = fund::<T>(origin, *message, amount)?;
+
+					Ok((address, commitment, fee.try_convert()?))
+				})()
+				.map_err(Self::map_err)?;
+
+				let commitment = commitment.0.into();
+				let event =
+					RequestFunded { origin, id: *message, commitment, amount: *amount, fee };
+				deposit_event(env, event)?;
+				Ok(fundRequestCall::abi_encode_returns(&fundRequestReturn {}))
+			},
 			IISMPCalls::get_0(get_0Call { request, fee }) => {
 				env.charge(<T as Config>::WeightInfo::ismp_get(
 					request.context.len().try_convert()?,
@@ -101,6 +168,54 @@ where
 				deposit_event(env, event)?;
 				Ok(get_1Call::abi_encode_returns(&id))
 			},
+			IISMPCalls::getBatch_0(getBatch_0Call { requests, fee }) => {
+				env.charge(<T as Config>::WeightInfo::ismp_get_batch(
+					requests.len().try_convert()?,
+					0,
+				))?;
+				let messages = requests.iter().map(try_get::<T>).collect::<Result<Vec<_>, _>>()?;
+
+				let (origin, id, commitments) = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let fee = (*fee).try_convert()?;
+					let address = origin.address();
+
+					let (id, commitments) = get_batch::<T>(origin, messages, fee, None)?;
+
+					Ok((address, id, commitments))
+				})()
+				.map_err(Self::map_err)?;
+
+				let commitments = commitments.into_iter().map(|c| c.0.into()).collect();
+				let event = GetBatchDispatched_0 { origin, id, commitments };
+				deposit_event(env, event)?;
+				Ok(getBatch_0Call::abi_encode_returns(&id))
+			},
+			IISMPCalls::getBatch_1(getBatch_1Call { requests, fee, callback }) => {
+				env.charge(<T as Config>::WeightInfo::ismp_get_batch(
+					requests.len().try_convert()?,
+					1,
+				))?;
+				let messages = requests.iter().map(try_get::<T>).collect::<Result<Vec<_>, _>>()?;
+				let cb = callback.try_into()?;
+
+				let (origin, id, commitments) = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let fee = (*fee).try_convert()?;
+					let address = origin.address();
+
+					let (id, commitments) = get_batch::<T>(origin, messages, fee, Some(cb))?;
+
+					Ok((address, id, commitments))
+				})()
+				.map_err(Self::map_err)?;
+
+				let commitments = commitments.into_iter().map(|c| c.0.into()).collect();
+				let event =
+					GetBatchDispatched_1 { origin, id, commitments, callback: callback.clone() };
+				deposit_event(env, event)?;
+				Ok(getBatch_1Call::abi_encode_returns(&id))
+			},
 			IISMPCalls::getResponse(getResponseCall { message }) => {
 				env.charge(<T as Config>::WeightInfo::get_response())?;
 
@@ -115,6 +230,15 @@ where
 
 				Ok(idCall::abi_encode_returns(&id))
 			},
+			IISMPCalls::pollBatch(pollBatchCall { message }) => {
+				env.charge(<T as Config>::WeightInfo::poll_batch())?;
+
+				let (status, requests) = poll_batch::<T>(*message);
+				let status = status.into();
+				let requests = requests.into_iter().map(Into::into).collect();
+
+				Ok(pollBatchCall::abi_encode_returns(&pollBatchReturn { status, requests }))
+			},
 			IISMPCalls::pollStatus(pollStatusCall { message }) => {
 				env.charge(<T as Config>::WeightInfo::poll_status())?;
 
@@ -170,6 +294,67 @@ where
 				deposit_event(env, event)?;
 				Ok(post_0Call::abi_encode_returns(&id))
 			},
+			IISMPCalls::quoteGet_0(quoteGet_0Call {}) => {
+				env.charge(<T as Config>::WeightInfo::quote())?;
+
+				let quote: Quote = quote_get::<T>(None).try_into().map_err(Self::map_err)?;
+
+				Ok(quoteGet_0Call::abi_encode_returns(&quote))
+			},
+			IISMPCalls::quoteGet_1(quoteGet_1Call { callback }) => {
+				env.charge(<T as Config>::WeightInfo::quote())?;
+
+				let quote: Quote = quote_get::<T>(Some((&callback.gasLimit).into()))
+					.try_into()
+					.map_err(Self::map_err)?;
+
+				Ok(quoteGet_1Call::abi_encode_returns(&quote))
+			},
+			IISMPCalls::quoteGetBatch_0(quoteGetBatch_0Call { requests }) => {
+				env.charge(<T as Config>::WeightInfo::quote())?;
+
+				let quote: Quote =
+					quote_get_batch::<T>(*requests, None).try_into().map_err(Self::map_err)?;
+
+				Ok(quoteGetBatch_0Call::abi_encode_returns(&quote))
+			},
+			IISMPCalls::quoteGetBatch_1(quoteGetBatch_1Call { requests, callback }) => {
+				env.charge(<T as Config>::WeightInfo::quote())?;
+
+				let quote: Quote =
+					quote_get_batch::<T>(*requests, Some((&callback.gasLimit).into()))
+						.try_into()
+						.map_err(Self::map_err)?;
+
+				Ok(quoteGetBatch_1Call::abi_encode_returns(&quote))
+			},
+			IISMPCalls::quotePost_0(quotePost_0Call {}) => {
+				env.charge(<T as Config>::WeightInfo::quote())?;
+
+				let quote: Quote = quote_post::<T>(None).try_into().map_err(Self::map_err)?;
+
+				Ok(quotePost_0Call::abi_encode_returns(&quote))
+			},
+			IISMPCalls::quotePost_1(quotePost_1Call { callback }) => {
+				env.charge(<T as Config>::WeightInfo::quote())?;
+
+				let quote: Quote = quote_post::<T>(Some((&callback.gasLimit).into()))
+					.try_into()
+					.map_err(Self::map_err)?;
+
+				Ok(quotePost_1Call::abi_encode_returns(&quote))
+			},
+			IISMPCalls::quoteSubscribe(quoteSubscribeCall { executions, fee, callback }) => {
+				env.charge(<T as Config>::WeightInfo::quote())?;
+
+				let quote: Quote = (|| {
+					let fee = (*fee).try_convert()?;
+					quote_subscribe::<T>(*executions, fee, (&callback.gasLimit).into()).try_into()
+				})()
+				.map_err(Self::map_err)?;
+
+				Ok(quoteSubscribeCall::abi_encode_returns(&quote))
+			},
 			IISMPCalls::remove_0(remove_0Call { message }) => {
 				env.charge(<T as Config>::WeightInfo::remove(1))?;
 
@@ -203,6 +388,55 @@ where
 				deposit_event(env, Removed { account, messages: messages.clone() })?;
 				Ok(remove_1Call::abi_encode_returns(&remove_1Return {}))
 			},
+			IISMPCalls::revokeSource(revokeSourceCall { source, from }) => {
+				env.charge(<T as Config>::WeightInfo::ismp_revoke_source())?;
+				let state_machine = try_source(source)?;
+
+				let origin = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let address = origin.address();
+
+					revoke_source::<T>(origin, state_machine, from)?;
+
+					Ok(address)
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(
+					env,
+					SourceRevoked { origin, source: source.clone(), from: from.clone() },
+				)?;
+				Ok(revokeSourceCall::abi_encode_returns(&revokeSourceReturn {}))
+			},
+			IISMPCalls::subscribe(subscribeCall { subscription, fee, callback }) => {
+				env.charge(<T as Config>::WeightInfo::ismp_subscribe(
+					subscription.context.len().try_convert()?,
+					subscription.keys.len().try_convert()?,
+				))?;
+				let request = try_subscription::<T>(subscription)?;
+				let cb = callback.try_into()?;
+
+				let (origin, id) = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let fee = (*fee).try_convert()?;
+					let address = origin.address();
+
+					let id = subscribe::<T>(
+						origin,
+						request,
+						subscription.interval.into(),
+						subscription.executions,
+						fee,
+						cb,
+					)?;
+
+					Ok((address, id))
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(env, Subscribed { origin, id, callback: callback.clone() })?;
+				Ok(subscribeCall::abi_encode_returns(&id))
+			},
 		}
 	}
 }
@@ -223,9 +457,16 @@ impl<const FIXED: u16, T: frame_system::Config> Ismp<FIXED, T> {
 				if Some(index as usize) == T::PalletInfo::index::<Pallet<T>>() {
 					use messaging::Error::{self, *};
 					match Error::<T>::decode(&mut error.as_slice()) {
+						Ok(InvalidBatch) => return IISMP::InvalidBatch.into(),
+						Ok(InvalidMessage) => return IISMP::InvalidMessage.into(),
+						Ok(InvalidSubscription) => return IISMP::InvalidSubscription.into(),
+						Ok(MessageCompleted) => return IISMP::MessageCompleted.into(),
 						Ok(MessageNotFound) => return self::MessageNotFound.into(),
 						Ok(RequestPending) => return self::RequestPending.into(),
+						Ok(RequestTimedOut) => return IISMP::RequestTimedOut.into(),
+						Ok(SubscriptionNotFound) => return IISMP::SubscriptionNotFound.into(),
 						Ok(TooManyMessages) => return self::TooManyMessages.into(),
+						Ok(TooManyPendingMessages) => return self::TooManyPendingMessages.into(),
 						_ => {},
 					}
 				}
@@ -243,16 +484,24 @@ impl<const FIXED: u16, T: frame_system::Config> Ismp<FIXED, T> {
 // Encoding of custom errors via `Error(String)`.
 impl_from_sol_error! {
 	// ISMP
+	IISMP::InvalidBatch,
+	IISMP::InvalidMessage,
+	IISMP::InvalidSource,
+	IISMP::InvalidSubscription,
 	IISMP::MaxContextExceeded,
 	IISMP::MaxDataExceeded,
 	IISMP::MaxKeyExceeded,
 	IISMP::MaxKeysExceeded,
 	IISMP::MaxRecipientExceeded,
+	IISMP::MessageCompleted,
+	IISMP::RequestTimedOut,
+	IISMP::SubscriptionNotFound,
 	// Messaging
 	InvalidEncoding,
 	MessageNotFound,
 	RequestPending,
 	TooManyMessages,
+	TooManyPendingMessages,
 	// Generic
 	Arithmetic,
 	Dispatch,
@@ -279,6 +528,29 @@ fn try_get<T: Config>(value: &Get) -> Result<DispatchGet, Error> {
 	})
 }
 
+fn try_subscription<T: Config>(value: &Subscription) -> Result<GetTemplate<T>, Error> {
+	Ok(GetTemplate {
+		dest: value.destination,
+		timeout: value.timeout,
+		context: value.context.to_vec().try_into().map_err(|_| IISMP::MaxContextExceeded)?,
+		keys: value
+			.keys
+			.iter()
+			.map(|key| key.to_vec().try_into().map_err(|_| IISMP::MaxKeyExceeded))
+			.collect::<Result<Vec<_>, _>>()?
+			.try_into()
+			.map_err(|_| IISMP::MaxKeysExceeded)?,
+	})
+}
+
+// Parses the identifier of a state machine, as provided to `IPostRequest::onPostRequest`.
+fn try_source(value: &[u8]) -> Result<StateMachine, Error> {
+	core::str::from_utf8(value)
+		.ok()
+		.and_then(|source| StateMachine::from_str(source).ok())
+		.ok_or(IISMP::InvalidSource.into())
+}
+
 fn try_post<T: Config>(value: &Post) -> Result<DispatchPost, Error> {
 	ensure!(value.to.len() as u32 <= T::MaxRecipientLen::get(), IISMP::MaxRecipientExceeded);
 	ensure!(value.data.len() as u32 <= T::MaxDataLen::get(), IISMP::MaxDataExceeded);
@@ -298,20 +570,38 @@ impl EncodeCallback for Vec<::ismp::router::StorageValue> {
 			Scale => [selector.to_vec(), (id, self).encode()].concat(),
 			SolidityAbi => {
 				// Use interface to encode call data
-				let call = IGetResponse::onGetResponseCall {
-					id,
-					// Clones required for ABI encoding of dynamic bytes type.
-					response: self
-						.into_iter()
-						.map(|v| IISMP::StorageValue {
-							key: v.key.clone().into(),
-							value: v.value.as_ref().map_or_else(
-								|| IISMP::Value { exists: false, value: Default::default() },
-								|v| IISMP::Value { exists: true, value: v.clone().into() },
-							),
-						})
-						.collect(),
-				};
+				let call = IGetResponse::onGetResponseCall { id, response: storage_values(self) };
+				let mut data = call.abi_encode();
+				debug_assert_eq!(data[..4], selector);
+				// Replace selector with that provided at request
+				data.splice(0..4, selector);
+				data
+			},
+		}
+	}
+
+	fn encoded_len(&self, encoding: messaging::Encoding) -> usize {
+		use messaging::Encoding::*;
+		match encoding {
+			Scale => self.encoded_size(),
+			// Excludes the word of the message identifier.
+			SolidityAbi =>
+				IGetResponse::onGetResponseCall { id: 0, response: storage_values(self) }
+					.abi_encoded_size()
+					.saturating_sub(32),
+		}
+	}
+}
+
+impl EncodeCallback for Vec<GetResult> {
+	fn encode(&self, encoding: messaging::Encoding, selector: [u8; 4], id: MessageId) -> Vec<u8> {
+		use messaging::Encoding::*;
+		match encoding {
+			Scale => [selector.to_vec(), (id, self).encode()].concat(),
+			SolidityAbi => {
+				// Use interface to encode call data
+				let call =
+					IGetBatchResponse::onGetBatchResponseCall { id, response: get_results(self) };
 				let mut data = call.abi_encode();
 				debug_assert_eq!(data[..4], selector);
 				// Replace selector with that provided at request
@@ -320,6 +610,44 @@ impl EncodeCallback for Vec<::ismp::router::StorageValue> {
 			},
 		}
 	}
+
+	fn encoded_len(&self, encoding: messaging::Encoding) -> usize {
+		use messaging::Encoding::*;
+		match encoding {
+			Scale => self.encoded_size(),
+			// Excludes the word of the message identifier.
+			SolidityAbi =>
+				IGetBatchResponse::onGetBatchResponseCall { id: 0, response: get_results(self) }
+					.abi_encoded_size()
+					.saturating_sub(32),
+		}
+	}
+}
+
+// Maps the outcomes of the requests of a batch to their Solidity representation.
+fn get_results(results: &[GetResult]) -> Vec<IISMP::GetResult> {
+	results
+		.iter()
+		.map(|result| IISMP::GetResult {
+			status: result.status.clone().into(),
+			values: storage_values(&result.values),
+		})
+		.collect()
+}
+
+// Maps storage values read by an ISMP `Get` request to their Solidity representation. Clones
+// required for ABI encoding of dynamic bytes type.
+fn storage_values(values: &[::ismp::router::StorageValue]) -> Vec<IISMP::StorageValue> {
+	values
+		.iter()
+		.map(|v| IISMP::StorageValue {
+			key: v.key.clone().into(),
+			value: v.value.as_ref().map_or_else(
+				|| IISMP::Value { exists: false, value: Default::default() },
+				|v| IISMP::Value { exists: true, value: v.clone().into() },
+			),
+		})
+		.collect()
 }
 
 impl EncodeCallback for Vec<u8> {
@@ -339,6 +667,61 @@ impl EncodeCallback for Vec<u8> {
 			},
 		}
 	}
+
+	fn encoded_len(&self, encoding: messaging::Encoding) -> usize {
+		use messaging::Encoding::*;
+		match encoding {
+			Scale => self.encoded_size(),
+			// The offset, length and the data padded to a multiple of 32 bytes.
+			SolidityAbi => 64 + self.len().div_ceil(32) * 32,
+		}
+	}
+}
+
+/// Encodes an inbound ISMP `Post` request as call data for `IPostRequest::onPostRequest`.
+///
+/// # Parameters
+/// - `request`: The inbound ISMP `Post` request.
+/// - `encoding`: The encoding to use.
+pub(crate) fn encode_post_request(request: &PostRequest, encoding: messaging::Encoding) -> Vec<u8> {
+	use messaging::Encoding::*;
+	let selector = IPostRequest::onPostRequestCall::SELECTOR;
+	match encoding {
+		Scale => [
+			selector.to_vec(),
+			(&request.source, request.nonce, &request.from, &request.body).encode(),
+		]
+		.concat(),
+		SolidityAbi => {
+			// Clones required for ABI encoding of dynamic bytes type.
+			let call = IPostRequest::onPostRequestCall {
+				source: request.source.to_string().into_bytes().into(),
+				nonce: request.nonce,
+				from: request.from.clone().into(),
+				body: request.body.clone().into(),
+			};
+			call.abi_encode()
+		},
+	}
+}
+
+/// Decodes the output of `IPostRequest::onPostRequest` into the response to be returned to the
+/// source state machine.
+///
+/// # Parameters
+/// - `output`: The output of the call.
+/// - `encoding`: The encoding used.
+pub(crate) fn decode_post_request_output(
+	output: &[u8],
+	encoding: messaging::Encoding,
+) -> Option<Vec<u8>> {
+	use messaging::Encoding::*;
+	match encoding {
+		Scale => Vec::<u8>::decode(&mut &output[..]).ok(),
+		SolidityAbi => IPostRequest::onPostRequestCall::abi_decode_returns(output)
+			.ok()
+			.map(|r| r.to_vec()),
+	}
 }
 
 impl<Balance> TryFrom<&Callback> for super::Callback<Balance>
@@ -468,10 +851,25 @@ impl From<messaging::MessageStatus> for self::MessageStatus {
 			Pending => Self::Pending,
 			Complete => Self::Complete,
 			Timeout => Self::Timeout,
+			Failed => Self::Failed,
 		}
 	}
 }
 
+impl<Balance: TryConvert<U256, Error = frame::DispatchError>> TryFrom<MessageQuote<Balance>>
+	for Quote
+{
+	type Error = frame::DispatchError;
+
+	fn try_from(quote: MessageQuote<Balance>) -> Result<Self, Self::Error> {
+		Ok(Self {
+			deposit: quote.message_deposit.try_convert()?,
+			prepaidFee: quote.prepaid_fee.try_convert()?,
+			callbackDeposit: quote.callback_deposit.try_convert()?,
+		})
+	}
+}
+
 impl From<&Weight> for super::Weight {
 	fn from(weight: &Weight) -> Self {
 		Self::from_parts(weight.refTime, weight.proofSize)
@@ -484,7 +882,7 @@ mod tests {
 	use frame_support::{
 		assert_ok,
 		traits::{Get, UnixTime},
-		weights::Weight,
+		weights::{Weight, WeightToFee as _},
 	};
 	use mock::{ExtBuilder, *};
 	use pallet_revive::{
@@ -497,7 +895,23 @@ mod tests {
 	use sp_io::hashing::keccak_256;
 
 	use super::{IISMPCalls::*, MessageStatus::*, *};
+	use crate::{
+		messaging::{
+			calculate_protocol_deposit,
+			transports::{
+				ismp::{
+					conclude_batch_request, source_key, timeout_commitment, IsmpBatchTransport,
+				},
+				MessageTransport,
+			},
+			ProtocolStorageDeposit,
+		},
+		mock::messaging::MinSubscriptionInterval,
+	};
 
+	type IsmpFees = crate::messaging::IsmpFees<Test>;
+	type IsmpPostRequestSources = crate::messaging::IsmpPostRequestSources<Test>;
+	type MaxBatchSize = <Test as Config>::MaxBatchSize;
 	type MaxContextLen = <Test as Config>::MaxContextLen;
 	type MaxDataLen = <Test as Config>::MaxDataLen;
 	type MaxKeyLen = <Test as Config>::MaxKeyLen;
@@ -505,100 +919,330 @@ mod tests {
 	type MaxRecipientLen = <Test as Config>::MaxRecipientLen;
 	type MaxRemovals = <Test as Config>::MaxRemovals;
 	type Messages = crate::messaging::Messages<Test>;
+	type OnChainByteFee = <Test as Config>::OnChainByteFee;
 	type Origin = super::Origin<Test>;
+	type PendingMessageQuotas = crate::messaging::PendingMessageQuotas<Test>;
+	type Subscriptions = crate::messaging::Subscriptions<Test>;
+	type WeightToFee = <Test as Config>::WeightToFee;
 
 	const ADDRESS: [u8; 20] = fixed_address(ISMP);
-	const GET_MESSAGE_DEPOSIT: u128 = 131_290;
-	const POST_MESSAGE_DEPOSIT: u128 = 134_415;
+	const GET_MESSAGE_DEPOSIT: u128 = 16_100;
+	const POST_MESSAGE_DEPOSIT: u128 = 19_225;
 
 	#[test]
-	fn get_reverts_when_max_context_exceeded() {
+	fn allow_source_reverts_when_invalid_source() {
 		let origin = ALICE;
 		ExtBuilder::new().build().execute_with(|| {
-			let request = IISMP::Get {
-				destination: 0,
-				height: u64::default(),
-				timeout: u64::default(),
-				context: vec![255u8; <MaxContextLen as Get<u32>>::get() as usize + 1].into(),
-				keys: Vec::default(),
-			};
-			let input = get_0(get_0Call { request, fee: U256::ZERO });
-			assert_revert!(call_precompile::<MessageId>(&origin, &input), MaxContextExceeded);
+			for source in [b"POLKADOT".to_vec(), b"POLKADOT-X".to_vec(), vec![255u8; 4]] {
+				let input = allowSource(allowSourceCall {
+					source: source.into(),
+					from: vec![1u8; 32].into(),
+				});
+				assert_revert!(call_precompile::<()>(&origin, &input), InvalidSource);
+			}
 		});
 	}
 
 	#[test]
-	fn get_reverts_when_max_keys_exceeded() {
-		let origin = ALICE;
-		ExtBuilder::new().build().execute_with(|| {
-			let request = IISMP::Get {
-				destination: 0,
-				height: u64::default(),
-				timeout: u64::default(),
-				context: Vec::default().into(),
-				keys: vec![vec![].into(); <MaxKeys as Get<u32>>::get() as usize + 1],
-			};
-			let input = get_0(get_0Call { request, fee: U256::ZERO });
-			assert_revert!(call_precompile::<MessageId>(&origin, &input), MaxKeysExceeded);
-		});
+	fn allow_source_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let source = StateMachine::Kusama(2_000);
+		let from = vec![1u8; 32];
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+			.build()
+			.execute_with(|| {
+				let deposit = calculate_protocol_deposit::<Test, OnChainByteFee>(
+					ProtocolStorageDeposit::IsmpPostRequestSources,
+				);
+				let input = allowSource(allowSourceCall {
+					source: source.to_string().into_bytes().into(),
+					from: from.clone().into(),
+				});
+
+				assert_ok!(call_precompile::<()>(&origin.account, &input));
+				assert_last_event(
+					ADDRESS,
+					SourceAllowed {
+						origin: origin.address(),
+						source: source.to_string().into_bytes().into(),
+						from: from.clone().into(),
+					},
+				);
+				assert_eq!(
+					IsmpPostRequestSources::get(
+						origin.address(),
+						source_key::<Test>(&source, &from)
+					),
+					Some(deposit)
+				);
+				assert_eq!(Messaging::held_deposit(&origin.account), deposit);
+
+				// Allowing again has no effect.
+				assert_ok!(call_precompile::<()>(&origin.account, &input));
+				assert_eq!(Messaging::held_deposit(&origin.account), deposit);
+			});
 	}
 
 	#[test]
-	fn get_reverts_when_max_key_exceeded() {
+	fn cancel_subscription_reverts_when_subscription_not_found() {
 		let origin = ALICE;
 		ExtBuilder::new().build().execute_with(|| {
-			let request = IISMP::Get {
-				destination: 0,
-				height: u64::default(),
-				timeout: u64::default(),
-				context: Vec::default().into(),
-				keys: vec![vec![0u8; <MaxKeyLen as Get<u32>>::get() as usize + 1].into()],
-			};
-			let input = get_0(get_0Call { request, fee: U256::ZERO });
-			assert_revert!(call_precompile::<MessageId>(&origin, &input), MaxKeyExceeded);
+			let input = cancelSubscription(cancelSubscriptionCall { subscription: 0 });
+			assert_revert!(call_precompile::<()>(&origin, &input), SubscriptionNotFound);
 		});
 	}
 
 	#[test]
-	fn get_works() {
+	fn cancel_subscription_works() {
 		let origin = Origin::from((ALICE_ADDR, ALICE));
-		let message = 1;
-		let request = IISMP::Get {
-			destination: 1_000,
-			height: u64::MAX,
-			timeout: u64::MAX,
-			context: vec![255u8; 64].into(),
-			keys: vec![vec![255u8; 32].into()].into(),
-		};
-		let fee = U256::from(100);
+		let (subscription, fee, callback) = subscription();
 		ExtBuilder::new()
-			.with_balances(vec![(origin.account.clone(), 1 * UNIT)]) // message deposit
-			.with_message_id(message)
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
 			.build()
 			.execute_with(|| {
-				let commitment = get_hash(&request);
+				let id = call_precompile::<u64>(
+					&origin.account,
+					&subscribe(subscribeCall { subscription, fee, callback }),
+				)
+				.unwrap();
+				assert_ne!(Messaging::held_deposit(&origin.account), 0);
 
-				assert_eq!(
-					call_precompile::<MessageId>(&origin.account, &get_0(get_0Call { request, fee })).unwrap(),
-					message
-				);
+				assert_ok!(call_precompile::<()>(
+					&origin.account,
+					&cancelSubscription(cancelSubscriptionCall { subscription: id })
+				));
 
-				let event = GetDispatched_0 { origin: origin.address(), id: message, commitment: commitment.0.into() };
-				assert_last_event(ADDRESS, event);
-				assert!(matches!(
-					Messages::get(message),
-					Some(Message::Ismp { origin: o, commitment: c, callback, message_deposit })
-					    if o == origin && c == commitment && callback.is_none() && message_deposit == GET_MESSAGE_DEPOSIT)
-				);
+				assert_last_event(ADDRESS, SubscriptionCancelled { origin: origin.address(), id });
+				assert!(Subscriptions::get(id).is_none());
+				assert_eq!(Messaging::held_deposit(&origin.account), 0);
 			});
 	}
 
 	#[test]
-	fn get_with_callback_works() {
-		let origin = Origin::from((ALICE_ADDR, ALICE));
+	fn encoded_len_works() {
+		use messaging::Encoding::*;
+		// Excludes the selector and message identifier which precede the response.
+		fn assert_encoded_len(response: &impl EncodeCallback, selector: [u8; 4]) {
+			let id = 42;
+			assert_eq!(
+				response.encoded_len(Scale),
+				response.encode(Scale, selector, id).len() - 4 - 8
+			);
+			assert_eq!(
+				response.encoded_len(SolidityAbi),
+				response.encode(SolidityAbi, selector, id).len() - 4 - 32
+			);
+		}
+
+		let values = vec![
+			::ismp::router::StorageValue { key: vec![1; 10], value: Some(vec![2; 33]) },
+			::ismp::router::StorageValue { key: vec![3; 64], value: None },
+		];
+		for values in [vec![], values] {
+			assert_encoded_len(&values, IGetResponse::onGetResponseCall::SELECTOR);
+			let results = vec![
+				GetResult { status: messaging::MessageStatus::Complete, values },
+				GetResult { status: messaging::MessageStatus::Timeout, values: vec![] },
+			];
+			assert_encoded_len(&results, IGetBatchResponse::onGetBatchResponseCall::SELECTOR);
+		}
+		for len in [0, 1, 31, 32, 33, 100] {
+			assert_encoded_len(&vec![255u8; len], IPostResponse::onPostResponseCall::SELECTOR);
+		}
+	}
+
+	#[test]
+	fn fund_request_reverts_when_message_not_found() {
+		let origin = ALICE;
 		let message = 1;
-		let request = IISMP::Get {
-			destination: 1_000,
+		ExtBuilder::new().build().execute_with(|| {
+			let input = fundRequest(fundRequestCall { message, amount: U256::from(1) });
+			assert_revert!(call_precompile::<()>(&origin, &input), MessageNotFound);
+		});
+	}
+
+	#[test]
+	fn fund_request_reverts_when_message_completed() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		ExtBuilder::new()
+			.with_messages(vec![(
+				origin.account.clone(),
+				message,
+				Message::ismp_response(origin.address, H256::default(), 0, Default::default()),
+				0,
+			)])
+			.build()
+			.execute_with(|| {
+				let input = fundRequest(fundRequestCall { message, amount: U256::from(1) });
+				assert_revert!(call_precompile::<()>(&origin.account, &input), MessageCompleted);
+			});
+	}
+
+	#[test]
+	fn fund_request_reverts_when_request_timed_out() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		ExtBuilder::new()
+			.with_messages(vec![(
+				origin.account.clone(),
+				message,
+				Message::ismp_timeout(origin.address, H256::default(), 0, None),
+				0,
+			)])
+			.build()
+			.execute_with(|| {
+				let input = fundRequest(fundRequestCall { message, amount: U256::from(1) });
+				assert_revert!(call_precompile::<()>(&origin.account, &input), RequestTimedOut);
+			});
+	}
+
+	#[test]
+	fn fund_request_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		let request = IISMP::Get {
+			destination: 1_000,
+			height: u64::MAX,
+			timeout: u64::MAX,
+			context: vec![255u8; 64].into(),
+			keys: vec![vec![255u8; 32].into()].into(),
+		};
+		let fee = U256::from(100);
+		let amount = U256::from(50);
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)]) // message deposit
+			.with_message_id(message)
+			.build()
+			.execute_with(|| {
+				let commitment = get_hash(&request);
+				call_precompile::<MessageId>(&origin.account, &get_0(get_0Call { request, fee }))
+					.unwrap();
+
+				assert_ok!(call_precompile::<()>(
+					&origin.account,
+					&fundRequest(fundRequestCall { message, amount })
+				));
+
+				let event = RequestFunded {
+					origin: origin.address(),
+					id: message,
+					commitment: commitment.0.into(),
+					amount,
+					fee: fee + amount,
+				};
+				assert_last_event(ADDRESS, event);
+				assert_eq!(IsmpFees::get(commitment), Some(150));
+			});
+	}
+
+	#[test]
+	fn get_reverts_when_max_context_exceeded() {
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			let request = IISMP::Get {
+				destination: 0,
+				height: u64::default(),
+				timeout: u64::default(),
+				context: vec![255u8; <MaxContextLen as Get<u32>>::get() as usize + 1].into(),
+				keys: Vec::default(),
+			};
+			let input = get_0(get_0Call { request, fee: U256::ZERO });
+			assert_revert!(call_precompile::<MessageId>(&origin, &input), MaxContextExceeded);
+		});
+	}
+
+	#[test]
+	fn get_reverts_when_max_keys_exceeded() {
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			let request = IISMP::Get {
+				destination: 0,
+				height: u64::default(),
+				timeout: u64::default(),
+				context: Vec::default().into(),
+				keys: vec![vec![].into(); <MaxKeys as Get<u32>>::get() as usize + 1],
+			};
+			let input = get_0(get_0Call { request, fee: U256::ZERO });
+			assert_revert!(call_precompile::<MessageId>(&origin, &input), MaxKeysExceeded);
+		});
+	}
+
+	#[test]
+	fn get_reverts_when_max_key_exceeded() {
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			let request = IISMP::Get {
+				destination: 0,
+				height: u64::default(),
+				timeout: u64::default(),
+				context: Vec::default().into(),
+				keys: vec![vec![0u8; <MaxKeyLen as Get<u32>>::get() as usize + 1].into()],
+			};
+			let input = get_0(get_0Call { request, fee: U256::ZERO });
+			assert_revert!(call_precompile::<MessageId>(&origin, &input), MaxKeyExceeded);
+		});
+	}
+
+	#[test]
+	fn get_reverts_when_too_many_pending_messages() {
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			PendingMessageQuotas::insert(ALICE_ADDR, 0);
+
+			let request = IISMP::Get {
+				destination: 1_000,
+				height: u64::MAX,
+				timeout: u64::MAX,
+				context: Vec::default().into(),
+				keys: Vec::default(),
+			};
+			let input = get_0(get_0Call { request, fee: U256::ZERO });
+			assert_revert!(call_precompile::<MessageId>(&origin, &input), TooManyPendingMessages);
+		});
+	}
+
+	#[test]
+	fn get_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		let request = IISMP::Get {
+			destination: 1_000,
+			height: u64::MAX,
+			timeout: u64::MAX,
+			context: vec![255u8; 64].into(),
+			keys: vec![vec![255u8; 32].into()].into(),
+		};
+		let fee = U256::from(100);
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)]) // message deposit
+			.with_message_id(message)
+			.build()
+			.execute_with(|| {
+				let commitment = get_hash(&request);
+
+				assert_eq!(
+					call_precompile::<MessageId>(&origin.account, &get_0(get_0Call { request, fee })).unwrap(),
+					message
+				);
+
+				let event = GetDispatched_0 { origin: origin.address(), id: message, commitment: commitment.0.into() };
+				assert_last_event(ADDRESS, event);
+				assert!(matches!(
+					Messages::get(message),
+					Some(Message::Pending { origin: o, transport: t, callback, message_deposit })
+					    if o == origin && t == MessageTransport::ismp::<Test>(commitment) && callback.is_none() && message_deposit == GET_MESSAGE_DEPOSIT)
+				);
+			});
+	}
+
+	#[test]
+	fn get_with_callback_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		let request = IISMP::Get {
+			destination: 1_000,
 			height: u64::MAX,
 			timeout: u64::MAX,
 			context: vec![255u8; 64].into(),
@@ -628,8 +1272,113 @@ mod tests {
 				assert_last_event(ADDRESS, event);
 				assert!(matches!(
 					Messages::get(message),
-					Some(Message::Ismp { origin: o, commitment: c, callback: cb, message_deposit })
-					    if o == origin && c == commitment && cb == Some((&callback).try_into().unwrap()) && message_deposit == GET_MESSAGE_DEPOSIT)
+					Some(Message::Pending { origin: o, transport: t, callback: cb, message_deposit })
+					    if o == origin && t == MessageTransport::ismp::<Test>(commitment) && cb == Some((&callback).try_into().unwrap()) && message_deposit == GET_MESSAGE_DEPOSIT)
+				);
+			});
+	}
+
+	#[test]
+	fn get_batch_reverts_when_batch_invalid() {
+		let origin = ALICE;
+		let request = IISMP::Get {
+			destination: 1_000,
+			height: u64::MAX,
+			timeout: u64::MAX,
+			context: Vec::default().into(),
+			keys: Vec::default(),
+		};
+		ExtBuilder::new()
+			.with_balances(vec![(origin.clone(), 1 * UNIT)])
+			.build()
+			.execute_with(|| {
+				for requests in [vec![], vec![request; MaxBatchSize::get() as usize + 1]] {
+					let input = getBatch_0(getBatch_0Call { requests, fee: U256::ZERO });
+					assert_revert!(call_precompile::<MessageId>(&origin, &input), InvalidBatch);
+				}
+			});
+	}
+
+	#[test]
+	fn get_batch_reverts_when_max_keys_exceeded() {
+		let origin = ALICE;
+		ExtBuilder::new().build().execute_with(|| {
+			let request = IISMP::Get {
+				destination: 1_000,
+				height: u64::MAX,
+				timeout: u64::MAX,
+				context: Vec::default().into(),
+				keys: vec![Vec::default().into(); MaxKeys::get() as usize + 1],
+			};
+			let input = getBatch_0(getBatch_0Call { requests: vec![request], fee: U256::ZERO });
+			assert_revert!(call_precompile::<MessageId>(&origin, &input), MaxKeysExceeded);
+		});
+	}
+
+	#[test]
+	fn get_batch_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		let requests = batch();
+		let fee = U256::from(100);
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)]) // message deposit
+			.with_message_id(message)
+			.build()
+			.execute_with(|| {
+				let commitments = get_hashes(&requests);
+				let deposit = quote_get_batch::<Test>(requests.len() as u32, None).message_deposit;
+
+				assert_eq!(
+					call_precompile::<MessageId>(&origin.account, &getBatch_0(getBatch_0Call { requests, fee })).unwrap(),
+					message
+				);
+
+				let event = GetBatchDispatched_0 { origin: origin.address(), id: message, commitments: commitments.iter().map(|c| c.0.into()).collect() };
+				assert_last_event(ADDRESS, event);
+				assert!(matches!(
+					Messages::get(message),
+					Some(Message::Pending { origin: o, transport: t, callback, message_deposit })
+					    if o == origin && t == MessageTransport::of::<Test, IsmpBatchTransport>(&message).unwrap() && callback.is_none() && message_deposit == deposit)
+				);
+				for commitment in commitments {
+					assert_eq!(IsmpFees::get(commitment), Some(100));
+				}
+			});
+	}
+
+	#[test]
+	fn get_batch_with_callback_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		let requests = batch();
+		let fee = U256::from(100);
+		let callback = Callback {
+			destination: [255u8; 20].into(),
+			encoding: super::Encoding::Scale,
+			selector: [255u8; 4].into(),
+			gasLimit: super::Weight { refTime: 100, proofSize: 10 },
+			storageDepositLimit: U256::from(100),
+		};
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)]) // message deposit
+			.with_message_id(message)
+			.build()
+			.execute_with(|| {
+				let commitments = get_hashes(&requests);
+				let deposit = quote_get_batch::<Test>(requests.len() as u32, None).message_deposit;
+
+				assert_eq!(
+					call_precompile::<MessageId>(&origin.account, &getBatch_1(getBatch_1Call { requests, fee, callback: callback.clone() })).unwrap(),
+					message
+				);
+
+				let event = GetBatchDispatched_1 { origin: origin.address(), id: message, commitments: commitments.iter().map(|c| c.0.into()).collect(), callback: callback.clone() };
+				assert_last_event(ADDRESS, event);
+				assert!(matches!(
+					Messages::get(message),
+					Some(Message::Pending { origin: o, transport: t, callback: cb, message_deposit })
+					    if o == origin && t == MessageTransport::of::<Test, IsmpBatchTransport>(&message).unwrap() && cb == Some((&callback).try_into().unwrap()) && message_deposit == deposit)
 				);
 			});
 	}
@@ -664,6 +1413,46 @@ mod tests {
 			});
 	}
 
+	#[test]
+	fn poll_batch_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		let requests = batch();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)]) // message deposit
+			.with_message_id(message)
+			.with_messages(vec![(
+				origin.account.clone(),
+				2,
+				Message::ismp(origin.clone(), H256::default(), None, 0),
+				0,
+			)])
+			.build()
+			.execute_with(|| {
+				let poll = |message| {
+					call_precompile::<(MessageStatus, Vec<MessageStatus>)>(
+						&origin.account,
+						&pollBatch(pollBatchCall { message }),
+					)
+					.unwrap()
+				};
+				let commitments = get_hashes(&requests);
+				let input = getBatch_0(getBatch_0Call { requests, fee: U256::ZERO });
+				assert_ok!(call_precompile::<MessageId>(&origin.account, &input));
+				assert_eq!(poll(message), (Pending, vec![Pending, Pending]));
+
+				assert_ok!(timeout_commitment::<Test>(&commitments[0]));
+				assert_eq!(poll(message), (Pending, vec![Timeout, Pending]));
+
+				assert_ok!(conclude_batch_request::<Test>(&commitments[1], Some(vec![])));
+				assert_eq!(poll(message), (Complete, vec![Timeout, Complete]));
+
+				// Other messages have no requests.
+				assert_eq!(poll(2), (Pending, vec![]));
+				assert_eq!(poll(3), (NotFound, vec![]));
+			});
+	}
+
 	#[test]
 	fn poll_status_works() {
 		let origin = Origin::from((ALICE_ADDR, ALICE));
@@ -749,8 +1538,8 @@ mod tests {
 				assert_last_event(ADDRESS, event);
 				assert!(matches!(
 					Messages::get( message),
-					Some(Message::Ismp { origin: o, commitment: c, callback, message_deposit })
-					    if o == origin && c == commitment && callback.is_none() && message_deposit == POST_MESSAGE_DEPOSIT)
+					Some(Message::Pending { origin: o, transport: t, callback, message_deposit })
+					    if o == origin && t == MessageTransport::ismp::<Test>(commitment) && callback.is_none() && message_deposit == POST_MESSAGE_DEPOSIT)
 				);
 			});
 	}
@@ -789,12 +1578,140 @@ mod tests {
 				assert_last_event(ADDRESS, event);
 				assert!(matches!(
 					Messages::get(message),
-					Some(Message::Ismp { origin: o, commitment: c, callback: cb, message_deposit })
-					    if o == origin && c == commitment && cb == Some((&callback).try_into().unwrap()) && message_deposit == POST_MESSAGE_DEPOSIT)
+					Some(Message::Pending { origin: o, transport: t, callback: cb, message_deposit })
+					    if o == origin && t == MessageTransport::ismp::<Test>(commitment) && cb == Some((&callback).try_into().unwrap()) && message_deposit == POST_MESSAGE_DEPOSIT)
 				);
 			});
 	}
 
+	#[test]
+	fn quote_get_works() {
+		let origin = ALICE;
+		let callback = Callback {
+			destination: [255u8; 20].into(),
+			encoding: super::Encoding::Scale,
+			selector: [255u8; 4].into(),
+			gasLimit: super::Weight { refTime: 100, proofSize: 10 },
+			storageDepositLimit: U256::from(100),
+		};
+		let callback_deposit = WeightToFee::weight_to_fee(&(&callback.gasLimit).into());
+		ExtBuilder::new().build().execute_with(|| {
+			let input = quoteGet_0(quoteGet_0Call {});
+			assert_eq!(
+				call_precompile::<Quote>(&origin, &input).unwrap(),
+				Quote {
+					deposit: U256::from(GET_MESSAGE_DEPOSIT),
+					prepaidFee: U256::from(0),
+					callbackDeposit: U256::ZERO
+				}
+			);
+
+			let input = quoteGet_1(quoteGet_1Call { callback });
+			assert_eq!(
+				call_precompile::<Quote>(&origin, &input).unwrap(),
+				Quote {
+					deposit: U256::from(GET_MESSAGE_DEPOSIT),
+					prepaidFee: U256::from(0),
+					callbackDeposit: U256::from(callback_deposit)
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn quote_get_batch_works() {
+		let origin = ALICE;
+		let callback = Callback {
+			destination: [255u8; 20].into(),
+			encoding: super::Encoding::Scale,
+			selector: [255u8; 4].into(),
+			gasLimit: super::Weight { refTime: 100, proofSize: 10 },
+			storageDepositLimit: U256::from(100),
+		};
+		let callback_deposit = WeightToFee::weight_to_fee(&(&callback.gasLimit).into());
+		ExtBuilder::new().build().execute_with(|| {
+			let deposit = |requests| quote_get_batch::<Test>(requests, None).message_deposit;
+			// Each additional request increases the deposit.
+			assert!(deposit(2) > deposit(1));
+
+			let input = quoteGetBatch_0(quoteGetBatch_0Call { requests: 2 });
+			assert_eq!(
+				call_precompile::<Quote>(&origin, &input).unwrap(),
+				Quote {
+					deposit: U256::from(deposit(2)),
+					prepaidFee: U256::from(0),
+					callbackDeposit: U256::ZERO
+				}
+			);
+
+			let input = quoteGetBatch_1(quoteGetBatch_1Call { requests: 2, callback });
+			assert_eq!(
+				call_precompile::<Quote>(&origin, &input).unwrap(),
+				Quote {
+					deposit: U256::from(deposit(2)),
+					prepaidFee: U256::from(0),
+					callbackDeposit: U256::from(callback_deposit)
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn quote_post_works() {
+		let origin = ALICE;
+		let callback = Callback {
+			destination: [255u8; 20].into(),
+			encoding: super::Encoding::Scale,
+			selector: [255u8; 4].into(),
+			gasLimit: super::Weight { refTime: 100, proofSize: 10 },
+			storageDepositLimit: U256::from(100),
+		};
+		let callback_deposit = WeightToFee::weight_to_fee(&(&callback.gasLimit).into());
+		ExtBuilder::new().build().execute_with(|| {
+			let input = quotePost_0(quotePost_0Call {});
+			assert_eq!(
+				call_precompile::<Quote>(&origin, &input).unwrap(),
+				Quote {
+					deposit: U256::from(POST_MESSAGE_DEPOSIT),
+					prepaidFee: U256::from(0),
+					callbackDeposit: U256::ZERO
+				}
+			);
+
+			let input = quotePost_1(quotePost_1Call { callback });
+			assert_eq!(
+				call_precompile::<Quote>(&origin, &input).unwrap(),
+				Quote {
+					deposit: U256::from(POST_MESSAGE_DEPOSIT),
+					prepaidFee: U256::from(0),
+					callbackDeposit: U256::from(callback_deposit)
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn quote_subscribe_works() {
+		let origin = ALICE;
+		let (_, fee, callback) = subscription();
+		let executions = 3;
+		let callback_deposit = WeightToFee::weight_to_fee(&(&callback.gasLimit).into());
+		ExtBuilder::new().build().execute_with(|| {
+			let deposit = calculate_protocol_deposit::<Test, OnChainByteFee>(
+				ProtocolStorageDeposit::IsmpSubscriptions,
+			);
+			let input = quoteSubscribe(quoteSubscribeCall { executions, fee, callback });
+			assert_eq!(
+				call_precompile::<Quote>(&origin, &input).unwrap(),
+				Quote {
+					deposit: U256::from(deposit + GET_MESSAGE_DEPOSIT * executions as u128),
+					prepaidFee: fee * U256::from(executions),
+					callbackDeposit: U256::from(callback_deposit * executions as u128)
+				}
+			);
+		});
+	}
+
 	#[test]
 	fn remove_reverts_when_message_pending() {
 		let origin = Origin::from((ALICE_ADDR, ALICE));
@@ -940,6 +1857,129 @@ mod tests {
 			});
 	}
 
+	#[test]
+	fn revoke_source_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let source = StateMachine::Evm(1);
+		let from = vec![1u8; 20];
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(call_precompile::<()>(
+					&origin.account,
+					&allowSource(allowSourceCall {
+						source: source.to_string().into_bytes().into(),
+						from: from.clone().into()
+					})
+				));
+				assert_ne!(Messaging::held_deposit(&origin.account), 0);
+
+				assert_ok!(call_precompile::<()>(
+					&origin.account,
+					&revokeSource(revokeSourceCall {
+						source: source.to_string().into_bytes().into(),
+						from: from.clone().into()
+					})
+				));
+
+				assert_last_event(
+					ADDRESS,
+					SourceRevoked {
+						origin: origin.address(),
+						source: source.to_string().into_bytes().into(),
+						from: from.clone().into(),
+					},
+				);
+				assert!(IsmpPostRequestSources::get(
+					origin.address(),
+					source_key::<Test>(&source, &from)
+				)
+				.is_none());
+				assert_eq!(Messaging::held_deposit(&origin.account), 0);
+			});
+	}
+
+	#[test]
+	fn subscribe_reverts_when_invalid_subscription() {
+		let origin = ALICE;
+		let (subscription, fee, callback) = subscription();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.clone(), 1 * UNIT)])
+			.build()
+			.execute_with(|| {
+				for subscription in [
+					Subscription { executions: 0, ..subscription.clone() },
+					Subscription { interval: 0, ..subscription.clone() },
+					Subscription {
+						interval: MinSubscriptionInterval::get() - 1,
+						..subscription.clone()
+					},
+				] {
+					let input =
+						subscribe(subscribeCall { subscription, fee, callback: callback.clone() });
+					assert_revert!(call_precompile::<u64>(&origin, &input), InvalidSubscription);
+				}
+			});
+	}
+
+	#[test]
+	fn subscribe_reverts_when_max_context_exceeded() {
+		let origin = ALICE;
+		let (subscription, fee, callback) = subscription();
+		ExtBuilder::new().build().execute_with(|| {
+			let subscription = Subscription {
+				context: vec![255u8; <MaxContextLen as Get<u32>>::get() as usize + 1].into(),
+				..subscription
+			};
+			let input = subscribe(subscribeCall { subscription, fee, callback });
+			assert_revert!(call_precompile::<u64>(&origin, &input), MaxContextExceeded);
+		});
+	}
+
+	#[test]
+	fn subscribe_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let (subscription, fee, callback) = subscription();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+			.build()
+			.execute_with(|| {
+				let quote = call_precompile::<Quote>(
+					&origin.account,
+					&quoteSubscribe(quoteSubscribeCall {
+						executions: subscription.executions,
+						fee,
+						callback: callback.clone(),
+					}),
+				)
+				.unwrap();
+
+				let id = call_precompile::<u64>(
+					&origin.account,
+					&subscribe(subscribeCall {
+						subscription: subscription.clone(),
+						fee,
+						callback: callback.clone(),
+					}),
+				)
+				.unwrap();
+
+				let event = Subscribed { origin: origin.address(), id, callback: callback.clone() };
+				assert_last_event(ADDRESS, event);
+				assert_eq!(
+					U256::from(Messaging::held_deposit(&origin.account)),
+					quote.deposit + quote.prepaidFee + quote.callbackDeposit
+				);
+				let next = System::block_number() + subscription.interval;
+				assert!(matches!(
+					Subscriptions::get(id),
+					Some(crate::messaging::transports::ismp::Subscription { origin: o, remaining, next: n, .. })
+						if o == origin && remaining == subscription.executions && n == next
+				));
+			});
+	}
+
 	fn call_precompile<Output: SolValue + From<<Output::SolType as SolType>::RustType>>(
 		origin: &AccountId,
 		input: &IISMPCalls,
@@ -954,12 +1994,38 @@ mod tests {
 		)
 	}
 
+	fn batch() -> Vec<IISMP::Get> {
+		(0..2u32)
+			.map(|i| IISMP::Get {
+				destination: 1_000 + i,
+				height: u64::MAX,
+				timeout: u64::MAX,
+				context: vec![255u8; 64].into(),
+				keys: vec![vec![255u8; 32].into()].into(),
+			})
+			.collect()
+	}
+
 	fn get_hash(request: &IISMP::Get) -> H256 {
+		get_hash_at(request, pallet_ismp::Nonce::<Test>::get())
+	}
+
+	// The commitments of a batch of requests, which are dispatched with consecutive nonces.
+	fn get_hashes(requests: &[IISMP::Get]) -> Vec<H256> {
+		let nonce = pallet_ismp::Nonce::<Test>::get();
+		requests
+			.iter()
+			.zip(nonce..)
+			.map(|(request, nonce)| get_hash_at(request, nonce))
+			.collect()
+	}
+
+	fn get_hash_at(request: &IISMP::Get, nonce: u64) -> H256 {
 		keccak_256(
 			Request::Get(GetRequest {
 				source: StateMachine::Polkadot(2_000),
 				dest: StateMachine::Polkadot(request.destination),
-				nonce: pallet_ismp::Nonce::<Test>::get(),
+				nonce,
 				from: ID.to_vec(),
 				keys: request.keys.iter().map(|key| key.to_vec()).collect(),
 				height: request.height,
@@ -988,4 +2054,23 @@ mod tests {
 		)
 		.into()
 	}
+
+	fn subscription() -> (Subscription, U256, Callback) {
+		let subscription = Subscription {
+			destination: 1_000,
+			timeout: u64::MAX,
+			context: vec![255u8; 64].into(),
+			keys: vec![vec![255u8; 32].into()].into(),
+			interval: MinSubscriptionInterval::get(),
+			executions: 3,
+		};
+		let callback = Callback {
+			destination: [255u8; 20].into(),
+			encoding: super::Encoding::Scale,
+			selector: [255u8; 4].into(),
+			gasLimit: super::Weight { refTime: 100, proofSize: 10 },
+			storageDepositLimit: U256::from(100),
+		};
+		(subscription, U256::from(100), callback)
+	}
 }
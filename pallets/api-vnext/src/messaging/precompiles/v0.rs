@@ -6,7 +6,9 @@ use crate::{
 	messaging::{
 		self,
 		precompiles::v0::IMessaging::{
-			getResponseCall, pollStatusCall, remove_0Call, remove_0Return, remove_1Call,
+			acceptMessageCall, acceptMessageReturn, getResponseCall, pollStatusCall, remove_0Call,
+			remove_0Return, remove_1Call, transferMessageCall, transferMessageReturn,
+			updateCallbackCall, updateCallbackReturn,
 		},
 		Config,
 	},
@@ -37,6 +39,28 @@ impl<
 		env: &mut impl Ext<T = Self::T>,
 	) -> Result<Vec<u8>, pallet_revive::precompiles::Error> {
 		match input {
+			IMessagingCalls::acceptMessage(acceptMessageCall { message }) => {
+				env.charge(<T as Config>::WeightInfo::accept_message())?;
+
+				let (previous_owner, account) = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let address = origin.address();
+
+					let previous_owner = accept_message::<T>(origin, *message)?;
+
+					Ok((previous_owner, address))
+				})()
+				.map_err(Self::map_err)?;
+
+				// The event is emitted on behalf of the previous owner, as with the offer.
+				let event = MessageTransferred {
+					account: previous_owner.0.into(),
+					message: *message,
+					newOwner: account,
+				};
+				deposit_event(env, event)?;
+				Ok(acceptMessageCall::abi_encode_returns(&acceptMessageReturn {}))
+			},
 			IMessagingCalls::getResponse(getResponseCall { message }) => {
 				env.charge(<T as Config>::WeightInfo::get_response())?;
 
@@ -91,6 +115,43 @@ impl<
 				deposit_event(env, Removed { account, messages: messages.clone() })?;
 				Ok(remove_1Call::abi_encode_returns(&remove_1Return {}))
 			},
+			IMessagingCalls::transferMessage(transferMessageCall { message, newOwner }) => {
+				env.charge(<T as Config>::WeightInfo::transfer_message())?;
+
+				let account = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let address = origin.address();
+
+					transfer_message::<T>(origin, *message, (*newOwner.0).into())?;
+
+					Ok(address)
+				})()
+				.map_err(Self::map_err)?;
+
+				let event =
+					MessageTransferOffered { account, message: *message, newOwner: *newOwner };
+				deposit_event(env, event)?;
+				Ok(transferMessageCall::abi_encode_returns(&transferMessageReturn {}))
+			},
+			IMessagingCalls::updateCallback(updateCallbackCall { message, callback }) => {
+				env.charge(<T as Config>::WeightInfo::update_callback())?;
+				let cb = callback.try_into()?;
+
+				let account = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let address = origin.address();
+
+					update_callback::<T>(origin, *message, cb)?;
+
+					Ok(address)
+				})()
+				.map_err(Self::map_err)?;
+
+				let event =
+					CallbackUpdated { account, message: *message, callback: callback.clone() };
+				deposit_event(env, event)?;
+				Ok(updateCallbackCall::abi_encode_returns(&updateCallbackReturn {}))
+			},
 		}
 	}
 }
@@ -111,9 +172,13 @@ impl<const FIXED: u16, T: frame_system::Config> Messaging<FIXED, T> {
 				if Some(index as usize) == T::PalletInfo::index::<Pallet<T>>() {
 					use messaging::Error::{self, *};
 					match Error::<T>::decode(&mut error.as_slice()) {
+						Ok(MessageCompleted) => return IMessaging::MessageCompleted.into(),
 						Ok(MessageNotFound) => return IMessaging::MessageNotFound.into(),
 						Ok(RequestPending) => return IMessaging::RequestPending.into(),
+						Ok(RequestTimedOut) => return IMessaging::RequestTimedOut.into(),
 						Ok(TooManyMessages) => return IMessaging::TooManyMessages.into(),
+						Ok(TooManyPendingMessages) =>
+							return IMessaging::TooManyPendingMessages.into(),
 						_ => {},
 					}
 				}
@@ -131,9 +196,13 @@ impl<const FIXED: u16, T: frame_system::Config> Messaging<FIXED, T> {
 // Encoding of custom errors via `Error(String)`.
 impl_from_sol_error! {
 	// Messaging
+	IMessaging::InvalidEncoding,
+	IMessaging::MessageCompleted,
 	IMessaging::MessageNotFound,
 	IMessaging::RequestPending,
+	IMessaging::RequestTimedOut,
 	IMessaging::TooManyMessages,
+	IMessaging::TooManyPendingMessages,
 	// Generic
 	Arithmetic,
 	Dispatch,
@@ -233,6 +302,35 @@ impl From<frame_support::traits::TrieError> for Trie {
 	}
 }
 
+impl<Balance> TryFrom<&IMessaging::Callback> for messaging::Callback<Balance>
+where
+	U256: TryConvert<Balance, Error = frame::DispatchError>,
+{
+	type Error = Error;
+
+	fn try_from(callback: &IMessaging::Callback) -> Result<Self, Self::Error> {
+		Ok(Self::new(
+			(*callback.destination.0).into(),
+			(&callback.encoding).try_into()?,
+			callback.selector.0,
+			(&callback.gasLimit).into(),
+			callback.storageDepositLimit.try_convert()?,
+		))
+	}
+}
+
+impl TryFrom<&IMessaging::Encoding> for messaging::Encoding {
+	type Error = Error;
+
+	fn try_from(encoding: &IMessaging::Encoding) -> Result<Self, Self::Error> {
+		match encoding {
+			IMessaging::Encoding::Scale => Ok(Self::Scale),
+			IMessaging::Encoding::SolidityAbi => Ok(Self::SolidityAbi),
+			IMessaging::Encoding::__Invalid => Err(IMessaging::InvalidEncoding.into()),
+		}
+	}
+}
+
 impl From<messaging::MessageStatus> for IMessaging::MessageStatus {
 	fn from(value: messaging::MessageStatus) -> Self {
 		use messaging::MessageStatus::*;
@@ -241,10 +339,17 @@ impl From<messaging::MessageStatus> for IMessaging::MessageStatus {
 			Pending => Self::Pending,
 			Complete => Self::Complete,
 			Timeout => Self::Timeout,
+			Failed => Self::Failed,
 		}
 	}
 }
 
+impl From<&IMessaging::Weight> for frame::Weight {
+	fn from(weight: &IMessaging::Weight) -> Self {
+		Self::from_parts(weight.refTime, weight.proofSize)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use ::xcm::latest::Response;
@@ -255,7 +360,7 @@ mod tests {
 			alloy::sol_types::{SolInterface, SolType},
 			Error,
 		},
-		test_utils::ALICE,
+		test_utils::{ALICE, BOB, BOB_ADDR},
 	};
 
 	use super::{super::MessageStatus::*, IMessagingCalls::*, *};
@@ -263,8 +368,54 @@ mod tests {
 	const ADDRESS: [u8; 20] = fixed_address(MESSAGING);
 
 	type MaxRemovals = <Test as Config>::MaxRemovals;
+	type MessageTransfers = crate::messaging::MessageTransfers<Test>;
+	type Messages = crate::messaging::Messages<Test>;
 	type Origin = super::Origin<Test>;
 
+	#[test]
+	fn accept_message_reverts_when_message_not_found() {
+		let origin = BOB;
+		let message = 1;
+		ExtBuilder::new().build().execute_with(|| {
+			let input = acceptMessage(acceptMessageCall { message });
+			assert_revert!(call_precompile::<()>(&origin, &input), MessageNotFound);
+		});
+	}
+
+	#[test]
+	fn accept_message_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let new_owner = Origin::from((BOB_ADDR, BOB));
+		let message = 1;
+		ExtBuilder::new()
+			.with_messages(vec![(
+				origin.account.clone(),
+				message,
+				Message::xcm_timeout(origin.address, 0, 0, None),
+				0,
+			)])
+			.build()
+			.execute_with(|| {
+				MessageTransfers::insert(message, (origin.clone(), new_owner.address));
+
+				let input = acceptMessage(acceptMessageCall { message });
+				assert_ok!(call_precompile::<()>(&new_owner.account, &input));
+
+				assert_last_event(
+					ADDRESS,
+					MessageTransferred {
+						account: origin.address(),
+						message,
+						newOwner: new_owner.address(),
+					},
+				);
+				assert_eq!(
+					Messages::get(message),
+					Some(Message::xcm_timeout(new_owner.address, 0, 0, None))
+				);
+			});
+	}
+
 	#[test]
 	fn get_response_works() {
 		let origin = Origin::from((ALICE_ADDR, ALICE));
@@ -481,6 +632,123 @@ mod tests {
 			});
 	}
 
+	#[test]
+	fn transfer_message_reverts_when_message_not_found() {
+		let origin = ALICE;
+		let message = 1;
+		ExtBuilder::new().build().execute_with(|| {
+			let input =
+				transferMessage(transferMessageCall { message, newOwner: BOB_ADDR.0.into() });
+			assert_revert!(call_precompile::<()>(&origin, &input), MessageNotFound);
+		});
+	}
+
+	#[test]
+	fn transfer_message_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		let new_owner = BOB_ADDR.0.into();
+		ExtBuilder::new()
+			.with_messages(vec![(
+				origin.account.clone(),
+				message,
+				Message::xcm_timeout(origin.address, 0, 0, None),
+				0,
+			)])
+			.build()
+			.execute_with(|| {
+				let input = transferMessage(transferMessageCall { message, newOwner: new_owner });
+				assert_ok!(call_precompile::<()>(&origin.account, &input));
+
+				let account = origin.address();
+				assert_last_event(
+					ADDRESS,
+					MessageTransferOffered { account, message, newOwner: new_owner },
+				);
+				// The ownership is only transferred once accepted.
+				assert_eq!(
+					Messages::get(message),
+					Some(Message::xcm_timeout(origin.address, 0, 0, None))
+				);
+				assert_eq!(MessageTransfers::get(message), Some((origin, BOB_ADDR)));
+			});
+	}
+
+	#[test]
+	fn update_callback_reverts_when_message_completed() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		ExtBuilder::new()
+			.with_messages(vec![(
+				origin.account.clone(),
+				message,
+				Message::xcm_response(origin.address, 0, 0, Response::Null),
+				0,
+			)])
+			.build()
+			.execute_with(|| {
+				let input = updateCallback(updateCallbackCall { message, callback: callback() });
+				assert_revert!(call_precompile::<()>(&origin.account, &input), MessageCompleted);
+			});
+	}
+
+	#[test]
+	fn update_callback_reverts_when_request_timed_out() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		ExtBuilder::new()
+			.with_messages(vec![(
+				origin.account.clone(),
+				message,
+				Message::xcm_timeout(origin.address, 0, 0, None),
+				0,
+			)])
+			.build()
+			.execute_with(|| {
+				let input = updateCallback(updateCallbackCall { message, callback: callback() });
+				assert_revert!(call_precompile::<()>(&origin.account, &input), RequestTimedOut);
+			});
+	}
+
+	#[test]
+	fn update_callback_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		let callback = callback();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)]) // callback deposit
+			.with_messages(vec![(
+				origin.account.clone(),
+				message,
+				Message::xcm_query(origin.clone(), 0, None, 0),
+				0,
+			)])
+			.build()
+			.execute_with(|| {
+				let input = updateCallback(updateCallbackCall { message, callback: callback.clone() });
+				assert_ok!(call_precompile::<()>(&origin.account, &input));
+
+				let account = origin.address();
+				let event = CallbackUpdated { account, message, callback: callback.clone() };
+				assert_last_event(ADDRESS, event);
+				assert!(matches!(
+					Messages::get(message),
+					Some(Message::Pending { callback: Some(cb), .. })
+						if cb == (&callback).try_into().unwrap()
+				));
+			});
+	}
+
+	fn callback() -> IMessaging::Callback {
+		IMessaging::Callback {
+			destination: [255u8; 20].into(),
+			encoding: IMessaging::Encoding::SolidityAbi,
+			selector: [255u8; 4].into(),
+			gasLimit: IMessaging::Weight { refTime: 100, proofSize: 10 },
+			storageDepositLimit: U256::from(100),
+		}
+	}
+
 	fn call_precompile<Output: SolValue + From<<Output::SolType as SolType>::RustType>>(
 		origin: &AccountId,
 		input: &IMessagingCalls,
@@ -1,12 +1,27 @@
-use ::xcm::{VersionedLocation, VersionedXcm, MAX_XCM_DECODE_DEPTH};
+use alloc::{boxed::Box, string::String};
+
+use ::xcm::{
+	latest::{
+		Asset, AssetId, AssetInstance, Assets, Error as XcmError, Fungibility, Instruction,
+		Junction, Junctions, Location, NetworkId, OriginKind, Outcome, WeightLimit, WildAsset,
+	},
+	VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm, MAX_XCM_DECODE_DEPTH,
+};
 use codec::{DecodeAll, DecodeLimit};
+use frame_support::{dispatch::GetDispatchInfo, traits::UnfilteredDispatchable};
 use pallet_xcm::WeightInfo as _;
 use sp_runtime::traits::{Block, Header};
 pub(crate) use IXCM::*;
 
 use super::*;
 use crate::{
-	messaging::{transports::xcm::new_query, Config},
+	messaging::{
+		transports::xcm::{
+			cancel_query, dry_run_xcm, get_response, new_query, quote_new_query, XcmApi,
+			XcmDryRunApiError, XcmDryRunEffects, XcmPaymentApiError,
+		},
+		Config, MessageQuote,
+	},
 	TryConvert,
 };
 
@@ -15,6 +30,9 @@ sol!(
 	"src/messaging/precompiles/interfaces/v0/IXCM.sol"
 );
 
+// The parachain identifier of Asset Hub.
+const ASSET_HUB: u32 = 1_000;
+
 pub(crate) type BlockNumberOf<T> =
 	<<<T as frame_system::Config>::Block as Block>::Header as Header>::Number;
 
@@ -32,6 +50,8 @@ impl<
 where
 	BlockNumberOf<T>: From<u32>,
 	u32: From<BlockNumberOf<T>>,
+	<<T as Config>::Fungibles as Inspect<T::AccountId>>::Balance:
+		TryConvert<U256, Error = frame::DispatchError>,
 	U256: TryConvert<
 		<<T as Config>::Fungibles as Inspect<T::AccountId>>::Balance,
 		Error = frame::DispatchError,
@@ -57,6 +77,54 @@ where
 
 				Ok(blockNumberCall::abi_encode_returns(&block_number))
 			},
+			IXCMCalls::cancel(cancelCall { message }) => {
+				env.charge(<T as Config>::WeightInfo::xcm_cancel_query())?;
+
+				let (account, query_id) = (|| {
+					let origin = Origin::try_from(env.caller())?;
+					let address = origin.address();
+
+					let query_id = cancel_query::<T>(origin, *message)?;
+
+					Ok((address, query_id))
+				})()
+				.map_err(Self::map_err)?;
+
+				deposit_event(env, QueryCancelled { account, id: *message, queryId: query_id })?;
+				Ok(cancelCall::abi_encode_returns(&cancelReturn {}))
+			},
+			IXCMCalls::dryRunXcm(dryRunXcmCall { message }) => {
+				env.charge(<T as pallet_xcm::Config>::WeightInfo::execute())?;
+				let weight = <T as Config>::XcmApi::query_xcm_weight(
+					VersionedXcm::decode_all_with_depth_limit(
+						MAX_XCM_DECODE_DEPTH,
+						&mut &message[..],
+					)
+					.map_err(|_| Error::from(IXCM::DecodingFailed))?,
+				)
+				.map_err(map_payment_err)?;
+				let charged = env.charge(weight)?;
+				let message = VersionedXcm::decode_all_with_depth_limit(
+					MAX_XCM_DECODE_DEPTH,
+					&mut &message[..],
+				)
+				.map_err(|_| Error::from(IXCM::DecodingFailed))?;
+				let origin = <T as pallet_xcm::Config>::ExecuteXcmOrigin::ensure_origin(
+					to_runtime_origin(env.caller()),
+				)
+				.map_err(|_| Error::from(IXCM::OriginConversionFailed))?;
+
+				let effects = dry_run_xcm::<T>(origin, message)
+					.map_err(Self::map_err)?
+					.map_err(map_dry_run_err)?;
+
+				let effects = DryRunEffects::from(&effects);
+				// TODO: replace with `env.adjust_gas(charged, result.weight);` once #8693 lands
+				env.gas_meter_mut()
+					.adjust_gas(charged, RuntimeCosts::Precompile((&effects.weightUsed).into()));
+
+				Ok(dryRunXcmCall::abi_encode_returns(&effects))
+			},
 			IXCMCalls::execute(executeCall { message, weight }) => {
 				// Based on https://github.com/paritytech/polkadot-sdk/blob/master/polkadot/xcm/pallet-xcm/src/precompiles.rs
 				let weight = weight.into();
@@ -89,7 +157,8 @@ where
 			IXCMCalls::getResponse(getResponseCall { message }) => {
 				env.charge(<T as Config>::WeightInfo::get_response())?;
 
-				let response = get::<T>(message).into();
+				let response: QueryResponse =
+					get_response::<T>(message).as_ref().unwrap_or(&Response::Null).into();
 
 				Ok(getResponseCall::abi_encode_returns(&response))
 			},
@@ -147,6 +216,54 @@ where
 
 				Ok(pollStatusCall::abi_encode_returns(&status))
 			},
+			IXCMCalls::queryDeliveryFees(queryDeliveryFeesCall { destination, message }) => {
+				// Estimating the delivery fees validates the message with the router, as when
+				// sending.
+				env.charge(<T as pallet_xcm::Config>::WeightInfo::send())?;
+				let destination = VersionedLocation::decode_all(&mut &destination[..])
+					.map_err(|_| Error::from(IXCM::DecodingFailed))?;
+				let message = VersionedXcm::decode_all_with_depth_limit(
+					MAX_XCM_DECODE_DEPTH,
+					&mut &message[..],
+				)
+				.map_err(|_| Error::from(IXCM::DecodingFailed))?;
+
+				let fees = <T as Config>::XcmApi::query_delivery_fees(destination, message)
+					.map_err(map_payment_err)?;
+				let fees = Assets::try_from(fees)
+					.map_err(|_| Error::from(IXCM::InvalidAsset))?
+					.inner()
+					.iter()
+					.map(IXCM::Asset::try_from)
+					.collect::<Result<Vec<_>, _>>()?;
+
+				Ok(queryDeliveryFeesCall::abi_encode_returns(&fees))
+			},
+			IXCMCalls::queryWeightToAssetFee(queryWeightToAssetFeeCall { weight, asset }) => {
+				env.charge(<T as Config>::WeightInfo::quote())?;
+				let asset = VersionedAssetId::from(AssetId(Location::try_from(asset)?));
+
+				let fee = <T as Config>::XcmApi::query_weight_to_asset_fee(weight.into(), asset)
+					.map_err(map_payment_err)?;
+
+				Ok(queryWeightToAssetFeeCall::abi_encode_returns(&fee))
+			},
+			IXCMCalls::quoteNewQuery_0(quoteNewQuery_0Call {}) => {
+				env.charge(<T as Config>::WeightInfo::quote())?;
+
+				let quote: Quote = quote_new_query::<T>(None).try_into().map_err(Self::map_err)?;
+
+				Ok(quoteNewQuery_0Call::abi_encode_returns(&quote))
+			},
+			IXCMCalls::quoteNewQuery_1(quoteNewQuery_1Call { callback }) => {
+				env.charge(<T as Config>::WeightInfo::quote())?;
+
+				let quote: Quote = quote_new_query::<T>(Some((&callback.gasLimit).into()))
+					.try_into()
+					.map_err(Self::map_err)?;
+
+				Ok(quoteNewQuery_1Call::abi_encode_returns(&quote))
+			},
 			IXCMCalls::remove_0(remove_0Call { message }) => {
 				env.charge(<T as Config>::WeightInfo::remove(1))?;
 
@@ -180,6 +297,26 @@ where
 				deposit_event(env, Removed { account, messages: messages.clone() })?;
 				Ok(remove_1Call::abi_encode_returns(&remove_1Return {}))
 			},
+			IXCMCalls::reserveTransferToAssetHub(reserveTransferToAssetHubCall {
+				beneficiary,
+				assets,
+				feeAssetIndex,
+			}) => {
+				let (assets, fee_asset_item) = to_assets(assets, *feeAssetIndex)?;
+				let call = pallet_xcm::Call::<T>::limited_reserve_transfer_assets {
+					dest: Box::new(Location::new(1, [Junction::Parachain(ASSET_HUB)]).into()),
+					beneficiary: Box::new(Location::try_from(beneficiary)?.into()),
+					assets: Box::new(assets.into()),
+					fee_asset_item,
+					weight_limit: WeightLimit::Unlimited,
+				};
+
+				dispatch(env, call)?.map_err(Self::map_err)?;
+
+				Ok(reserveTransferToAssetHubCall::abi_encode_returns(
+					&reserveTransferToAssetHubReturn {},
+				))
+			},
 			IXCMCalls::send(sendCall { destination, message }) => {
 				// Based on https://github.com/paritytech/polkadot-sdk/blob/master/polkadot/xcm/pallet-xcm/src/precompiles.rs
 				env.charge(<T as pallet_xcm::Config>::WeightInfo::send())?;
@@ -201,10 +338,201 @@ where
 
 				Ok(sendCall::abi_encode_returns(&result.encode().into()))
 			},
+			IXCMCalls::teleportAssets(teleportAssetsCall {
+				destination,
+				beneficiary,
+				assets,
+				feeAssetIndex,
+			}) => {
+				let (assets, fee_asset_item) = to_assets(assets, *feeAssetIndex)?;
+				let call = pallet_xcm::Call::<T>::limited_teleport_assets {
+					dest: Box::new(Location::try_from(destination)?.into()),
+					beneficiary: Box::new(Location::try_from(beneficiary)?.into()),
+					assets: Box::new(assets.into()),
+					fee_asset_item,
+					weight_limit: WeightLimit::Unlimited,
+				};
+
+				dispatch(env, call)?.map_err(Self::map_err)?;
+
+				Ok(teleportAssetsCall::abi_encode_returns(&teleportAssetsReturn {}))
+			},
+			IXCMCalls::transact(transactCall {
+				destination,
+				call: encoded_call,
+				weight,
+				feeAsset,
+			}) => {
+				let destination = Location::try_from(destination)?;
+				let fee_asset = Asset::try_from(feeAsset)?;
+
+				// Express the caller and the fee asset relative to the destination, so that the fee
+				// asset can be withdrawn from, and any surplus deposited back to, the sovereign
+				// account of the caller.
+				let universal_location = <T as pallet_xcm::Config>::UniversalLocation::get();
+				let beneficiary = <T as pallet_xcm::Config>::SendXcmOrigin::ensure_origin(
+					to_runtime_origin(env.caller()),
+				)
+				.map_err(|_| Error::from(IXCM::OriginConversionFailed))?
+				.reanchored(&destination, &universal_location)
+				.map_err(|_| Error::from(IXCM::InvalidLocation))?;
+				let fee_asset = fee_asset
+					.reanchored(&destination, &universal_location)
+					.map_err(|_| Error::from(IXCM::InvalidAsset))?;
+
+				let message = ::xcm::latest::Xcm(vec![
+					Instruction::WithdrawAsset(fee_asset.clone().into()),
+					Instruction::BuyExecution {
+						fees: fee_asset,
+						weight_limit: WeightLimit::Unlimited,
+					},
+					Instruction::Transact {
+						origin_kind: OriginKind::SovereignAccount,
+						fallback_max_weight: Some(weight.into()),
+						call: encoded_call.to_vec().into(),
+					},
+					Instruction::RefundSurplus,
+					Instruction::DepositAsset {
+						assets: WildAsset::AllCounted(1).into(),
+						beneficiary,
+					},
+				]);
+				let call = pallet_xcm::Call::<T>::send {
+					dest: Box::new(destination.into()),
+					message: Box::new(VersionedXcm::from(message)),
+				};
+
+				dispatch(env, call)?.map_err(Self::map_err)?;
+
+				Ok(transactCall::abi_encode_returns(&transactReturn {}))
+			},
+			IXCMCalls::transferAssets(transferAssetsCall {
+				destination,
+				beneficiary,
+				assets,
+				feeAssetIndex,
+			}) => {
+				let (assets, fee_asset_item) = to_assets(assets, *feeAssetIndex)?;
+				let call = pallet_xcm::Call::<T>::transfer_assets {
+					dest: Box::new(Location::try_from(destination)?.into()),
+					beneficiary: Box::new(Location::try_from(beneficiary)?.into()),
+					assets: Box::new(assets.into()),
+					fee_asset_item,
+					weight_limit: WeightLimit::Unlimited,
+				};
+
+				dispatch(env, call)?.map_err(Self::map_err)?;
+
+				Ok(transferAssetsCall::abi_encode_returns(&transferAssetsReturn {}))
+			},
 		}
 	}
 }
 
+// Dispatches a call to `pallet_xcm` from the caller, charging the weight of the call upfront and
+// refunding any unused weight.
+fn dispatch<T: pallet_revive::Config + pallet_xcm::Config>(
+	env: &mut impl Ext<T = T>,
+	call: pallet_xcm::Call<T>,
+) -> Result<DispatchResult, Error> {
+	let charged = env.charge(call.get_dispatch_info().call_weight)?;
+	let result = call.dispatch_bypass_filter(to_runtime_origin(env.caller()));
+	let post_info = match &result {
+		Ok(post_info) => *post_info,
+		Err(error) => error.post_info,
+	};
+	if let Some(actual_weight) = post_info.actual_weight {
+		// TODO: replace with `env.adjust_gas(charged, result.weight);` once #8693 lands
+		env.gas_meter_mut().adjust_gas(charged, RuntimeCosts::Precompile(actual_weight));
+	}
+	Ok(result.map(|_| ()).map_err(|e| e.error))
+}
+
+// Maps errors of the dry run runtime API to errors of the precompile.
+fn map_dry_run_err(e: XcmDryRunApiError) -> Error {
+	use XcmDryRunApiError::*;
+	match e {
+		Unimplemented => IXCM::Unsupported.into(),
+		VersionedConversionFailed => IXCM::InvalidMessage.into(),
+	}
+}
+
+// Maps errors of the payment runtime API to errors of the precompile.
+fn map_payment_err(e: XcmPaymentApiError) -> Error {
+	use XcmPaymentApiError::*;
+	match e {
+		Unimplemented => IXCM::Unsupported.into(),
+		VersionedConversionFailed | UnhandledXcmVersion => IXCM::InvalidMessage.into(),
+		WeightNotComputable => IXCM::WeightNotComputable.into(),
+		AssetNotFound => IXCM::AssetNotFound.into(),
+		Unroutable => IXCM::Unroutable.into(),
+	}
+}
+
+// Converts a network into the index and key of a `GlobalConsensus` junction, if supported.
+fn from_network(network: &NetworkId) -> Option<(u128, [u8; 32])> {
+	use NetworkId::*;
+	Some(match network {
+		ByGenesis(genesis) => (0, *genesis),
+		ByFork { .. } => return None,
+		Polkadot => (2, [0; 32]),
+		Kusama => (3, [0; 32]),
+		Ethereum { chain_id } => {
+			let mut key = [0; 32];
+			key[24..].copy_from_slice(&chain_id.to_be_bytes());
+			(7, key)
+		},
+		BitcoinCore => (8, [0; 32]),
+		BitcoinCash => (9, [0; 32]),
+		PolkadotBulletin => (10, [0; 32]),
+	})
+}
+
+// Converts the index and key of a `GlobalConsensus` junction into a network, if supported.
+fn to_network(index: u128, key: &[u8; 32]) -> Option<NetworkId> {
+	use NetworkId::*;
+	Some(match index {
+		0 => ByGenesis(*key),
+		2 => Polkadot,
+		3 => Kusama,
+		7 => {
+			if key[..24].iter().any(|byte| *byte != 0) {
+				return None;
+			}
+			Ethereum { chain_id: u64::from_be_bytes(key[24..].try_into().ok()?) }
+		},
+		8 => BitcoinCore,
+		9 => BitcoinCash,
+		10 => PolkadotBulletin,
+		_ => return None,
+	})
+}
+
+// Pads bytes to 32 bytes, with the bytes leading.
+fn pad<const N: usize>(bytes: &[u8; N]) -> [u8; 32] {
+	let mut padded = [0; 32];
+	padded[..N].copy_from_slice(bytes);
+	padded
+}
+
+// Converts assets into the sorted and deduplicated form expected by `pallet_xcm`, returning the
+// position of the fee asset within the result.
+fn to_assets(assets: &[IXCM::Asset], fee_asset_index: u32) -> Result<(Assets, u32), Error> {
+	let assets = assets.iter().map(Asset::try_from).collect::<Result<Vec<_>, _>>()?;
+	let fee_asset = assets
+		.get(fee_asset_index as usize)
+		.map(|asset| asset.id.clone())
+		.ok_or(Error::from(IXCM::InvalidAsset))?;
+	let assets = Assets::from(assets);
+	let fee_asset_item = assets
+		.inner()
+		.iter()
+		.position(|asset| asset.id == fee_asset)
+		.and_then(|position| position.try_into().ok())
+		.ok_or(Error::from(IXCM::InvalidAsset))?;
+	Ok((assets, fee_asset_item))
+}
+
 impl<const FIXED: u16, T: frame_system::Config> Xcm<FIXED, T> {
 	/// The address of the precompile.
 	pub const fn address() -> [u8; 20] {
@@ -222,12 +550,14 @@ impl<const FIXED: u16, T: frame_system::Config> Xcm<FIXED, T> {
 					use messaging::Error::{self, *};
 					match Error::<T>::decode(&mut error.as_slice()) {
 						Ok(FutureTimeoutMandatory) => return IXCM::FutureTimeoutMandatory.into(),
-						Ok(MaxMessageTimeoutPerBlockReached) =>
-							return IXCM::MaxMessageTimeoutPerBlockReached.into(),
+						Ok(InvalidMessage) => return IXCM::InvalidMessage.into(),
+						Ok(MessageCompleted) => return IXCM::MessageCompleted.into(),
 						Ok(MessageNotFound) => return self::MessageNotFound.into(),
 						Ok(OriginConversionFailed) => IXCM::OriginConversionFailed.into(),
 						Ok(RequestPending) => return self::RequestPending.into(),
+						Ok(RequestTimedOut) => return IXCM::RequestTimedOut.into(),
 						Ok(TooManyMessages) => return self::TooManyMessages.into(),
+						Ok(TooManyPendingMessages) => return self::TooManyPendingMessages.into(),
 						_ => {},
 					}
 				}
@@ -246,16 +576,25 @@ impl<const FIXED: u16, T: frame_system::Config> Xcm<FIXED, T> {
 // Encoding of custom errors via `Error(String)`.
 impl_from_sol_error! {
 	// XCM
+	IXCM::AssetNotFound,
 	IXCM::DecodingFailed,
 	IXCM::FundsUnavailable,
 	IXCM::FutureTimeoutMandatory,
-	IXCM::MaxMessageTimeoutPerBlockReached,
+	IXCM::InvalidAsset,
+	IXCM::InvalidLocation,
+	IXCM::InvalidMessage,
+	IXCM::MessageCompleted,
 	IXCM::OriginConversionFailed,
+	IXCM::RequestTimedOut,
+	IXCM::Unroutable,
+	IXCM::Unsupported,
+	IXCM::WeightNotComputable,
 	// Messaging
 	InvalidEncoding,
 	MessageNotFound,
 	RequestPending,
 	TooManyMessages,
+	TooManyPendingMessages,
 	// Generic
 	Arithmetic,
 	Dispatch,
@@ -268,12 +607,10 @@ impl_from_sol_error! {
 impl EncodeCallback for Response {
 	fn encode(&self, encoding: messaging::Encoding, selector: [u8; 4], id: MessageId) -> Vec<u8> {
 		use messaging::Encoding::*;
-		// XCM responses are always SCALE-encoded.
-		let response = codec::Encode::encode(&self);
 		match encoding {
-			Scale => [selector.to_vec(), (id, response).encode()].concat(),
+			Scale => [selector.to_vec(), (id, codec::Encode::encode(&self)).encode()].concat(),
 			SolidityAbi => {
-				let call = IQueryResponse::onQueryResponseCall { id, response: response.into() };
+				let call = IQueryResponse::onQueryResponseCall { id, response: self.into() };
 				let mut data = call.abi_encode();
 				debug_assert_eq!(data[..4], selector);
 				// Replace selector with that provided at request
@@ -282,6 +619,18 @@ impl EncodeCallback for Response {
 			},
 		}
 	}
+
+	fn encoded_len(&self, encoding: messaging::Encoding) -> usize {
+		use messaging::Encoding::*;
+		match encoding {
+			// The response is provided as encoded bytes.
+			Scale => codec::Encode::encode(&self).encoded_size(),
+			// Excludes the word of the message identifier.
+			SolidityAbi => IQueryResponse::onQueryResponseCall { id: 0, response: self.into() }
+				.abi_encoded_size()
+				.saturating_sub(32),
+		}
+	}
 }
 
 impl<Balance> TryFrom<&Callback> for super::Callback<Balance>
@@ -313,6 +662,99 @@ impl TryFrom<&Encoding> for super::Encoding {
 	}
 }
 
+impl TryFrom<&Asset> for IXCM::Asset {
+	type Error = Error;
+
+	fn try_from(asset: &Asset) -> Result<Self, Self::Error> {
+		let Fungibility::Fungible(amount) = asset.fun else {
+			return Err(IXCM::InvalidAsset.into());
+		};
+		Ok(Self { id: (&asset.id.0).into(), amount })
+	}
+}
+
+impl TryFrom<&IXCM::Asset> for Asset {
+	type Error = Error;
+
+	fn try_from(asset: &IXCM::Asset) -> Result<Self, Self::Error> {
+		Ok((Location::try_from(&asset.id)?, asset.amount).into())
+	}
+}
+
+impl From<&Junction> for IXCM::Junction {
+	fn from(junction: &Junction) -> Self {
+		use IXCM::JunctionType::*;
+		let (junction_type, index, key) = match junction {
+			Junction::Parachain(id) => (Parachain, (*id).into(), [0; 32]),
+			Junction::AccountId32 { network: None, id } => (AccountId32, 0, *id),
+			Junction::AccountKey20 { network: None, key } => (AccountKey20, 0, pad(key)),
+			Junction::PalletInstance(instance) => (PalletInstance, (*instance).into(), [0; 32]),
+			Junction::GeneralIndex(index) => (GeneralIndex, *index, [0; 32]),
+			Junction::GeneralKey { length, data } => (GeneralKey, (*length).into(), *data),
+			Junction::GlobalConsensus(network) => match from_network(network) {
+				Some((index, key)) => (GlobalConsensus, index, key),
+				None => (Unsupported, 0, [0; 32]),
+			},
+			_ => (Unsupported, 0, [0; 32]),
+		};
+		Self { junctionType: junction_type, index, key: key.into() }
+	}
+}
+
+impl TryFrom<&IXCM::Junction> for Junction {
+	type Error = Error;
+
+	fn try_from(junction: &IXCM::Junction) -> Result<Self, Self::Error> {
+		use IXCM::JunctionType::*;
+		let invalid = |_| Error::from(IXCM::InvalidLocation);
+		Ok(match &junction.junctionType {
+			Parachain => Self::Parachain(junction.index.try_into().map_err(invalid)?),
+			AccountId32 => Self::AccountId32 { network: None, id: junction.key.0 },
+			AccountKey20 => {
+				let mut key = [0; 20];
+				key.copy_from_slice(&junction.key[..20]);
+				Self::AccountKey20 { network: None, key }
+			},
+			PalletInstance => Self::PalletInstance(junction.index.try_into().map_err(invalid)?),
+			GeneralIndex => Self::GeneralIndex(junction.index),
+			GeneralKey => {
+				let length = junction.index.try_into().map_err(invalid)?;
+				if length > 32 {
+					return Err(IXCM::InvalidLocation.into());
+				}
+				Self::GeneralKey { length, data: junction.key.0 }
+			},
+			GlobalConsensus => Self::GlobalConsensus(
+				to_network(junction.index, &junction.key.0).ok_or(IXCM::InvalidLocation)?,
+			),
+			Unsupported | __Invalid => return Err(IXCM::InvalidLocation.into()),
+		})
+	}
+}
+
+impl From<&Location> for IXCM::Location {
+	fn from(location: &Location) -> Self {
+		Self {
+			parents: location.parent_count(),
+			interior: location.interior().iter().map(Into::into).collect(),
+		}
+	}
+}
+
+impl TryFrom<&IXCM::Location> for Location {
+	type Error = Error;
+
+	fn try_from(location: &IXCM::Location) -> Result<Self, Self::Error> {
+		let mut interior = Junctions::Here;
+		for junction in &location.interior {
+			interior
+				.push(Junction::try_from(junction)?)
+				.map_err(|_| Error::from(IXCM::InvalidLocation))?;
+		}
+		Ok(Self::new(location.parents, interior))
+	}
+}
+
 impl From<frame_support::sp_runtime::ArithmeticError> for Arithmetic {
 	fn from(error: frame_support::sp_runtime::ArithmeticError) -> Self {
 		use frame_support::sp_runtime::ArithmeticError::*;
@@ -411,10 +853,166 @@ impl From<messaging::MessageStatus> for self::MessageStatus {
 			Pending => Self::Pending,
 			Complete => Self::Complete,
 			Timeout => Self::Timeout,
+			Failed => Self::Failed,
+		}
+	}
+}
+
+impl From<&Response> for QueryResponse {
+	fn from(response: &Response) -> Self {
+		let mut result = Self {
+			responseType: ResponseType::Null,
+			assets: Vec::new(),
+			nonFungibleAssets: Vec::new(),
+			executionResult: ExecutionResult { success: false, ..succeeded() },
+			version: 0,
+			palletsInfo: Vec::new(),
+			dispatchResult: MaybeErrorCode {
+				success: false,
+				error: Default::default(),
+				truncated: false,
+			},
+		};
+		match response {
+			Response::Null => {},
+			Response::Assets(assets) => {
+				result.responseType = ResponseType::Assets;
+				for asset in assets.inner() {
+					let id = (&asset.id.0).into();
+					match &asset.fun {
+						Fungibility::Fungible(amount) =>
+							result.assets.push(IXCM::Asset { id, amount: *amount }),
+						Fungibility::NonFungible(instance) =>
+							result.nonFungibleAssets.push(non_fungible(id, instance)),
+					}
+				}
+			},
+			Response::ExecutionResult(outcome) => {
+				result.responseType = ResponseType::ExecutionResult;
+				result.executionResult = match outcome {
+					Some((index, error)) => failed(*index, error),
+					None => succeeded(),
+				};
+			},
+			Response::Version(version) => {
+				result.responseType = ResponseType::Version;
+				result.version = *version;
+			},
+			Response::PalletsInfo(pallets) => {
+				result.responseType = ResponseType::PalletsInfo;
+				result.palletsInfo = pallets
+					.iter()
+					.map(|pallet| IXCM::PalletInfo {
+						index: pallet.index,
+						name: String::from_utf8_lossy(&pallet.name).into_owned(),
+						moduleName: String::from_utf8_lossy(&pallet.module_name).into_owned(),
+						major: pallet.major,
+						minor: pallet.minor,
+						patch: pallet.patch,
+					})
+					.collect();
+			},
+			Response::DispatchResult(outcome) => {
+				use ::xcm::latest::MaybeErrorCode::*;
+				result.responseType = ResponseType::DispatchResult;
+				result.dispatchResult = match outcome {
+					Success => MaybeErrorCode {
+						success: true,
+						error: Default::default(),
+						truncated: false,
+					},
+					Error(error) => MaybeErrorCode {
+						success: false,
+						error: error.to_vec().into(),
+						truncated: false,
+					},
+					TruncatedError(error) => MaybeErrorCode {
+						success: false,
+						error: error.to_vec().into(),
+						truncated: true,
+					},
+				};
+			},
+		}
+		result
+	}
+}
+
+impl<Event: Encode> From<&XcmDryRunEffects<Event>> for DryRunEffects {
+	fn from(effects: &XcmDryRunEffects<Event>) -> Self {
+		let (execution_result, weight_used) = match &effects.execution_result {
+			Outcome::Complete { used } => (succeeded(), *used),
+			Outcome::Incomplete { used, error } =>
+				(failed(error.index.into(), &error.error), *used),
+			Outcome::Error(error) =>
+				(failed(error.index.into(), &error.error), super::Weight::zero()),
+		};
+		Self {
+			executionResult: execution_result,
+			weightUsed: weight_used.into(),
+			emittedEvents: effects
+				.emitted_events
+				.iter()
+				.map(|event| event.encode().into())
+				.collect(),
+			forwardedMessages: effects
+				.forwarded_xcms
+				.iter()
+				.map(|(destination, messages)| ForwardedMessages {
+					destination: destination.encode().into(),
+					messages: messages.iter().map(|message| message.encode().into()).collect(),
+				})
+				.collect(),
 		}
 	}
 }
 
+// The result of a successful execution.
+fn succeeded() -> ExecutionResult {
+	ExecutionResult { success: true, index: 0, error: 0, trapCode: 0 }
+}
+
+// The result of an execution which failed at the instruction with the given index.
+fn failed(index: u32, error: &XcmError) -> ExecutionResult {
+	ExecutionResult {
+		success: false,
+		index,
+		error: codec::Encode::encode(error)[0],
+		trapCode: match error {
+			XcmError::Trap(code) => *code,
+			_ => 0,
+		},
+	}
+}
+
+// Converts a non-fungible asset into its Solidity representation.
+fn non_fungible(id: IXCM::Location, instance: &AssetInstance) -> NonFungibleAsset {
+	use AssetInstanceType::*;
+	let (instance_type, index, data) = match instance {
+		AssetInstance::Undefined => (Undefined, 0, [0; 32]),
+		AssetInstance::Index(index) => (Index, *index, [0; 32]),
+		AssetInstance::Array4(array) => (Array4, 0, pad(array)),
+		AssetInstance::Array8(array) => (Array8, 0, pad(array)),
+		AssetInstance::Array16(array) => (Array16, 0, pad(array)),
+		AssetInstance::Array32(array) => (Array32, 0, *array),
+	};
+	NonFungibleAsset { id, instanceType: instance_type, index, data: data.into() }
+}
+
+impl<Balance: TryConvert<U256, Error = frame::DispatchError>> TryFrom<MessageQuote<Balance>>
+	for Quote
+{
+	type Error = frame::DispatchError;
+
+	fn try_from(quote: MessageQuote<Balance>) -> Result<Self, Self::Error> {
+		Ok(Self {
+			deposit: quote.message_deposit.try_convert()?,
+			prepaidFee: quote.prepaid_fee.try_convert()?,
+			callbackDeposit: quote.callback_deposit.try_convert()?,
+		})
+	}
+}
+
 impl From<&Weight> for super::Weight {
 	fn from(weight: &Weight) -> Self {
 		Self::from_parts(weight.refTime, weight.proofSize)
@@ -430,11 +1028,18 @@ impl From<super::Weight> for Weight {
 #[cfg(test)]
 mod tests {
 	use ::xcm::{
-		latest::{Junction::Parachain, Response, Xcm},
+		latest::{Instruction::ClearOrigin, InstructionError, Junction::Parachain, Response, Xcm},
 		v5::WeightLimit,
 	};
-	use frame_support::{assert_ok, dispatch::PostDispatchInfo, weights::Weight};
-	use mock::{messaging::RESPONSE_LOCATION, ExtBuilder, *};
+	use frame_support::{
+		assert_ok,
+		dispatch::PostDispatchInfo,
+		weights::{Weight, WeightToFee as _},
+	};
+	use mock::{
+		messaging::{UnitWeightCost, RESPONSE_LOCATION},
+		ExtBuilder, *,
+	};
 	use pallet_revive::{
 		precompiles::{
 			alloy::sol_types::{SolInterface, SolType},
@@ -445,15 +1050,22 @@ mod tests {
 	use pallet_xcm::ExecutionError;
 
 	use super::{IXCMCalls::*, MessageStatus::*, *};
+	use crate::messaging::transports::MessageTransport;
 
+	type CallbackExecutor = <Test as Config>::CallbackExecutor;
 	type MaxXcmQueryTimeoutsPerBlock = <Test as Config>::MaxXcmQueryTimeoutsPerBlock;
 	type MaxRemovals = <Test as Config>::MaxRemovals;
+	type MaxResponseLen = <Test as Config>::MaxResponseLen;
 	type Messages = crate::messaging::Messages<Test>;
 	type Origin = super::Origin<Test>;
+	type PendingMessageQuotas = crate::messaging::PendingMessageQuotas<Test>;
+	type WeightInfo = <Test as Config>::WeightInfo;
+	type WeightToFee = <Test as Config>::WeightToFee;
+	type XcmQueryTimeoutOverflow = crate::messaging::XcmQueryTimeoutOverflow<Test>;
 	type XcmQueryTimeouts = crate::messaging::XcmQueryTimeouts<Test>;
 
 	const ADDRESS: [u8; 20] = fixed_address(XCM);
-	const MESSAGE_DEPOSIT: u128 = 129_600;
+	const MESSAGE_DEPOSIT: u128 = 13_790;
 
 	#[test]
 	fn block_number_works() {
@@ -467,6 +1079,192 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn cancel_reverts_when_message_not_found() {
+		let origin = ALICE;
+		let message = 1;
+		ExtBuilder::new().build().execute_with(|| {
+			assert_revert!(
+				call_precompile::<()>(&origin, &cancel(cancelCall { message })),
+				MessageNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn cancel_reverts_when_message_completed() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		ExtBuilder::new()
+			.with_messages(vec![(
+				origin.account.clone(),
+				message,
+				Message::xcm_response(origin.address, 0, 0, Response::Null),
+				0,
+			)])
+			.build()
+			.execute_with(|| {
+				assert_revert!(
+					call_precompile::<()>(&origin.account, &cancel(cancelCall { message })),
+					MessageCompleted
+				);
+			});
+	}
+
+	#[test]
+	fn cancel_reverts_when_request_timed_out() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = 1;
+		ExtBuilder::new()
+			.with_messages(vec![(
+				origin.account.clone(),
+				message,
+				Message::xcm_timeout(origin.address, 0, 0, None),
+				0,
+			)])
+			.build()
+			.execute_with(|| {
+				assert_revert!(
+					call_precompile::<()>(&origin.account, &cancel(cancelCall { message })),
+					RequestTimedOut
+				);
+			});
+	}
+
+	#[test]
+	fn cancel_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let responder = RESPONSE_LOCATION.encode().into();
+		let timeout = 100;
+		let message = 1;
+		let query_id = 2;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+			.with_message_id(message)
+			.with_query_id(query_id)
+			.build()
+			.execute_with(|| {
+				let call = newQuery_0(newQuery_0Call { responder, timeout });
+				call_precompile::<MessageId>(&origin.account, &call).unwrap();
+
+				assert_ok!(call_precompile::<()>(&origin.account, &cancel(cancelCall { message })));
+
+				let account = origin.address();
+				assert_last_event(
+					ADDRESS,
+					QueryCancelled { account, id: message, queryId: query_id },
+				);
+				assert!(Messages::get(message).is_none());
+			});
+	}
+
+	#[test]
+	fn dry_run_effects_conversion_works() {
+		let used = Weight::from_parts(1_000, 100);
+		let error = InstructionError { index: 2, error: XcmError::Trap(5) };
+		for (outcome, execution_result, weight_used) in [
+			(Outcome::Complete { used }, succeeded(), used),
+			(Outcome::Incomplete { used, error }, failed(2, &XcmError::Trap(5)), used),
+			(Outcome::Error(error), failed(2, &XcmError::Trap(5)), Weight::zero()),
+		] {
+			let effects = XcmDryRunEffects::<RuntimeEvent> {
+				execution_result: outcome,
+				emitted_events: vec![],
+				forwarded_xcms: vec![],
+			};
+			assert_eq!(
+				DryRunEffects::from(&effects),
+				DryRunEffects {
+					executionResult: execution_result,
+					weightUsed: weight_used.into(),
+					emittedEvents: vec![],
+					forwardedMessages: vec![],
+				}
+			);
+		}
+	}
+
+	#[test]
+	fn dry_run_xcm_reverts_when_decoding_failed() {
+		let origin = ALICE;
+		let message = Vec::default().into();
+		ExtBuilder::new().build().execute_with(|| {
+			let input = dryRunXcm(dryRunXcmCall { message });
+			assert_revert!(call_precompile::<()>(&origin, &input), DecodingFailed);
+		});
+	}
+
+	#[test]
+	fn dry_run_xcm_works() {
+		let origin = ALICE;
+		let message = VersionedXcm::from(Xcm::<()>(vec![ClearOrigin, ClearOrigin]));
+		let location = VersionedLocation::from(Location::new(
+			0,
+			Junction::AccountId32 { network: Some(NetworkId::Polkadot), id: origin.clone().into() },
+		));
+		ExtBuilder::new().build().execute_with(|| {
+			System::deposit_event(frame_system::Event::CodeUpdated);
+			let events = System::events();
+
+			let input = dryRunXcm(dryRunXcmCall { message: message.encode().into() });
+			assert_eq!(
+				call_precompile::<DryRunEffects>(&origin, &input).unwrap(),
+				DryRunEffects {
+					executionResult: succeeded(),
+					weightUsed: (UnitWeightCost::get() * 2).into(),
+					emittedEvents: vec![RuntimeEvent::System(frame_system::Event::CodeUpdated)
+						.encode()
+						.into()],
+					forwardedMessages: vec![ForwardedMessages {
+						destination: location.encode().into(),
+						messages: vec![VersionedXcm::from(Xcm::<()>::new()).encode().into()],
+					}],
+				}
+			);
+			// Any changes made by the dry run are discarded.
+			assert_eq!(System::events(), events);
+		});
+	}
+
+	#[test]
+	fn encode_callback_works() {
+		use messaging::Encoding::*;
+		let id = 42;
+		let response = Response::Version(5);
+		let selector = [255; 4];
+
+		assert_eq!(
+			EncodeCallback::encode(&response, Scale, selector, id),
+			[selector.to_vec(), (id, Encode::encode(&response)).encode()].concat()
+		);
+
+		let selector = IQueryResponse::onQueryResponseCall::SELECTOR;
+		let encoded = EncodeCallback::encode(&response, SolidityAbi, selector, id);
+		assert_eq!(
+			IQueryResponse::onQueryResponseCall::abi_decode(&encoded).unwrap(),
+			IQueryResponse::onQueryResponseCall { id, response: (&response).into() }
+		);
+	}
+
+	#[test]
+	fn encoded_len_works() {
+		use messaging::Encoding::*;
+		let id = 42;
+		let selector = IQueryResponse::onQueryResponseCall::SELECTOR;
+
+		for response in [Response::Null, Response::Version(5), Response::ExecutionResult(None)] {
+			// Excludes the selector and message identifier which precede the response.
+			assert_eq!(
+				EncodeCallback::encoded_len(&response, Scale),
+				EncodeCallback::encode(&response, Scale, selector, id).len() - 4 - 8
+			);
+			assert_eq!(
+				EncodeCallback::encoded_len(&response, SolidityAbi),
+				EncodeCallback::encode(&response, SolidityAbi, selector, id).len() - 4 - 32
+			);
+		}
+	}
+
 	#[test]
 	fn execute_reverts_when_decoding_failed() {
 		let origin = ALICE;
@@ -519,7 +1317,7 @@ mod tests {
 	fn get_response_works() {
 		let origin = Origin::from((ALICE_ADDR, ALICE));
 		let message = 1;
-		let response = Response::Null;
+		let response = Response::Version(5);
 		ExtBuilder::new()
 			.with_messages(vec![(
 				origin.account.clone(),
@@ -530,12 +1328,21 @@ mod tests {
 			.build()
 			.execute_with(|| {
 				assert_eq!(
-					call_precompile::<Vec<u8>>(
+					call_precompile::<QueryResponse>(
 						&origin.account,
 						&getResponse(getResponseCall { message })
 					)
 					.unwrap(),
-					Encode::encode(&response)
+					(&response).into()
+				);
+				// Unknown messages return a null response.
+				assert_eq!(
+					call_precompile::<QueryResponse>(
+						&origin.account,
+						&getResponse(getResponseCall { message: message + 1 })
+					)
+					.unwrap(),
+					(&Response::Null).into()
 				);
 			});
 	}
@@ -552,6 +1359,93 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn location_conversion_works() {
+		let key = [255; 32];
+		let location = IXCM::Location {
+			parents: 1,
+			interior: vec![
+				junction(JunctionType::Parachain, 1_000, [0; 32]),
+				junction(JunctionType::PalletInstance, 50, [0; 32]),
+				junction(JunctionType::GeneralIndex, 1_984, [0; 32]),
+				junction(JunctionType::AccountId32, 0, key),
+				junction(JunctionType::AccountKey20, 0, key),
+			],
+		};
+		assert_eq!(
+			Location::try_from(&location).unwrap(),
+			Location::new(
+				1,
+				[
+					Parachain(1_000),
+					Junction::PalletInstance(50),
+					Junction::GeneralIndex(1_984),
+					Junction::AccountId32 { network: None, id: key },
+					Junction::AccountKey20 { network: None, key: [255; 20] },
+				]
+			)
+		);
+	}
+
+	#[test]
+	fn location_conversion_reverts_when_invalid_location() {
+		for interior in [
+			vec![junction(JunctionType::Parachain, u32::MAX as u128 + 1, [0; 32])],
+			vec![junction(JunctionType::PalletInstance, u8::MAX as u128 + 1, [0; 32])],
+			vec![junction(JunctionType::GeneralIndex, 0, [0; 32]); 9],
+			vec![junction(JunctionType::GeneralKey, 33, [0; 32])],
+			vec![junction(JunctionType::GlobalConsensus, 1, [0; 32])],
+			vec![junction(JunctionType::GlobalConsensus, 7, [255; 32])],
+			vec![junction(JunctionType::Unsupported, 0, [0; 32])],
+		] {
+			let location = IXCM::Location { parents: 0, interior };
+			assert_revert!(Location::try_from(&location), InvalidLocation);
+		}
+	}
+
+	#[test]
+	fn location_conversion_round_trips() {
+		let mut chain_id = [0; 32];
+		chain_id[24..].copy_from_slice(&1u64.to_be_bytes());
+		for (junction, expected) in [
+			(
+				Junction::GeneralKey { length: 2, data: [255; 32] },
+				junction(JunctionType::GeneralKey, 2, [255; 32]),
+			),
+			(
+				Junction::GlobalConsensus(NetworkId::ByGenesis([255; 32])),
+				junction(JunctionType::GlobalConsensus, 0, [255; 32]),
+			),
+			(
+				Junction::GlobalConsensus(NetworkId::Polkadot),
+				junction(JunctionType::GlobalConsensus, 2, [0; 32]),
+			),
+			(
+				Junction::GlobalConsensus(NetworkId::Ethereum { chain_id: 1 }),
+				junction(JunctionType::GlobalConsensus, 7, chain_id),
+			),
+		] {
+			let location = Location::new(1, [junction]);
+			let converted = IXCM::Location::from(&location);
+			assert_eq!(converted, self::location(1, vec![expected]));
+			assert_eq!(Location::try_from(&converted).unwrap(), location);
+		}
+	}
+
+	#[test]
+	fn location_conversion_marks_unsupported_junctions() {
+		for junction in [
+			Junction::AccountId32 { network: Some(NetworkId::Polkadot), id: [0; 32] },
+			Junction::GlobalConsensus(NetworkId::ByFork { block_number: 0, block_hash: [0; 32] }),
+			Junction::OnlyChild,
+		] {
+			assert_eq!(
+				IXCM::Location::from(&Location::new(0, [junction])),
+				location(0, vec![self::junction(JunctionType::Unsupported, 0, [0; 32])])
+			);
+		}
+	}
+
 	#[test]
 	fn new_query_reverts_when_decoding_failed() {
 		let origin = ALICE;
@@ -575,22 +1469,38 @@ mod tests {
 	}
 
 	#[test]
-	fn new_query_reverts_when_max_query_timeouts_reached() {
+	fn new_query_spills_over_when_max_query_timeouts_reached() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let responder = RESPONSE_LOCATION.encode().into();
+		let timeout = 2;
+		let message = 1;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+			.with_message_id(message)
+			.build()
+			.execute_with(|| {
+				let timeout_block = frame_system::Pallet::<Test>::block_number() + timeout;
+				XcmQueryTimeouts::set(
+					timeout_block,
+					vec![0; MaxXcmQueryTimeoutsPerBlock::get() as usize].try_into().unwrap(),
+				);
+
+				let input = newQuery_0(newQuery_0Call { responder, timeout });
+				assert_eq!(call_precompile::<MessageId>(&origin.account, &input).unwrap(), message);
+				assert!(XcmQueryTimeoutOverflow::contains_key(timeout_block, message));
+			});
+	}
+
+	#[test]
+	fn new_query_reverts_when_too_many_pending_messages() {
 		let origin = ALICE;
 		let responder = RESPONSE_LOCATION.encode().into();
 		let timeout = 2;
 		ExtBuilder::new().build().execute_with(|| {
-			let current_block = frame_system::Pallet::<Test>::block_number();
-			XcmQueryTimeouts::set(
-				current_block + timeout,
-				vec![0; MaxXcmQueryTimeoutsPerBlock::get() as usize].try_into().unwrap(),
-			);
+			PendingMessageQuotas::insert(ALICE_ADDR, 0);
 
 			let input = newQuery_0(newQuery_0Call { responder, timeout });
-			assert_revert!(
-				call_precompile::<()>(&origin, &input),
-				MaxMessageTimeoutPerBlockReached
-			);
+			assert_revert!(call_precompile::<()>(&origin, &input), TooManyPendingMessages);
 		});
 	}
 
@@ -629,8 +1539,8 @@ mod tests {
 				assert_last_event(ADDRESS, event);
 				assert!(matches!(
 					Messages::get(message),
-					Some(Message::XcmQuery { origin: o, query_id: qid, callback, message_deposit })
-					    if o == origin && qid == query_id && callback.is_none() && message_deposit == MESSAGE_DEPOSIT)
+					Some(Message::Pending { origin: o, transport: t, callback, message_deposit })
+					    if o == origin && t == MessageTransport::xcm::<Test>(query_id) && callback.is_none() && message_deposit == MESSAGE_DEPOSIT)
 				);
 			});
 	}
@@ -672,8 +1582,8 @@ mod tests {
 	}
 
 	#[test]
-	fn new_query_with_callback_reverts_when_max_query_timeouts_reached() {
-		let origin = ALICE;
+	fn new_query_with_callback_spills_over_when_max_query_timeouts_reached() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
 		let responder = RESPONSE_LOCATION.encode().into();
 		let timeout = 2;
 		let callback = Callback {
@@ -683,19 +1593,22 @@ mod tests {
 			gasLimit: super::Weight { refTime: 100, proofSize: 10 },
 			storageDepositLimit: U256::from(100),
 		};
-		ExtBuilder::new().build().execute_with(|| {
-			let current_block = frame_system::Pallet::<Test>::block_number();
-			XcmQueryTimeouts::set(
-				current_block + timeout,
-				vec![0; MaxXcmQueryTimeoutsPerBlock::get() as usize].try_into().unwrap(),
-			);
+		let message = 1;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+			.with_message_id(message)
+			.build()
+			.execute_with(|| {
+				let timeout_block = frame_system::Pallet::<Test>::block_number() + timeout;
+				XcmQueryTimeouts::set(
+					timeout_block,
+					vec![0; MaxXcmQueryTimeoutsPerBlock::get() as usize].try_into().unwrap(),
+				);
 
-			let input = newQuery_1(newQuery_1Call { responder, timeout, callback });
-			assert_revert!(
-				call_precompile::<()>(&origin, &input),
-				MaxMessageTimeoutPerBlockReached
-			);
-		});
+				let input = newQuery_1(newQuery_1Call { responder, timeout, callback });
+				assert_eq!(call_precompile::<MessageId>(&origin.account, &input).unwrap(), message);
+				assert!(XcmQueryTimeoutOverflow::contains_key(timeout_block, message));
+			});
 	}
 
 	#[test]
@@ -747,8 +1660,8 @@ mod tests {
 				assert_last_event(ADDRESS, event);
 				assert!(matches!(
 					Messages::get(message),
-					Some(Message::XcmQuery { origin: o, query_id: qid, callback: cb, message_deposit })
-					    if o == origin && qid == query_id && cb == Some((&callback).try_into().unwrap()) && message_deposit == MESSAGE_DEPOSIT)
+					Some(Message::Pending { origin: o, transport: t, callback: cb, message_deposit })
+					    if o == origin && t == MessageTransport::xcm::<Test>(query_id) && cb == Some((&callback).try_into().unwrap()) && message_deposit == MESSAGE_DEPOSIT)
 				);
 			});
 	}
@@ -779,6 +1692,103 @@ mod tests {
 			});
 	}
 
+	#[test]
+	fn query_delivery_fees_reverts_when_unroutable() {
+		let origin = ALICE;
+		let destination = VersionedLocation::from(Location::new(1, [Parachain(1_000)]));
+		let message = VersionedXcm::from(Xcm::<()>(vec![ClearOrigin]));
+		ExtBuilder::new().build().execute_with(|| {
+			let input = queryDeliveryFees(queryDeliveryFeesCall {
+				destination: destination.encode().into(),
+				message: message.encode().into(),
+			});
+			assert_revert!(call_precompile::<Vec<IXCM::Asset>>(&origin, &input), Unroutable);
+		});
+	}
+
+	#[test]
+	fn query_delivery_fees_works() {
+		let origin = ALICE;
+		let destination = VersionedLocation::from(Location::parent());
+		let message = VersionedXcm::from(Xcm::<()>(vec![ClearOrigin]));
+		ExtBuilder::new().build().execute_with(|| {
+			let input = queryDeliveryFees(queryDeliveryFeesCall {
+				destination: destination.encode().into(),
+				message: message.encode().into(),
+			});
+			assert_eq!(
+				call_precompile::<Vec<IXCM::Asset>>(&origin, &input).unwrap(),
+				vec![asset(location(1, vec![]), 100)]
+			);
+		});
+	}
+
+	#[test]
+	fn query_weight_to_asset_fee_reverts_when_asset_not_found() {
+		let origin = ALICE;
+		let weight = Weight::from_parts(1_000, 100).into();
+		let asset = location(0, vec![junction(JunctionType::GeneralIndex, 1, [0; 32])]);
+		ExtBuilder::new().build().execute_with(|| {
+			let input = queryWeightToAssetFee(queryWeightToAssetFeeCall { weight, asset });
+			assert_revert!(call_precompile::<u128>(&origin, &input), AssetNotFound);
+		});
+	}
+
+	#[test]
+	fn query_weight_to_asset_fee_works() {
+		let origin = ALICE;
+		let weight = Weight::from_parts(1_000, 100);
+		ExtBuilder::new().build().execute_with(|| {
+			let input = queryWeightToAssetFee(queryWeightToAssetFeeCall {
+				weight: weight.into(),
+				asset: location(1, vec![]),
+			});
+			assert_eq!(
+				call_precompile::<u128>(&origin, &input).unwrap(),
+				WeightToFee::weight_to_fee(&weight)
+			);
+		});
+	}
+
+	#[test]
+	fn quote_new_query_works() {
+		let origin = ALICE;
+		let callback = Callback {
+			destination: [255u8; 20].into(),
+			encoding: super::Encoding::Scale,
+			selector: [255u8; 4].into(),
+			gasLimit: super::Weight { refTime: 100, proofSize: 10 },
+			storageDepositLimit: U256::from(100),
+		};
+		let callback_deposit = WeightToFee::weight_to_fee(&(&callback.gasLimit).into());
+		ExtBuilder::new().build().execute_with(|| {
+			let input = quoteNewQuery_0(quoteNewQuery_0Call {});
+			assert_eq!(
+				call_precompile::<Quote>(&origin, &input).unwrap(),
+				Quote {
+					deposit: U256::from(MESSAGE_DEPOSIT),
+					prepaidFee: U256::from(WeightToFee::weight_to_fee(&WeightInfo::xcm_response(
+						<MaxResponseLen as Get<u32>>::get()
+					))),
+					callbackDeposit: U256::ZERO
+				}
+			);
+
+			let input = quoteNewQuery_1(quoteNewQuery_1Call { callback });
+			assert_eq!(
+				call_precompile::<Quote>(&origin, &input).unwrap(),
+				Quote {
+					deposit: U256::from(MESSAGE_DEPOSIT),
+					prepaidFee: U256::from(WeightToFee::weight_to_fee(
+						&(WeightInfo::xcm_response(<MaxResponseLen as Get<u32>>::get()) +
+							CallbackExecutor::execution_weight())
+					)),
+					callbackDeposit: U256::from(callback_deposit)
+				}
+			);
+		});
+	}
+
 	#[test]
 	fn remove_reverts_when_message_pending() {
 		let origin = Origin::from((ALICE_ADDR, ALICE));
@@ -923,6 +1933,140 @@ mod tests {
 			});
 	}
 
+	#[test]
+	fn reserve_transfer_to_asset_hub_reverts_when_invalid_asset() {
+		let origin = ALICE;
+		let beneficiary = location(0, vec![junction(JunctionType::AccountId32, 0, [255; 32])]);
+		let assets = vec![asset(location(1, vec![]), 100)];
+		ExtBuilder::new().build().execute_with(|| {
+			let input = reserveTransferToAssetHub(reserveTransferToAssetHubCall {
+				beneficiary,
+				assets,
+				feeAssetIndex: 1,
+			});
+			assert_revert!(call_precompile::<()>(&origin, &input), InvalidAsset);
+		});
+	}
+
+	#[test]
+	fn reserve_transfer_to_asset_hub_works() {
+		let origin = ALICE;
+		let beneficiary = location(0, vec![junction(JunctionType::AccountId32, 0, [255; 32])]);
+		let assets = vec![asset(location(1, vec![]), 100)];
+		ExtBuilder::new().build().execute_with(|| {
+			let input = reserveTransferToAssetHub(reserveTransferToAssetHubCall {
+				beneficiary,
+				assets,
+				feeAssetIndex: 0,
+			});
+			// No reserve transfers currently permitted in mock runtime
+			assert_revert!(
+				call_precompile::<()>(&origin, &input),
+				module_error(pallet_xcm::Error::<Test>::Filtered)
+			);
+		});
+	}
+
+	#[test]
+	fn response_conversion_works() {
+		use ::xcm::latest::{MaybeErrorCode as XcmMaybeErrorCode, PalletInfo as XcmPalletInfo};
+
+		let id = Location::new(1, [Parachain(1_000)]);
+		let null = QueryResponse::from(&Response::Null);
+		assert_eq!(null.responseType, ResponseType::Null);
+
+		let assets: Assets = vec![
+			(id.clone(), 100u128).into(),
+			(id.clone(), AssetInstance::Array4([255; 4])).into(),
+		]
+		.into();
+		let mut data = [0; 32];
+		data[..4].copy_from_slice(&[255; 4]);
+		assert_eq!(
+			QueryResponse::from(&Response::Assets(assets)),
+			QueryResponse {
+				responseType: ResponseType::Assets,
+				assets: vec![asset((&id).into(), 100)],
+				nonFungibleAssets: vec![NonFungibleAsset {
+					id: (&id).into(),
+					instanceType: AssetInstanceType::Array4,
+					index: 0,
+					data: data.into(),
+				}],
+				..null.clone()
+			}
+		);
+
+		assert_eq!(
+			QueryResponse::from(&Response::ExecutionResult(None)),
+			QueryResponse {
+				responseType: ResponseType::ExecutionResult,
+				executionResult: ExecutionResult { success: true, index: 0, error: 0, trapCode: 0 },
+				..null.clone()
+			}
+		);
+		assert_eq!(
+			QueryResponse::from(&Response::ExecutionResult(Some((2, XcmError::Trap(5))))),
+			QueryResponse {
+				responseType: ResponseType::ExecutionResult,
+				executionResult: ExecutionResult {
+					success: false,
+					index: 2,
+					error: Encode::encode(&XcmError::Trap(5))[0],
+					trapCode: 5
+				},
+				..null.clone()
+			}
+		);
+
+		assert_eq!(
+			QueryResponse::from(&Response::Version(5)),
+			QueryResponse { responseType: ResponseType::Version, version: 5, ..null.clone() }
+		);
+
+		let pallet =
+			XcmPalletInfo::new(50, b"Assets".to_vec(), b"pallet_assets".to_vec(), 1, 2, 3).unwrap();
+		assert_eq!(
+			QueryResponse::from(&Response::PalletsInfo(vec![pallet].try_into().unwrap())),
+			QueryResponse {
+				responseType: ResponseType::PalletsInfo,
+				palletsInfo: vec![IXCM::PalletInfo {
+					index: 50,
+					name: "Assets".into(),
+					moduleName: "pallet_assets".into(),
+					major: 1,
+					minor: 2,
+					patch: 3
+				}],
+				..null.clone()
+			}
+		);
+
+		for (outcome, expected) in [
+			(
+				XcmMaybeErrorCode::Success,
+				MaybeErrorCode { success: true, error: Default::default(), truncated: false },
+			),
+			(
+				XcmMaybeErrorCode::Error(vec![1, 2].try_into().unwrap()),
+				MaybeErrorCode { success: false, error: vec![1, 2].into(), truncated: false },
+			),
+			(
+				XcmMaybeErrorCode::TruncatedError(vec![1, 2].try_into().unwrap()),
+				MaybeErrorCode { success: false, error: vec![1, 2].into(), truncated: true },
+			),
+		] {
+			assert_eq!(
+				QueryResponse::from(&Response::DispatchResult(outcome)),
+				QueryResponse {
+					responseType: ResponseType::DispatchResult,
+					dispatchResult: expected,
+					..null.clone()
+				}
+			);
+		}
+	}
+
 	#[test]
 	fn send_reverts_when_decoding_failed() {
 		let origin = ALICE;
@@ -964,6 +2108,101 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn teleport_assets_reverts_when_invalid_location() {
+		let origin = ALICE;
+		let destination =
+			location(1, vec![junction(JunctionType::Parachain, u32::MAX as u128 + 1, [0; 32])]);
+		let beneficiary = location(0, vec![junction(JunctionType::AccountId32, 0, [255; 32])]);
+		let assets = vec![asset(location(0, vec![]), 100)];
+		ExtBuilder::new().build().execute_with(|| {
+			let input = teleportAssets(teleportAssetsCall {
+				destination,
+				beneficiary,
+				assets,
+				feeAssetIndex: 0,
+			});
+			assert_revert!(call_precompile::<()>(&origin, &input), InvalidLocation);
+		});
+	}
+
+	#[test]
+	fn teleport_assets_works() {
+		let origin = ALICE;
+		let destination = location(1, vec![junction(JunctionType::Parachain, 1_000, [0; 32])]);
+		let beneficiary = location(0, vec![junction(JunctionType::AccountId32, 0, [255; 32])]);
+		let assets = vec![asset(location(0, vec![]), 100)];
+		ExtBuilder::new().build().execute_with(|| {
+			let input = teleportAssets(teleportAssetsCall {
+				destination,
+				beneficiary,
+				assets,
+				feeAssetIndex: 0,
+			});
+			// No teleports currently permitted in mock runtime
+			assert_revert!(
+				call_precompile::<()>(&origin, &input),
+				module_error(pallet_xcm::Error::<Test>::Filtered)
+			);
+		});
+	}
+
+	#[test]
+	fn to_assets_returns_position_of_fee_asset() {
+		let assets = vec![
+			asset(location(1, vec![]), 100),
+			asset(location(0, vec![]), 200),
+			asset(location(1, vec![]), 50),
+		];
+
+		let (result, fee_asset_item) = to_assets(&assets, 1).unwrap();
+
+		// Assets are sorted and deduplicated
+		assert_eq!(
+			result.into_inner(),
+			vec![(Location::here(), 200u128).into(), (Location::parent(), 150u128).into()]
+		);
+		assert_eq!(fee_asset_item, 0);
+		assert_revert!(to_assets(&assets, 3), InvalidAsset);
+	}
+
+	#[test]
+	fn transact_works() {
+		let origin = ALICE;
+		let destination = location(1, vec![junction(JunctionType::Parachain, 1_000, [0; 32])]);
+		let call = vec![0u8; 2].into();
+		let weight = Weight::from_parts(100_000, 100_000).into();
+		let fee_asset = asset(location(1, vec![]), 100);
+		ExtBuilder::new().build().execute_with(|| {
+			let input = transact(transactCall { destination, call, weight, feeAsset: fee_asset });
+			// No xcm router currently configured in mock runtime
+			assert_revert!(
+				call_precompile::<()>(&origin, &input),
+				module_error(pallet_xcm::Error::<Test>::Unreachable)
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_assets_reverts_when_invalid_asset() {
+		let origin = ALICE;
+		let destination = location(1, vec![junction(JunctionType::Parachain, 1_000, [0; 32])]);
+		let beneficiary = location(0, vec![junction(JunctionType::AccountId32, 0, [255; 32])]);
+		ExtBuilder::new().build().execute_with(|| {
+			let input = transferAssets(transferAssetsCall {
+				destination,
+				beneficiary,
+				assets: vec![],
+				feeAssetIndex: 0,
+			});
+			assert_revert!(call_precompile::<()>(&origin, &input), InvalidAsset);
+		});
+	}
+
+	fn asset(id: IXCM::Location, amount: u128) -> IXCM::Asset {
+		IXCM::Asset { id, amount }
+	}
+
 	fn call_precompile<Output: SolValue + From<<Output::SolType as SolType>::RustType>>(
 		origin: &AccountId,
 		input: &IXCMCalls,
@@ -977,4 +2216,19 @@ mod tests {
 			input.abi_encode(),
 		)
 	}
+
+	fn junction(junction_type: JunctionType, index: u128, key: [u8; 32]) -> IXCM::Junction {
+		IXCM::Junction { junctionType: junction_type, index, key: key.into() }
+	}
+
+	fn location(parents: u8, interior: Vec<IXCM::Junction>) -> IXCM::Location {
+		IXCM::Location { parents, interior }
+	}
+
+	fn module_error(error: pallet_xcm::Error<Test>) -> Module {
+		let frame::DispatchError::Module(error) = error.into() else {
+			unreachable!("pallet errors are module errors")
+		};
+		error.into()
+	}
 }
@@ -0,0 +1,88 @@
+use codec::Codec;
+
+use super::*;
+
+sp_api::decl_runtime_apis! {
+	/// The API for enumerating and inspecting the messages of the messaging pallet, and quoting the
+	/// amounts taken for new messages.
+	///
+	/// Only implemented by runtimes which include the pallet, with the node serving the messaging
+	/// RPC for those runtimes alone. Runtimes without the pallet are not required to implement it.
+	pub trait MessagingApi<Balance>
+	where
+		Balance: Codec,
+	{
+		/// Get the messages of an origin.
+		///
+		/// Messages are returned in storage order, with `start_after` used as a cursor to
+		/// continue from the last message of a previous page.
+		///
+		/// # Parameters
+		/// - `origin`: The address of the origin of the messages.
+		/// - `start_after`: The identifier of the message after which to start, if any.
+		/// - `limit`: The maximum number of messages to return.
+		fn messages(
+			origin: H160,
+			start_after: Option<MessageId>,
+			limit: u32,
+		) -> Vec<MessageDetails<Balance>>;
+
+		/// Get a message by its identifier.
+		///
+		/// # Parameters
+		/// - `id`: The message identifier.
+		fn message(id: MessageId) -> Option<MessageDetails<Balance>>;
+
+		/// Get a message by the commitment of its ISMP request.
+		///
+		/// # Parameters
+		/// - `commitment`: The commitment of the ISMP request.
+		fn ismp_message(commitment: H256) -> Option<MessageDetails<Balance>>;
+
+		/// Get a message by the identifier of its XCM query.
+		///
+		/// # Parameters
+		/// - `query_id`: The identifier of the XCM query.
+		fn xcm_message(query_id: QueryId) -> Option<MessageDetails<Balance>>;
+
+		/// The total deposit held for the messages of an origin, including any deposits held
+		/// for callback execution.
+		///
+		/// # Parameters
+		/// - `origin`: The address of the origin of the messages.
+		fn held_deposit(origin: H160) -> Balance;
+
+		/// Quote the amounts taken for a new ISMP `Get` request, excluding the fee paid to
+		/// relayers.
+		///
+		/// # Parameters
+		/// - `callback_gas_limit`: The gas limit of the callback, if any.
+		fn quote_get(callback_gas_limit: Option<Weight>) -> MessageQuote<Balance>;
+
+		/// Quote the amounts taken for a new ISMP `Post` request, excluding the fee paid to
+		/// relayers.
+		///
+		/// # Parameters
+		/// - `callback_gas_limit`: The gas limit of the callback, if any.
+		fn quote_post(callback_gas_limit: Option<Weight>) -> MessageQuote<Balance>;
+
+		/// Quote the amounts taken for a new subscription to recurring ISMP `Get` requests,
+		/// including the fees paid to relayers for all executions.
+		///
+		/// # Parameters
+		/// - `executions`: The number of executions of the subscription.
+		/// - `fee`: The fee to be paid to relayers per execution.
+		/// - `callback_gas_limit`: The gas limit of the callback.
+		fn quote_subscribe(
+			executions: u32,
+			fee: Balance,
+			callback_gas_limit: Weight,
+		) -> MessageQuote<Balance>;
+
+		/// Quote the amounts taken for a new XCM query.
+		///
+		/// # Parameters
+		/// - `callback_gas_limit`: The gas limit of the callback, if any.
+		fn quote_new_query(callback_gas_limit: Option<Weight>) -> MessageQuote<Balance>;
+	}
+}
@@ -5,6 +5,7 @@ use frame_support::{
 	traits::fungible::InspectHold,
 	weights::WeightToFee as _,
 };
+use pallet_revive::test_utils::CHARLIE_ADDR;
 use sp_runtime::TokenError::FundsUnavailable;
 use HoldReason::*;
 
@@ -14,13 +15,13 @@ use crate::mock::*;
 type CallbackExecutor = <Test as Config>::CallbackExecutor;
 type Error = super::Error<Test>;
 type Fungibles = <Test as Config>::Fungibles;
-type IsmpRequests = super::IsmpRequests<Test>;
 type Message = super::Message<Test>;
 type Messages = super::Messages<Test>;
 type Origin = super::Origin<Test>;
+type OriginMessages = super::OriginMessages<Test>;
+type TransportMessages = super::TransportMessages<Test>;
 type WeightInfo = <Test as Config>::WeightInfo;
 type WeightToFee = <Test as Config>::WeightToFee;
-type XcmQueries = super::XcmQueries<Test>;
 
 mod remove {
 	use super::*;
@@ -175,7 +176,7 @@ mod remove {
 			.build()
 			.execute_with(|| {
 				Messages::insert(id, &message);
-				IsmpRequests::insert(commitment, &id);
+				TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), &id);
 				assert_ok!(Fungibles::hold(&Messaging.into(), &origin.account, deposit));
 
 				assert_noop!(remove(origin.clone(), &[id]), Error::RequestPending);
@@ -185,7 +186,7 @@ mod remove {
 					"Message should not have been removed but has."
 				);
 				assert!(
-					IsmpRequests::get(commitment).is_some(),
+					TransportMessages::get(MessageTransport::ismp::<Test>(commitment)).is_some(),
 					"Message should not have been removed but has."
 				);
 			})
@@ -204,14 +205,16 @@ mod remove {
 			.build()
 			.execute_with(|| {
 				Messages::insert(id, &message);
-				IsmpRequests::insert(commitment, &id);
+				OriginMessages::insert(origin.address, id, ());
+				TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), &id);
 				assert_ok!(Fungibles::hold(&Messaging.into(), &origin.account, deposit));
 
-				assert_ok!(remove(origin, &[id]));
+				assert_ok!(remove(origin.clone(), &[id]));
 
 				assert!(Messages::get(id).is_none(), "Message should have been removed but hasnt.");
+				assert!(!OriginMessages::contains_key(origin.address, id));
 				assert!(
-					IsmpRequests::get(commitment).is_none(),
+					TransportMessages::get(MessageTransport::ismp::<Test>(commitment)).is_none(),
 					"Request should have been removed but hasnt."
 				);
 			})
@@ -235,13 +238,13 @@ mod remove {
 				assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin.account, callback_deposit));
 
 				Messages::insert(id, &message);
-				IsmpRequests::insert(commitment, id);
+				TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), id);
 
 				assert_ok!(remove(origin.clone(), &[id]));
 
 				assert!(Messages::get(id).is_none(), "Message should have been removed but hasnt.");
 				assert!(
-					IsmpRequests::get(commitment).is_none(),
+					TransportMessages::get(MessageTransport::ismp::<Test>(commitment)).is_none(),
 					"Request should have been removed but hasnt."
 				);
 				assert_eq!(Balances::total_balance_on_hold(&origin.account), 0);
@@ -260,7 +263,7 @@ mod remove {
 			.build()
 			.execute_with(|| {
 				Messages::insert(id, &message);
-				XcmQueries::insert(query_id, &id);
+				TransportMessages::insert(MessageTransport::xcm::<Test>(query_id), &id);
 				assert_ok!(Fungibles::hold(&Messaging.into(), &origin.account, deposit));
 
 				assert_noop!(remove(origin, &[id]), Error::RequestPending);
@@ -269,7 +272,7 @@ mod remove {
 					"Message should not have been removed but has"
 				);
 				assert!(
-					XcmQueries::get(query_id).is_some(),
+					TransportMessages::get(MessageTransport::xcm::<Test>(query_id)).is_some(),
 					"Message should not have been removed but has."
 				);
 			})
@@ -288,14 +291,14 @@ mod remove {
 			.build()
 			.execute_with(|| {
 				Messages::insert(id, &message);
-				XcmQueries::insert(query_id, &id);
+				TransportMessages::insert(MessageTransport::xcm::<Test>(query_id), &id);
 				assert_ok!(Fungibles::hold(&Messaging.into(), &origin.account, message_deposit));
 
 				assert_ok!(remove(origin, &[id]));
 
 				assert!(Messages::get(id).is_none(), "Message should have been removed but hasnt");
 				assert!(
-					XcmQueries::get(query_id).is_none(),
+					TransportMessages::get(MessageTransport::xcm::<Test>(query_id)).is_none(),
 					"Message should have been removed but hasnt."
 				);
 			})
@@ -319,13 +322,13 @@ mod remove {
 				assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin.account, callback_deposit));
 
 				Messages::insert(id, &message);
-				XcmQueries::insert(query_id, id);
+				TransportMessages::insert(MessageTransport::xcm::<Test>(query_id), id);
 
 				assert_ok!(remove(origin.clone(), &[id]));
 
 				assert!(Messages::get(id).is_none(), "Message should have been removed but hasnt");
 				assert!(
-					XcmQueries::get(query_id).is_none(),
+					TransportMessages::get(MessageTransport::xcm::<Test>(query_id)).is_none(),
 					"Message should have been removed but hasnt."
 				);
 
@@ -387,18 +390,18 @@ mod xcm_response {
 
 				// Update the message to XcmTimedOut
 				Messages::mutate(message_id, |message| {
-					let Some(Message::XcmQuery { origin, query_id, message_deposit, .. }): &mut Option<
+					let Some(Message::Pending { origin, transport, message_deposit, .. }): &mut Option<
 						Message,
 					> = message
 					else {
 						panic!("No message!");
 					};
-					*message = Some(Message::xcm_timeout(
-						origin.address,
-						*query_id,
-						*message_deposit,
-						None,
-					));
+					*message = Some(Message::Timeout {
+						origin: origin.address,
+						transport: transport.clone(),
+						message_deposit: *message_deposit,
+						callback_deposit: None,
+					});
 				});
 
 				assert_noop!(
@@ -453,14 +456,14 @@ mod xcm_response {
 
 				assert_ok!(Pallet::xcm_response(root(), query_id, response.clone()));
 
-				let Some(Message::XcmResponse { query_id: q, response: r, .. }): Option<Message> =
+				let Some(Message::Complete { transport, response: r, .. }): Option<Message> =
 					Messages::get(id)
 				else {
 					panic!("wrong message type");
 				};
 
-				assert_eq!(q, query_id);
-				assert_eq!(r, response);
+				assert_eq!(transport, MessageTransport::xcm::<Test>(query_id));
+				assert_eq!(r.into_inner(), response.encode());
 			})
 	}
 
@@ -487,7 +490,7 @@ mod xcm_response {
 				assert_ok!(Pallet::xcm_response(root(), query_id, response.clone()));
 
 				assert!(Messages::get(id).is_none());
-				assert!(XcmQueries::get(query_id).is_none());
+				assert!(TransportMessages::get(MessageTransport::xcm::<Test>(query_id)).is_none());
 			})
 	}
 
@@ -544,7 +547,7 @@ mod xcm_response {
 					"Please set a callback executor execution_weight to run this test."
 				);
 				assert_ne!(
-					WeightInfo::xcm_response(),
+					WeightInfo::xcm_response(0),
 					Zero::zero(),
 					"Please set an T::WeightInfo::xcm_response() to run this test."
 				);
@@ -557,7 +560,8 @@ mod xcm_response {
 
 				assert_eq!(
 					block_weight_post_call - block_weight_pre_call,
-					WeightInfo::xcm_response() + CallbackExecutor::execution_weight()
+					WeightInfo::xcm_response(xcm_response.encoded_size() as u32) +
+						CallbackExecutor::execution_weight()
 				)
 			})
 	}
@@ -618,132 +622,1425 @@ mod call {
 	}
 }
 
-mod process_callback_weight {
+mod deferred_callbacks {
+	use mock::messaging::{DeferredCallbackWeightLimit, MaxCallbackRetries, MaxDeferredCallbacks};
+
 	use super::*;
 
+	type DeferredCallbackQueue = super::DeferredCallbackQueue<Test>;
+	type DeferredCallbacks = super::DeferredCallbacks<Test>;
+	type Pallet = super::Pallet<Test>;
+
+	const DEPOSIT: Balance = 100;
+
 	#[test]
-	fn ok_with_weight_returns_weight() {
-		let weight = Weight::from_parts(100_000, 100_000);
-		let result = DispatchResultWithPostInfo::Ok(PostDispatchInfo {
-			actual_weight: Some(weight),
-			pays_fee: Pays::Yes,
-		});
-		let max_weight = Weight::zero();
-		assert_eq!(process_callback_weight(&result, max_weight), weight);
+	fn executes_callback_when_blockspace_available() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(1))])
+			.build()
+			.execute_with(|| {
+				let id = response(&origin, 0);
+
+				assert!(call_or_defer::<Test>(&origin.account, callback(), &id, &data()));
+
+				assert!(DeferredCallbacks::get(id).is_none());
+				assert!(DeferredCallbackQueue::get().is_empty());
+			})
 	}
 
 	#[test]
-	fn ok_without_weight_returns_max_weight() {
-		let result = DispatchResultWithPostInfo::Ok(PostDispatchInfo {
-			actual_weight: None,
-			pays_fee: Pays::Yes,
-		});
-		let max_weight = Weight::from_parts(200_000, 200_000);
-		assert_eq!(process_callback_weight(&result, max_weight), max_weight);
+	fn defers_callback_when_blockspace_exhausted() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(1))])
+			.build()
+			.execute_with(|| {
+				let id = response(&origin, 0);
+				let callback = callback();
+
+				defer(&origin, id);
+
+				assert_eq!(
+					DeferredCallbacks::get(id),
+					Some(DeferredCallback {
+						origin: origin.account.clone(),
+						callback,
+						input: EncodeCallback::encode(
+							&data(),
+							callback.encoding,
+							callback.selector,
+							id
+						)
+						.try_into()
+						.unwrap(),
+						retries: 0,
+					})
+				);
+				assert_eq!(DeferredCallbackQueue::get().into_inner(), vec![id]);
+				// The callback deposit remains held until the callback is resolved.
+				assert_eq!(
+					Fungibles::balance_on_hold(&CallbackGas.into(), &origin.account),
+					callback_deposit()
+				);
+				System::assert_last_event(
+					Event::CallbackDeferred { origin: origin.account, id, callback }.into(),
+				);
+			})
 	}
 
 	#[test]
-	fn err_returns_max_weight() {
-		let result = DispatchResultWithPostInfo::Err(DispatchErrorWithPostInfo {
-			post_info: Default::default(),
-			error: Error::InvalidMessage.into(),
-		});
-		let max_weight = Weight::from_parts(200_000, 200_000);
-		assert_eq!(process_callback_weight(&result, max_weight), max_weight);
+	fn abandons_callback_when_queue_full() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let max = MaxDeferredCallbacks::get() as MessageId;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(max + 1))])
+			.build()
+			.execute_with(|| {
+				for id in 0..max {
+					defer(&origin, response(&origin, id));
+				}
+				let id = response(&origin, max);
+				let callback = callback();
+
+				System::set_block_consumed_resources(Weight::MAX, 0);
+				assert!(!call_or_defer::<Test>(&origin.account, callback, &id, &data()));
+
+				assert!(DeferredCallbacks::get(id).is_none());
+				assert_eq!(DeferredCallbackQueue::get().len() as MessageId, max);
+				assert_eq!(
+					Fungibles::balance_on_hold(&CallbackGas.into(), &origin.account),
+					callback_deposit() * max as Balance
+				);
+				System::assert_last_event(
+					Event::CallbackAbandoned { origin: origin.account, id, callback }.into(),
+				);
+			})
 	}
-}
 
-mod deposit_callback_event {
-	use super::*;
+	#[test]
+	fn retries_in_fifo_order() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let messages = 3;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(messages))])
+			.build()
+			.execute_with(|| {
+				for id in 0..messages {
+					defer(&origin, response(&origin, id));
+				}
+				System::reset_events();
+
+				retry_deferred_callbacks::<Test>(Weight::MAX);
+
+				let executed: Vec<_> = events()
+					.into_iter()
+					.filter_map(|e| match e {
+						Event::CallbackExecuted { id, .. } => Some(id),
+						_ => None,
+					})
+					.collect();
+				assert_eq!(executed, (0..messages).collect::<Vec<_>>());
+				assert!(DeferredCallbackQueue::get().is_empty());
+				for id in 0..messages {
+					assert!(DeferredCallbacks::get(id).is_none());
+					assert!(Messages::get(id).is_none(), "message should have been completed");
+				}
+				assert_eq!(Fungibles::balance_on_hold(&Messaging.into(), &origin.account), 0);
+			})
+	}
 
 	#[test]
-	fn emits_callback_executed_event_on_success() {
-		let origin = ALICE;
-		let message_id = 1;
-		let weight = Weight::from_parts(100_000, 100_000);
-		let callback = Callback::new(H160::zero(), Encoding::Scale, [0; 4], weight, 100_000);
-		let result: DispatchResultWithPostInfo = Ok(PostDispatchInfo {
-			actual_weight: Some(Weight::from_parts(1_000, 0)),
-			pays_fee: Default::default(),
-		});
-		ExtBuilder::new().build().execute_with(|| {
-			deposit_callback_event::<Test>(origin.clone(), message_id, &callback, &result);
-			System::assert_last_event(
-				Event::<Test>::CallbackExecuted { origin, id: message_id, callback }.into(),
-			);
-		});
+	fn retries_within_weight_limit() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let messages = 3;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(messages))])
+			.build()
+			.execute_with(|| {
+				for id in 0..messages {
+					defer(&origin, response(&origin, id));
+				}
+				let retry_weight = WeightInfo::retry_deferred_callback() +
+					CallbackExecutor::execution_weight() +
+					callback().gas_limit;
+				// Sufficient weight to retry a single callback.
+				let limit = <Test as frame_system::Config>::DbWeight::get().reads(1) + retry_weight;
+
+				for retried in 1..=messages {
+					let weight = retry_deferred_callbacks::<Test>(limit);
+
+					assert!(weight.all_lte(limit));
+					assert_eq!(
+						DeferredCallbackQueue::get().into_inner(),
+						(retried..messages).collect::<Vec<_>>()
+					);
+				}
+			})
 	}
 
 	#[test]
-	fn emits_callback_failed_event_on_error() {
-		let origin = BOB;
-		let message_id = 2;
-		let weight = Weight::from_parts(100_000, 100_000);
-		let callback = Callback::new(H160::zero(), Encoding::Scale, [0; 4], weight, 100_000);
-		let result = DispatchResultWithPostInfo::Err(DispatchErrorWithPostInfo {
-			post_info: Default::default(),
-			error: Error::InvalidMessage.into(),
-		});
-		ExtBuilder::new().build().execute_with(|| {
-			deposit_callback_event::<Test>(origin.clone(), message_id, &callback, &result);
+	fn retry_weight_includes_callback_execution() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(1))])
+			.build()
+			.execute_with(|| {
+				defer(&origin, response(&origin, 0));
+				let block_weight_pre_retry =
+					frame_system::BlockWeight::<Test>::get().get(DispatchClass::Normal).to_owned();
 
-			System::assert_last_event(
-				Event::CallbackFailed {
-					origin,
-					id: message_id,
-					callback,
-					error: result.unwrap_err(),
+				let weight = retry_deferred_callbacks::<Test>(Weight::MAX);
+
+				// callback weight used in tests is total / 2.
+				let db_weight = <Test as frame_system::Config>::DbWeight::get();
+				assert_eq!(
+					weight,
+					db_weight.reads(1) +
+						WeightInfo::retry_deferred_callback() +
+						CallbackExecutor::execution_weight() +
+						callback().gas_limit / 2 +
+						db_weight.writes(1)
+				);
+				// The callback weight is accounted for by the caller, rather than registered.
+				assert_eq!(
+					frame_system::BlockWeight::<Test>::get().get(DispatchClass::Normal).to_owned(),
+					block_weight_pre_retry
+				);
+			})
+	}
+
+	#[test]
+	fn on_idle_respects_weight_limit() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(1))])
+			.build()
+			.execute_with(|| {
+				let id = response(&origin, 0);
+				defer(&origin, id);
+
+				assert_eq!(Pallet::on_idle(1, Weight::zero()), Weight::zero());
+				assert_eq!(DeferredCallbackQueue::get().into_inner(), vec![id]);
+
+				// Any remaining weight is used to check for expired messages.
+				let expiry_weight = <Test as frame_system::Config>::DbWeight::get().reads(1);
+				assert!(Pallet::on_idle(1, Weight::MAX)
+					.all_lte(DeferredCallbackWeightLimit::get() + expiry_weight));
+				assert!(DeferredCallbackQueue::get().is_empty());
+			})
+	}
+
+	#[test]
+	fn retains_position_when_blockspace_exhausted() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let messages = 2;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(messages))])
+			.build()
+			.execute_with(|| {
+				for id in 0..messages {
+					defer(&origin, response(&origin, id));
 				}
-				.into(),
-			);
-		});
+
+				System::set_block_consumed_resources(Weight::MAX, 0);
+				retry_deferred_callbacks::<Test>(Weight::MAX);
+
+				assert_eq!(DeferredCallbackQueue::get().into_inner(), vec![0, 1]);
+				assert_eq!(DeferredCallbacks::get(0).unwrap().retries, 1);
+				assert_eq!(DeferredCallbacks::get(1).unwrap().retries, 0);
+				System::assert_last_event(
+					Event::CallbackRetried { origin: origin.account, id: 0, retries: 1 }.into(),
+				);
+			})
+	}
+
+	#[test]
+	fn abandons_callback_after_max_retries() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(1))])
+			.build()
+			.execute_with(|| {
+				let id = response(&origin, 0);
+				defer(&origin, id);
+
+				System::set_block_consumed_resources(Weight::MAX, 0);
+				for _ in 0..MaxCallbackRetries::get() {
+					retry_deferred_callbacks::<Test>(Weight::MAX);
+				}
+
+				assert!(DeferredCallbacks::get(id).is_none());
+				assert!(DeferredCallbackQueue::get().is_empty());
+				assert_eq!(Fungibles::balance_on_hold(&CallbackGas.into(), &origin.account), 0);
+				// The response remains available for polling.
+				assert!(matches!(Messages::get(id), Some(Message::Complete { .. })));
+				System::assert_last_event(
+					Event::CallbackAbandoned { origin: origin.account, id, callback: callback() }
+						.into(),
+				);
+			})
+	}
+
+	#[test]
+	fn remove_cancels_deferred_callback() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(1))])
+			.build()
+			.execute_with(|| {
+				let id = response(&origin, 0);
+				defer(&origin, id);
+
+				assert_ok!(remove::<Test>(origin.clone(), &[id]));
+
+				assert!(DeferredCallbacks::get(id).is_none());
+				assert!(DeferredCallbackQueue::get().is_empty());
+				assert_eq!(Fungibles::balance_on_hold(&CallbackGas.into(), &origin.account), 0);
+				assert_eq!(Fungibles::balance_on_hold(&Messaging.into(), &origin.account), 0);
+			})
+	}
+
+	fn callback() -> Callback<Balance> {
+		Callback::new(
+			H160::zero(),
+			Encoding::Scale,
+			[1; 4],
+			Weight::from_parts(10_000_000, 10_000),
+			0,
+		)
+	}
+
+	fn callback_deposit() -> Balance {
+		WeightToFee::weight_to_fee(&callback().gas_limit)
+	}
+
+	fn data() -> Vec<u8> {
+		vec![255; 10]
+	}
+
+	// Defers the callback of a message by exhausting the blockspace.
+	fn defer(origin: &Origin, id: MessageId) {
+		System::set_block_consumed_resources(Weight::MAX, 0);
+		assert!(!call_or_defer::<Test>(&origin.account, callback(), &id, &data()));
+		System::set_block_consumed_resources(Weight::zero(), 0);
+	}
+
+	fn endowment(messages: MessageId) -> Balance {
+		existential_deposit() + (DEPOSIT + callback_deposit()) * messages as Balance
+	}
+
+	// Stores a response for a message with a callback, taking the required deposits.
+	fn response(origin: &Origin, id: MessageId) -> MessageId {
+		let commitment = H256::from_low_u64_be(id);
+		Messages::insert(
+			id,
+			Message::ismp_response(origin.address, commitment, DEPOSIT, BoundedVec::default()),
+		);
+		TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), id);
+		assert_ok!(Fungibles::hold(&Messaging.into(), &origin.account, DEPOSIT));
+		assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin.account, callback_deposit()));
+		id
 	}
 }
 
-mod manage_fees {
-	use mock::messaging::Treasury;
+mod expiry {
+	use mock::messaging::{MessageRetentionPeriod, RESPONSE_LOCATION};
+	use transports::{
+		ismp::timeout_commitment,
+		xcm::tests::{deposit, new_query, xcm_response_fee},
+	};
 
 	use super::*;
 
+	type DbWeight = <Test as frame_system::Config>::DbWeight;
+	type DeferredCallbackQueue = super::DeferredCallbackQueue<Test>;
+	type DeferredCallbacks = super::DeferredCallbacks<Test>;
+	type MessageExpiries = super::MessageExpiries<Test>;
+	type MessageExpiryQueue = super::MessageExpiryQueue<Test>;
+	type Pallet = super::Pallet<Test>;
+
+	const DEPOSIT: Balance = 100;
+	const CALLBACK_DEPOSIT: Balance = 1_000;
+
 	#[test]
-	fn assert_payback_when_execution_weight_is_less_than_deposit_held() {
-		let origin = ALICE;
-		let actual_weight_executed = Weight::from_parts(50_000_000, 70_000_000);
-		let callback_weight_reserved = Weight::from_parts(100_000_000, 100_000_000);
-		let deposit = WeightToFee::weight_to_fee(&callback_weight_reserved);
-		assert!(deposit != 0, "Please set an appropriate weight to fee implementation.");
-		let endowment = existential_deposit() + deposit;
-		let fee_account = Treasury::get();
+	fn schedules_expiry_of_xcm_response() {
+		let origin = origin();
+		let id = 1;
+		let query_id = 42;
+		let endowment = existential_deposit() + deposit() + xcm_response_fee();
 		ExtBuilder::new()
-			.with_balances(vec![(origin.clone(), endowment)])
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.with_message_id(id)
+			.with_query_id(query_id)
 			.build()
 			.execute_with(|| {
-				// Artificially take the deposit
-				assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin, deposit));
-
-				let expected_refund = deposit - WeightToFee::weight_to_fee(&actual_weight_executed);
-				assert!(expected_refund != 0);
+				let timeout = System::block_number() + 1;
+				assert_ok!(new_query(origin, RESPONSE_LOCATION, timeout, None));
 
-				let fee_pot_payment = deposit - expected_refund;
+				assert_ok!(Pallet::xcm_response(root(), query_id, Response::Null));
 
-				let fee_account_pre_handle = Balances::free_balance(&fee_account);
-				let origin_balance_pre_handle = Balances::free_balance(&origin);
+				assert_eq!(
+					MessageExpiries::get(0),
+					Some((id, System::block_number() + MessageRetentionPeriod::get()))
+				);
+				assert_eq!(MessageExpiryQueue::get(), (0, 1));
+			})
+	}
 
-				assert_ok!(manage_fees::<Test>(
-					&origin,
-					actual_weight_executed,
-					callback_weight_reserved
-				));
+	#[test]
+	fn schedules_expiry_of_xcm_timeout() {
+		let origin = origin();
+		let id = 1;
+		let endowment = existential_deposit() + deposit() + xcm_response_fee();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.with_message_id(id)
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 1;
+				assert_ok!(new_query(origin, RESPONSE_LOCATION, timeout, None));
 
-				// origin should have been refunded by the tune of expected refund.
-				// the fee pot should have been increased by fee_pot_payment.
-				let fee_account_post_handle = Balances::free_balance(&fee_account);
-				let origin_balance_post_handle = Balances::free_balance(&origin);
+				let (timeout, _) = super::XcmQueryTimeouts::<Test>::iter().next().unwrap();
+				System::set_block_number(timeout);
+				Pallet::on_initialize(timeout);
 
-				assert_eq!(origin_balance_post_handle - origin_balance_pre_handle, expected_refund);
-				assert_eq!(fee_account_post_handle, fee_account_pre_handle + fee_pot_payment);
+				assert!(matches!(Messages::get(id), Some(Message::Timeout { .. })));
+				assert_eq!(
+					MessageExpiries::get(0),
+					Some((id, timeout + MessageRetentionPeriod::get()))
+				);
+				assert_eq!(MessageExpiryQueue::get(), (0, 1));
 			})
 	}
+
+	#[test]
+	fn schedules_expiry_of_ismp_timeout() {
+		let origin = origin();
+		let id = 1;
+		let commitment = H256::repeat_byte(1);
+		ExtBuilder::new().build().execute_with(|| {
+			Messages::insert(id, Message::ismp(origin, commitment, None, DEPOSIT));
+			TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), id);
+
+			assert_ok!(timeout_commitment::<Test>(&commitment));
+
+			assert_eq!(
+				MessageExpiries::get(0),
+				Some((id, System::block_number() + MessageRetentionPeriod::get()))
+			);
+			assert_eq!(MessageExpiryQueue::get(), (0, 1));
+		})
+	}
+
+	#[test]
+	fn retains_messages_until_retention_period_elapsed() {
+		let origin = origin();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(1))])
+			.build()
+			.execute_with(|| {
+				let id = ismp_response(&origin, 0);
+				let now = System::block_number() + MessageRetentionPeriod::get() - 1;
+
+				expire_messages::<Test>(now, Weight::MAX);
+
+				assert!(Messages::get(id).is_some());
+				assert_eq!(MessageExpiryQueue::get(), (0, 1));
+				assert_eq!(Fungibles::balance_on_hold(&Messaging.into(), &origin.account), DEPOSIT);
+			})
+	}
+
+	#[test]
+	fn expires_messages_after_retention_period() {
+		let origin = origin();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(4))])
+			.build()
+			.execute_with(|| {
+				let messages = vec![
+					ismp_response(&origin, 0),
+					ismp_timeout(&origin, 1),
+					xcm_response(&origin, 2),
+					xcm_timeout(&origin, 3),
+				];
+				let now = System::block_number() + MessageRetentionPeriod::get();
+
+				expire_messages::<Test>(now, Weight::MAX);
+
+				for (position, id) in messages.iter().enumerate() {
+					assert!(Messages::get(id).is_none(), "message should have expired");
+					assert!(MessageExpiries::get(position as u64).is_none());
+				}
+				assert!(TransportMessages::get(MessageTransport::ismp::<Test>(
+					H256::from_low_u64_be(0)
+				))
+				.is_none());
+				assert!(TransportMessages::get(MessageTransport::ismp::<Test>(
+					H256::from_low_u64_be(1)
+				))
+				.is_none());
+				assert!(TransportMessages::get(MessageTransport::xcm::<Test>(2)).is_none());
+				assert!(TransportMessages::get(MessageTransport::xcm::<Test>(3)).is_none());
+				assert_eq!(MessageExpiryQueue::get(), (4, 4));
+				assert_eq!(Balances::total_balance_on_hold(&origin.account), 0);
+				System::assert_last_event(Event::MessagesExpired { messages }.into());
+			})
+	}
+
+	#[test]
+	fn ignores_removed_messages() {
+		let origin = origin();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(1))])
+			.build()
+			.execute_with(|| {
+				let id = ismp_response(&origin, 0);
+				assert_ok!(remove::<Test>(origin.clone(), &[id]));
+				System::reset_events();
+				let now = System::block_number() + MessageRetentionPeriod::get();
+
+				expire_messages::<Test>(now, Weight::MAX);
+
+				assert_eq!(MessageExpiryQueue::get(), (1, 1));
+				assert!(events().is_empty());
+			})
+	}
+
+	#[test]
+	fn cancels_deferred_callback() {
+		let origin = origin();
+		let callback = Callback::new(
+			H160::zero(),
+			Encoding::Scale,
+			[1; 4],
+			Weight::from_parts(10_000_000, 10_000),
+			0,
+		);
+		let callback_deposit = WeightToFee::weight_to_fee(&callback.gas_limit);
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(1) + callback_deposit)])
+			.build()
+			.execute_with(|| {
+				let id = ismp_response(&origin, 0);
+				assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin.account, callback_deposit));
+				System::set_block_consumed_resources(Weight::MAX, 0);
+				assert!(!call_or_defer::<Test>(&origin.account, callback, &id, &vec![255u8; 10]));
+				let now = System::block_number() + MessageRetentionPeriod::get();
+
+				expire_messages::<Test>(now, Weight::MAX);
+
+				assert!(Messages::get(id).is_none());
+				assert!(DeferredCallbacks::get(id).is_none());
+				assert!(DeferredCallbackQueue::get().is_empty());
+				assert_eq!(Balances::total_balance_on_hold(&origin.account), 0);
+			})
+	}
+
+	#[test]
+	fn expires_within_weight_limit() {
+		let origin = origin();
+		let messages = 3;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(messages))])
+			.build()
+			.execute_with(|| {
+				for id in 0..messages {
+					ismp_response(&origin, id);
+				}
+				let now = System::block_number() + MessageRetentionPeriod::get();
+				// Sufficient weight to expire a single message.
+				let limit = DbWeight::get().reads_writes(1, 1) + WeightInfo::expire_message();
+
+				for expired in 1..=messages {
+					let weight = expire_messages::<Test>(now, limit);
+
+					assert!(weight.all_lte(limit));
+					assert_eq!(MessageExpiryQueue::get(), (expired, messages));
+					assert!(Messages::get(expired - 1).is_none());
+				}
+			})
+	}
+
+	#[test]
+	fn on_idle_expires_messages() {
+		let origin = origin();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment(1))])
+			.build()
+			.execute_with(|| {
+				let id = ismp_response(&origin, 0);
+				let now = System::block_number() + MessageRetentionPeriod::get();
+
+				assert_eq!(Pallet::on_idle(now, Weight::zero()), Weight::zero());
+				assert!(Messages::get(id).is_some());
+
+				assert!(Pallet::on_idle(now, Weight::MAX).any_gt(Weight::zero()));
+				assert!(Messages::get(id).is_none());
+				System::assert_last_event(Event::MessagesExpired { messages: vec![id] }.into());
+			})
+	}
+
+	fn endowment(messages: MessageId) -> Balance {
+		existential_deposit() + (DEPOSIT + CALLBACK_DEPOSIT) * messages as Balance
+	}
+
+	// Stores a completed ISMP message, taking the required deposit and scheduling its expiry.
+	fn ismp_response(origin: &Origin, id: MessageId) -> MessageId {
+		let commitment = H256::from_low_u64_be(id);
+		Messages::insert(
+			id,
+			Message::ismp_response(origin.address, commitment, DEPOSIT, BoundedVec::default()),
+		);
+		TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), id);
+		assert_ok!(Fungibles::hold(&Messaging.into(), &origin.account, DEPOSIT));
+		schedule_expiry::<Test>(id);
+		id
+	}
+
+	// Stores a timed out ISMP message, taking the required deposits and scheduling its expiry.
+	fn ismp_timeout(origin: &Origin, id: MessageId) -> MessageId {
+		let commitment = H256::from_low_u64_be(id);
+		Messages::insert(
+			id,
+			Message::ismp_timeout(origin.address, commitment, DEPOSIT, Some(CALLBACK_DEPOSIT)),
+		);
+		TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), id);
+		assert_ok!(Fungibles::hold(&Messaging.into(), &origin.account, DEPOSIT));
+		assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin.account, CALLBACK_DEPOSIT));
+		schedule_expiry::<Test>(id);
+		id
+	}
+
+	// The origin of messages, whose account is derived from its address.
+	fn origin() -> Origin {
+		Origin::from((ALICE_ADDR, AddressToAccountId::<Test>::convert(ALICE_ADDR)))
+	}
+
+	// Stores a completed XCM message, taking the required deposit and scheduling its expiry.
+	fn xcm_response(origin: &Origin, id: MessageId) -> MessageId {
+		Messages::insert(id, Message::xcm_response(origin.address, id, DEPOSIT, Response::Null));
+		TransportMessages::insert(MessageTransport::xcm::<Test>(id), id);
+		assert_ok!(Fungibles::hold(&Messaging.into(), &origin.account, DEPOSIT));
+		schedule_expiry::<Test>(id);
+		id
+	}
+
+	// Stores a timed out XCM message, taking the required deposits and scheduling its expiry.
+	fn xcm_timeout(origin: &Origin, id: MessageId) -> MessageId {
+		Messages::insert(
+			id,
+			Message::xcm_timeout(origin.address, id, DEPOSIT, Some(CALLBACK_DEPOSIT)),
+		);
+		TransportMessages::insert(MessageTransport::xcm::<Test>(id), id);
+		assert_ok!(Fungibles::hold(&Messaging.into(), &origin.account, DEPOSIT));
+		assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin.account, CALLBACK_DEPOSIT));
+		schedule_expiry::<Test>(id);
+		id
+	}
+}
+
+mod process_callback_weight {
+	use super::*;
+
+	#[test]
+	fn ok_with_weight_returns_weight() {
+		let weight = Weight::from_parts(100_000, 100_000);
+		let result = DispatchResultWithPostInfo::Ok(PostDispatchInfo {
+			actual_weight: Some(weight),
+			pays_fee: Pays::Yes,
+		});
+		let max_weight = Weight::zero();
+		assert_eq!(process_callback_weight(&result, max_weight), weight);
+	}
+
+	#[test]
+	fn ok_without_weight_returns_max_weight() {
+		let result = DispatchResultWithPostInfo::Ok(PostDispatchInfo {
+			actual_weight: None,
+			pays_fee: Pays::Yes,
+		});
+		let max_weight = Weight::from_parts(200_000, 200_000);
+		assert_eq!(process_callback_weight(&result, max_weight), max_weight);
+	}
+
+	#[test]
+	fn err_returns_max_weight() {
+		let result = DispatchResultWithPostInfo::Err(DispatchErrorWithPostInfo {
+			post_info: Default::default(),
+			error: Error::InvalidMessage.into(),
+		});
+		let max_weight = Weight::from_parts(200_000, 200_000);
+		assert_eq!(process_callback_weight(&result, max_weight), max_weight);
+	}
+}
+
+mod deposit_callback_event {
+	use super::*;
+
+	#[test]
+	fn emits_callback_executed_event_on_success() {
+		let origin = ALICE;
+		let message_id = 1;
+		let weight = Weight::from_parts(100_000, 100_000);
+		let callback = Callback::new(H160::zero(), Encoding::Scale, [0; 4], weight, 100_000);
+		let result: DispatchResultWithPostInfo = Ok(PostDispatchInfo {
+			actual_weight: Some(Weight::from_parts(1_000, 0)),
+			pays_fee: Default::default(),
+		});
+		ExtBuilder::new().build().execute_with(|| {
+			deposit_callback_event::<Test>(origin.clone(), message_id, &callback, &result);
+			System::assert_last_event(
+				Event::<Test>::CallbackExecuted { origin, id: message_id, callback }.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn emits_callback_failed_event_on_error() {
+		let origin = BOB;
+		let message_id = 2;
+		let weight = Weight::from_parts(100_000, 100_000);
+		let callback = Callback::new(H160::zero(), Encoding::Scale, [0; 4], weight, 100_000);
+		let result = DispatchResultWithPostInfo::Err(DispatchErrorWithPostInfo {
+			post_info: Default::default(),
+			error: Error::InvalidMessage.into(),
+		});
+		ExtBuilder::new().build().execute_with(|| {
+			deposit_callback_event::<Test>(origin.clone(), message_id, &callback, &result);
+
+			System::assert_last_event(
+				Event::CallbackFailed {
+					origin,
+					id: message_id,
+					callback,
+					error: result.unwrap_err(),
+				}
+				.into(),
+			);
+		});
+	}
+}
+
+mod manage_fees {
+	use mock::messaging::Treasury;
+
+	use super::*;
+
+	#[test]
+	fn assert_payback_when_execution_weight_is_less_than_deposit_held() {
+		let origin = ALICE;
+		let actual_weight_executed = Weight::from_parts(50_000_000, 70_000_000);
+		let callback_weight_reserved = Weight::from_parts(100_000_000, 100_000_000);
+		let deposit = WeightToFee::weight_to_fee(&callback_weight_reserved);
+		assert!(deposit != 0, "Please set an appropriate weight to fee implementation.");
+		let endowment = existential_deposit() + deposit;
+		let fee_account = Treasury::get();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				// Artificially take the deposit
+				assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin, deposit));
+
+				let expected_refund = deposit - WeightToFee::weight_to_fee(&actual_weight_executed);
+				assert!(expected_refund != 0);
+
+				let fee_pot_payment = deposit - expected_refund;
+
+				let fee_account_pre_handle = Balances::free_balance(&fee_account);
+				let origin_balance_pre_handle = Balances::free_balance(&origin);
+
+				assert_ok!(manage_fees::<Test>(
+					&origin,
+					actual_weight_executed,
+					callback_weight_reserved
+				));
+
+				// origin should have been refunded by the tune of expected refund.
+				// the fee pot should have been increased by fee_pot_payment.
+				let fee_account_post_handle = Balances::free_balance(&fee_account);
+				let origin_balance_post_handle = Balances::free_balance(&origin);
+
+				assert_eq!(origin_balance_post_handle - origin_balance_pre_handle, expected_refund);
+				assert_eq!(fee_account_post_handle, fee_account_pre_handle + fee_pot_payment);
+			})
+	}
+}
+
+mod pending_messages {
+	use mock::messaging::{MaxPendingMessages, RESPONSE_LOCATION};
+	use transports::xcm::{
+		cancel_query,
+		tests::{deposit, new_query, xcm_response_fee},
+	};
+
+	use super::*;
+
+	type Pallet = super::Pallet<Test>;
+	type PendingMessageQuotas = super::PendingMessageQuotas<Test>;
+	type PendingMessages = super::PendingMessages<Test>;
+
+	#[test]
+	fn set_pending_message_quota_requires_quota_origin() {
+		ExtBuilder::new().build().execute_with(|| {
+			assert_noop!(
+				Pallet::set_pending_message_quota(
+					RuntimeOrigin::signed(ALICE),
+					ALICE_ADDR,
+					Some(1)
+				),
+				DispatchError::BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn set_pending_message_quota_works() {
+		ExtBuilder::new().build().execute_with(|| {
+			assert_eq!(Pallet::pending_message_quota(ALICE_ADDR), MaxPendingMessages::get());
+
+			let quota = Some(MaxPendingMessages::get() * 2);
+			assert_ok!(Pallet::set_pending_message_quota(root(), ALICE_ADDR, quota));
+			assert_eq!(PendingMessageQuotas::get(ALICE_ADDR), quota);
+			assert_eq!(Pallet::pending_message_quota(ALICE_ADDR), quota.unwrap());
+			assert_eq!(Pallet::pending_message_quota(BOB_ADDR), MaxPendingMessages::get());
+			System::assert_last_event(
+				Event::PendingMessageQuotaSet { origin: ALICE_ADDR, quota }.into(),
+			);
+
+			assert_ok!(Pallet::set_pending_message_quota(root(), ALICE_ADDR, None));
+			assert!(!PendingMessageQuotas::contains_key(ALICE_ADDR));
+			assert_eq!(Pallet::pending_message_quota(ALICE_ADDR), MaxPendingMessages::get());
+			System::assert_last_event(
+				Event::PendingMessageQuotaSet { origin: ALICE_ADDR, quota: None }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn send_fails_when_too_many_pending_messages() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let quota = 2;
+		let endowment = existential_deposit() + deposit() * (quota as Balance + 1);
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment), (BOB, endowment)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(Pallet::set_pending_message_quota(root(), origin.address, Some(quota)));
+				let timeout = System::block_number() + 10;
+				for _ in 0..quota {
+					assert_ok!(new_query(origin.clone(), RESPONSE_LOCATION, timeout, None));
+				}
+				assert_eq!(PendingMessages::get(origin.address), quota);
+
+				assert_noop!(
+					new_query(origin.clone(), RESPONSE_LOCATION, timeout, None),
+					Error::TooManyPendingMessages
+				);
+
+				// Other origins are unaffected.
+				let other = Origin::from((BOB_ADDR, BOB));
+				assert_ok!(new_query(other.clone(), RESPONSE_LOCATION, timeout, None));
+				assert_eq!(PendingMessages::get(other.address), 1);
+			})
+	}
+
+	#[test]
+	fn concluded_messages_are_no_longer_pending() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let endowment = existential_deposit() + (deposit() + xcm_response_fee()) * 3;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(Pallet::set_pending_message_quota(root(), origin.address, Some(3)));
+				let timeout = System::block_number() + 1;
+				let (_, responded) =
+					new_query(origin.clone(), RESPONSE_LOCATION, timeout + 1, None).unwrap();
+				let (cancelled, _) =
+					new_query(origin.clone(), RESPONSE_LOCATION, timeout + 1, None).unwrap();
+				assert_ok!(new_query(origin.clone(), RESPONSE_LOCATION, timeout, None));
+				assert_eq!(PendingMessages::get(origin.address), 3);
+
+				assert_ok!(Pallet::xcm_response(root(), responded, Response::Null));
+				assert_eq!(PendingMessages::get(origin.address), 2);
+
+				assert_ok!(cancel_query::<Test>(origin.clone(), cancelled));
+				assert_eq!(PendingMessages::get(origin.address), 1);
+
+				System::set_block_number(timeout);
+				Pallet::on_initialize(timeout);
+				assert!(!PendingMessages::contains_key(origin.address));
+
+				// The quota is available once again.
+				assert_ok!(new_query(origin.clone(), RESPONSE_LOCATION, timeout + 1, None));
+			})
+	}
+}
+
+mod transfer_message {
+	use super::*;
+
+	type MessageTransfers = super::MessageTransfers<Test>;
+	type PendingMessageQuotas = super::PendingMessageQuotas<Test>;
+	type PendingMessages = super::PendingMessages<Test>;
+
+	#[test]
+	fn message_not_found() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		ExtBuilder::new().build().execute_with(|| {
+			assert_noop!(transfer_message(origin, 0, BOB_ADDR), Error::MessageNotFound);
+		})
+	}
+
+	#[test]
+	fn only_originator_can_transfer() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = Message::xcm_timeout(origin.address, 0, 0, None);
+		let caller = Origin::from((BOB_ADDR, BOB));
+		ExtBuilder::new()
+			.with_messages(vec![(origin.account.clone(), 0, message, 0)])
+			.build()
+			.execute_with(|| {
+				assert_noop!(transfer_message(caller, 0, BOB_ADDR), DispatchError::BadOrigin);
+			})
+	}
+
+	#[test]
+	fn offers_message() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = Message::xcm_query(origin.clone(), 0, None, 0);
+		ExtBuilder::new()
+			.with_messages(vec![(origin.account.clone(), 0, message.clone(), 0)])
+			.build()
+			.execute_with(|| {
+				// The new owner does not need to have capacity for the message until accepting it.
+				PendingMessageQuotas::insert(BOB_ADDR, 0);
+				PendingMessages::insert(origin.address, 1);
+
+				assert_ok!(transfer_message(origin.clone(), 0, BOB_ADDR));
+
+				assert_eq!(MessageTransfers::get(0), Some((origin.clone(), BOB_ADDR)));
+				assert_eq!(Messages::get(0), Some(message));
+				assert_eq!(PendingMessages::get(origin.address), 1);
+				assert!(!PendingMessages::contains_key(BOB_ADDR));
+				assert!(OriginMessages::contains_key(origin.address, 0));
+			})
+	}
+
+	#[test]
+	fn replaces_offer() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = Message::xcm_timeout(origin.address, 0, 0, None);
+		ExtBuilder::new()
+			.with_messages(vec![(origin.account.clone(), 0, message, 0)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(transfer_message(origin.clone(), 0, BOB_ADDR));
+				assert_ok!(transfer_message(origin.clone(), 0, CHARLIE_ADDR));
+
+				assert_eq!(MessageTransfers::get(0), Some((origin, CHARLIE_ADDR)));
+			})
+	}
+
+	#[test]
+	fn transferring_to_origin_withdraws_offer() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = Message::xcm_timeout(origin.address, 0, 0, None);
+		ExtBuilder::new()
+			.with_messages(vec![(origin.account.clone(), 0, message, 0)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(transfer_message(origin.clone(), 0, BOB_ADDR));
+				assert_ok!(transfer_message(origin.clone(), 0, origin.address));
+
+				assert!(!MessageTransfers::contains_key(0));
+			})
+	}
+
+	#[test]
+	fn removing_message_withdraws_offer() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = Message::xcm_timeout(origin.address, 0, 0, None);
+		ExtBuilder::new()
+			.with_messages(vec![(origin.account.clone(), 0, message, 0)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(transfer_message(origin.clone(), 0, BOB_ADDR));
+				assert_ok!(remove(origin, &[0]));
+
+				assert!(!MessageTransfers::contains_key(0));
+			})
+	}
+}
+
+mod accept_message {
+	use super::*;
+
+	type AddressConverter = <Test as Config>::AddressConverter;
+	type DeferredCallbacks = super::DeferredCallbacks<Test>;
+	type MessageTransfers = super::MessageTransfers<Test>;
+	type PendingMessageQuotas = super::PendingMessageQuotas<Test>;
+	type PendingMessages = super::PendingMessages<Test>;
+
+	#[test]
+	fn message_not_found() {
+		let origin = Origin::from((BOB_ADDR, BOB));
+		ExtBuilder::new().build().execute_with(|| {
+			assert_noop!(accept_message(origin, 0), Error::MessageNotFound);
+		})
+	}
+
+	#[test]
+	fn only_offered_account_can_accept() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = Message::xcm_timeout(origin.address, 0, 0, None);
+		let caller = Origin::from((CHARLIE_ADDR, CHARLIE));
+		ExtBuilder::new()
+			.with_messages(vec![(origin.account.clone(), 0, message, 0)])
+			.build()
+			.execute_with(|| {
+				// Not offered.
+				assert_noop!(accept_message(caller.clone(), 0), DispatchError::BadOrigin);
+
+				// Offered to another account.
+				assert_ok!(transfer_message(origin, 0, BOB_ADDR));
+				assert_noop!(accept_message(caller, 0), DispatchError::BadOrigin);
+			})
+	}
+
+	#[test]
+	fn fails_when_new_owner_has_too_many_pending_messages() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let new_owner = Origin::from((BOB_ADDR, BOB));
+		let message = Message::xcm_query(origin.clone(), 0, None, 0);
+		ExtBuilder::new()
+			.with_messages(vec![(origin.account.clone(), 0, message, 0)])
+			.build()
+			.execute_with(|| {
+				PendingMessageQuotas::insert(BOB_ADDR, 0);
+				assert_ok!(transfer_message(origin, 0, BOB_ADDR));
+
+				assert_noop!(accept_message(new_owner, 0), Error::TooManyPendingMessages);
+			})
+	}
+
+	#[test]
+	fn transfers_pending_message() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let new_owner = Origin::from((BOB_ADDR, AddressConverter::convert(BOB_ADDR)));
+		let id = 1;
+		let message_deposit = 100;
+		let callback = Callback::new(
+			H160::zero(),
+			Encoding::Scale,
+			[1; 4],
+			Weight::from_parts(10_000_000, 10_000),
+			0,
+		);
+		let callback_deposit = WeightToFee::weight_to_fee(&callback.gas_limit);
+		let message =
+			Message::xcm_query(origin.clone(), 0, Some(callback.clone()), message_deposit);
+		let endowment = existential_deposit() + message_deposit + callback_deposit;
+		ExtBuilder::new()
+			.with_balances(vec![
+				(origin.account.clone(), endowment),
+				(new_owner.account.clone(), existential_deposit()),
+			])
+			.with_messages(vec![(origin.account.clone(), id, message, message_deposit)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin.account, callback_deposit));
+				PendingMessages::insert(origin.address, 1);
+
+				assert_ok!(transfer_message(origin.clone(), id, new_owner.address));
+				assert_eq!(accept_message(new_owner.clone(), id), Ok(origin.address));
+
+				assert_eq!(
+					Messages::get(id),
+					Some(Message::xcm_query(new_owner.clone(), 0, Some(callback), message_deposit))
+				);
+				assert!(!MessageTransfers::contains_key(id));
+				assert!(!PendingMessages::contains_key(origin.address));
+				assert_eq!(PendingMessages::get(new_owner.address), 1);
+				assert!(!OriginMessages::contains_key(origin.address, id));
+				assert!(OriginMessages::contains_key(new_owner.address, id));
+				assert_eq!(Balances::total_balance_on_hold(&origin.account), 0);
+				assert_eq!(
+					Fungibles::balance_on_hold(&Messaging.into(), &new_owner.account),
+					message_deposit
+				);
+				assert_eq!(
+					Fungibles::balance_on_hold(&CallbackGas.into(), &new_owner.account),
+					callback_deposit
+				);
+			})
+	}
+
+	#[test]
+	fn transfers_timed_out_message() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let new_owner = Origin::from((BOB_ADDR, AddressConverter::convert(BOB_ADDR)));
+		let id = 1;
+		let message_deposit = 100;
+		let callback_deposit = 50;
+		let message =
+			Message::xcm_timeout(origin.address, 0, message_deposit, Some(callback_deposit));
+		let endowment = existential_deposit() + message_deposit + callback_deposit;
+		ExtBuilder::new()
+			.with_balances(vec![
+				(origin.account.clone(), endowment),
+				(new_owner.account.clone(), existential_deposit()),
+			])
+			.with_messages(vec![(origin.account.clone(), id, message, message_deposit)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin.account, callback_deposit));
+
+				assert_ok!(transfer_message(origin.clone(), id, new_owner.address));
+				assert_eq!(accept_message(new_owner.clone(), id), Ok(origin.address));
+
+				assert_eq!(
+					Messages::get(id),
+					Some(Message::xcm_timeout(
+						new_owner.address,
+						0,
+						message_deposit,
+						Some(callback_deposit)
+					))
+				);
+				assert_eq!(Balances::total_balance_on_hold(&origin.account), 0);
+				assert_eq!(
+					Balances::total_balance_on_hold(&new_owner.account),
+					message_deposit + callback_deposit
+				);
+
+				// The new owner can now reclaim the deposits.
+				assert_ok!(remove(new_owner.clone(), &[id]));
+				assert_eq!(Balances::total_balance_on_hold(&new_owner.account), 0);
+			})
+	}
+
+	#[test]
+	fn transfers_deferred_callback() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let new_owner = Origin::from((BOB_ADDR, AddressConverter::convert(BOB_ADDR)));
+		let id = 1;
+		let callback = Callback::new(
+			H160::zero(),
+			Encoding::Scale,
+			[1; 4],
+			Weight::from_parts(10_000_000, 10_000),
+			0,
+		);
+		let callback_deposit = WeightToFee::weight_to_fee(&callback.gas_limit);
+		let message = Message::xcm_response(origin.address, 0, 0, Response::Null);
+		ExtBuilder::new()
+			.with_balances(vec![
+				(origin.account.clone(), existential_deposit() + callback_deposit),
+				(new_owner.account.clone(), existential_deposit()),
+			])
+			.with_messages(vec![(origin.account.clone(), id, message, 0)])
+			.build()
+			.execute_with(|| {
+				assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin.account, callback_deposit));
+				DeferredCallbacks::insert(
+					id,
+					DeferredCallback {
+						origin: origin.account.clone(),
+						callback,
+						input: Default::default(),
+						retries: 0,
+					},
+				);
+
+				assert_ok!(transfer_message(origin.clone(), id, new_owner.address));
+				assert_eq!(accept_message(new_owner.clone(), id), Ok(origin.address));
+
+				assert_eq!(DeferredCallbacks::get(id).unwrap().origin, new_owner.account);
+				assert_eq!(
+					Fungibles::balance_on_hold(&CallbackGas.into(), &new_owner.account),
+					callback_deposit
+				);
+				assert_eq!(Balances::total_balance_on_hold(&origin.account), 0);
+			})
+	}
+}
+
+mod update_callback {
+	use super::*;
+
+	#[test]
+	fn message_not_found() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		ExtBuilder::new().build().execute_with(|| {
+			assert_noop!(update_callback(origin, 0, callback(100)), Error::MessageNotFound);
+		})
+	}
+
+	#[test]
+	fn only_originator_can_update() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = Message::xcm_query(origin.clone(), 0, None, 0);
+		let caller = Origin::from((BOB_ADDR, BOB));
+		ExtBuilder::new()
+			.with_messages(vec![(origin.account.clone(), 0, message, 0)])
+			.build()
+			.execute_with(|| {
+				assert_noop!(update_callback(caller, 0, callback(100)), DispatchError::BadOrigin);
+			})
+	}
+
+	#[test]
+	fn only_pending_messages_can_be_updated() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let messages = vec![
+			(
+				origin.account.clone(),
+				0,
+				Message::xcm_response(origin.address, 0, 0, Response::Null),
+				0,
+			),
+			(origin.account.clone(), 1, Message::xcm_timeout(origin.address, 1, 0, None), 0),
+		];
+		ExtBuilder::new().with_messages(messages).build().execute_with(|| {
+			assert_noop!(
+				update_callback(origin.clone(), 0, callback(100)),
+				Error::MessageCompleted
+			);
+			assert_noop!(update_callback(origin, 1, callback(100)), Error::RequestTimedOut);
+		})
+	}
+
+	#[test]
+	fn adjusts_callback_deposit() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let id = 1;
+		let message = Message::xcm_query(origin.clone(), 0, None, 0);
+		let deposit =
+			|callback: &Callback<Balance>| WeightToFee::weight_to_fee(&callback.gas_limit);
+		let (smaller, larger) = (callback(1_000_000), callback(10_000_000));
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), existential_deposit() + deposit(&larger))])
+			.with_messages(vec![(origin.account.clone(), id, message, 0)])
+			.build()
+			.execute_with(|| {
+				for callback in [smaller.clone(), larger, smaller] {
+					assert_ok!(update_callback(origin.clone(), id, callback.clone()));
+
+					assert_eq!(
+						Messages::get(id),
+						Some(Message::xcm_query(origin.clone(), 0, Some(callback.clone()), 0))
+					);
+					assert_eq!(
+						Fungibles::balance_on_hold(&CallbackGas.into(), &origin.account),
+						deposit(&callback)
+					);
+				}
+			})
+	}
+
+	#[test]
+	fn fails_when_funds_unavailable() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message = Message::xcm_query(origin.clone(), 0, None, 0);
+		ExtBuilder::new()
+			.with_messages(vec![(origin.account.clone(), 0, message, 0)])
+			.build()
+			.execute_with(|| {
+				assert_noop!(
+					update_callback(origin, 0, callback(10_000_000)),
+					DispatchError::Token(FundsUnavailable)
+				);
+			})
+	}
+
+	fn callback(ref_time: u64) -> Callback<Balance> {
+		Callback::new(
+			H160::zero(),
+			Encoding::Scale,
+			[1; 4],
+			Weight::from_parts(ref_time, 10_000),
+			0,
+		)
+	}
+}
+
+mod runtime_api {
+	use super::*;
+
+	type Pallet = super::Pallet<Test>;
+
+	#[test]
+	fn messages_filters_by_origin() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let other = Origin::from((BOB_ADDR, BOB));
+		ExtBuilder::new()
+			.with_balances(vec![
+				(origin.account.clone(), existential_deposit()),
+				(other.account.clone(), existential_deposit()),
+			])
+			.with_messages(vec![
+				(
+					origin.account.clone(),
+					0,
+					Message::xcm_response(origin.address, 0, 0, Response::Null),
+					0,
+				),
+				(
+					other.account.clone(),
+					1,
+					Message::xcm_response(other.address, 1, 0, Response::Null),
+					0,
+				),
+				(origin.account.clone(), 2, Message::xcm_timeout(origin.address, 2, 0, None), 0),
+			])
+			.build()
+			.execute_with(|| {
+				let mut ids: Vec<_> =
+					Pallet::messages(origin.address, None, u32::MAX).iter().map(|m| m.id).collect();
+				ids.sort();
+				assert_eq!(ids, vec![0, 2]);
+				assert!(Pallet::messages(origin.address, None, 0).is_empty());
+			})
+	}
+
+	#[test]
+	fn messages_paginates() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let messages = 5;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), existential_deposit())])
+			.with_messages(
+				(0..messages)
+					.map(|i| {
+						(
+							origin.account.clone(),
+							i,
+							Message::xcm_timeout(origin.address, i, 0, None),
+							0,
+						)
+					})
+					.collect(),
+			)
+			.build()
+			.execute_with(|| {
+				let mut ids = Vec::new();
+				let mut start_after = None;
+				loop {
+					let page = Pallet::messages(origin.address, start_after, 2);
+					assert!(page.len() <= 2);
+					let Some(last) = page.last() else { break };
+					start_after = Some(last.id);
+					ids.extend(page.iter().map(|m| m.id));
+				}
+				ids.sort();
+				assert_eq!(ids, (0..messages).collect::<Vec<_>>());
+			})
+	}
+
+	#[test]
+	fn message_details_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let commitment = H256::repeat_byte(1);
+		let query_id = 2;
+		let gas_limit = Weight::from_parts(100_000_000, 100_000_000);
+		let callback = Callback::new(H160::zero(), Encoding::Scale, [1; 4], gas_limit, 0);
+		let callback_deposit = WeightToFee::weight_to_fee(&gas_limit);
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), existential_deposit() + 200)])
+			.with_messages(vec![
+				(
+					origin.account.clone(),
+					0,
+					Message::ismp(origin.clone(), commitment, Some(callback), 100),
+					100,
+				),
+				(
+					origin.account.clone(),
+					1,
+					Message::xcm_timeout(origin.address, query_id, 100, Some(10)),
+					100,
+				),
+			])
+			.build()
+			.execute_with(|| {
+				TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), 0);
+				TransportMessages::insert(MessageTransport::xcm::<Test>(query_id), 1);
+
+				let expected = MessageDetails {
+					id: 0,
+					origin: origin.address,
+					transport: TransportId::Ismp(commitment),
+					status: MessageStatus::Pending,
+					message_deposit: 100,
+					callback_deposit: Some(callback_deposit),
+				};
+				assert_eq!(Pallet::message(0), Some(expected.clone()));
+				assert_eq!(Pallet::ismp_message(commitment), Some(expected));
+				assert_eq!(
+					Pallet::xcm_message(query_id),
+					Some(MessageDetails {
+						id: 1,
+						origin: origin.address,
+						transport: TransportId::Xcm(query_id),
+						status: MessageStatus::Timeout,
+						message_deposit: 100,
+						callback_deposit: Some(10),
+					})
+				);
+				assert_eq!(Pallet::message(2), None);
+				assert_eq!(Pallet::ismp_message(H256::zero()), None);
+				assert_eq!(Pallet::xcm_message(0), None);
+			})
+	}
+
+	#[test]
+	fn held_deposit_works() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let message_deposit = 100;
+		let callback_deposit = 50;
+		ExtBuilder::new()
+			.with_balances(vec![(
+				origin.account.clone(),
+				existential_deposit() + message_deposit + callback_deposit,
+			)])
+			.with_messages(vec![(
+				origin.account.clone(),
+				0,
+				Message::xcm_timeout(origin.address, 0, message_deposit, Some(callback_deposit)),
+				message_deposit,
+			)])
+			.build()
+			.execute_with(|| {
+				assert_eq!(Pallet::held_deposit(&origin.account), message_deposit);
+				assert_ok!(Fungibles::hold(&CallbackGas.into(), &origin.account, callback_deposit));
+				assert_eq!(
+					Pallet::held_deposit(&origin.account),
+					message_deposit + callback_deposit
+				);
+				assert_eq!(Pallet::held_deposit(&BOB), 0);
+			})
+	}
+
+	#[test]
+	fn quotes_include_callback_deposit() {
+		let gas_limit = Weight::from_parts(100_000_000, 100_000_000);
+		let callback_deposit = WeightToFee::weight_to_fee(&gas_limit);
+		ExtBuilder::new().build().execute_with(|| {
+			for quote in [Pallet::quote_get, Pallet::quote_post, Pallet::quote_new_query] {
+				let without_callback = quote(None);
+				let with_callback = quote(Some(gas_limit));
+
+				assert_ne!(without_callback.message_deposit, 0);
+				assert_eq!(without_callback.callback_deposit, 0);
+				assert_eq!(with_callback.message_deposit, without_callback.message_deposit);
+				assert_eq!(with_callback.callback_deposit, callback_deposit);
+				assert!(with_callback.total() > without_callback.total());
+			}
+		})
+	}
 }
 
 pub fn events() -> Vec<Event<Test>> {
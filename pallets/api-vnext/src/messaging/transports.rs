@@ -4,5 +4,339 @@ use super::*;
 
 /// Messaging using the Interoperable State Machine Protocol (ISMP).
 pub mod ismp;
+/// A development-only loopback, capturing ISMP requests and XCM queries for the injection of
+/// responses and timeouts.
+#[cfg(feature = "messaging-loopback")]
+pub mod loopback;
 /// Messaging using Polkadot's Cross-Consensus Messaging (XCM).
 pub mod xcm;
+
+/// The index of a transport, which must be unique amongst the transports of a runtime.
+pub type TransportIndex = u8;
+/// The maximum length of the encoded key of a message on its transport.
+pub type MaxTransportKeyLen = ConstU32<32>;
+
+/// A means of sending messages, whose responses and timeouts are correlated with the originating
+/// message by a key.
+///
+/// The pallet manages the lifecycle of messages independently of their transport: deposits, the
+/// execution of callbacks, polling, removal and expiry. A transport dispatches requests and reports
+/// the outcome of each via [`respond`] or [`time_out`], allowing runtimes to register transports
+/// beyond those provided by the pallet without modification of the pallet.
+pub trait Transport<T: Config> {
+	/// The index of the transport.
+	const INDEX: TransportIndex;
+
+	/// The key correlating responses and timeouts with messages sent via the transport, which
+	/// must not exceed [`MaxTransportKeyLen`] when encoded.
+	type Key: Clone + Decode + Encode + MaxEncodedLen;
+
+	/// A request which can be dispatched via the transport.
+	type Request;
+
+	/// Dispatch a request.
+	///
+	/// # Parameters
+	/// - `origin`: The origin of the request.
+	/// - `id`: The identifier of the message of the request.
+	/// - `request`: The request to be dispatched.
+	///
+	/// # Returns
+	/// The key with which any response or timeout is correlated with the message.
+	fn dispatch(
+		origin: &Origin<T>,
+		id: MessageId,
+		request: Self::Request,
+	) -> Result<Self::Key, DispatchError>;
+
+	/// The number of bytes stored on-chain by the transport for each pending message, which is
+	/// included in the deposit held for the message.
+	fn footprint() -> u32;
+
+	/// Called once a message has concluded, either by a response, a timeout or its
+	/// cancellation, allowing any state kept by the transport for the message to be removed.
+	///
+	/// # Parameters
+	/// - `key`: The key of the message.
+	fn on_conclude(_key: &Self::Key) {}
+}
+
+/// The transport of a message and the key by which it is correlated on that transport.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct MessageTransport {
+	/// The index of the transport.
+	pub index: TransportIndex,
+	/// The encoded key of the message on its transport.
+	pub key: BoundedVec<u8, MaxTransportKeyLen>,
+}
+
+impl MessageTransport {
+	/// The transport of a message sent via `Tr`.
+	///
+	/// # Parameters
+	/// - `key`: The key of the message on the transport.
+	pub fn of<T: Config, Tr: Transport<T>>(key: &Tr::Key) -> Result<Self, DispatchError> {
+		let key = BoundedVec::try_from(key.encode()).map_err(|_| Error::<T>::InvalidMessage)?;
+		Ok(Self { index: Tr::INDEX, key })
+	}
+
+	/// The key of the message, if sent via `Tr`.
+	pub fn key<T: Config, Tr: Transport<T>>(&self) -> Option<Tr::Key> {
+		if self.index != Tr::INDEX {
+			return None;
+		}
+		Tr::Key::decode(&mut self.key.as_slice()).ok()
+	}
+
+	#[cfg(any(test, feature = "runtime-benchmarks"))]
+	pub(crate) fn ismp<T: Config>(commitment: H256) -> Self {
+		Self::of::<T, ismp::IsmpTransport>(&commitment).expect("commitment within key length")
+	}
+
+	#[cfg(test)]
+	pub(crate) fn xcm<T: Config>(query_id: ::xcm::latest::QueryId) -> Self {
+		Self::of::<T, xcm::XcmTransport>(&query_id).expect("query identifier within key length")
+	}
+}
+
+/// The identifier of the message sent via a transport with the given key, if any.
+///
+/// # Parameters
+/// - `key`: The key of the message on the transport.
+pub fn message_id<T: Config, Tr: Transport<T>>(key: &Tr::Key) -> Option<MessageId> {
+	TransportMessages::<T>::get(MessageTransport::of::<T, Tr>(key).ok()?)
+}
+
+/// Send a message via a transport.
+///
+/// The deposits of the quote are held and its prepaid fee is taken, before the request is
+/// dispatched via the transport. The message is counted amongst the pending messages of its origin,
+/// which must not have reached its maximum.
+///
+/// # Parameters
+/// - `origin`: The origin of the message.
+/// - `request`: The request to be dispatched via the transport.
+/// - `callback`: An optional callback to execute upon receiving a response.
+/// - `quote`: The amounts taken for the message.
+///
+/// # Returns
+/// The identifier of the message and its key on the transport.
+pub fn send<T: Config, Tr: Transport<T>>(
+	origin: Origin<T>,
+	request: Tr::Request,
+	callback: Option<Callback<BalanceOf<T>>>,
+	quote: MessageQuote<BalanceOf<T>>,
+) -> Result<(MessageId, Tr::Key), DispatchError> {
+	let MessageQuote { message_deposit, prepaid_fee, callback_deposit } = quote;
+	add_pending::<T>(&origin.address)?;
+	T::Fungibles::hold(&HoldReason::Messaging.into(), &origin.account, message_deposit)?;
+	if callback.is_some() {
+		T::Fungibles::hold(&HoldReason::CallbackGas.into(), &origin.account, callback_deposit)?;
+	}
+	if !prepaid_fee.is_zero() {
+		let credit = T::Fungibles::withdraw(
+			&origin.account,
+			prepaid_fee,
+			Precision::Exact,
+			Preservation::Preserve,
+			Fortitude::Polite,
+		)?;
+		T::FeeHandler::on_unbalanced(credit);
+	}
+
+	let id = next_message_id::<T>()?;
+	let key = Tr::dispatch(&origin, id, request)?;
+	// Store the key for lookup on response or timeout, and the message for querying.
+	let transport = MessageTransport::of::<T, Tr>(&key)?;
+	ensure!(!TransportMessages::<T>::contains_key(&transport), Error::<T>::MessageExists);
+	TransportMessages::<T>::insert(&transport, id);
+	OriginMessages::<T>::insert(origin.address, id, ());
+	Messages::<T>::insert(id, Message::Pending { origin, transport, callback, message_deposit });
+	Ok((id, key))
+}
+
+/// Handle a response received via a transport.
+///
+/// Executes the callback of the message, if any, with the message being removed and its deposit
+/// released once executed. Otherwise the response is stored, to be polled by its origin.
+///
+/// # Parameters
+/// - `key`: The key of the message on the transport.
+/// - `response`: The response.
+/// - `event`: The event indicating the receipt of the response, deposited before any callback is
+///   executed, given the address of the origin and the identifier of the message.
+///
+/// # Returns
+/// The identifier of the message.
+pub fn respond<T: Config, Tr: Transport<T>>(
+	key: &Tr::Key,
+	response: &(impl Encode + EncodeCallback),
+	event: impl FnOnce(H160, MessageId) -> Event<T>,
+) -> Result<MessageId, DispatchError> {
+	let encoded: BoundedVec<u8, T::MaxResponseLen> =
+		response.encode().try_into().map_err(|_| Error::<T>::InvalidMessage)?;
+	let id = message_id::<T, Tr>(key).ok_or(Error::<T>::MessageNotFound)?;
+	let (origin, transport, callback, message_deposit) = pending::<T, Tr>(id)?;
+	ensure!(within_max_response_len::<T>(response, callback.as_ref()), Error::<T>::InvalidMessage);
+	Tr::on_conclude(key);
+	conclude_pending::<T>(&origin.address);
+
+	// Deposit that the response has been received before a potential callback execution.
+	Pallet::<T>::deposit_event(event(origin.address, id));
+
+	if let Some(callback) = callback {
+		if call_or_defer::<T>(&origin.account, callback, &id, response) {
+			// Clean storage, return deposit.
+			Messages::<T>::remove(id);
+			MessageTransfers::<T>::remove(id);
+			OriginMessages::<T>::remove(origin.address, id);
+			TransportMessages::<T>::remove(&transport);
+			T::Fungibles::release(
+				&HoldReason::Messaging.into(),
+				&origin.account,
+				message_deposit,
+				Precision::Exact,
+			)?;
+			return Ok(id);
+		}
+	}
+
+	// No callback or the callback was deferred: store the response for polling and removal.
+	Messages::<T>::insert(
+		id,
+		Message::Complete { origin: origin.address, transport, message_deposit, response: encoded },
+	);
+	schedule_expiry::<T>(id);
+	Ok(id)
+}
+
+/// Whether a response is within [`Config::MaxResponseLen`], both when encoded for storage and
+/// when encoded for the callback of the message, if any.
+///
+/// # Parameters
+/// - `response`: The response.
+/// - `callback`: The callback of the message, if any.
+pub(crate) fn within_max_response_len<T: Config>(
+	response: &(impl Encode + EncodeCallback),
+	callback: Option<&Callback<BalanceOf<T>>>,
+) -> bool {
+	let max = T::MaxResponseLen::get() as usize;
+	response.encoded_size() <= max &&
+		callback.is_none_or(|callback| response.encoded_len(callback.encoding) <= max)
+}
+
+/// Handle the timeout of a message sent via a transport.
+///
+/// The deposits of the message are retained until it is removed by its origin or expires.
+///
+/// # Parameters
+/// - `key`: The key of the message on the transport.
+///
+/// # Returns
+/// The identifier of the message.
+pub fn time_out<T: Config, Tr: Transport<T>>(key: &Tr::Key) -> Result<MessageId, DispatchError> {
+	let id = message_id::<T, Tr>(key).ok_or(Error::<T>::MessageNotFound)?;
+	let (origin, transport, callback, message_deposit) = pending::<T, Tr>(id)?;
+	Tr::on_conclude(key);
+	conclude_pending::<T>(&origin.address);
+
+	let callback_deposit = callback.map(|cb| T::WeightToFee::weight_to_fee(&cb.gas_limit));
+	Messages::<T>::insert(
+		id,
+		Message::Timeout { origin: origin.address, transport, message_deposit, callback_deposit },
+	);
+	schedule_expiry::<T>(id);
+	Ok(id)
+}
+
+/// Cancel a pending message sent via a transport, removing it and releasing its deposits.
+///
+/// The transport is responsible for ensuring that any subsequent response is ignored.
+///
+/// # Parameters
+/// - `origin`: The account cancelling the message, which must be the origin of the message.
+/// - `id`: The identifier of the message.
+///
+/// # Returns
+/// The key of the message on the transport.
+pub fn cancel<T: Config, Tr: Transport<T>>(
+	origin: Origin<T>,
+	id: MessageId,
+) -> Result<Tr::Key, DispatchError> {
+	let (initiator, transport, callback, message_deposit) = pending::<T, Tr>(id)?;
+	ensure!(origin.address == initiator.address, BadOrigin);
+	let key = transport.key::<T, Tr>().ok_or(Error::<T>::InvalidMessage)?;
+	Tr::on_conclude(&key);
+	conclude_pending::<T>(&origin.address);
+
+	Messages::<T>::remove(id);
+	MessageTransfers::<T>::remove(id);
+	OriginMessages::<T>::remove(origin.address, id);
+	TransportMessages::<T>::remove(&transport);
+	T::Fungibles::release(
+		&HoldReason::Messaging.into(),
+		&origin.account,
+		message_deposit,
+		Precision::Exact,
+	)?;
+	if let Some(callback) = callback {
+		T::Fungibles::release(
+			&HoldReason::CallbackGas.into(),
+			&origin.account,
+			T::WeightToFee::weight_to_fee(&callback.gas_limit),
+			Precision::Exact,
+		)?;
+	}
+	Ok(key)
+}
+
+/// The pending message sent via a transport with the given identifier.
+///
+/// # Parameters
+/// - `id`: The identifier of the message.
+///
+/// # Returns
+/// The origin, transport, callback and deposit of the message.
+pub fn pending<T: Config, Tr: Transport<T>>(
+	id: MessageId,
+) -> Result<
+	(Origin<T>, MessageTransport, Option<Callback<BalanceOf<T>>>, BalanceOf<T>),
+	DispatchError,
+> {
+	let message = Messages::<T>::get(id).ok_or(Error::<T>::MessageNotFound)?;
+	ensure!(message.transport().index == Tr::INDEX, Error::<T>::InvalidMessage);
+	match message {
+		Message::Pending { origin, transport, callback, message_deposit } =>
+			Ok((origin, transport, callback, message_deposit)),
+		Message::Complete { .. } => Err(Error::<T>::MessageCompleted.into()),
+		Message::Timeout { .. } => Err(Error::<T>::RequestTimedOut.into()),
+	}
+}
+
+/// Add a message to the pending messages of its origin, provided the origin has not reached its
+/// maximum.
+///
+/// # Parameters
+/// - `origin`: The address of the origin of the message.
+pub(crate) fn add_pending<T: Config>(origin: &H160) -> DispatchResult {
+	PendingMessages::<T>::try_mutate(origin, |pending| {
+		ensure!(
+			*pending < Pallet::<T>::pending_message_quota(*origin),
+			Error::<T>::TooManyPendingMessages
+		);
+		*pending = pending.saturating_add(1);
+		Ok(())
+	})
+}
+
+/// Remove a concluded message from the pending messages of its origin.
+///
+/// # Parameters
+/// - `origin`: The address of the origin of the message.
+pub(crate) fn conclude_pending<T: Config>(origin: &H160) {
+	PendingMessages::<T>::mutate_exists(origin, |pending| {
+		*pending = pending
+			.and_then(|pending| pending.checked_sub(1))
+			.filter(|pending| *pending > 0);
+	});
+}
@@ -1,3 +1,5 @@
+use alloc::vec;
+
 pub(crate) use ::ismp::dispatcher::{FeeMetadata, IsmpDispatcher};
 use ::ismp::{
 	dispatcher::{
@@ -6,17 +8,28 @@ use ::ismp::{
 	},
 	messaging::hash_request,
 	module::IsmpModule,
-	router::{GetResponse, PostRequest, PostResponse, Request, Response, Timeout},
+	router::{GetResponse, PostRequest, PostResponse, Request, Response, StorageValue, Timeout},
 };
 use frame_support::{
 	ensure,
 	pallet_prelude::Weight,
-	traits::{fungible::MutateHold, tokens::Precision::Exact, Get as _},
+	storage::with_storage_layer,
+	traits::{
+		fungible::MutateHold,
+		tokens::Precision::{BestEffort, Exact},
+		Get as _,
+	},
 };
-use pallet_ismp::weights::IsmpModuleWeight;
+use pallet_ismp::{weights::IsmpModuleWeight, FundMessageParams, MessageCommitment};
+use pallet_revive::AddressMapper as _;
 
 use super::{
-	super::{Message, Pallet},
+	super::{
+		precompiles::ismp::v0::{decode_post_request_output, encode_post_request},
+		BlockNumberOf, IsmpBatchRequests, IsmpBatches, IsmpFees, IsmpPostRequestSources,
+		MessageQuote, MessageStatus, NextSubscriptionId, Pallet, SubscriptionId,
+		SubscriptionSchedule, Subscriptions,
+	},
 	*,
 };
 
@@ -34,6 +47,166 @@ type StateMachine = (u8, u32);
 
 pub const ID: [u8; 3] = *b"pop";
 
+/// A means of adding to the fee paid to relayers for an outstanding ISMP request.
+pub trait FundRequest<AccountId, Balance> {
+	/// Add to the fee paid to relayers for an outstanding request.
+	///
+	/// # Parameters
+	/// - `payer`: The account paying the additional fee.
+	/// - `commitment`: The commitment of the request.
+	/// - `amount`: The amount to be added to the fee.
+	fn fund_request(payer: &AccountId, commitment: H256, amount: Balance) -> DispatchResult;
+
+	/// The fee paid to relayers for an outstanding request, if known.
+	///
+	/// # Parameters
+	/// - `commitment`: The commitment of the request.
+	fn request_fee(commitment: H256) -> Option<Balance>;
+}
+
+impl<T: pallet_ismp::Config> FundRequest<T::AccountId, T::Balance> for pallet_ismp::Pallet<T> {
+	fn fund_request(payer: &T::AccountId, commitment: H256, amount: T::Balance) -> DispatchResult {
+		Self::fund_message(
+			frame_system::RawOrigin::Signed(payer.clone()).into(),
+			FundMessageParams { commitment: MessageCommitment::Request(commitment), amount },
+		)
+	}
+
+	fn request_fee(commitment: H256) -> Option<T::Balance> {
+		pallet_ismp::child_trie::RequestCommitments::<T>::get(commitment).map(|leaf| leaf.meta.fee)
+	}
+}
+
+/// A means of determining the latest height of a state machine known to the ISMP host.
+pub trait StateMachineHeight {
+	/// The latest height of a state machine known to the host, if any.
+	///
+	/// # Parameters
+	/// - `state_machine`: The state machine.
+	fn latest_height(state_machine: ::ismp::host::StateMachine) -> Option<u64>;
+}
+
+impl<T: pallet_ismp::Config> StateMachineHeight for pallet_ismp::Pallet<T> {
+	fn latest_height(state_machine: ::ismp::host::StateMachine) -> Option<u64> {
+		// A state machine may be tracked by more than one consensus client.
+		pallet_ismp::LatestStateMachineHeight::<T>::iter()
+			.filter_map(|(id, height)| (id.state_id == state_machine).then_some(height))
+			.max()
+	}
+}
+
+/// Quote the amounts taken for a new ISMP `Get` request, excluding the fee paid to relayers.
+///
+/// # Parameters
+/// - `callback_gas_limit`: The gas limit of the callback, if any.
+pub(crate) fn quote_get<T: Config>(
+	callback_gas_limit: Option<Weight>,
+) -> MessageQuote<BalanceOf<T>> {
+	quote::<T, GetState<T>>(callback_gas_limit)
+}
+
+/// Quote the amounts taken for a new ISMP `Post` request, excluding the fee paid to relayers.
+///
+/// # Parameters
+/// - `callback_gas_limit`: The gas limit of the callback, if any.
+pub(crate) fn quote_post<T: Config>(
+	callback_gas_limit: Option<Weight>,
+) -> MessageQuote<BalanceOf<T>> {
+	quote::<T, PostState<T>>(callback_gas_limit)
+}
+
+/// Quote the amounts taken for a new batch of ISMP `Get` requests, excluding the fees paid to
+/// relayers.
+///
+/// A single deposit is held for the batch, covering the storage of the batch and of each of its
+/// requests.
+///
+/// # Parameters
+/// - `requests`: The number of requests of the batch.
+/// - `callback_gas_limit`: The gas limit of the callback, if any.
+pub(crate) fn quote_get_batch<T: Config>(
+	requests: u32,
+	callback_gas_limit: Option<Weight>,
+) -> MessageQuote<BalanceOf<T>> {
+	MessageQuote {
+		message_deposit: calculate_transport_deposit::<T, IsmpBatchTransport, T::OnChainByteFee>()
+			.saturating_add(calculate_message_deposit::<T, T::OnChainByteFee>())
+			.saturating_add(batch_request_deposit::<T>().saturating_mul(requests.into())),
+		prepaid_fee: Zero::zero(),
+		callback_deposit: callback_gas_limit
+			.map_or(Zero::zero(), |gas_limit| T::WeightToFee::weight_to_fee(&gas_limit)),
+	}
+}
+
+/// The deposit held for each request of a batch: the lookup of the batch by the commitment of the
+/// request, the fee paid to relayers and the state stored off-chain for the request.
+fn batch_request_deposit<T: Config>() -> BalanceOf<T> {
+	let on_chain = (KeyLenOf::<IsmpBatchRequests<T>>::get() as usize)
+		.saturating_add(<(MessageId, u32)>::max_encoded_len())
+		.saturating_add(KeyLenOf::<IsmpFees<T>>::get() as usize)
+		.saturating_add(BalanceOf::<T>::max_encoded_len());
+	T::OnChainByteFee::get()
+		.saturating_mul(on_chain.saturated_into())
+		.saturating_add(calculate_deposit_of::<T, T::OffChainByteFee, GetState<T>>())
+}
+
+/// The amounts taken for a new ISMP request, where `State` is the state stored off-chain for the
+/// request. No fee is prepaid, as the delivery of responses is paid for by relayers.
+fn quote<T: Config, State: MaxEncodedLen>(
+	callback_gas_limit: Option<Weight>,
+) -> MessageQuote<BalanceOf<T>> {
+	MessageQuote {
+		message_deposit: calculate_transport_deposit::<T, IsmpTransport, T::OnChainByteFee>()
+			.saturating_add(calculate_message_deposit::<T, T::OnChainByteFee>())
+			.saturating_add(calculate_deposit_of::<T, T::OffChainByteFee, State>()),
+		prepaid_fee: Zero::zero(),
+		callback_deposit: callback_gas_limit
+			.map_or(Zero::zero(), |gas_limit| T::WeightToFee::weight_to_fee(&gas_limit)),
+	}
+}
+
+/// Quote the amounts taken for a new subscription to recurring ISMP `Get` requests.
+///
+/// The amounts for all executions are taken upfront, including the fees paid to relayers, with
+/// those of any remaining executions being released when the subscription is cancelled.
+///
+/// # Parameters
+/// - `executions`: The number of executions of the subscription.
+/// - `fee`: The fee to be paid to relayers per execution.
+/// - `callback_gas_limit`: The gas limit of the callback.
+pub(crate) fn quote_subscribe<T: Config>(
+	executions: u32,
+	fee: BalanceOf<T>,
+	callback_gas_limit: Weight,
+) -> MessageQuote<BalanceOf<T>> {
+	let MessageQuote { message_deposit, callback_deposit, .. } =
+		quote_get::<T>(Some(callback_gas_limit));
+	let executions = BalanceOf::<T>::from(executions);
+	MessageQuote {
+		message_deposit: subscription_deposit::<T>()
+			.saturating_add(message_deposit.saturating_mul(executions)),
+		prepaid_fee: fee.saturating_mul(executions),
+		callback_deposit: callback_deposit.saturating_mul(executions),
+	}
+}
+
+/// The deposit held for the storage of a subscription, released once it is removed.
+fn subscription_deposit<T: Config>() -> BalanceOf<T> {
+	calculate_protocol_deposit::<T, T::OnChainByteFee>(ProtocolStorageDeposit::IsmpSubscriptions)
+}
+
+/// The amount prepaid for each execution of a subscription: the deposits of its request and the
+/// fee paid to relayers.
+///
+/// # Parameters
+/// - `fee`: The fee to be paid to relayers per execution.
+/// - `callback_gas_limit`: The gas limit of the callback.
+fn execution_deposit<T: Config>(fee: BalanceOf<T>, callback_gas_limit: Weight) -> BalanceOf<T> {
+	let MessageQuote { message_deposit, callback_deposit, .. } =
+		quote_get::<T>(Some(callback_gas_limit));
+	message_deposit.saturating_add(callback_deposit).saturating_add(fee)
+}
+
 /// Submit a new ISMP `Get` request.
 ///
 /// This sends a `Get` request through ISMP, optionally with a callback to handle the
@@ -53,41 +226,41 @@ pub(crate) fn get<T: Config>(
 	fee: BalanceOf<T>,
 	callback: Option<Callback<BalanceOf<T>>>,
 ) -> Result<(MessageId, H256), DispatchError> {
-	// Take deposits and fees.
-	let message_deposit =
-		calculate_protocol_deposit::<T, T::OnChainByteFee>(ProtocolStorageDeposit::IsmpRequests)
-			.saturating_add(calculate_message_deposit::<T, T::OnChainByteFee>())
-			.saturating_add(calculate_deposit_of::<T, T::OffChainByteFee, GetState<T>>());
-
-	T::Fungibles::hold(&HoldReason::Messaging.into(), &origin.account, message_deposit)?;
-
-	if let Some(cb) = callback.as_ref() {
-		T::Fungibles::hold(
-			&HoldReason::CallbackGas.into(),
-			&origin.account,
-			T::WeightToFee::weight_to_fee(&cb.gas_limit),
-		)?;
-	}
+	let quote = quote_get::<T>(callback.as_ref().map(|cb| cb.gas_limit));
+	send::<T, IsmpTransport>(origin, (DispatchRequest::Get(message), fee), callback, quote)
+}
 
-	// Process message by dispatching request via ISMP.
-	let commitment = match T::IsmpDispatcher::default().dispatch_request(
-		DispatchRequest::Get(message),
-		FeeMetadata { payer: origin.account.clone(), fee },
-	) {
-		Ok(commitment) => Ok::<H256, DispatchError>(commitment),
-		Err(e) => {
-			if let Ok(err) = e.downcast::<::ismp::Error>() {
-				log::error!("ISMP Dispatch failed!! {:?}", err);
-			}
-			return Err(Error::<T>::IsmpDispatchFailed.into());
-		},
-	}?;
-	// Store commitment for lookup on response, message for querying,
-	// response/timeout handling.
-	let id = next_message_id::<T>()?;
-	IsmpRequests::<T>::insert(commitment, id);
-	Messages::<T>::insert(id, Message::Ismp { origin, commitment, callback, message_deposit });
-	Ok((id, commitment))
+/// Submit a new batch of ISMP `Get` requests.
+///
+/// Each request is sent through ISMP, with the batch completing once every request has been
+/// answered or has timed out. The outcome of every request is then delivered via a single
+/// execution of the callback, if any, otherwise stored to be polled.
+///
+/// # Parameters
+/// - `origin`: The account submitting the batch.
+/// - `requests`: The ISMP `Get` messages containing query details.
+/// - `fee`: The fee to be paid to relayers for each request.
+/// - `callback`: Optional callback to execute once the batch completes.
+///
+/// # Returns
+/// A unique identifier for the message, along with the commitments of the requests.
+pub(crate) fn get_batch<T: Config>(
+	origin: Origin<T>,
+	requests: Vec<DispatchGet>,
+	fee: BalanceOf<T>,
+	callback: Option<Callback<BalanceOf<T>>>,
+) -> Result<(MessageId, Vec<H256>), DispatchError> {
+	ensure!(
+		!requests.is_empty() && requests.len() <= T::MaxBatchSize::get() as usize,
+		Error::<T>::InvalidBatch
+	);
+	let quote =
+		quote_get_batch::<T>(requests.len() as u32, callback.as_ref().map(|cb| cb.gas_limit));
+	let (id, _) = send::<T, IsmpBatchTransport>(origin, (requests, fee), callback, quote)?;
+	let commitments = IsmpBatches::<T>::get(id)
+		.map(|batch| batch.commitments.into_inner())
+		.unwrap_or_default();
+	Ok((id, commitments))
 }
 
 /// Submit a new ISMP `Post` request.
@@ -108,110 +281,713 @@ pub(crate) fn post<T: Config>(
 	fee: BalanceOf<T>,
 	callback: Option<Callback<BalanceOf<T>>>,
 ) -> Result<(MessageId, H256), DispatchError> {
-	// Take deposits and fees.
-	let message_deposit =
-		calculate_protocol_deposit::<T, T::OnChainByteFee>(ProtocolStorageDeposit::IsmpRequests)
-			.saturating_add(calculate_message_deposit::<T, T::OnChainByteFee>())
-			.saturating_add(calculate_deposit_of::<T, T::OffChainByteFee, PostState<T>>());
+	let quote = quote_post::<T>(callback.as_ref().map(|cb| cb.gas_limit));
+	send::<T, IsmpTransport>(origin, (DispatchRequest::Post(message), fee), callback, quote)
+}
+
+/// Add to the fee paid to relayers for an outstanding ISMP request.
+///
+/// # Parameters
+/// - `origin`: The account funding the request, which must be the origin of the request.
+/// - `id`: The identifier of the message.
+/// - `amount`: The amount to be added to the fee paid to relayers.
+///
+/// # Returns
+/// The commitment of the request and the cumulative fee paid to relayers.
+pub(crate) fn fund<T: Config>(
+	origin: Origin<T>,
+	id: MessageId,
+	amount: BalanceOf<T>,
+) -> Result<(H256, BalanceOf<T>), DispatchError> {
+	let (initiator, transport, ..) = pending::<T, IsmpTransport>(id)?;
+	ensure!(origin.address == initiator.address, BadOrigin);
+	let commitment = transport.key::<T, IsmpTransport>().ok_or(Error::<T>::InvalidMessage)?;
+
+	T::IsmpDispatcher::fund_request(&origin.account, commitment, amount)?;
+	let fee = IsmpFees::<T>::mutate(commitment, |fee| {
+		let fee = fee.get_or_insert_with(Zero::zero);
+		*fee = fee.saturating_add(amount);
+		*fee
+	});
+	Ok((commitment, fee))
+}
+
+/// Subscribe to recurring ISMP `Get` requests.
+///
+/// A `Get` request is dispatched from `on_idle` at each interval, reading the destination at the
+/// latest height known to the host, until the number of executions is reached. Each response is
+/// delivered via the callback. The deposit of the subscription and the amounts for all executions
+/// are taken upfront, with the amounts for each execution being drawn down as it is dispatched.
+///
+/// # Parameters
+/// - `origin`: The account subscribing.
+/// - `request`: The template of the `Get` requests to be dispatched.
+/// - `interval`: The number of blocks between executions.
+/// - `executions`: The number of executions.
+/// - `fee`: The fee to be paid to relayers per execution.
+/// - `callback`: The callback to execute upon receiving each response.
+///
+/// # Returns
+/// A unique identifier for the subscription.
+pub(crate) fn subscribe<T: Config>(
+	origin: Origin<T>,
+	request: GetTemplate<T>,
+	interval: BlockNumberOf<T>,
+	executions: u32,
+	fee: BalanceOf<T>,
+	callback: Callback<BalanceOf<T>>,
+) -> Result<SubscriptionId, DispatchError> {
+	ensure!(
+		executions > 0 && !interval.is_zero() && interval >= T::MinSubscriptionInterval::get(),
+		Error::<T>::InvalidSubscription
+	);
 
-	T::Fungibles::hold(&HoldReason::Messaging.into(), &origin.account, message_deposit)?;
+	// Take deposits and fees for all executions.
+	let deposit = subscription_deposit::<T>();
+	let execution_deposit = execution_deposit::<T>(fee, callback.gas_limit);
+	let prepaid = execution_deposit.saturating_mul(executions.into());
+	T::Fungibles::hold(&HoldReason::Messaging.into(), &origin.account, deposit)?;
+	T::Fungibles::hold(&HoldReason::Subscription.into(), &origin.account, prepaid)?;
+
+	let id = NextSubscriptionId::<T>::try_mutate(|next| {
+		let id = *next;
+		*next = next.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+		Ok::<_, DispatchError>(id)
+	})?;
+	let next = frame_system::Pallet::<T>::block_number().saturating_add(interval);
+	SubscriptionSchedule::<T>::insert(next, id, ());
+	Subscriptions::<T>::insert(
+		id,
+		Subscription {
+			origin,
+			request,
+			interval,
+			remaining: executions,
+			next,
+			fee,
+			callback,
+			deposit,
+			execution_deposit,
+		},
+	);
+	Ok(id)
+}
 
-	if let Some(cb) = callback.as_ref() {
-		T::Fungibles::hold(
-			&HoldReason::CallbackGas.into(),
+/// Cancel a subscription, releasing its deposit and the amounts prepaid for its remaining
+/// executions.
+///
+/// Requests which have already been dispatched are unaffected.
+///
+/// # Parameters
+/// - `origin`: The account cancelling the subscription, which must be the origin of the
+///   subscription.
+/// - `id`: The identifier of the subscription.
+pub(crate) fn cancel_subscription<T: Config>(
+	origin: Origin<T>,
+	id: SubscriptionId,
+) -> DispatchResult {
+	let subscription = Subscriptions::<T>::get(id).ok_or(Error::<T>::SubscriptionNotFound)?;
+	ensure!(origin.address == subscription.origin.address, BadOrigin);
+
+	Subscriptions::<T>::remove(id);
+	SubscriptionSchedule::<T>::remove(subscription.next, id);
+	T::Fungibles::release(
+		&HoldReason::Subscription.into(),
+		&origin.account,
+		subscription.execution_deposit.saturating_mul(subscription.remaining.into()),
+		Exact,
+	)?;
+	T::Fungibles::release(
+		&HoldReason::Messaging.into(),
+		&origin.account,
+		subscription.deposit,
+		Exact,
+	)?;
+	Ok(())
+}
+
+/// Executes a subscription which is due, dispatching its `Get` request and scheduling its next
+/// execution.
+///
+/// The amount prepaid for the execution is released to be taken by the request. A request which
+/// cannot be dispatched, for instance as the height of the destination is not yet known, is
+/// retried at the next interval without counting as an execution. The subscription is removed
+/// after its final execution, with its deposit released.
+///
+/// # Parameters
+/// - `id`: The identifier of the subscription.
+pub(crate) fn execute_subscription<T: Config>(id: SubscriptionId) {
+	let Some(mut subscription) = Subscriptions::<T>::get(id) else {
+		// The subscription has since been cancelled.
+		return;
+	};
+	let Subscription { origin, request, fee, callback, execution_deposit, .. } = &subscription;
+	let address = origin.address;
+
+	let result = with_storage_layer(|| {
+		let dest = ::ismp::host::StateMachine::Polkadot(request.dest);
+		let height =
+			T::IsmpDispatcher::latest_height(dest).ok_or(Error::<T>::IsmpDispatchFailed)?;
+		T::Fungibles::release(
+			&HoldReason::Subscription.into(),
 			&origin.account,
-			T::WeightToFee::weight_to_fee(&cb.gas_limit),
+			*execution_deposit,
+			Exact,
 		)?;
+		let message = DispatchGet {
+			dest,
+			from: ID.into(),
+			keys: request.keys.iter().map(|key| key.to_vec()).collect(),
+			height,
+			context: request.context.to_vec(),
+			timeout: request.timeout,
+		};
+		get::<T>(origin.clone(), message, *fee, Some(*callback))
+	});
+	match result {
+		Ok((message, commitment)) => {
+			subscription.remaining.saturating_dec();
+			Pallet::<T>::deposit_event(Event::<T>::SubscriptionDispatched {
+				origin: address,
+				subscription: id,
+				id: message,
+				commitment,
+			});
+		},
+		Err(error) => Pallet::<T>::deposit_event(Event::<T>::SubscriptionDispatchFailed {
+			origin: address,
+			subscription: id,
+			error,
+		}),
 	}
 
-	// Process message by dispatching request via ISMP.
-	let commitment = T::IsmpDispatcher::default()
-		.dispatch_request(
-			DispatchRequest::Post(message),
-			FeeMetadata { payer: origin.account.clone(), fee },
-		)
-		.map_err(|_| Error::<T>::IsmpDispatchFailed)?;
+	if subscription.remaining == 0 {
+		Subscriptions::<T>::remove(id);
+		if let Err(error) = T::Fungibles::release(
+			&HoldReason::Messaging.into(),
+			&subscription.origin.account,
+			subscription.deposit,
+			BestEffort,
+		) {
+			log::error!(target: "pop-api::messaging::ismp", "failed to release subscription deposit: id={id}, error={error:?}");
+		}
+		Pallet::<T>::deposit_event(Event::<T>::SubscriptionExhausted {
+			origin: address,
+			subscription: id,
+		});
+		return;
+	}
 
-	// Store commitment for lookup on response, message for querying,
-	// response/timeout handling.
-	let id = next_message_id::<T>()?;
-	IsmpRequests::<T>::insert(commitment, id);
-	Messages::<T>::insert(id, Message::Ismp { origin, commitment, callback, message_deposit });
-	Ok((id, commitment))
+	subscription.next =
+		frame_system::Pallet::<T>::block_number().saturating_add(subscription.interval);
+	SubscriptionSchedule::<T>::insert(subscription.next, id, ());
+	Subscriptions::<T>::insert(id, subscription);
 }
 
+/// Handle a response to an ISMP request.
+///
+/// # Parameters
+/// - `commitment`: The commitment of the request.
+/// - `response_data`: The response.
+/// - `event`: The event indicating the receipt of the response.
 pub(crate) fn process_response<T: Config>(
 	commitment: &H256,
 	response_data: impl Encode + EncodeCallback,
 	event: impl Fn(H160, MessageId) -> Event<T>,
 ) -> Result<(), anyhow::Error> {
-	// TODO: handle Solidity encoding size
 	ensure!(
 		response_data.encoded_size() <= T::MaxResponseLen::get() as usize,
 		::ismp::Error::Custom("Response length exceeds maximum allowed length.".into())
 	);
 
-	let id = IsmpRequests::<T>::get(commitment)
+	let id = message_id::<T, IsmpTransport>(commitment)
+		.ok_or(::ismp::Error::Custom("Request not found.".into()))?;
+	let (_, _, callback, _) = pending::<T, IsmpTransport>(id)
+		.map_err(|_| ::ismp::Error::Custom("Message must be an ismp request.".into()))?;
+	// The response must also be within the limit once encoded for the callback.
+	ensure!(
+		within_max_response_len::<T>(&response_data, callback.as_ref()),
+		::ismp::Error::Custom("Response length exceeds maximum allowed length.".into())
+	);
+
+	respond::<T, IsmpTransport>(commitment, &response_data, event)
+		.map_err(|_| ::ismp::Error::Custom("Failed to process response.".into()))?;
+	Ok(())
+}
+
+/// Handle the timeout of an ISMP request.
+///
+/// # Parameters
+/// - `commitment`: The commitment of the request.
+pub(crate) fn timeout_commitment<T: Config>(commitment: &H256) -> Result<(), anyhow::Error> {
+	if IsmpBatchRequests::<T>::contains_key(commitment) {
+		return conclude_batch_request::<T>(commitment, None);
+	}
+	let id = message_id::<T, IsmpTransport>(commitment).ok_or(::ismp::Error::Custom(
+		"Request commitment not found while processing timeout.".into(),
+	))?;
+	ensure!(
+		pending::<T, IsmpTransport>(id).is_ok(),
+		::ismp::Error::Custom("Invalid message".into())
+	);
+	time_out::<T, IsmpTransport>(commitment)
+		.map_err(|_| ::ismp::Error::Custom("Failed to process timeout.".into()))?;
+
+	Pallet::<T>::deposit_event(Event::<T>::IsmpTimedOut { commitment: *commitment });
+	Ok(())
+}
+
+/// Handle the conclusion of a `Get` request of a batch, either by a response or its timeout.
+///
+/// The batch completes once every request has concluded, with the outcome of every request being
+/// handled as the response to the batch. A request whose values would exceed the maximum length of
+/// the response to the batch fails, with its values being discarded so that the batch can still
+/// complete.
+///
+/// # Parameters
+/// - `commitment`: The commitment of the request.
+/// - `values`: The values read by the request, or `None` if the request timed out.
+pub(crate) fn conclude_batch_request<T: Config>(
+	commitment: &H256,
+	values: Option<Vec<StorageValue>>,
+) -> Result<(), anyhow::Error> {
+	let (id, position) = IsmpBatchRequests::<T>::get(commitment)
 		.ok_or(::ismp::Error::Custom("Request not found.".into()))?;
+	let (origin, _, callback, _) = pending::<T, IsmpBatchTransport>(id)
+		.map_err(|_| ::ismp::Error::Custom("Message must be an ismp batch.".into()))?;
+	let mut batch =
+		IsmpBatches::<T>::get(id).ok_or(::ismp::Error::Custom("Batch not found.".into()))?;
+	let mut results = batch.results();
+	let result = results
+		.get_mut(position as usize)
+		.filter(|result| result.status == MessageStatus::Pending)
+		.ok_or(::ismp::Error::Custom("Request already concluded.".into()))?;
+	match values {
+		Some(values) => *result = GetResult { status: MessageStatus::Complete, values },
+		None => result.status = MessageStatus::Timeout,
+	}
+	if !within_max_response_len::<T>(&results, callback.as_ref()) {
+		results[position as usize] =
+			GetResult { status: MessageStatus::Failed, values: Vec::new() };
+	}
+	ensure!(
+		within_max_response_len::<T>(&results, callback.as_ref()),
+		::ismp::Error::Custom("Response length exceeds maximum allowed length.".into())
+	);
 
-	let Some(Message::Ismp { origin, commitment, callback, message_deposit }) =
-		Messages::<T>::get(id)
-	else {
-		return Err(::ismp::Error::Custom("Message must be an ismp request.".into()).into());
+	IsmpBatchRequests::<T>::remove(commitment);
+	IsmpFees::<T>::remove(commitment);
+	let event = match &results[position as usize].status {
+		MessageStatus::Timeout => Event::<T>::IsmpTimedOut { commitment: *commitment },
+		_ => Event::<T>::IsmpGetResponseReceived {
+			dest: origin.address,
+			id,
+			commitment: *commitment,
+		},
 	};
+	Pallet::<T>::deposit_event(event);
+
+	if results.iter().any(|result| result.status == MessageStatus::Pending) {
+		batch
+			.update(&results)
+			.map_err(|_| ::ismp::Error::Custom("Invalid batch.".into()))?;
+		IsmpBatches::<T>::insert(id, batch);
+		return Ok(());
+	}
+	respond::<T, IsmpBatchTransport>(&id, &results, |dest, id| Event::<T>::IsmpGetBatchCompleted {
+		dest,
+		id,
+	})
+	.map_err(|_| ::ismp::Error::Custom("Failed to process response.".into()))?;
+	Ok(())
+}
 
-	// Deposit that the message has been recieved before a potential callback execution.
-	Pallet::<T>::deposit_event(event(origin.address, id));
-
-	// Attempt callback with result if specified.
-	if let Some(callback) = callback {
-		if call::<T>(&origin.account, callback, &id, &response_data).is_ok() {
-			// Clean storage, return deposit
-			Messages::<T>::remove(id);
-			IsmpRequests::<T>::remove(commitment);
-			T::Fungibles::release(
-				&HoldReason::Messaging.into(),
-				&origin.account,
-				message_deposit,
-				Exact,
-			)
-			.map_err(|_| ::ismp::Error::Custom("failed to release message deposit.".into()))?;
+/// The status of a batch of ISMP `Get` requests, along with the status of each of its requests.
+///
+/// The statuses of the requests are available whilst the batch is pending and once it has
+/// completed, until its response is removed. The statuses of the requests of any other message
+/// are empty.
+///
+/// # Parameters
+/// - `id`: The message identifier of the batch.
+pub(crate) fn poll_batch<T: Config>(id: MessageId) -> (MessageStatus, Vec<MessageStatus>) {
+	if let Some(batch) = IsmpBatches::<T>::get(id) {
+		return (MessageStatus::Pending, batch.statuses.into_inner());
+	}
+	match Messages::<T>::get(id) {
+		Some(Message::Complete { transport, response, .. })
+			if transport.index == <IsmpBatchTransport as Transport<T>>::INDEX =>
+		{
+			let statuses = Vec::<GetResult>::decode(&mut response.as_slice())
+				.map(|results| results.into_iter().map(|result| result.status).collect())
+				.unwrap_or_default();
+			(MessageStatus::Complete, statuses)
+		},
+		Some(message) => ((&message).into(), Vec::new()),
+		None => (MessageStatus::NotFound, Vec::new()),
+	}
+}
 
-			return Ok(());
-		}
+/// Allow a contract to receive inbound ISMP `Post` requests from a module on a source state
+/// machine.
+///
+/// A deposit is held from the contract for the registration, released once it is revoked.
+/// Allowing a source which is already allowed has no effect.
+///
+/// # Parameters
+/// - `origin`: The contract receiving the requests.
+/// - `source`: The source state machine.
+/// - `from`: The identifier of the sending module on the source state machine.
+pub(crate) fn allow_source<T: Config>(
+	origin: Origin<T>,
+	source: ::ismp::host::StateMachine,
+	from: &[u8],
+) -> DispatchResult {
+	let key = source_key::<T>(&source, from);
+	if IsmpPostRequestSources::<T>::contains_key(origin.address, key) {
+		return Ok(());
 	}
+	let deposit = source_deposit::<T>();
+	T::Fungibles::hold(&HoldReason::Messaging.into(), &origin.account, deposit)?;
+	IsmpPostRequestSources::<T>::insert(origin.address, key, deposit);
+	Ok(())
+}
 
-	// No callback or callback error: store response for manual retrieval and removal.
-	let response: BoundedVec<u8, T::MaxResponseLen> = codec::Encode::encode(&response_data)
-		.try_into()
-		.map_err(|_| ::ismp::Error::Custom("response exceeds max".into()))?;
-	Messages::<T>::insert(
-		id,
-		Message::IsmpResponse { origin: origin.address, commitment, message_deposit, response },
+/// Revoke the allowance of a contract to receive inbound ISMP `Post` requests from a module on a
+/// source state machine, releasing the deposit held for the registration.
+///
+/// # Parameters
+/// - `origin`: The contract receiving the requests.
+/// - `source`: The source state machine.
+/// - `from`: The identifier of the sending module on the source state machine.
+pub(crate) fn revoke_source<T: Config>(
+	origin: Origin<T>,
+	source: ::ismp::host::StateMachine,
+	from: &[u8],
+) -> DispatchResult {
+	let Some(deposit) =
+		IsmpPostRequestSources::<T>::take(origin.address, source_key::<T>(&source, from))
+	else {
+		return Ok(());
+	};
+	T::Fungibles::release(&HoldReason::Messaging.into(), &origin.account, deposit, Exact)?;
+	Ok(())
+}
+
+/// The key of a source of inbound ISMP `Post` requests: the hash of the source state machine and
+/// the identifier of the sending module.
+pub(crate) fn source_key<T: Config>(source: &::ismp::host::StateMachine, from: &[u8]) -> H256 {
+	<T::Keccak256 as ::ismp::messaging::Keccak256>::keccak256(&(source, from).encode())
+}
+
+/// The deposit held for the registration of a source of inbound ISMP `Post` requests.
+fn source_deposit<T: Config>() -> BalanceOf<T> {
+	calculate_protocol_deposit::<T, T::OnChainByteFee>(
+		ProtocolStorageDeposit::IsmpPostRequestSources,
+	)
+}
+
+/// Deliver an inbound ISMP `Post` request to a contract.
+///
+/// The recipient contract and the encoding of the data going to the contract are determined by the
+/// `to` field of the request, which must be the module identifier followed by the contract address
+/// and the encoding (see [`recipient`]). Requests are only accepted from modules on source state
+/// machines which the contract has explicitly allowed (see [`allow_source`]). The request is
+/// delivered via a call to `onPostRequest` on the contract, the gas of which is paid for by the
+/// contract. Any non-empty output of the call is returned to the source state machine as a
+/// `PostResponse`.
+///
+/// # Parameters
+/// - `request`: The inbound ISMP `Post` request.
+pub(crate) fn accept<T: Config + pallet_revive::Config>(
+	request: PostRequest,
+) -> Result<(), anyhow::Error> {
+	let (dest, encoding) =
+		recipient(&request.to).ok_or(::ismp::Error::Custom("Invalid recipient.".into()))?;
+	ensure!(
+		request.body.len() <= T::MaxDataLen::get() as usize,
+		::ismp::Error::Custom("Request body exceeds maximum allowed length.".into())
+	);
+	// Nothing is charged to the contract unless it has opted in to requests from the source.
+	ensure!(
+		IsmpPostRequestSources::<T>::contains_key(
+			dest,
+			source_key::<T>(&request.source, &request.from)
+		),
+		::ismp::Error::Custom("Source not allowed.".into())
 	);
+
+	let account = <T as pallet_revive::Config>::AddressMapper::to_account_id(&dest);
+	// The gas limit is included in the weight of the request (see `IsmpModuleWeight::on_accept`).
+	let gas_limit = T::IsmpPostRequestGasLimit::get();
+
+	// The recipient pays for the delivery of the request.
+	T::Fungibles::hold(
+		&HoldReason::CallbackGas.into(),
+		&account,
+		T::WeightToFee::weight_to_fee(&gas_limit),
+	)
+	.map_err(|_| ::ismp::Error::Custom("Recipient cannot pay for delivery.".into()))?;
+
+	let commitment = hash_request::<T::Keccak256>(&Request::Post(request.clone()));
+	Pallet::<T>::deposit_event(Event::<T>::IsmpPostRequestReceived { dest, commitment });
+
+	// Execute call. The fees for the weight used must be managed, hence all calls after this must
+	// not return an error and state should not be rolled back.
+	let data = encode_post_request(&request, encoding);
+	let (result, output) = match T::CallbackExecutor::execute_with_output(
+		&account,
+		dest,
+		data,
+		gas_limit,
+		T::IsmpPostRequestStorageDepositLimit::get(),
+	) {
+		Ok((post_info, output)) => (Ok(post_info), output),
+		Err(error) => (Err(error), Vec::default()),
+	};
+	log::debug!(target: "pop-api::messaging::ismp", "post request gas_limit={:?}, result={result:?}", gas_limit);
+	let weight_used = process_callback_weight(&result, gas_limit);
+
+	if let Err(error) = manage_fees::<T>(&account, weight_used, gas_limit) {
+		log::error!(target: "pop-api::messaging::ismp", "failed to manage post request fees: {:?}", error);
+	}
+
+	if let Err(error) = result {
+		Pallet::<T>::deposit_event(Event::<T>::IsmpPostRequestFailed { dest, commitment, error });
+		return Ok(());
+	}
+
+	// Return any response to the source state machine.
+	match decode_post_request_output(&output, encoding) {
+		Some(response) if response.is_empty() => {},
+		Some(response) if response.len() <= T::MaxDataLen::get() as usize => {
+			let timeout_timestamp = request.timeout_timestamp;
+			let response = PostResponse { post: request, response, timeout_timestamp };
+			match T::IsmpDispatcher::default()
+				.dispatch_response(response, FeeMetadata { payer: account, fee: Zero::zero() })
+			{
+				Ok(_) => Pallet::<T>::deposit_event(Event::<T>::IsmpPostResponseDispatched {
+					origin: dest,
+					commitment,
+				}),
+				Err(e) => {
+					log::error!(target: "pop-api::messaging::ismp", "failed to dispatch post response: {:?}", e);
+				},
+			}
+		},
+		_ => {
+			log::error!(target: "pop-api::messaging::ismp", "invalid post request output={:?}", output);
+		},
+	}
 	Ok(())
 }
 
-pub(crate) fn timeout_commitment<T: Config>(commitment: &H256) -> Result<(), anyhow::Error> {
-	let key = IsmpRequests::<T>::get(commitment).ok_or(::ismp::Error::Custom(
-		"Request commitment not found while processing timeout.".into(),
-	))?;
-	Messages::<T>::try_mutate(key, |message| {
-		let Some(Message::Ismp { origin, commitment, message_deposit, callback }) = message else {
-			return Err(::ismp::Error::Custom("Invalid message".into()));
-		};
-		let callback_deposit = callback.map(|cb| T::WeightToFee::weight_to_fee(&cb.gas_limit));
-		*message = Some(Message::IsmpTimeout {
-			origin: origin.address,
-			message_deposit: *message_deposit,
-			commitment: *commitment,
-			callback_deposit,
-		});
+/// Determines the recipient contract and encoding from the `to` field of an inbound ISMP `Post`
+/// request, expected to be the module identifier followed by the contract address and the
+/// encoding (i.e. `ID ++ H160 ++ Encoding`).
+///
+/// # Parameters
+/// - `to`: The `to` field of the request.
+pub(crate) fn recipient(to: &[u8]) -> Option<(H160, Encoding)> {
+	let mut input = to.strip_prefix(&ID)?;
+	let recipient = <(H160, Encoding)>::decode(&mut input).ok()?;
+	input.is_empty().then_some(recipient)
+}
+
+/// Whether a module identifier belongs to this module: either the module identifier itself, as used
+/// by the responses to and timeouts of outbound requests, or that of a contract receiving inbound
+/// requests (see [`recipient`]).
+///
+/// # Parameters
+/// - `id`: The module identifier.
+pub fn is_module_id(id: &[u8]) -> bool {
+	id == ID || recipient(id).is_some()
+}
+
+/// The template of the ISMP `Get` requests dispatched by a subscription.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct GetTemplate<T: Config> {
+	/// The parachain identifier of the destination state machine.
+	pub(crate) dest: u32,
+	/// Relative from the current timestamp at which each request expires in seconds.
+	pub(crate) timeout: u64,
+	/// Some application-specific metadata relating to each request.
+	pub(crate) context: BoundedVec<u8, T::MaxContextLen>,
+	/// Raw storage keys used to fetch the values from the destination.
+	pub(crate) keys: BoundedVec<BoundedVec<u8, T::MaxKeyLen>, T::MaxKeys>,
+}
+
+/// A subscription to recurring ISMP `Get` requests.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct Subscription<T: Config> {
+	/// The origin of the subscription.
+	pub(crate) origin: Origin<T>,
+	/// The template of the requests.
+	pub(crate) request: GetTemplate<T>,
+	/// The number of blocks between executions.
+	pub(crate) interval: BlockNumberOf<T>,
+	/// The number of executions remaining.
+	pub(crate) remaining: u32,
+	/// The block at which the next execution is due.
+	pub(crate) next: BlockNumberOf<T>,
+	/// The fee paid to relayers per execution.
+	pub(crate) fee: BalanceOf<T>,
+	/// The callback to execute upon receiving each response.
+	pub(crate) callback: Callback<BalanceOf<T>>,
+	/// The deposit held for the storage of the subscription.
+	pub(crate) deposit: BalanceOf<T>,
+	/// The amount prepaid for each execution.
+	pub(crate) execution_deposit: BalanceOf<T>,
+}
+
+/// The outcome of a `Get` request of a batch.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub struct GetResult {
+	/// The status of the request.
+	pub status: MessageStatus,
+	/// The values read by the request, which are empty unless the request has completed.
+	pub values: Vec<StorageValue>,
+}
+
+/// A pending batch of ISMP `Get` requests.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct GetBatch<T: Config> {
+	/// The commitments of the requests, in the order in which they were specified.
+	pub(crate) commitments: BoundedVec<H256, T::MaxBatchSize>,
+	/// The status of each request.
+	pub(crate) statuses: BoundedVec<MessageStatus, T::MaxBatchSize>,
+	/// The encoded values read by each request so far, bounded by the maximum length of a
+	/// response as they form the response to the batch.
+	pub(crate) values: BoundedVec<u8, T::MaxResponseLen>,
+}
+
+impl<T: Config> GetBatch<T> {
+	/// The outcome of each request of the batch.
+	fn results(&self) -> Vec<GetResult> {
+		let values =
+			Vec::<Vec<StorageValue>>::decode(&mut self.values.as_slice()).unwrap_or_default();
+		self.statuses
+			.iter()
+			.cloned()
+			.zip(values.into_iter().chain(core::iter::repeat(Vec::new())))
+			.map(|(status, values)| GetResult { status, values })
+			.collect()
+	}
+
+	/// Update the batch with the outcome of each of its requests.
+	///
+	/// # Parameters
+	/// - `results`: The outcome of each request.
+	fn update(&mut self, results: &[GetResult]) -> Result<(), ()> {
+		self.statuses = results
+			.iter()
+			.map(|result| result.status.clone())
+			.collect::<Vec<_>>()
+			.try_into()
+			.map_err(|_| ())?;
+		self.values = results
+			.iter()
+			.map(|result| &result.values)
+			.collect::<Vec<_>>()
+			.encode()
+			.try_into()
+			.map_err(|_| ())?;
 		Ok(())
-	})?;
+	}
+}
 
-	Pallet::<T>::deposit_event(Event::<T>::IsmpTimedOut { commitment: *commitment });
-	Ok(())
+/// The ISMP transport, dispatching `Get` and `Post` requests via the ISMP dispatcher.
+pub struct IsmpTransport;
+
+impl<T: Config> Transport<T> for IsmpTransport {
+	/// The commitment of the request.
+	type Key = H256;
+	/// The request, along with the fee to be paid to relayers.
+	type Request = (DispatchRequest, BalanceOf<T>);
+
+	const INDEX: TransportIndex = 0;
+
+	fn dispatch(
+		origin: &Origin<T>,
+		_id: MessageId,
+		(request, fee): Self::Request,
+	) -> Result<Self::Key, DispatchError> {
+		let commitment = T::IsmpDispatcher::default()
+			.dispatch_request(request, FeeMetadata { payer: origin.account.clone(), fee })
+			.map_err(|e| {
+				if let Ok(err) = e.downcast::<::ismp::Error>() {
+					log::error!(target: "pop-api::messaging::ismp", "dispatch failed: {:?}", err);
+				}
+				Error::<T>::IsmpDispatchFailed
+			})?;
+		IsmpFees::<T>::insert(commitment, fee);
+		Ok(commitment)
+	}
+
+	fn footprint() -> u32 {
+		KeyLenOf::<IsmpFees<T>>::get().saturating_add(BalanceOf::<T>::max_encoded_len() as u32)
+	}
+
+	fn on_conclude(commitment: &Self::Key) {
+		IsmpFees::<T>::remove(commitment);
+	}
+}
+
+/// The transport of batches of ISMP `Get` requests, dispatching each request of a batch via the
+/// ISMP dispatcher. A batch concludes once every request has been answered or has timed out.
+pub struct IsmpBatchTransport;
+
+impl<T: Config> Transport<T> for IsmpBatchTransport {
+	/// The identifier of the message of the batch.
+	type Key = MessageId;
+	/// The requests, along with the fee to be paid to relayers for each.
+	type Request = (Vec<DispatchGet>, BalanceOf<T>);
+
+	const INDEX: TransportIndex = 2;
+
+	fn dispatch(
+		origin: &Origin<T>,
+		id: MessageId,
+		(requests, fee): Self::Request,
+	) -> Result<Self::Key, DispatchError> {
+		let mut commitments = BoundedVec::<H256, T::MaxBatchSize>::new();
+		for request in requests {
+			let commitment = <IsmpTransport as Transport<T>>::dispatch(
+				origin,
+				id,
+				(DispatchRequest::Get(request), fee),
+			)?;
+			IsmpBatchRequests::<T>::insert(commitment, (id, commitments.len() as u32));
+			commitments.try_push(commitment).map_err(|_| Error::<T>::InvalidBatch)?;
+		}
+		let pending = vec![MessageStatus::Pending; commitments.len()];
+		let values = vec![Vec::<StorageValue>::new(); commitments.len()].encode();
+		IsmpBatches::<T>::insert(
+			id,
+			GetBatch {
+				commitments,
+				statuses: pending.try_into().map_err(|_| Error::<T>::InvalidBatch)?,
+				values: values.try_into().map_err(|_| Error::<T>::InvalidBatch)?,
+			},
+		);
+		Ok(id)
+	}
+
+	fn footprint() -> u32 {
+		KeyLenOf::<IsmpBatches<T>>::get().saturating_add(GetBatch::<T>::max_encoded_len() as u32)
+	}
+
+	fn on_conclude(id: &Self::Key) {
+		let Some(batch) = IsmpBatches::<T>::take(id) else { return };
+		// Remove any requests yet to conclude.
+		for commitment in batch.commitments {
+			IsmpBatchRequests::<T>::remove(commitment);
+			IsmpFees::<T>::remove(commitment);
+		}
+	}
 }
 
 pub struct Module<T>(PhantomData<T>);
@@ -227,18 +1003,27 @@ impl<T> Module<T> {
 	}
 }
 
-impl<T: Config> IsmpModule for Module<T> {
-	fn on_accept(&self, _request: PostRequest) -> Result<(), anyhow::Error> {
-		// ISMP POSTs are currently not supported.
+impl<T: Config + pallet_revive::Config> IsmpModule for Module<T> {
+	fn on_accept(&self, request: PostRequest) -> Result<(), anyhow::Error> {
+		// An error must not be returned, otherwise the receipt is removed and a replay attack is
+		// possible.
+		if let Err(e) = accept::<T>(request) {
+			log::error!(target: "pop-api::messaging::ismp", "failed to accept post request: {:?}", e);
+		}
 		Ok(())
 	}
 
 	fn on_response(&self, response: Response) -> Result<(), anyhow::Error> {
+		// Rejected whilst the storage of the pallet is being migrated, allowing later redelivery.
+		ensure!(!migrating::<T>(), ::ismp::Error::Custom("Messaging is being migrated.".into()));
 		// Hash request to determine key for message lookup.
 		match response {
 			Response::Get(GetResponse { get, values }) => {
 				log::debug!(target: "pop-api::messaging::ismp", "StorageValue={:?}", values);
 				let commitment = hash_request::<T::Keccak256>(&Request::Get(get));
+				if IsmpBatchRequests::<T>::contains_key(commitment) {
+					return conclude_batch_request::<T>(&commitment, Some(values));
+				}
 				process_response(&commitment, values, |dest, id| {
 					Event::<T>::IsmpGetResponseReceived { dest, id, commitment }
 				})
@@ -253,6 +1038,8 @@ impl<T: Config> IsmpModule for Module<T> {
 	}
 
 	fn on_timeout(&self, timeout: Timeout) -> Result<(), anyhow::Error> {
+		// Rejected whilst the storage of the pallet is being migrated, allowing later redelivery.
+		ensure!(!migrating::<T>(), ::ismp::Error::Custom("Messaging is being migrated.".into()));
 		match timeout {
 			Timeout::Request(request) => {
 				// hash request to determine key for original request id lookup
@@ -270,11 +1057,20 @@ impl<T: Config> IsmpModule for Module<T> {
 impl<T: Config> IsmpModuleWeight for Module<T> {
 	fn on_accept(&self, _request: &PostRequest) -> Weight {
 		T::WeightInfo::ismp_on_accept()
+		// Also add actual weight consumed by contract env.
+		.saturating_add(T::CallbackExecutor::execution_weight())
+		// Also add the gas limit of the call to the recipient contract.
+		.saturating_add(T::IsmpPostRequestGasLimit::get())
 	}
 
 	fn on_timeout(&self, timeout: &Timeout) -> Weight {
 		let x = match timeout {
-			Timeout::Request(Request::Get(_)) => 0u32,
+			// The timeout of the final request of a batch completes the batch, executing any
+			// callback.
+			Timeout::Request(Request::Get(_)) =>
+				return T::WeightInfo::ismp_on_timeout(0)
+					.max(T::WeightInfo::ismp_conclude_batch_request(1))
+					.saturating_add(T::CallbackExecutor::execution_weight()),
 			Timeout::Request(Request::Post(_)) => 1u32,
 			Timeout::Response(_) => 2u32,
 		};
@@ -282,26 +1078,46 @@ impl<T: Config> IsmpModuleWeight for Module<T> {
 	}
 
 	fn on_response(&self, response: &Response) -> Weight {
-		let x = match response {
-			Response::Get(_) => 0,
-			Response::Post(_) => 1,
+		// Responses exceeding the maximum length are rejected, so are charged at most the weight
+		// of a response of the maximum length.
+		let len = |size: usize| (size as u32).min(T::MaxResponseLen::get());
+		let weight = match response {
+			Response::Get(response) =>
+				T::WeightInfo::ismp_on_response(0, len(response.values.encoded_size()))
+					.max(T::WeightInfo::ismp_conclude_batch_request(0)),
+			Response::Post(response) =>
+				T::WeightInfo::ismp_on_response(1, len(response.response.encoded_size())),
 		};
-		T::WeightInfo::ismp_on_response(x)
-		// Also add actual weight consumed by contract env.
-		.saturating_add(T::CallbackExecutor::execution_weight())
+		weight
+			// Also add actual weight consumed by contract env.
+			.saturating_add(T::CallbackExecutor::execution_weight())
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use ::ismp::{host::StateMachine, module::IsmpModule, Error as IsmpError};
-	use frame_support::{assert_ok, traits::fungible::InspectHold, weights::WeightToFee as _};
+	use ::ismp::{
+		host::{IsmpHost, StateMachine},
+		module::IsmpModule,
+		Error as IsmpError,
+	};
+	use frame_support::{
+		assert_noop, assert_ok, traits::fungible::InspectHold, weights::WeightToFee as _,
+	};
 
 	use super::{super::tests::events, messaging::HoldReason::*, mock::*, *};
+	use crate::{
+		messaging::precompiles::ismp::v0::IPostRequest,
+		mock::messaging::{CallbackOutput, IsmpPostRequestGasLimit},
+	};
 
 	type Fungibles = <Test as Config>::Fungibles;
 	type GetState = super::GetState<Test>;
-	type IsmpRequests = super::IsmpRequests<Test>;
+	type IsmpBatchRequests = super::IsmpBatchRequests<Test>;
+	type IsmpBatches = super::IsmpBatches<Test>;
+	type IsmpFees = super::IsmpFees<Test>;
+	type IsmpPostRequestSources = super::IsmpPostRequestSources<Test>;
+	type MaxBatchSize = <Test as Config>::MaxBatchSize;
 	type MaxContextLen = <Test as Config>::MaxContextLen;
 	type MaxDataLen = <Test as Config>::MaxDataLen;
 	type MaxKeyLen = <Test as Config>::MaxKeyLen;
@@ -312,11 +1128,43 @@ mod tests {
 	type OnChainByteFee = <Test as Config>::OnChainByteFee;
 	type Origin = super::Origin<Test>;
 	type PostState = super::PostState<Test>;
+	type TransportMessages = super::TransportMessages<Test>;
 	type WeightToFee = <Test as Config>::WeightToFee;
 
 	mod get {
 		use super::*;
 
+		#[test]
+		fn quote_matches_amounts_taken() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let weight = Weight::from_parts(100_000_000, 100_000_000);
+			let callback =
+				Callback::new(H160::zero(), Encoding::Scale, [1; 4], weight, 100_000_000);
+			let fee: Balance = u32::MAX.into();
+			let quote = quote_get::<Test>(Some(weight));
+			let endowment = existential_deposit() + quote.clone().total() + fee;
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), endowment)])
+				.build()
+				.execute_with(|| {
+					assert_ne!(quote.message_deposit, 0);
+					assert_eq!(quote.prepaid_fee, 0);
+					assert_eq!(quote.callback_deposit, WeightToFee::weight_to_fee(&weight));
+
+					assert_ok!(get::<Test>(origin.clone(), message(), fee, Some(callback)));
+
+					assert_eq!(
+						Fungibles::balance_on_hold(&Messaging.into(), &origin.account),
+						quote.message_deposit
+					);
+					assert_eq!(
+						Fungibles::balance_on_hold(&CallbackGas.into(), &origin.account),
+						quote.callback_deposit
+					);
+					assert_eq!(quote_get::<Test>(None).callback_deposit, 0);
+				})
+		}
+
 		#[test]
 		fn takes_deposit() {
 			let origin = Origin::from((ALICE_ADDR, ALICE));
@@ -325,11 +1173,11 @@ mod tests {
 				Callback::new(H160::zero(), Encoding::Scale, [1; 4], weight, 100_000_000);
 			let callback_deposit = WeightToFee::weight_to_fee(&weight);
 			let fee: Balance = u32::MAX.into();
-			let expected_deposit = calculate_protocol_deposit::<Test, OnChainByteFee>(
-				ProtocolStorageDeposit::IsmpRequests,
-			) + calculate_message_deposit::<Test, OnChainByteFee>() +
-				calculate_deposit_of::<Test, OffChainByteFee, GetState>() +
-				callback_deposit;
+			let expected_deposit =
+				calculate_transport_deposit::<Test, IsmpTransport, OnChainByteFee>() +
+					calculate_message_deposit::<Test, OnChainByteFee>() +
+					calculate_deposit_of::<Test, OffChainByteFee, GetState>() +
+					callback_deposit;
 			let endowment = existential_deposit() + expected_deposit + fee;
 			ExtBuilder::new()
 				.with_balances(vec![(origin.account.clone(), endowment)])
@@ -352,9 +1200,8 @@ mod tests {
 			let id = 1;
 			let fee: Balance = u32::MAX.into();
 			let callback = None;
-			let deposit = calculate_protocol_deposit::<Test, OnChainByteFee>(
-				ProtocolStorageDeposit::IsmpRequests,
-			) + calculate_message_deposit::<Test, OnChainByteFee>() +
+			let deposit = calculate_transport_deposit::<Test, IsmpTransport, OnChainByteFee>() +
+				calculate_message_deposit::<Test, OnChainByteFee>() +
 				calculate_deposit_of::<Test, OffChainByteFee, GetState>();
 			let endowment = existential_deposit() + deposit + fee;
 			ExtBuilder::new()
@@ -365,17 +1212,24 @@ mod tests {
 					let (id, commitment) =
 						get::<Test>(origin.clone(), message(), fee, callback).unwrap();
 
-					assert_eq!(IsmpRequests::get(commitment), Some(id));
-					let Some(Message::Ismp {
+					assert_eq!(
+						TransportMessages::get(MessageTransport::ismp::<Test>(commitment)),
+						Some(id)
+					);
+					let Some(Message::Pending {
 						origin: o,
-						commitment: c,
+						transport: t,
 						callback: cb,
 						message_deposit: d,
 					}) = Messages::get(id)
 					else {
 						panic!("wrong message type");
 					};
-					assert_eq!((o, c, cb, d), (origin, commitment, callback, deposit))
+					assert_eq!(
+						(o, t, cb, d),
+						(origin, MessageTransport::ismp::<Test>(commitment), callback, deposit)
+					);
+					assert_eq!(IsmpFees::get(commitment), Some(fee));
 				})
 		}
 
@@ -405,9 +1259,281 @@ mod tests {
 		}
 	}
 
+	mod get_batch {
+		use super::*;
+
+		#[test]
+		fn rejects_invalid_batches() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			ExtBuilder::new().build().execute_with(|| {
+				for requests in
+					[vec![], vec![get::message(); <MaxBatchSize as Get<u32>>::get() as usize + 1]]
+				{
+					assert_noop!(
+						get_batch::<Test>(origin.clone(), requests, 0, None),
+						Error::<Test>::InvalidBatch
+					);
+				}
+			})
+		}
+
+		#[test]
+		fn quote_matches_amounts_taken() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let weight = Weight::from_parts(100_000_000, 100_000_000);
+			let callback =
+				Callback::new(H160::zero(), Encoding::Scale, [1; 4], weight, 100_000_000);
+			let fee: Balance = u32::MAX.into();
+			let requests = <MaxBatchSize as Get<u32>>::get();
+			let quote = quote_get_batch::<Test>(requests, Some(weight));
+			let endowment = existential_deposit() + quote.clone().total() + fee * requests as u128;
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), endowment)])
+				.build()
+				.execute_with(|| {
+					assert!(
+						quote.message_deposit > quote_get_batch::<Test>(1, None).message_deposit
+					);
+					assert_eq!(quote.prepaid_fee, 0);
+					assert_eq!(quote.callback_deposit, WeightToFee::weight_to_fee(&weight));
+
+					assert_ok!(get_batch::<Test>(
+						origin.clone(),
+						vec![get::message(); requests as usize],
+						fee,
+						Some(callback)
+					));
+
+					assert_eq!(
+						Fungibles::balance_on_hold(&Messaging.into(), &origin.account),
+						quote.message_deposit
+					);
+					assert_eq!(
+						Fungibles::balance_on_hold(&CallbackGas.into(), &origin.account),
+						quote.callback_deposit
+					);
+				})
+		}
+
+		#[test]
+		fn assert_state() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let id = 1;
+			let fee: Balance = u32::MAX.into();
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+				.with_message_id(id)
+				.build()
+				.execute_with(|| {
+					let (id, commitments) =
+						get_batch::<Test>(origin.clone(), batch(), fee, None).unwrap();
+
+					let transport = MessageTransport::of::<Test, IsmpBatchTransport>(&id).unwrap();
+					assert_eq!(TransportMessages::get(&transport), Some(id));
+					assert!(matches!(
+						Messages::get(id),
+						Some(Message::Pending { origin: o, transport: t, callback: None, .. })
+							if o == origin && t == transport
+					));
+					assert_eq!(commitments.len(), 2);
+					for (position, commitment) in commitments.iter().enumerate() {
+						assert_eq!(IsmpBatchRequests::get(commitment), Some((id, position as u32)));
+						assert_eq!(IsmpFees::get(commitment), Some(fee));
+						// Requests of a batch are not messages in their own right.
+						assert_eq!(
+							TransportMessages::get(MessageTransport::ismp::<Test>(*commitment)),
+							None
+						);
+					}
+					assert_eq!(
+						poll_batch::<Test>(id),
+						(MessageStatus::Pending, vec![MessageStatus::Pending; 2])
+					);
+				})
+		}
+
+		#[test]
+		fn completes_once_all_requests_concluded() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let value = StorageValue { key: vec![1], value: Some(vec![2]) };
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+				.build()
+				.execute_with(|| {
+					let (id, commitments) =
+						get_batch::<Test>(origin.clone(), batch(), 0, None).unwrap();
+
+					assert_ok!(conclude_batch_request::<Test>(
+						&commitments[1],
+						Some(vec![value.clone()])
+					));
+					assert!(events().contains(&Event::IsmpGetResponseReceived {
+						dest: origin.address,
+						id,
+						commitment: commitments[1]
+					}));
+					assert_eq!(
+						poll_batch::<Test>(id),
+						(
+							MessageStatus::Pending,
+							vec![MessageStatus::Pending, MessageStatus::Complete]
+						)
+					);
+					assert!(IsmpBatchRequests::get(commitments[1]).is_none());
+					assert!(IsmpFees::get(commitments[1]).is_none());
+
+					// A request can only conclude once.
+					assert!(conclude_batch_request::<Test>(&commitments[1], None).is_err());
+
+					assert_ok!(timeout_commitment::<Test>(&commitments[0]));
+					assert!(events().contains(&Event::IsmpTimedOut { commitment: commitments[0] }));
+					assert!(events()
+						.contains(&Event::IsmpGetBatchCompleted { dest: origin.address, id }));
+
+					let expected = vec![
+						GetResult { status: MessageStatus::Timeout, values: vec![] },
+						GetResult { status: MessageStatus::Complete, values: vec![value] },
+					];
+					let Some(Message::Complete { response, .. }) = Messages::get(id) else {
+						panic!("wrong message type");
+					};
+					assert_eq!(Vec::<GetResult>::decode(&mut &response[..]).unwrap(), expected);
+					assert_eq!(
+						poll_batch::<Test>(id),
+						(
+							MessageStatus::Complete,
+							vec![MessageStatus::Timeout, MessageStatus::Complete]
+						)
+					);
+					assert!(IsmpBatches::get(id).is_none());
+					assert_eq!(IsmpBatchRequests::iter().count(), 0);
+				})
+		}
+
+		#[test]
+		fn oversized_response_fails_request() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let max_response_len = <MaxResponseLen as Get<u32>>::get() as usize;
+			let value = StorageValue { key: vec![1], value: Some(vec![2; max_response_len]) };
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+				.build()
+				.execute_with(|| {
+					let (id, commitments) =
+						get_batch::<Test>(origin.clone(), batch(), 0, None).unwrap();
+
+					// The values are discarded, with the batch still progressing.
+					assert_ok!(conclude_batch_request::<Test>(&commitments[1], Some(vec![value])));
+					assert_eq!(
+						poll_batch::<Test>(id),
+						(
+							MessageStatus::Pending,
+							vec![MessageStatus::Pending, MessageStatus::Failed]
+						)
+					);
+					assert!(IsmpBatchRequests::get(commitments[1]).is_none());
+
+					assert_ok!(timeout_commitment::<Test>(&commitments[0]));
+					let expected = vec![
+						GetResult { status: MessageStatus::Timeout, values: vec![] },
+						GetResult { status: MessageStatus::Failed, values: vec![] },
+					];
+					let Some(Message::Complete { response, .. }) = Messages::get(id) else {
+						panic!("wrong message type");
+					};
+					assert_eq!(Vec::<GetResult>::decode(&mut &response[..]).unwrap(), expected);
+				})
+		}
+
+		#[test]
+		fn completion_executes_callback() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let callback = Callback::new(H160::zero(), Encoding::Scale, [1; 4], 100.into(), 100);
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+				.build()
+				.execute_with(|| {
+					let (id, commitments) =
+						get_batch::<Test>(origin.clone(), batch(), 0, Some(callback)).unwrap();
+
+					for commitment in &commitments {
+						assert_ok!(conclude_batch_request::<Test>(commitment, Some(vec![])));
+					}
+
+					assert!(events()
+						.contains(&Event::IsmpGetBatchCompleted { dest: origin.address, id }));
+					// The callback succeeded, so the message is removed and its deposit released.
+					assert!(Messages::get(id).is_none());
+					assert_eq!(Fungibles::balance_on_hold(&Messaging.into(), &origin.account), 0);
+				})
+		}
+
+		#[test]
+		fn response_exceeding_max_len_fails() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let value = StorageValue {
+				key: vec![1; <MaxResponseLen as Get<u32>>::get() as usize],
+				value: None,
+			};
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+				.build()
+				.execute_with(|| {
+					let (id, commitments) =
+						get_batch::<Test>(origin.clone(), batch(), 0, None).unwrap();
+
+					let err = conclude_batch_request::<Test>(&commitments[0], Some(vec![value]))
+						.unwrap_err();
+					assert_eq!(
+						err.downcast::<IsmpError>().unwrap(),
+						IsmpError::Custom("Response length exceeds maximum allowed length.".into())
+					);
+					assert_eq!(
+						poll_batch::<Test>(id),
+						(MessageStatus::Pending, vec![MessageStatus::Pending; 2])
+					);
+				})
+		}
+
+		fn batch() -> Vec<DispatchGet> {
+			vec![get::message(); 2]
+		}
+	}
+
 	mod post {
 		use super::*;
 
+		#[test]
+		fn quote_matches_amounts_taken() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let weight = Weight::from_parts(100_000_000, 100_000_000);
+			let callback =
+				Callback::new(H160::zero(), Encoding::Scale, [1; 4], weight, 100_000_000);
+			let fee: Balance = u32::MAX.into();
+			let quote = quote_post::<Test>(Some(weight));
+			let endowment = existential_deposit() + quote.clone().total() + fee;
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), endowment)])
+				.build()
+				.execute_with(|| {
+					assert_ne!(quote.message_deposit, 0);
+					assert_eq!(quote.prepaid_fee, 0);
+					assert_eq!(quote.callback_deposit, WeightToFee::weight_to_fee(&weight));
+
+					assert_ok!(post::<Test>(origin.clone(), message(), fee, Some(callback)));
+
+					assert_eq!(
+						Fungibles::balance_on_hold(&Messaging.into(), &origin.account),
+						quote.message_deposit
+					);
+					assert_eq!(
+						Fungibles::balance_on_hold(&CallbackGas.into(), &origin.account),
+						quote.callback_deposit
+					);
+					assert_eq!(quote_post::<Test>(None).callback_deposit, 0);
+				})
+		}
+
 		#[test]
 		fn takes_deposit() {
 			let origin = Origin::from((ALICE_ADDR, ALICE));
@@ -416,12 +1542,15 @@ mod tests {
 				Callback::new(H160::zero(), Encoding::Scale, [1; 4], weight, 100_000_000);
 			let callback_deposit = <Test as Config>::WeightToFee::weight_to_fee(&weight);
 			let fee: Balance = u32::MAX.into();
-			let expected_deposit =
-				calculate_protocol_deposit::<Test, <Test as Config>::OnChainByteFee>(
-					ProtocolStorageDeposit::IsmpRequests,
-				) + calculate_message_deposit::<Test, <Test as Config>::OnChainByteFee>() +
-					calculate_deposit_of::<Test, OffChainByteFee, PostState>() +
-					callback_deposit;
+			let expected_deposit = calculate_transport_deposit::<
+				Test,
+				IsmpTransport,
+				<Test as Config>::OnChainByteFee,
+			>() + calculate_message_deposit::<
+				Test,
+				<Test as Config>::OnChainByteFee,
+			>() + calculate_deposit_of::<Test, OffChainByteFee, PostState>() +
+				callback_deposit;
 			let endowment = existential_deposit() + expected_deposit + fee;
 			ExtBuilder::new()
 				.with_balances(vec![(origin.account.clone(), endowment)])
@@ -445,9 +1574,8 @@ mod tests {
 			let id = 1;
 			let fee: Balance = u32::MAX.into();
 			let callback = None;
-			let deposit = calculate_protocol_deposit::<Test, OnChainByteFee>(
-				ProtocolStorageDeposit::IsmpRequests,
-			) + calculate_message_deposit::<Test, OnChainByteFee>() +
+			let deposit = calculate_transport_deposit::<Test, IsmpTransport, OnChainByteFee>() +
+				calculate_message_deposit::<Test, OnChainByteFee>() +
 				calculate_deposit_of::<Test, OffChainByteFee, PostState>();
 			let endowment = existential_deposit() + deposit + fee;
 			ExtBuilder::new()
@@ -458,37 +1586,151 @@ mod tests {
 					let (id, commitment) =
 						post::<Test>(origin.clone(), message(), fee, callback).unwrap();
 
-					assert_eq!(IsmpRequests::get(commitment), Some(id));
-					let Some(Message::Ismp {
-						origin: o,
-						commitment: c,
-						callback: cb,
-						message_deposit: d,
-					}) = Messages::get(id)
-					else {
-						panic!("wrong message type");
-					};
-					assert_eq!((o, c, cb, d), (origin, commitment, callback, deposit))
+					assert_eq!(
+						TransportMessages::get(MessageTransport::ismp::<Test>(commitment)),
+						Some(id)
+					);
+					let Some(Message::Pending {
+						origin: o,
+						transport: t,
+						callback: cb,
+						message_deposit: d,
+					}) = Messages::get(id)
+					else {
+						panic!("wrong message type");
+					};
+					assert_eq!(
+						(o, t, cb, d),
+						(origin, MessageTransport::ismp::<Test>(commitment), callback, deposit)
+					);
+					assert_eq!(IsmpFees::get(commitment), Some(fee));
+				})
+		}
+
+		#[test]
+		fn max_len_works() {
+			let post = message();
+			assert_eq!(
+				PostState::max_encoded_len(),
+				(post.dest, post.from, post.to, post.timeout, post.body).encode().len()
+			)
+		}
+
+		pub(super) fn message() -> DispatchPost {
+			DispatchPost {
+				dest: StateMachine::Polkadot(u32::MAX),
+				from: ID.to_vec(),
+				to: ID.to_vec(),
+				timeout: u64::MAX,
+				body: vec![255; <MaxDataLen as Get<u32>>::get() as usize],
+			}
+		}
+	}
+
+	mod fund {
+		use super::*;
+
+		#[test]
+		fn message_not_found() {
+			ExtBuilder::new().build().execute_with(|| {
+				assert_noop!(
+					fund::<Test>(Origin::from((ALICE_ADDR, ALICE)), 0, 1),
+					Error::<Test>::MessageNotFound
+				);
+			})
+		}
+
+		#[test]
+		fn only_origin_can_fund() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let id = 1;
+			let message = Message::ismp(origin, [1u8; 32].into(), None, 100);
+			ExtBuilder::new().build().execute_with(|| {
+				Messages::insert(id, &message);
+
+				assert_noop!(fund::<Test>(Origin::from((BOB_ADDR, BOB)), id, 1), BadOrigin);
+			})
+		}
+
+		#[test]
+		fn concluded_messages_cannot_be_funded() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let commitment: H256 = [1u8; 32].into();
+			ExtBuilder::new().build().execute_with(|| {
+				for (id, message, error) in [
+					(
+						1,
+						Message::ismp_response(
+							origin.address,
+							commitment,
+							100,
+							BoundedVec::default(),
+						),
+						Error::<Test>::MessageCompleted,
+					),
+					(
+						2,
+						Message::ismp_timeout(origin.address, commitment, 100, None),
+						Error::<Test>::RequestTimedOut,
+					),
+					(
+						3,
+						Message::xcm_query(origin.clone(), 0, None, 100),
+						Error::<Test>::InvalidMessage,
+					),
+				] {
+					Messages::insert(id, &message);
+
+					assert_noop!(fund::<Test>(origin.clone(), id, 1), error);
+				}
+			})
+		}
+
+		#[test]
+		fn funds_request() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let fee: Balance = u32::MAX.into();
+			let amount: Balance = u16::MAX.into();
+			let deposit = calculate_transport_deposit::<Test, IsmpTransport, OnChainByteFee>() +
+				calculate_message_deposit::<Test, OnChainByteFee>() +
+				calculate_deposit_of::<Test, OffChainByteFee, GetState>();
+			let endowment = existential_deposit() + deposit + fee + amount * 2;
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), endowment)])
+				.build()
+				.execute_with(|| {
+					let (id, commitment) =
+						get::<Test>(origin.clone(), get::message(), fee, None).unwrap();
+					let balance = Balances::free_balance(&origin.account);
+
+					assert_eq!(
+						fund::<Test>(origin.clone(), id, amount),
+						Ok((commitment, fee + amount))
+					);
+					assert_eq!(
+						fund::<Test>(origin.clone(), id, amount),
+						Ok((commitment, fee + amount * 2))
+					);
+
+					assert_eq!(Balances::free_balance(&origin.account), balance - amount * 2);
+					assert_eq!(IsmpFees::get(commitment), Some(fee + amount * 2));
 				})
 		}
 
 		#[test]
-		fn max_len_works() {
-			let post = message();
-			assert_eq!(
-				PostState::max_encoded_len(),
-				(post.dest, post.from, post.to, post.timeout, post.body).encode().len()
-			)
-		}
+		fn unknown_request_cannot_be_funded() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let id = 1;
+			let message = Message::ismp(origin.clone(), [1u8; 32].into(), None, 100);
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), existential_deposit() + 100)])
+				.build()
+				.execute_with(|| {
+					Messages::insert(id, &message);
 
-		pub(super) fn message() -> DispatchPost {
-			DispatchPost {
-				dest: StateMachine::Polkadot(u32::MAX),
-				from: ID.to_vec(),
-				to: ID.to_vec(),
-				timeout: u64::MAX,
-				body: vec![255; <MaxDataLen as Get<u32>>::get() as usize],
-			}
+					assert!(fund::<Test>(origin, id, 1).is_err());
+					assert_eq!(Messages::get(id), Some(message));
+				})
 		}
 	}
 
@@ -507,6 +1749,273 @@ mod tests {
 					assert!(IsmpModule::on_accept(&module, post_request(100usize)).is_ok())
 				})
 			}
+
+			#[test]
+			fn invalid_recipient_fails() {
+				ExtBuilder::new().build().execute_with(|| {
+					for to in [
+						[1u8; 32].to_vec(),
+						ID.to_vec(),
+						to(Encoding::Scale)[..ID.len() + 20].to_vec(),
+						[to(Encoding::Scale), vec![0]].concat(),
+					] {
+						let request = PostRequest { to, ..post_request(100) };
+						let err = accept::<Test>(request).unwrap_err();
+						assert_eq!(
+							err.downcast::<IsmpError>().unwrap(),
+							IsmpError::Custom("Invalid recipient.".to_string())
+						);
+					}
+				})
+			}
+
+			#[test]
+			fn body_exceeds_max_len_fails() {
+				ExtBuilder::new().build().execute_with(|| {
+					let request = PostRequest {
+						to: to(Encoding::Scale),
+						body: vec![1u8; <MaxDataLen as Get<u32>>::get() as usize + 1],
+						..post_request(0)
+					};
+					let err = accept::<Test>(request).unwrap_err();
+					assert_eq!(
+						err.downcast::<IsmpError>().unwrap(),
+						IsmpError::Custom(
+							"Request body exceeds maximum allowed length.".to_string()
+						)
+					);
+				})
+			}
+
+			#[test]
+			fn source_not_allowed_fails() {
+				let account = account();
+				ExtBuilder::new()
+					.with_balances(vec![(account.clone(), UNIT)])
+					.build()
+					.execute_with(|| {
+						// Requests from other sources remain rejected.
+						IsmpPostRequestSources::insert(
+							DEST,
+							source_key::<Test>(&StateMachine::Polkadot(SOURCE + 1), &FROM),
+							0,
+						);
+						let request = PostRequest { to: to(Encoding::Scale), ..post_request(100) };
+						let err = accept::<Test>(request).unwrap_err();
+						assert_eq!(
+							err.downcast::<IsmpError>().unwrap(),
+							IsmpError::Custom("Source not allowed.".to_string())
+						);
+						assert_eq!(Balances::free_balance(&account), UNIT);
+						assert!(events().is_empty());
+					})
+			}
+
+			#[test]
+			fn module_not_allowed_fails() {
+				let account = account();
+				ExtBuilder::new()
+					.with_balances(vec![(account.clone(), UNIT)])
+					.build()
+					.execute_with(|| {
+						// Requests from other modules on an allowed source remain rejected.
+						allow_source();
+						let request = PostRequest {
+							to: to(Encoding::Scale),
+							from: [2u8; 32].to_vec(),
+							..post_request(100)
+						};
+						let err = accept::<Test>(request).unwrap_err();
+						assert_eq!(
+							err.downcast::<IsmpError>().unwrap(),
+							IsmpError::Custom("Source not allowed.".to_string())
+						);
+						assert_eq!(Balances::free_balance(&account), UNIT);
+						assert!(events().is_empty());
+					})
+			}
+
+			#[test]
+			fn allowed_sources_work() {
+				let account = account();
+				for source in [
+					StateMachine::Polkadot(SOURCE),
+					StateMachine::Kusama(SOURCE),
+					StateMachine::Evm(1),
+				] {
+					ExtBuilder::new()
+						.with_balances(vec![(account.clone(), UNIT)])
+						.build()
+						.execute_with(|| {
+							IsmpPostRequestSources::insert(
+								DEST,
+								source_key::<Test>(&source, &FROM),
+								0,
+							);
+							let request = PostRequest {
+								source,
+								to: to(Encoding::Scale),
+								..post_request(100)
+							};
+							let commitment = hash_request::<<Test as Config>::Keccak256>(
+								&Request::Post(request.clone()),
+							);
+
+							assert_ok!(accept::<Test>(request));
+
+							assert_eq!(
+								events(),
+								vec![Event::IsmpPostRequestReceived { dest: DEST, commitment }]
+							);
+						});
+				}
+			}
+
+			#[test]
+			fn recipient_cannot_pay_fails() {
+				ExtBuilder::new().build().execute_with(|| {
+					allow_source();
+					let request = PostRequest { to: to(Encoding::Scale), ..post_request(100) };
+					let err = accept::<Test>(request).unwrap_err();
+					assert_eq!(
+						err.downcast::<IsmpError>().unwrap(),
+						IsmpError::Custom("Recipient cannot pay for delivery.".to_string())
+					);
+					assert!(events().is_empty());
+				})
+			}
+
+			#[test]
+			fn charges_recipient() {
+				let account = account();
+				let gas_limit = IsmpPostRequestGasLimit::get();
+				let fee = WeightToFee::weight_to_fee(&(gas_limit / 2));
+				ExtBuilder::new()
+					.with_balances(vec![(account.clone(), existential_deposit() + fee * 2)])
+					.build()
+					.execute_with(|| {
+						allow_source();
+						let request = PostRequest { to: to(Encoding::Scale), ..post_request(100) };
+						let commitment = hash_request::<<Test as Config>::Keccak256>(
+							&Request::Post(request.clone()),
+						);
+
+						assert_ok!(accept::<Test>(request));
+
+						assert_eq!(Balances::free_balance(&account), existential_deposit() + fee);
+						assert_eq!(Fungibles::total_balance_on_hold(&account), 0);
+						assert_eq!(
+							events(),
+							vec![Event::IsmpPostRequestReceived { dest: DEST, commitment }]
+						);
+					})
+			}
+
+			#[test]
+			fn dispatches_response() {
+				let account = account();
+				for encoding in [Encoding::Scale, Encoding::SolidityAbi] {
+					let response = vec![255u8; 10];
+					CallbackOutput::set(match encoding {
+						Encoding::Scale => codec::Encode::encode(&response),
+						Encoding::SolidityAbi =>
+							IPostRequest::onPostRequestCall::abi_encode_returns(&response.into()),
+					});
+					ExtBuilder::new()
+						.with_balances(vec![(account.clone(), UNIT)])
+						.build()
+						.execute_with(|| {
+							allow_source();
+							let request = PostRequest { to: to(encoding), ..post_request(100) };
+							let commitment = hash_request::<<Test as Config>::Keccak256>(
+								&Request::Post(request.clone()),
+							);
+							// Request receipt stored by the ISMP handler before the request is
+							// accepted.
+							assert_ok!(Ismp::default()
+								.store_request_receipt(&Request::Post(request.clone()), &vec![]));
+
+							assert_ok!(accept::<Test>(request));
+
+							assert!(events().contains(&Event::IsmpPostResponseDispatched {
+								origin: DEST,
+								commitment
+							}));
+						});
+				}
+				CallbackOutput::set(Vec::default());
+			}
+
+			#[test]
+			fn empty_response_not_dispatched() {
+				let account = account();
+				ExtBuilder::new()
+					.with_balances(vec![(account.clone(), UNIT)])
+					.build()
+					.execute_with(|| {
+						allow_source();
+						let request = PostRequest { to: to(Encoding::Scale), ..post_request(100) };
+						let commitment = hash_request::<<Test as Config>::Keccak256>(
+							&Request::Post(request.clone()),
+						);
+						assert_ok!(Ismp::default()
+							.store_request_receipt(&Request::Post(request.clone()), &vec![]));
+						CallbackOutput::set(codec::Encode::encode(&Vec::<u8>::default()));
+
+						assert_ok!(accept::<Test>(request));
+
+						assert_eq!(
+							events(),
+							vec![Event::IsmpPostRequestReceived { dest: DEST, commitment }]
+						);
+						CallbackOutput::set(Vec::default());
+					})
+			}
+
+			#[test]
+			fn is_module_id_works() {
+				assert!(is_module_id(&ID));
+				for encoding in [Encoding::Scale, Encoding::SolidityAbi] {
+					assert!(is_module_id(&to(encoding)));
+				}
+				for id in [
+					b"po".to_vec(),
+					b"popular".to_vec(),
+					to(Encoding::Scale)[..ID.len() + 20].to_vec(),
+					[to(Encoding::Scale), vec![0]].concat(),
+					[ID.to_vec(), (DEST, 2u8).encode()].concat(),
+				] {
+					assert!(!is_module_id(&id));
+				}
+			}
+
+			#[test]
+			fn recipient_works() {
+				for encoding in [Encoding::Scale, Encoding::SolidityAbi] {
+					assert_eq!(recipient(&to(encoding)), Some((DEST, encoding)));
+				}
+			}
+
+			const DEST: H160 = H160([2u8; 20]);
+			// The source parachain and sending module of `post_request`.
+			const SOURCE: u32 = 2000;
+			const FROM: [u8; 32] = [1u8; 32];
+
+			fn account() -> AccountId {
+				<Test as pallet_revive::Config>::AddressMapper::to_account_id(&DEST)
+			}
+
+			fn allow_source() {
+				IsmpPostRequestSources::insert(
+					DEST,
+					source_key::<Test>(&StateMachine::Polkadot(SOURCE), &FROM),
+					0,
+				);
+			}
+
+			fn to(encoding: Encoding) -> Vec<u8> {
+				[ID.to_vec(), (DEST, encoding).encode()].concat()
+			}
 		}
 
 		mod timeout_commitment {
@@ -532,7 +2041,7 @@ mod tests {
 				let commitment: H256 = [1u8; 32].into();
 				let message = Message::xcm_query(origin, 0, None, 100);
 				ExtBuilder::new().build().execute_with(|| {
-					IsmpRequests::insert(commitment, id);
+					TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), id);
 					Messages::insert(id, &message);
 
 					let err = timeout_commitment::<Test>(&commitment).unwrap_err();
@@ -551,18 +2060,18 @@ mod tests {
 				let message_deposit = 100;
 				let message = Message::ismp(origin, commitment, None, message_deposit);
 				ExtBuilder::new().build().execute_with(|| {
-					IsmpRequests::insert(commitment, id);
+					TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), id);
 					Messages::insert(id, &message);
 
 					let res = timeout_commitment::<Test>(&commitment);
 
 					assert!(res.is_ok(), "{:?}", res.unwrap_err().downcast::<IsmpError>().unwrap());
 
-					if let Some(Message::IsmpTimeout { commitment, .. }) = Messages::get(id) {
-						assert!(events().contains(&Event::IsmpTimedOut { commitment }))
-					} else {
+					let Some(Message::Timeout { .. }) = Messages::get(id) else {
 						panic!("Message not timed out.")
-					}
+					};
+					assert!(events().contains(&Event::IsmpTimedOut { commitment }));
+					assert!(IsmpFees::get(commitment).is_none());
 				})
 			}
 
@@ -591,7 +2100,7 @@ mod tests {
 						));
 						let post_hold = Balances::free_balance(&origin.account);
 
-						IsmpRequests::insert(commitment, id);
+						TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), id);
 						Messages::insert(id, message);
 
 						let res = process_response::<Test>(&commitment, response, |dest, id| {
@@ -630,6 +2139,40 @@ mod tests {
 				})
 			}
 
+			#[test]
+			fn response_exceeds_max_len_once_encoded_for_callback() {
+				let origin = Origin::from((ALICE_ADDR, ALICE));
+				let commitment = H256::default();
+				let id = 1;
+				let callback = Callback::new(
+					H160::zero(),
+					Encoding::SolidityAbi,
+					[1; 4],
+					Weight::from_parts(100_000_000, 100_000_000),
+					100_000,
+				);
+				// Within the limit when SCALE encoded, but not once padded by the Solidity ABI.
+				let response = vec![1u8; <MaxResponseLen as Get<u32>>::get() as usize - 2];
+				let message = Message::ismp(origin, commitment, Some(callback), 100);
+				ExtBuilder::new().build().execute_with(|| {
+					TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), id);
+					Messages::insert(id, message.clone());
+
+					let err = process_response::<Test>(&commitment, response, |dest, id| {
+						Event::IsmpPostResponseReceived { dest, id, commitment }
+					})
+					.unwrap_err();
+
+					assert_eq!(
+						err.downcast::<IsmpError>().unwrap(),
+						IsmpError::Custom(
+							"Response length exceeds maximum allowed length.".to_string()
+						)
+					);
+					assert_eq!(Messages::get(id), Some(message));
+				})
+			}
+
 			#[test]
 			fn request_not_found() {
 				let commitment = H256::zero();
@@ -655,7 +2198,7 @@ mod tests {
 				let id = 1;
 				let message = Message::ismp_response(origin.0, commitment, 100, response);
 				ExtBuilder::new().build().execute_with(|| {
-					IsmpRequests::insert(commitment, id);
+					TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), id);
 					Messages::insert(id, message);
 
 					let err = process_response::<Test>(&commitment, vec![1u8], |dest, id| {
@@ -677,7 +2220,7 @@ mod tests {
 				let id = 1;
 				let message = Message::ismp(origin, commitment, None, 100);
 				ExtBuilder::new().build().execute_with(|| {
-					IsmpRequests::insert(commitment, id);
+					TransportMessages::insert(MessageTransport::ismp::<Test>(commitment), id);
 					Messages::insert(id, message);
 
 					let res = process_response::<Test>(&commitment, response, |dest, id| {
@@ -686,13 +2229,31 @@ mod tests {
 
 					assert!(res.is_ok(), "process_response failed");
 
-					let Some(Message::IsmpResponse { .. }) = Messages::get(id) else {
+					let Some(Message::Complete { .. }) = Messages::get(id) else {
 						panic!("wrong message type.")
 					};
 				})
 			}
 		}
 
+		#[test]
+		fn rejected_whilst_migrating() {
+			ExtBuilder::new().build().execute_with(|| {
+				StorageVersion::new(0).put::<Pallet<Test>>();
+				let response =
+					PostResponse { post: post_request(0), response: vec![], timeout_timestamp: 0 };
+				for result in [
+					module().on_response(Response::Post(response)),
+					module().on_timeout(Timeout::Request(Request::Post(post_request(0)))),
+				] {
+					assert_eq!(
+						result.unwrap_err().downcast::<IsmpError>().unwrap(),
+						IsmpError::Custom("Messaging is being migrated.".into())
+					);
+				}
+			})
+		}
+
 		fn module() -> ismp::Module<Test> {
 			ismp::Module::<Test>::new()
 		}
@@ -710,6 +2271,346 @@ mod tests {
 		}
 	}
 
+	mod subscription {
+		use ::ismp::consensus::StateMachineId;
+
+		use super::*;
+		use crate::{
+			messaging::{
+				dispatch_subscriptions, Event, Pallet, SubscriptionSchedule,
+				SubscriptionScheduleQueue, Subscriptions,
+			},
+			mock::messaging::MinSubscriptionInterval,
+		};
+
+		const DEST: u32 = 1_000;
+
+		#[test]
+		fn subscribe_rejects_invalid_subscriptions() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			ExtBuilder::new().build().execute_with(|| {
+				for (interval, executions) in [
+					(MinSubscriptionInterval::get(), 0),
+					(0, 1),
+					(MinSubscriptionInterval::get() - 1, 1),
+				] {
+					assert_noop!(
+						subscribe::<Test>(
+							origin.clone(),
+							request(),
+							interval,
+							executions,
+							0,
+							callback()
+						),
+						Error::<Test>::InvalidSubscription
+					);
+				}
+			})
+		}
+
+		#[test]
+		fn quote_matches_amounts_taken() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let fee: Balance = 100;
+			let executions = 3;
+			let quote = quote_subscribe::<Test>(executions, fee, callback().gas_limit);
+			let endowment = existential_deposit() + quote.clone().total();
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), endowment)])
+				.build()
+				.execute_with(|| {
+					let interval = MinSubscriptionInterval::get();
+					assert_ok!(subscribe::<Test>(
+						origin.clone(),
+						request(),
+						interval,
+						executions,
+						fee,
+						callback()
+					));
+
+					assert_eq!(Pallet::<Test>::held_deposit(&origin.account), quote.total());
+				})
+		}
+
+		#[test]
+		fn subscribe_schedules_first_execution() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+				.build()
+				.execute_with(|| {
+					let interval = MinSubscriptionInterval::get();
+					let id =
+						subscribe::<Test>(origin, request(), interval, 2, 100, callback()).unwrap();
+
+					let next = System::block_number() + interval;
+					assert!(SubscriptionSchedule::<Test>::contains_key(next, id));
+					assert_eq!(Subscriptions::<Test>::get(id).map(|s| s.next), Some(next));
+				})
+		}
+
+		#[test]
+		fn cancel_reverts_when_not_origin() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+				.build()
+				.execute_with(|| {
+					let id = subscribe::<Test>(
+						origin,
+						request(),
+						MinSubscriptionInterval::get(),
+						2,
+						100,
+						callback(),
+					)
+					.unwrap();
+
+					assert_noop!(
+						cancel_subscription::<Test>(Origin::from((BOB_ADDR, BOB)), id),
+						BadOrigin
+					);
+				})
+		}
+
+		#[test]
+		fn cancel_removes_schedule_and_releases_deposits() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+				.build()
+				.execute_with(|| {
+					let interval = MinSubscriptionInterval::get();
+					let id =
+						subscribe::<Test>(origin.clone(), request(), interval, 2, 100, callback())
+							.unwrap();
+
+					assert_ok!(cancel_subscription::<Test>(origin.clone(), id));
+
+					assert!(Subscriptions::<Test>::get(id).is_none());
+					assert_eq!(SubscriptionSchedule::<Test>::iter().count(), 0);
+					assert_eq!(Pallet::<Test>::held_deposit(&origin.account), 0);
+					// Nothing is dispatched once the subscription would have been due.
+					run_to(System::block_number() + interval);
+					dispatch_subscriptions::<Test>(Weight::MAX);
+					assert!(Messages::iter().next().is_none());
+				})
+		}
+
+		#[test]
+		fn dispatches_request_when_due() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let fee = 100;
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+				.build()
+				.execute_with(|| {
+					set_latest_height(10);
+					let interval = MinSubscriptionInterval::get();
+					let id =
+						subscribe::<Test>(origin.clone(), request(), interval, 2, fee, callback())
+							.unwrap();
+					let held = Pallet::<Test>::held_deposit(&origin.account);
+					let next = System::block_number() + interval;
+
+					// Nothing is dispatched before the subscription is due.
+					dispatch_subscriptions::<Test>(Weight::MAX);
+					assert!(Messages::iter().next().is_none());
+
+					run_to(next);
+					events();
+					dispatch_subscriptions::<Test>(Weight::MAX);
+
+					let Some((message, Message::Pending { transport, callback: cb, .. })) =
+						Messages::iter().next()
+					else {
+						panic!("request should have been dispatched")
+					};
+					let commitment = transport.key::<Test, IsmpTransport>().unwrap();
+					assert_eq!((cb, IsmpFees::get(commitment)), (Some(callback()), Some(fee)));
+					assert_eq!(
+						TransportMessages::get(MessageTransport::ismp::<Test>(commitment)),
+						Some(message)
+					);
+					assert_eq!(
+						events(),
+						vec![Event::SubscriptionDispatched {
+							origin: origin.address,
+							subscription: id,
+							id: message,
+							commitment
+						}]
+					);
+					// The fee is paid to relayers, with the deposits for the request now held by
+					// the message.
+					assert_eq!(Pallet::<Test>::held_deposit(&origin.account), held - fee);
+					let subscription = Subscriptions::<Test>::get(id).unwrap();
+					assert_eq!(subscription.remaining, 1);
+					assert_eq!(subscription.next, next + interval);
+					assert!(SubscriptionSchedule::<Test>::contains_key(next + interval, id));
+				})
+		}
+
+		#[test]
+		fn final_execution_removes_subscription() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let fee = 100;
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+				.build()
+				.execute_with(|| {
+					set_latest_height(10);
+					let interval = MinSubscriptionInterval::get();
+					let id =
+						subscribe::<Test>(origin.clone(), request(), interval, 1, fee, callback())
+							.unwrap();
+					let MessageQuote { message_deposit, callback_deposit, .. } =
+						quote_get::<Test>(Some(callback().gas_limit));
+
+					run_to(System::block_number() + interval);
+					events();
+					dispatch_subscriptions::<Test>(Weight::MAX);
+
+					assert!(matches!(
+						events().as_slice(),
+						[
+							Event::SubscriptionDispatched { subscription, .. },
+							Event::SubscriptionExhausted { subscription: s, .. }
+						] if *subscription == id && *s == id
+					));
+					assert!(Subscriptions::<Test>::get(id).is_none());
+					assert_eq!(SubscriptionSchedule::<Test>::iter().count(), 0);
+					assert_eq!(
+						Fungibles::balance_on_hold(&Subscription.into(), &origin.account),
+						0
+					);
+					// Only the deposits of the dispatched request remain held.
+					assert_eq!(
+						Pallet::<Test>::held_deposit(&origin.account),
+						message_deposit + callback_deposit
+					);
+				})
+		}
+
+		#[test]
+		fn retries_when_height_unknown() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 1 * UNIT)])
+				.build()
+				.execute_with(|| {
+					let interval = MinSubscriptionInterval::get();
+					let id =
+						subscribe::<Test>(origin.clone(), request(), interval, 2, 100, callback())
+							.unwrap();
+					let held = Pallet::<Test>::held_deposit(&origin.account);
+					let next = System::block_number() + interval;
+
+					run_to(next);
+					events();
+					dispatch_subscriptions::<Test>(Weight::MAX);
+
+					assert_eq!(
+						events(),
+						vec![Event::SubscriptionDispatchFailed {
+							origin: origin.address,
+							subscription: id,
+							error: Error::<Test>::IsmpDispatchFailed.into()
+						}]
+					);
+					assert!(Messages::iter().next().is_none());
+					assert_eq!(Pallet::<Test>::held_deposit(&origin.account), held);
+					let subscription = Subscriptions::<Test>::get(id).unwrap();
+					assert_eq!(subscription.remaining, 2);
+					assert!(SubscriptionSchedule::<Test>::contains_key(next + interval, id));
+				})
+		}
+
+		#[test]
+		fn dispatch_respects_weight_limit() {
+			let origin = Origin::from((ALICE_ADDR, ALICE));
+			let subscriptions = 3;
+			ExtBuilder::new()
+				.with_balances(vec![(origin.account.clone(), 10 * UNIT)])
+				.build()
+				.execute_with(|| {
+					set_latest_height(10);
+					let interval = MinSubscriptionInterval::get();
+					for _ in 0..subscriptions {
+						subscribe::<Test>(origin.clone(), request(), interval, 2, 100, callback())
+							.unwrap();
+					}
+					let next = System::block_number() + interval;
+					run_to(next);
+
+					// Sufficient for a single execution per call.
+					let db_weight = <Test as frame_system::Config>::DbWeight::get();
+					let weight_limit = db_weight
+						.reads(1)
+						.saturating_add(<Test as Config>::WeightInfo::ismp_dispatch_subscription())
+						.saturating_add(db_weight.reads_writes(2, 1))
+						.saturating_add(db_weight.writes(1));
+					for processed in 1..=subscriptions {
+						let weight = dispatch_subscriptions::<Test>(weight_limit);
+
+						assert!(weight.all_lte(weight_limit));
+						assert_eq!(
+							SubscriptionSchedule::<Test>::iter_key_prefix(next).count() as u64,
+							subscriptions - processed
+						);
+					}
+					assert_eq!(SubscriptionScheduleQueue::<Test>::get(), (0, 1));
+
+					// The block is removed from the queue once all its executions have been
+					// processed.
+					dispatch_subscriptions::<Test>(weight_limit);
+					assert_eq!(SubscriptionScheduleQueue::<Test>::get(), (1, 1));
+					assert_eq!(Messages::iter().count() as u64, subscriptions);
+				})
+		}
+
+		fn callback() -> Callback<Balance> {
+			Callback::new(
+				H160::zero(),
+				Encoding::Scale,
+				[1; 4],
+				Weight::from_parts(100_000_000, 100_000_000),
+				100_000_000,
+			)
+		}
+
+		fn request() -> GetTemplate<Test> {
+			GetTemplate {
+				dest: DEST,
+				timeout: u64::MAX,
+				context: BoundedVec::truncate_from(vec![
+					255;
+					<MaxContextLen as Get<u32>>::get() as usize
+				]),
+				keys: BoundedVec::truncate_from(vec![
+					BoundedVec::truncate_from(vec![
+						255;
+						<MaxKeyLen as Get<u32>>::get()
+							as usize
+					]);
+					<MaxKeys as Get<u32>>::get() as usize
+				]),
+			}
+		}
+
+		fn set_latest_height(height: u64) {
+			pallet_ismp::LatestStateMachineHeight::<Test>::insert(
+				StateMachineId {
+					state_id: StateMachine::Polkadot(DEST),
+					consensus_state_id: *b"PARA",
+				},
+				height,
+			);
+		}
+	}
+
 	mod weight {
 		use ::ismp::router::GetRequest;
 
@@ -724,28 +2625,55 @@ mod tests {
 			assert_eq!(
 				IsmpModuleWeight::on_accept(&Module::new(), &ismp_hooks::post_request(100)),
 				WeightInfo::ismp_on_accept()
+					.saturating_add(CallbackExecutor::execution_weight())
+					.saturating_add(IsmpPostRequestGasLimit::get())
 			)
 		}
 
 		#[test]
 		fn on_response() {
 			let module = Module::new();
-			for (response, x) in
-				[(Response::Get(get_response()), 0), (Response::Post(post_response()), 1)]
-			{
-				assert_eq!(
-					IsmpModuleWeight::on_response(&module, &response),
-					WeightInfo::ismp_on_response(x)
-						.saturating_add(CallbackExecutor::execution_weight())
-				);
-			}
+			let response = get_response();
+			assert_eq!(
+				IsmpModuleWeight::on_response(&module, &Response::Get(response.clone())),
+				WeightInfo::ismp_on_response(0, response.values.encoded_size() as u32)
+					.max(WeightInfo::ismp_conclude_batch_request(0))
+					.saturating_add(CallbackExecutor::execution_weight())
+			);
+			let response = post_response();
+			assert_eq!(
+				IsmpModuleWeight::on_response(&module, &Response::Post(response.clone())),
+				WeightInfo::ismp_on_response(1, response.response.encoded_size() as u32)
+					.saturating_add(CallbackExecutor::execution_weight())
+			);
+		}
+
+		#[test]
+		fn on_response_charges_at_most_max_response_len() {
+			let module = Module::new();
+			let max_len = <MaxResponseLen as Get<u32>>::get();
+			let response =
+				PostResponse { response: vec![1u8; max_len as usize * 2], ..post_response() };
+			assert_eq!(
+				IsmpModuleWeight::on_response(&module, &Response::Post(response)),
+				WeightInfo::ismp_on_response(1, max_len)
+					.saturating_add(CallbackExecutor::execution_weight())
+			);
 		}
 
 		#[test]
 		fn on_timeout() {
 			let module = Module::new();
+			assert_eq!(
+				IsmpModuleWeight::on_timeout(
+					&module,
+					&Timeout::Request(Request::Get(get_request()))
+				),
+				WeightInfo::ismp_on_timeout(0)
+					.max(WeightInfo::ismp_conclude_batch_request(1))
+					.saturating_add(CallbackExecutor::execution_weight())
+			);
 			for (response, x) in [
-				(Timeout::Request(Request::Get(get_request())), 0),
 				(Timeout::Request(Request::Post(ismp_hooks::post_request(100))), 1),
 				(Timeout::Response(post_response()), 2),
 			] {
@@ -0,0 +1,628 @@
+//! A development-only loopback for the ISMP and XCM transports.
+//!
+//! Once enabled, requests dispatched via ISMP and queries registered via XCM are captured rather
+//! than being sent, with a privileged origin then able to inject a chosen response or timeout for
+//! any captured message. Injections are processed by the same paths as responses and timeouts
+//! received via the transports, allowing complete callback scenarios to be run on a single local
+//! node. Requests and queries are passed to the inner dispatcher and query handler whilst the
+//! loopback is disabled.
+//!
+//! The loopback is only available with the `messaging-loopback` feature, which must never be
+//! enabled for a production runtime.
+
+use alloc::{vec, vec::Vec};
+
+use ::ismp::{
+	dispatcher::{DispatchGet, DispatchPost, DispatchRequest, FeeMetadata, IsmpDispatcher},
+	messaging::hash_request,
+	module::IsmpModule,
+	router::{
+		GetRequest, GetResponse, PostRequest, PostResponse, Request, Response as IsmpResponse,
+		Timeout,
+	},
+};
+use ::xcm::latest::{Location, QueryId, Response};
+use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::UnixTime};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use pallet_revive::H256;
+use sp_runtime::traits::Convert;
+
+use super::{
+	ismp::{FundRequest, IsmpTransport, Module, StateMachineHeight},
+	xcm::{NotifyQueryHandler, XcmTransport},
+};
+use crate::messaging::{
+	self, timeout_xcm_query, weights::WeightInfo as _, CallbackExecutor as _, Message, MessageId,
+	Messages,
+};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + messaging::Config + pallet_ismp::Config + pallet_revive::Config
+	{
+		/// The origin permitted to enable the loopback and to inject responses and timeouts.
+		type ControlOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// A converter for conversion of the location of a XCM responder to an origin satisfying
+		/// the `XcmResponseOrigin` of the messaging pallet.
+		type ResponseOrigin: Convert<Location, <Self as frame_system::Config>::RuntimeOrigin>;
+	}
+
+	/// Whether requests and queries are captured by the loopback.
+	#[pallet::storage]
+	pub(super) type Enabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// The captured ISMP requests, by commitment.
+	#[pallet::storage]
+	pub(super) type Requests<T: Config> = StorageMap<_, Identity, H256, Request>;
+
+	/// The captured XCM queries, with the location of the responder, by query identifier.
+	#[pallet::storage]
+	pub(super) type Queries<T: Config> = StorageMap<_, Twox64Concat, QueryId, Location>;
+
+	/// The number of ISMP requests and XCM queries captured, from which the nonces of requests
+	/// and the identifiers of queries are derived.
+	#[pallet::storage]
+	pub(super) type Captured<T: Config> = StorageValue<_, (u64, u64), ValueQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The message was not captured by the loopback.
+		NotCaptured,
+		/// The response does not correspond to the request of the message.
+		ResponseMismatch,
+		/// The response or timeout was rejected.
+		InjectionFailed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enable or disable the capture of requests and queries.
+		///
+		/// Messages captured whilst enabled can still be responded to or timed out once disabled.
+		///
+		/// # Parameters
+		/// - `enabled`: Whether requests and queries are to be captured.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+			Enabled::<T>::put(enabled);
+			Ok(())
+		}
+
+		/// Inject a response for a captured message.
+		///
+		/// # Parameters
+		/// - `id`: The identifier of the message.
+		/// - `reply`: The response, which must correspond to the request of the message.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 1).saturating_add(match reply {
+			Reply::Get(values) => <T as messaging::Config>::WeightInfo::ismp_on_response(
+				0,
+				response_len::<T>(values.encoded_size()),
+			)
+			.saturating_add(T::CallbackExecutor::execution_weight()),
+			Reply::Post(response) => <T as messaging::Config>::WeightInfo::ismp_on_response(
+				1,
+				response_len::<T>(response.encoded_size()),
+			)
+			.saturating_add(T::CallbackExecutor::execution_weight()),
+			// Registered by the handling of the response.
+			Reply::Xcm(_) => Weight::zero(),
+		}))]
+		pub fn respond(origin: OriginFor<T>, id: MessageId, reply: Reply) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+			match (captured::<T>(id)?, reply) {
+				(Capture::Request(commitment, Request::Get(get)), Reply::Get(values)) => {
+					let values = values
+						.into_iter()
+						.map(|(key, value)| ::ismp::router::StorageValue { key, value })
+						.collect();
+					respond_to_request::<T>(
+						commitment,
+						IsmpResponse::Get(GetResponse { get, values }),
+					)
+				},
+				(Capture::Request(commitment, Request::Post(post)), Reply::Post(response)) => {
+					let timeout_timestamp = post.timeout_timestamp;
+					respond_to_request::<T>(
+						commitment,
+						IsmpResponse::Post(PostResponse { post, response, timeout_timestamp }),
+					)
+				},
+				(Capture::Query(query_id, responder), Reply::Xcm(response)) => {
+					messaging::Pallet::<T>::xcm_response(
+						T::ResponseOrigin::convert(responder),
+						query_id,
+						response,
+					)?;
+					Queries::<T>::remove(query_id);
+					Ok(())
+				},
+				_ => Err(Error::<T>::ResponseMismatch.into()),
+			}
+		}
+
+		/// Inject a timeout for a captured message.
+		///
+		/// # Parameters
+		/// - `id`: The identifier of the message.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 1).saturating_add(
+			<T as messaging::Config>::WeightInfo::ismp_on_timeout(0)
+				.max(<T as messaging::Config>::WeightInfo::ismp_on_timeout(1))
+				.max(<T as messaging::Config>::WeightInfo::xcm_timeout_query())
+		))]
+		pub fn time_out(origin: OriginFor<T>, id: MessageId) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+			match captured::<T>(id)? {
+				Capture::Request(commitment, request) => {
+					Module::<T>::new().on_timeout(Timeout::Request(request)).map_err(|e| {
+						log::error!(target: "pop-api::messaging::loopback", "timeout failed: {:?}", e);
+						Error::<T>::InjectionFailed
+					})?;
+					Requests::<T>::remove(commitment);
+				},
+				Capture::Query(query_id, _) => {
+					timeout_xcm_query::<T>(id).ok_or(Error::<T>::InjectionFailed)?;
+					messaging::Pallet::<T>::deposit_event(
+						messaging::Event::<T>::XcmQueriesTimedOut { query_ids: vec![query_id] },
+					);
+					Queries::<T>::remove(query_id);
+				},
+			}
+			Ok(())
+		}
+	}
+}
+
+/// A response to be injected for a captured message.
+#[derive(Clone, Debug, Decode, DecodeWithMemTracking, Encode, Eq, PartialEq, TypeInfo)]
+pub enum Reply {
+	/// The values read by an ISMP `Get` request, as pairs of keys and optional values.
+	Get(Vec<(Vec<u8>, Option<Vec<u8>>)>),
+	/// The response to an ISMP `Post` request.
+	Post(Vec<u8>),
+	/// The response to a XCM query.
+	Xcm(Response),
+}
+
+/// A captured request or query.
+enum Capture {
+	/// An ISMP request, with its commitment.
+	Request(H256, Request),
+	/// A XCM query, with the location of its responder.
+	Query(QueryId, Location),
+}
+
+/// The captured request or query of a pending message.
+///
+/// # Parameters
+/// - `id`: The identifier of the message.
+fn captured<T: Config>(id: MessageId) -> Result<Capture, DispatchError> {
+	let Some(Message::Pending { transport, .. }) = Messages::<T>::get(id) else {
+		return Err(Error::<T>::NotCaptured.into());
+	};
+	if let Some(commitment) = transport.key::<T, IsmpTransport>() {
+		let request = Requests::<T>::get(commitment).ok_or(Error::<T>::NotCaptured)?;
+		return Ok(Capture::Request(commitment, request));
+	}
+	let query_id = transport.key::<T, XcmTransport>().ok_or(Error::<T>::NotCaptured)?;
+	let responder = Queries::<T>::get(query_id).ok_or(Error::<T>::NotCaptured)?;
+	Ok(Capture::Query(query_id, responder))
+}
+
+// The length of a response used to weigh its handling, which is at most the maximum length given
+// longer responses are rejected.
+fn response_len<T: Config>(size: usize) -> u32 {
+	(size as u32).min(<T as messaging::Config>::MaxResponseLen::get())
+}
+
+fn respond_to_request<T: Config>(commitment: H256, response: IsmpResponse) -> DispatchResult {
+	Module::<T>::new().on_response(response).map_err(|e| {
+		log::error!(target: "pop-api::messaging::loopback", "response failed: {:?}", e);
+		Error::<T>::InjectionFailed
+	})?;
+	Requests::<T>::remove(commitment);
+	Ok(())
+}
+
+/// An ISMP dispatcher which captures requests whilst the loopback is enabled, otherwise passing
+/// them to the inner dispatcher.
+///
+/// Requests are captured as they would be dispatched by `pallet-ismp`, with nonces counting down
+/// from the maximum to avoid clashing with the commitments of requests of the inner dispatcher.
+pub struct Dispatcher<T, I>(PhantomData<(T, I)>);
+
+impl<T, I> Default for Dispatcher<T, I> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config, I: IsmpDispatcher + Default> IsmpDispatcher for Dispatcher<T, I> {
+	type Account = I::Account;
+	type Balance = I::Balance;
+
+	fn dispatch_request(
+		&self,
+		request: DispatchRequest,
+		fee: FeeMetadata<Self::Account, Self::Balance>,
+	) -> Result<H256, anyhow::Error> {
+		if !Enabled::<T>::get() {
+			return I::default().dispatch_request(request, fee);
+		}
+
+		let nonce = Captured::<T>::mutate(|(requests, _)| {
+			*requests = requests.saturating_add(1);
+			u64::MAX - *requests
+		});
+		let source = <T as pallet_ismp::Config>::HostStateMachine::get();
+		let now = <T as pallet_ismp::Config>::TimestampProvider::now().as_secs();
+		let timeout_timestamp = |timeout: u64| match timeout {
+			0 => 0,
+			timeout => now.saturating_add(timeout),
+		};
+		let request = match request {
+			DispatchRequest::Get(DispatchGet { dest, from, keys, height, context, timeout }) =>
+				Request::Get(GetRequest {
+					source,
+					dest,
+					nonce,
+					from,
+					keys,
+					height,
+					context,
+					timeout_timestamp: timeout_timestamp(timeout),
+				}),
+			DispatchRequest::Post(DispatchPost { dest, from, to, timeout, body }) =>
+				Request::Post(PostRequest {
+					source,
+					dest,
+					nonce,
+					from,
+					to,
+					timeout_timestamp: timeout_timestamp(timeout),
+					body,
+				}),
+		};
+		let commitment = hash_request::<<T as messaging::Config>::Keccak256>(&request);
+		Requests::<T>::insert(commitment, request);
+		Ok(commitment)
+	}
+
+	fn dispatch_response(
+		&self,
+		response: PostResponse,
+		fee: FeeMetadata<Self::Account, Self::Balance>,
+	) -> Result<H256, anyhow::Error> {
+		// Inbound requests are only received via the inner dispatcher.
+		I::default().dispatch_response(response, fee)
+	}
+}
+
+impl<T: Config, I: FundRequest<AccountId, Balance>, AccountId, Balance>
+	FundRequest<AccountId, Balance> for Dispatcher<T, I>
+{
+	fn fund_request(payer: &AccountId, commitment: H256, amount: Balance) -> DispatchResult {
+		// There are no relayers to be paid for captured requests.
+		if Requests::<T>::contains_key(commitment) {
+			return Ok(());
+		}
+		I::fund_request(payer, commitment, amount)
+	}
+
+	fn request_fee(commitment: H256) -> Option<Balance> {
+		if Requests::<T>::contains_key(commitment) {
+			return None;
+		}
+		I::request_fee(commitment)
+	}
+}
+
+impl<T, I: StateMachineHeight> StateMachineHeight for Dispatcher<T, I> {
+	fn latest_height(state_machine: ::ismp::host::StateMachine) -> Option<u64> {
+		I::latest_height(state_machine)
+	}
+}
+
+/// A XCM query handler which captures queries whilst the loopback is enabled, otherwise passing
+/// them to the inner handler.
+///
+/// Query identifiers count down from the maximum to avoid clashing with those of the inner handler.
+/// The timeouts of captured queries are still handled by the messaging pallet.
+pub struct QueryHandler<T, I>(PhantomData<(T, I)>);
+
+impl<T: Config, I: NotifyQueryHandler<T>> NotifyQueryHandler<T> for QueryHandler<T, I> {
+	type WeightInfo = I::WeightInfo;
+
+	fn new_notify_query(
+		responder: impl Into<Location>,
+		notify: messaging::Call<T>,
+		timeout: BlockNumberFor<T>,
+		match_querier: impl Into<Location>,
+	) -> QueryId {
+		if !Enabled::<T>::get() {
+			return I::new_notify_query(responder, notify, timeout, match_querier);
+		}
+
+		let query_id = Captured::<T>::mutate(|(_, queries)| {
+			*queries = queries.saturating_add(1);
+			u64::MAX - *queries
+		});
+		Queries::<T>::insert(query_id, responder.into());
+		query_id
+	}
+
+	fn cancel_query(query_id: QueryId) {
+		if Queries::<T>::take(query_id).is_none() {
+			I::cancel_query(query_id)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ::ismp::host::StateMachine;
+	use frame_support::{assert_noop, assert_ok};
+	use sp_runtime::DispatchError::BadOrigin;
+
+	use super::{super::super::tests::events, *};
+	use crate::{
+		messaging::{
+			self,
+			transports::{ismp, xcm},
+			Event::*,
+			Origin,
+		},
+		mock::{messaging::RESPONSE_LOCATION, *},
+	};
+
+	#[test]
+	fn set_enabled_requires_admin_origin() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_noop!(Loopback::set_enabled(RuntimeOrigin::signed(ALICE), true), BadOrigin);
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			assert!(Enabled::<Test>::get());
+		})
+	}
+
+	#[test]
+	fn requests_pass_to_inner_dispatcher_when_disabled() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			let (_, commitment) = get();
+			assert!(!Requests::<Test>::contains_key(commitment));
+			assert_eq!(pallet_ismp::Nonce::<Test>::get(), 1);
+		})
+	}
+
+	#[test]
+	fn queries_pass_to_inner_handler_when_disabled() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			let (_, query_id) = new_query();
+			assert!(!Queries::<Test>::contains_key(query_id));
+			assert!(pallet_xcm::Pallet::<Test>::query(&query_id).is_some());
+		})
+	}
+
+	#[test]
+	fn respond_to_get_works() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			let (id, commitment) = get();
+			assert!(Requests::<Test>::contains_key(commitment));
+			assert_eq!(pallet_ismp::Nonce::<Test>::get(), 0);
+			let values = vec![(b"key".to_vec(), Some(b"value".to_vec()))];
+
+			assert_ok!(Loopback::respond(RuntimeOrigin::root(), id, Reply::Get(values.clone())));
+
+			assert!(!Requests::<Test>::contains_key(commitment));
+			let values: Vec<_> = values
+				.into_iter()
+				.map(|(key, value)| ::ismp::router::StorageValue { key, value })
+				.collect();
+			assert_eq!(messaging::get::<Test>(&id), values.encode());
+			assert!(events().contains(&IsmpGetResponseReceived {
+				dest: ALICE_ADDR,
+				id,
+				commitment
+			}));
+		})
+	}
+
+	#[test]
+	fn respond_to_post_works() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			let (id, commitment) = post();
+			let response = b"response".to_vec();
+
+			assert_ok!(Loopback::respond(RuntimeOrigin::root(), id, Reply::Post(response.clone())));
+
+			assert!(!Requests::<Test>::contains_key(commitment));
+			assert_eq!(messaging::get::<Test>(&id), response.encode());
+			assert!(events().contains(&IsmpPostResponseReceived {
+				dest: ALICE_ADDR,
+				id,
+				commitment
+			}));
+		})
+	}
+
+	#[test]
+	fn respond_to_query_works() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			let (id, query_id) = new_query();
+			assert_eq!(Queries::<Test>::get(query_id), Some(RESPONSE_LOCATION));
+			assert!(pallet_xcm::Pallet::<Test>::query(&query_id).is_none());
+			let response = Response::Null;
+
+			assert_ok!(Loopback::respond(RuntimeOrigin::root(), id, Reply::Xcm(response.clone())));
+
+			assert!(!Queries::<Test>::contains_key(query_id));
+			assert_eq!(messaging::get::<Test>(&id), response.encode());
+			assert!(events().contains(&XcmResponseReceived {
+				dest: ALICE_ADDR,
+				id,
+				query_id,
+				response
+			}));
+		})
+	}
+
+	#[test]
+	fn respond_requires_admin_origin() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			let (id, _) = get();
+			assert_noop!(
+				Loopback::respond(RuntimeOrigin::signed(ALICE), id, Reply::Get(vec![])),
+				BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn respond_fails_when_not_captured() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			let (id, _) = get();
+			assert_noop!(
+				Loopback::respond(RuntimeOrigin::root(), id, Reply::Get(vec![])),
+				Error::<Test>::NotCaptured
+			);
+			assert_noop!(
+				Loopback::respond(RuntimeOrigin::root(), id + 1, Reply::Get(vec![])),
+				Error::<Test>::NotCaptured
+			);
+		})
+	}
+
+	#[test]
+	fn respond_fails_on_mismatch() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			let (id, _) = get();
+			assert_noop!(
+				Loopback::respond(RuntimeOrigin::root(), id, Reply::Post(vec![])),
+				Error::<Test>::ResponseMismatch
+			);
+			assert_noop!(
+				Loopback::respond(RuntimeOrigin::root(), id, Reply::Xcm(Response::Null)),
+				Error::<Test>::ResponseMismatch
+			);
+		})
+	}
+
+	#[test]
+	fn respond_fails_when_concluded() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			let (id, _) = post();
+			assert_ok!(Loopback::respond(RuntimeOrigin::root(), id, Reply::Post(vec![])));
+			assert_noop!(
+				Loopback::respond(RuntimeOrigin::root(), id, Reply::Post(vec![])),
+				Error::<Test>::NotCaptured
+			);
+		})
+	}
+
+	#[test]
+	fn time_out_request_works() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			let (id, commitment) = get();
+
+			assert_ok!(Loopback::time_out(RuntimeOrigin::root(), id));
+
+			assert!(!Requests::<Test>::contains_key(commitment));
+			assert!(matches!(Messages::<Test>::get(id), Some(Message::Timeout { .. })));
+			assert!(events().contains(&IsmpTimedOut { commitment }));
+		})
+	}
+
+	#[test]
+	fn time_out_query_works() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			let (id, query_id) = new_query();
+
+			assert_ok!(Loopback::time_out(RuntimeOrigin::root(), id));
+
+			assert!(!Queries::<Test>::contains_key(query_id));
+			assert!(matches!(Messages::<Test>::get(id), Some(Message::Timeout { .. })));
+			assert!(events().contains(&XcmQueriesTimedOut { query_ids: vec![query_id] }));
+		})
+	}
+
+	#[test]
+	fn time_out_requires_admin_origin() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			let (id, _) = new_query();
+			assert_noop!(Loopback::time_out(RuntimeOrigin::signed(ALICE), id), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn cancelled_queries_are_released() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			let (id, query_id) = new_query();
+
+			assert_ok!(xcm::cancel_query::<Test>(origin(), id));
+
+			assert!(!Queries::<Test>::contains_key(query_id));
+			assert_noop!(Loopback::time_out(RuntimeOrigin::root(), id), Error::<Test>::NotCaptured);
+		})
+	}
+
+	#[test]
+	fn funding_captured_requests_works() {
+		ExtBuilder::new().with_balances(vec![(ALICE, UNIT)]).build().execute_with(|| {
+			assert_ok!(Loopback::set_enabled(RuntimeOrigin::root(), true));
+			let (id, commitment) = get();
+			assert_eq!(ismp::fund::<Test>(origin(), id, 100), Ok((commitment, 100)));
+		})
+	}
+
+	fn origin() -> Origin<Test> {
+		Origin::from((ALICE_ADDR, ALICE))
+	}
+
+	fn get() -> (MessageId, H256) {
+		let message = DispatchGet {
+			dest: StateMachine::Polkadot(2_001),
+			from: ismp::ID.to_vec(),
+			keys: vec![b"key".to_vec()],
+			height: 0,
+			context: vec![],
+			timeout: 100,
+		};
+		ismp::get::<Test>(origin(), message, 0, None).unwrap()
+	}
+
+	fn post() -> (MessageId, H256) {
+		let message = DispatchPost {
+			dest: StateMachine::Polkadot(2_001),
+			from: ismp::ID.to_vec(),
+			to: ismp::ID.to_vec(),
+			timeout: 100,
+			body: b"body".to_vec(),
+		};
+		ismp::post::<Test>(origin(), message, 0, None).unwrap()
+	}
+
+	fn new_query() -> (MessageId, QueryId) {
+		xcm::new_query::<Test>(origin(), RESPONSE_LOCATION, 10, None).unwrap()
+	}
+}
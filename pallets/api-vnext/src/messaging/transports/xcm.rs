@@ -1,8 +1,39 @@
 pub(crate) use ::xcm::latest::{Location, QueryId, Response};
+use ::xcm::{VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm};
+use frame_support::storage::{with_transaction, TransactionOutcome};
 use xcm_builder::QueryControllerWeightInfo;
+pub use xcm_runtime_apis::{
+	dry_run::{Error as XcmDryRunApiError, XcmDryRunEffects},
+	fees::Error as XcmPaymentApiError,
+};
 
 use super::*;
-use crate::messaging::{pallet::Call, BlockNumberOf, Config};
+use crate::messaging::{pallet::Call, BlockNumberOf, Config, MessageQuote};
+
+/// Quote the amounts taken for a new XCM query.
+///
+/// The prepaid fee covers the handling of a response of the maximum length, including the execution
+/// of the callback if any. The part of the fee covering the length of the response is held until
+/// the response is received, with any fee unused by a shorter response being refunded.
+///
+/// # Parameters
+/// - `callback_gas_limit`: The gas limit of the callback, if any.
+pub(crate) fn quote_new_query<T: Config>(
+	callback_gas_limit: Option<Weight>,
+) -> MessageQuote<BalanceOf<T>> {
+	let callback_execution_weight =
+		callback_gas_limit.map_or(Weight::zero(), |_| T::CallbackExecutor::execution_weight());
+	MessageQuote {
+		message_deposit: calculate_transport_deposit::<T, XcmTransport, T::OnChainByteFee>()
+			.saturating_add(calculate_message_deposit::<T, T::OnChainByteFee>()),
+		prepaid_fee: T::WeightToFee::weight_to_fee(
+			&T::WeightInfo::xcm_response(T::MaxResponseLen::get())
+				.saturating_add(callback_execution_weight),
+		),
+		callback_deposit: callback_gas_limit
+			.map_or(Zero::zero(), |gas_limit| T::WeightToFee::weight_to_fee(&gas_limit)),
+	}
+}
 
 /// Initiate a new XCM query.
 ///
@@ -30,56 +61,151 @@ pub(crate) fn new_query<T: Config>(
 	let current_block = frame_system::Pallet::<T>::block_number();
 	ensure!(current_block < timeout, Error::<T>::FutureTimeoutMandatory);
 
-	let id = next_message_id::<T>()?;
-	XcmQueryTimeouts::<T>::try_mutate(current_block.saturating_add(timeout), |bounded_vec| {
-		bounded_vec
-			.try_push(id)
-			.map_err(|_| Error::<T>::MaxMessageTimeoutPerBlockReached)
-	})?;
-
-	// Take deposits and fees.
-	let message_deposit =
-		calculate_protocol_deposit::<T, T::OnChainByteFee>(ProtocolStorageDeposit::XcmQueries)
-			.saturating_add(calculate_message_deposit::<T, T::OnChainByteFee>());
-	T::Fungibles::hold(&HoldReason::Messaging.into(), &origin.account, message_deposit)?;
-
-	let mut callback_execution_weight = Weight::zero();
-
-	if let Some(cb) = callback.as_ref() {
-		T::Fungibles::hold(
-			&HoldReason::CallbackGas.into(),
-			&origin.account,
-			T::WeightToFee::weight_to_fee(&cb.gas_limit),
+	let quote = quote_new_query::<T>(callback.as_ref().map(|cb| cb.gas_limit));
+	// The fee for the length of the response is held rather than paid, to be settled once the
+	// length of the response is known.
+	let response_fee = response_fee::<T>(T::MaxResponseLen::get());
+	let prepaid_fee = quote.prepaid_fee.saturating_sub(response_fee);
+	let account = origin.account.clone();
+	let (id, query_id) = send::<T, XcmTransport>(
+		origin,
+		(responder, timeout, querier_location),
+		callback,
+		MessageQuote { prepaid_fee, ..quote },
+	)?;
+	if !response_fee.is_zero() {
+		T::Fungibles::hold(&HoldReason::ResponseFee.into(), &account, response_fee)?;
+		XcmResponseFees::<T>::insert(query_id, response_fee);
+	}
+	Ok((id, query_id))
+}
+
+/// The fee for handling a response of the given length, beyond that of an empty response.
+///
+/// # Parameters
+/// - `len`: The length of the response.
+fn response_fee<T: Config>(len: u32) -> BalanceOf<T> {
+	T::WeightToFee::weight_to_fee(&T::WeightInfo::xcm_response(len))
+		.saturating_sub(T::WeightToFee::weight_to_fee(&T::WeightInfo::xcm_response(0)))
+}
+
+/// Settle the fee held for handling the response to a query.
+///
+/// The fee for the length of the response is paid, with the remainder released. The whole fee is
+/// released if the query concluded without a response.
+///
+/// # Parameters
+/// - `query_id`: The identifier of the query.
+/// - `account`: The account of the origin of the query.
+/// - `response_len`: The length of the response, if any.
+pub(crate) fn settle_response_fee<T: Config>(
+	query_id: &QueryId,
+	account: &AccountIdOf<T>,
+	response_len: Option<u32>,
+) -> DispatchResult {
+	let Some(held) = XcmResponseFees::<T>::take(query_id) else { return Ok(()) };
+	T::Fungibles::release(&HoldReason::ResponseFee.into(), account, held, Precision::Exact)?;
+	let fee = response_len.map_or(Zero::zero(), |len| response_fee::<T>(len).min(held));
+	if !fee.is_zero() {
+		let credit = T::Fungibles::withdraw(
+			account,
+			fee,
+			Precision::Exact,
+			Preservation::Preserve,
+			Fortitude::Polite,
 		)?;
+		T::FeeHandler::on_unbalanced(credit);
+	}
+	Ok(())
+}
 
-		callback_execution_weight = T::CallbackExecutor::execution_weight();
+/// Cancel a pending XCM query.
+///
+/// Deregisters the query, such that any subsequent response is ignored, and releases the deposits
+/// held for the message.
+///
+/// # Parameters
+/// - `origin`: The account cancelling the query, which must be the origin of the query.
+/// - `id`: The identifier of the message.
+///
+/// # Returns
+/// The identifier of the cancelled XCM query.
+pub(crate) fn cancel_query<T: Config>(
+	origin: Origin<T>,
+	id: MessageId,
+) -> Result<QueryId, DispatchError> {
+	let (_, transport, ..) = pending::<T, XcmTransport>(id)?;
+	let query_id = transport.key::<T, XcmTransport>().ok_or(Error::<T>::InvalidMessage)?;
+	let timeout = PendingXcmQueries::<T>::get(query_id);
+	let account = origin.account.clone();
+	cancel::<T, XcmTransport>(origin, id)?;
+	settle_response_fee::<T>(&query_id, &account, None)?;
+
+	if let Some(timeout) = timeout {
+		XcmQueryTimeouts::<T>::mutate(timeout, |ids| ids.retain(|i| *i != id));
+		XcmQueryTimeoutOverflow::<T>::remove(timeout, id);
 	}
+	T::Xcm::cancel_query(query_id);
+	Ok(query_id)
+}
 
-	let response_prepayment_amount = T::WeightToFee::weight_to_fee(
-		&T::WeightInfo::xcm_response().saturating_add(callback_execution_weight),
-	);
+/// The XCM transport, creating queries whose responses are notified to the pallet.
+pub struct XcmTransport;
 
-	let credit = T::Fungibles::withdraw(
-		&origin.account,
-		response_prepayment_amount,
-		Precision::Exact,
-		Preservation::Preserve,
-		Fortitude::Polite,
-	)?;
+impl<T: Config> Transport<T> for XcmTransport {
+	/// The identifier of the query.
+	type Key = QueryId;
+	/// The responder, the number of blocks until the query times out and the querier.
+	type Request = (Location, BlockNumberOf<T>, Location);
 
-	T::FeeHandler::on_unbalanced(credit);
+	const INDEX: TransportIndex = 1;
 
-	// Process message by creating new query via XCM.
-	// Xcm only uses/stores pallet, index - i.e. (u8,u8), hence the fields in xcm_response
-	// are ignored.
-	let notify = Call::<T>::xcm_response { query_id: 0, xcm_response: Default::default() };
-	let query_id = T::Xcm::new_notify_query(responder, notify, timeout, querier_location);
+	fn dispatch(
+		_origin: &Origin<T>,
+		id: MessageId,
+		(responder, timeout, querier): Self::Request,
+	) -> Result<Self::Key, DispatchError> {
+		let timeout_block = frame_system::Pallet::<T>::block_number().saturating_add(timeout);
+		// Xcm only uses/stores pallet, index - i.e. (u8,u8), hence the fields in xcm_response
+		// are ignored.
+		let notify = Call::<T>::xcm_response { query_id: 0, xcm_response: Default::default() };
+		let query_id = T::Xcm::new_notify_query(responder, notify, timeout, querier);
+		schedule_timeout::<T>(query_id, id, timeout_block);
+		Ok(query_id)
+	}
 
-	// Store query id for later lookup on response, message for querying status,
-	// response/timeout handling.
-	XcmQueries::<T>::insert(query_id, id);
-	Messages::<T>::insert(id, Message::XcmQuery { origin, query_id, callback, message_deposit });
-	Ok((id, query_id))
+	fn footprint() -> u32 {
+		// The pending query, its entry amongst the timeouts of its block and its response fee.
+		KeyLenOf::<PendingXcmQueries<T>>::get()
+			.saturating_add(BlockNumberOf::<T>::max_encoded_len() as u32)
+			.saturating_add(MessageId::max_encoded_len() as u32)
+			.saturating_add(KeyLenOf::<XcmResponseFees<T>>::get())
+			.saturating_add(BalanceOf::<T>::max_encoded_len() as u32)
+	}
+
+	fn on_conclude(query_id: &Self::Key) {
+		PendingXcmQueries::<T>::remove(query_id);
+	}
+}
+
+/// Schedule the timeout of a pending query.
+///
+/// # Parameters
+/// - `query_id`: The identifier of the query.
+/// - `id`: The message identifier of the query.
+/// - `timeout_block`: The block at which the query times out.
+pub(crate) fn schedule_timeout<T: Config>(
+	query_id: QueryId,
+	id: MessageId,
+	timeout_block: BlockNumberOf<T>,
+) {
+	// Spill over into the overflow of the block once its timeout limit has been reached, which is
+	// processed as blockspace allows.
+	if XcmQueryTimeouts::<T>::mutate(timeout_block, |bounded_vec| bounded_vec.try_push(id)).is_err()
+	{
+		XcmQueryTimeoutOverflow::<T>::insert(timeout_block, id, ());
+	}
+	PendingXcmQueries::<T>::insert(query_id, timeout_block);
 }
 
 /// A handler for the creation of a XCM query notification.
@@ -93,6 +219,138 @@ pub trait NotifyQueryHandler<T: Config> {
 		timeout: BlockNumberOf<T>,
 		match_querier: impl Into<Location>,
 	) -> QueryId;
+
+	/// Handle the cancellation of a query that is yet to respond, deregistering the query so that
+	/// any subsequent response is rejected.
+	fn cancel_query(query_id: QueryId);
+}
+
+/// Dry-running and fee estimation of XCM messages, as offered to off-chain clients by the XCM
+/// runtime APIs.
+pub trait XcmApi<T: frame_system::Config> {
+	/// Dry-run an XCM message from a given origin, returning its effects.
+	fn dry_run_xcm(
+		origin: VersionedLocation,
+		message: VersionedXcm<<T as frame_system::Config>::RuntimeCall>,
+	) -> Result<XcmDryRunEffects<<T as frame_system::Config>::RuntimeEvent>, XcmDryRunApiError>;
+
+	/// The fees for delivering a message to a destination.
+	fn query_delivery_fees(
+		destination: VersionedLocation,
+		message: VersionedXcm<()>,
+	) -> Result<VersionedAssets, XcmPaymentApiError>;
+
+	/// Converts a weight into a fee, payable in the specified asset.
+	fn query_weight_to_asset_fee(
+		weight: Weight,
+		asset: VersionedAssetId,
+	) -> Result<u128, XcmPaymentApiError>;
+
+	/// The weight required to execute a message.
+	fn query_xcm_weight(message: VersionedXcm<()>) -> Result<Weight, XcmPaymentApiError>;
+}
+
+/// Dry-run an XCM message from a given origin, returning its effects.
+///
+/// A dry run resets the events of the block and drains the queues of the message router, so it is
+/// performed within a storage transaction which is always rolled back.
+///
+/// # Parameters
+/// - `origin`: The origin of the message.
+/// - `message`: The message to be dry-run.
+pub(crate) fn dry_run_xcm<T: Config>(
+	origin: Location,
+	message: VersionedXcm<<T as frame_system::Config>::RuntimeCall>,
+) -> Result<
+	Result<XcmDryRunEffects<<T as frame_system::Config>::RuntimeEvent>, XcmDryRunApiError>,
+	DispatchError,
+> {
+	with_transaction(|| {
+		TransactionOutcome::Rollback(Ok(T::XcmApi::dry_run_xcm(origin.into(), message)))
+	})
+}
+
+/// Returns the response to a query, if any.
+///
+/// # Parameters
+/// - `id`: The message identifier of the query.
+pub(crate) fn get_response<T: Config>(id: &MessageId) -> Option<Response> {
+	match Messages::<T>::get(id)? {
+		Message::Complete { transport, response, .. } if transport.index == XcmTransport::INDEX =>
+			Response::decode(&mut response.as_slice()).ok(),
+		_ => None,
+	}
+}
+
+/// Removes a query that is yet to respond from `pallet-xcm`, so that any subsequent response is
+/// rejected as unexpected.
+///
+/// `pallet-xcm` does not offer a means of removing a pending query, so the query is removed from
+/// its storage directly.
+///
+/// # Parameters
+/// - `query_id`: The identifier of the query.
+pub fn remove_pallet_xcm_query<T: pallet_xcm::Config>(query_id: QueryId) {
+	use frame_support::{
+		storage::migration::{get_storage_value, take_storage_value},
+		traits::PalletInfoAccess,
+		StorageHasher,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use pallet_xcm::QueryStatus;
+
+	type Status<T> = QueryStatus<BlockNumberFor<T>>;
+	let pallet = <pallet_xcm::Pallet<T>>::name();
+	let key = Blake2_128Concat::hash(&query_id.encode());
+	if let Some(QueryStatus::Pending { .. }) =
+		get_storage_value::<Status<T>>(pallet.as_bytes(), b"Queries", &key)
+	{
+		take_storage_value::<Status<T>>(pallet.as_bytes(), b"Queries", &key);
+	}
+}
+
+/// Redirects the notification of a query that is yet to respond to `xcm_response` of this pallet,
+/// such as for queries created by another pallet whose messages have since been migrated.
+///
+/// `pallet-xcm` does not offer a means of updating a pending query, so the query is updated within
+/// its storage directly.
+///
+/// # Parameters
+/// - `query_id`: The identifier of the query.
+///
+/// # Returns
+/// The block at which the query times out, or `None` if the query is not pending.
+pub(crate) fn renotify_pallet_xcm_query<T: Config + pallet_xcm::Config>(
+	query_id: QueryId,
+) -> Option<BlockNumberOf<T>>
+where
+	<T as pallet_xcm::Config>::RuntimeCall: From<Call<T>>,
+{
+	use frame_support::{
+		storage::migration::{get_storage_value, put_storage_value},
+		traits::PalletInfoAccess,
+		StorageHasher,
+	};
+	use pallet_xcm::QueryStatus;
+
+	let pallet = <pallet_xcm::Pallet<T>>::name();
+	let key = Blake2_128Concat::hash(&query_id.encode());
+	let Some(QueryStatus::Pending { responder, maybe_match_querier, timeout, .. }) =
+		get_storage_value::<QueryStatus<BlockNumberOf<T>>>(pallet.as_bytes(), b"Queries", &key)
+	else {
+		return None;
+	};
+	// Only the indices of the pallet and call are used, as per `pallet_xcm::new_notify_query`.
+	let notify: <T as pallet_xcm::Config>::RuntimeCall =
+		Call::<T>::xcm_response { query_id: 0, xcm_response: Default::default() }.into();
+	let maybe_notify = notify.using_encoded(|call| Some((call[0], call[1])));
+	put_storage_value(
+		pallet.as_bytes(),
+		b"Queries",
+		&key,
+		QueryStatus::Pending { responder, maybe_match_querier, maybe_notify, timeout },
+	);
+	Some(timeout)
 }
 
 #[cfg(test)]
@@ -100,7 +358,7 @@ pub(crate) mod tests {
 	use frame_support::{
 		assert_noop, assert_ok,
 		storage::{with_transaction, TransactionOutcome},
-		traits::fungible::InspectHold,
+		traits::{fungible::InspectHold, Hooks},
 		weights::WeightToFee as _,
 	};
 
@@ -111,20 +369,27 @@ pub(crate) mod tests {
 	};
 
 	type CallbackExecutor = <Test as Config>::CallbackExecutor;
+	type DbWeight = <Test as frame_system::Config>::DbWeight;
 	type Error = super::Error<Test>;
 	type Event = super::Event<Test>;
 	type Fungibles = <Test as Config>::Fungibles;
+	type MaxResponseLen = <Test as Config>::MaxResponseLen;
 	type Messages = super::Messages<Test>;
 	type OnChainByteFee = <Test as Config>::OnChainByteFee;
 	type Origin = super::Origin<Test>;
 	type WeightInfo = <Test as Config>::WeightInfo;
+	type PendingXcmQueries = super::PendingXcmQueries<Test>;
+	type TransportMessages = super::TransportMessages<Test>;
 	type WeightToFee = <Test as Config>::WeightToFee;
-	type XcmQueries = super::XcmQueries<Test>;
+	type XcmQueryTimeoutOverflow = super::XcmQueryTimeoutOverflow<Test>;
+	type XcmQueryTimeoutOverflowQueue = super::XcmQueryTimeoutOverflowQueue<Test>;
+	type XcmQueryTimeouts = super::XcmQueryTimeouts<Test>;
+	type XcmResponseFees = super::XcmResponseFees<Test>;
 
 	#[test]
 	fn ensure_xcm_response_has_weight() {
 		assert_ne!(
-			WeightInfo::xcm_response(),
+			WeightInfo::xcm_response(0),
 			Weight::zero(),
 			"Please set a weight for xcm_response to run these tests."
 		);
@@ -132,7 +397,7 @@ pub(crate) mod tests {
 
 	#[test]
 	fn ensure_xcm_response_fee() {
-		assert_ne!(WeightToFee::weight_to_fee(&(WeightInfo::xcm_response())), 0);
+		assert_ne!(WeightToFee::weight_to_fee(&(response_weight())), 0);
 		assert_ne!(WeightToFee::weight_to_fee(&(CallbackExecutor::execution_weight())), 0);
 	}
 
@@ -148,7 +413,7 @@ pub(crate) mod tests {
 	#[test]
 	fn takes_response_fee_no_callback() {
 		let origin = Origin::from((ALICE_ADDR, ALICE));
-		let response_fee = WeightToFee::weight_to_fee(&(WeightInfo::xcm_response()));
+		let response_fee = WeightToFee::weight_to_fee(&(response_weight()));
 		let callback = None;
 		let endowment = existential_deposit() + deposit() + response_fee;
 		ExtBuilder::new()
@@ -162,8 +427,12 @@ pub(crate) mod tests {
 
 				let balance_post_transfer = Balances::free_balance(&origin.account);
 				let total_balance_on_hold = Balances::total_balance_on_hold(&origin.account);
+				let response_fee_on_hold =
+					Fungibles::balance_on_hold(&ResponseFee.into(), &origin.account);
+				assert_ne!(response_fee_on_hold, 0);
 				assert_eq!(
-					balance_pre_transfer - balance_post_transfer - total_balance_on_hold,
+					balance_pre_transfer - balance_post_transfer - total_balance_on_hold +
+						response_fee_on_hold,
 					response_fee
 				);
 			})
@@ -187,9 +456,12 @@ pub(crate) mod tests {
 
 				let balance_post_query = Balances::free_balance(&origin.account);
 				let total_balance_on_hold = Balances::total_balance_on_hold(&origin.account);
+				let response_fee_on_hold =
+					Fungibles::balance_on_hold(&ResponseFee.into(), &origin.account);
 
 				assert_eq!(
-					balance_pre_query - balance_post_query - total_balance_on_hold,
+					balance_pre_query - balance_post_query - total_balance_on_hold +
+						response_fee_on_hold,
 					response_fee
 				);
 			})
@@ -220,6 +492,102 @@ pub(crate) mod tests {
 			})
 	}
 
+	#[test]
+	fn quote_matches_amounts_taken() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let weight = Weight::from_parts(100_000_000, 100_000_000);
+		let callback = Callback::new(H160::zero(), Encoding::Scale, [1; 4], weight, 100_000_000);
+		let quote = quote_new_query::<Test>(Some(weight));
+		let endowment = existential_deposit() + quote.clone().total();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 1;
+				assert_eq!(quote.message_deposit, deposit());
+				assert_eq!(quote.prepaid_fee, xcm_response_fee());
+				assert_eq!(quote.callback_deposit, WeightToFee::weight_to_fee(&weight));
+
+				assert_ok!(new_query(origin.clone(), RESPONSE_LOCATION, timeout, Some(callback)));
+
+				assert_eq!(Balances::free_balance(&origin.account), existential_deposit());
+				assert_eq!(
+					Fungibles::balance_on_hold(&Messaging.into(), &origin.account),
+					quote.message_deposit
+				);
+				assert_eq!(
+					Fungibles::balance_on_hold(&CallbackGas.into(), &origin.account),
+					quote.callback_deposit
+				);
+				assert_eq!(
+					Fungibles::balance_on_hold(&ResponseFee.into(), &origin.account),
+					held_response_fee()
+				);
+			})
+	}
+
+	#[test]
+	fn quote_without_callback_excludes_callback_execution() {
+		let quote = quote_new_query::<Test>(None);
+		assert_eq!(quote.prepaid_fee, WeightToFee::weight_to_fee(&response_weight()));
+		assert_eq!(quote.callback_deposit, 0);
+	}
+
+	#[test]
+	fn refunds_unused_response_fee() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let response = Response::Null;
+		let endowment = existential_deposit() + deposit() + xcm_response_fee();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 1;
+				let (_, query_id) =
+					new_query(origin.clone(), RESPONSE_LOCATION, timeout, None).unwrap();
+				let held = held_response_fee();
+				assert_ne!(held, 0);
+				assert_eq!(XcmResponseFees::get(query_id), Some(held));
+				let balance_pre_response = Balances::free_balance(&origin.account);
+
+				assert_ok!(super::Pallet::<Test>::xcm_response(root(), query_id, response.clone()));
+
+				// Only the fee for the length of the response is taken from the held fee.
+				let fee = WeightToFee::weight_to_fee(&WeightInfo::xcm_response(
+					response.encoded_size() as u32,
+				)) - WeightToFee::weight_to_fee(&WeightInfo::xcm_response(0));
+				assert!(fee < held);
+				assert_eq!(Fungibles::balance_on_hold(&ResponseFee.into(), &origin.account), 0);
+				assert!(XcmResponseFees::get(query_id).is_none());
+				assert_eq!(
+					Balances::free_balance(&origin.account),
+					balance_pre_response + held - fee
+				);
+			})
+	}
+
+	#[test]
+	fn timeout_releases_response_fee() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let endowment = existential_deposit() + deposit() + xcm_response_fee();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 1;
+				let (_, query_id) =
+					new_query(origin.clone(), RESPONSE_LOCATION, timeout, None).unwrap();
+				let held = held_response_fee();
+				let balance_pre_timeout = Balances::free_balance(&origin.account);
+
+				run_to(timeout + 1);
+
+				assert_eq!(Fungibles::balance_on_hold(&ResponseFee.into(), &origin.account), 0);
+				assert!(XcmResponseFees::get(query_id).is_none());
+				assert_eq!(Balances::free_balance(&origin.account), balance_pre_timeout + held);
+			})
+	}
+
 	#[test]
 	fn takes_messaging_hold() {
 		let origin = Origin::from((ALICE_ADDR, ALICE));
@@ -266,12 +634,15 @@ pub(crate) mod tests {
 				assert_ok!(new_query(origin.clone(), RESPONSE_LOCATION, timeout, Some(callback)));
 
 				let message = Messages::get(id).expect("should exist after xcm_new_query.");
-				let Message::XcmQuery { query_id: qid, callback: c, .. } = message else {
+				let Message::Pending { transport, callback: c, .. } = message else {
 					panic!("Wrong message type.")
 				};
-				assert_eq!(qid, query_id);
+				assert_eq!(transport.key::<Test, XcmTransport>(), Some(query_id));
 				assert_eq!(c, Some(callback));
-				assert_eq!(XcmQueries::get(query_id), Some(id));
+				assert_eq!(
+					TransportMessages::get(MessageTransport::xcm::<Test>(query_id)),
+					Some(id)
+				);
 			})
 	}
 
@@ -312,7 +683,7 @@ pub(crate) mod tests {
 				run_to(timeout + 1);
 
 				for id in 0..messages {
-					let Some(Message::XcmTimeout { .. }) = Messages::get(id) else {
+					let Some(Message::Timeout { .. }) = Messages::get(id) else {
 						panic!("Message should be timed out!")
 					};
 				}
@@ -321,8 +692,303 @@ pub(crate) mod tests {
 			})
 	}
 
+	#[test]
+	fn xcm_query_timeouts_spill_over_when_block_limit_reached() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let limit = MaxXcmQueryTimeoutsPerBlock::get() as MessageId;
+		let messages = limit * 2 + 1;
+		let endowment =
+			existential_deposit() + (deposit() + xcm_response_fee()) * messages as Balance;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 10;
+				for _ in 0..messages {
+					assert_ok!(new_query(origin.clone(), RESPONSE_LOCATION, timeout, None));
+				}
+				let timeout = timeout_block(0);
+				assert_eq!(XcmQueryTimeouts::get(timeout).len() as MessageId, limit);
+				assert_eq!(
+					XcmQueryTimeoutOverflow::iter_key_prefix(timeout).count() as MessageId,
+					messages - limit
+				);
+
+				run_to(timeout);
+
+				// Only the timeouts within the block limit are processed on initialize.
+				for id in 0..messages {
+					match Messages::get(id) {
+						Some(Message::Timeout { .. }) if id < limit => {},
+						Some(Message::Pending { .. }) if id >= limit => {},
+						_ => panic!("Unexpected message state!"),
+					}
+				}
+				assert_eq!(XcmQueryTimeoutOverflowQueue::get(), (0, 1));
+
+				assert!(super::Pallet::<Test>::on_idle(timeout, Weight::MAX).any_gt(Weight::zero()));
+
+				for id in 0..messages {
+					let Some(Message::Timeout { .. }) = Messages::get(id) else {
+						panic!("Message should be timed out!")
+					};
+				}
+				assert_eq!(XcmQueryTimeoutOverflow::iter_key_prefix(timeout).count(), 0);
+				assert_eq!(XcmQueryTimeoutOverflowQueue::get(), (1, 1));
+				let query_ids = (limit..messages).collect();
+				System::assert_has_event(Event::XcmQueriesTimedOut { query_ids }.into());
+			})
+	}
+
+	#[test]
+	fn overflowing_xcm_query_timeouts_respect_weight_limit() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let limit = MaxXcmQueryTimeoutsPerBlock::get() as MessageId;
+		let overflow = 3;
+		let messages = limit + overflow;
+		let endowment =
+			existential_deposit() + (deposit() + xcm_response_fee()) * messages as Balance;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 10;
+				for _ in 0..messages {
+					assert_ok!(new_query(origin.clone(), RESPONSE_LOCATION, timeout, None));
+				}
+				let timeout = timeout_block(0);
+				run_to(timeout);
+
+				assert_eq!(timeout_overflowing_xcm_queries::<Test>(Weight::zero()), Weight::zero());
+				// Sufficient weight to time out a single query.
+				let weight_limit =
+					DbWeight::get().reads_writes(3, 2) + WeightInfo::xcm_timeout_query();
+				for processed in 1..=overflow {
+					let weight = timeout_overflowing_xcm_queries::<Test>(weight_limit);
+
+					assert!(weight.all_lte(weight_limit));
+					assert_eq!(
+						XcmQueryTimeoutOverflow::iter_key_prefix(timeout).count() as MessageId,
+						overflow - processed
+					);
+				}
+				assert_eq!(XcmQueryTimeoutOverflowQueue::get(), (0, 1));
+
+				// The block is removed from the queue once all its timeouts have been processed.
+				timeout_overflowing_xcm_queries::<Test>(weight_limit);
+				assert_eq!(XcmQueryTimeoutOverflowQueue::get(), (1, 1));
+				for id in 0..messages {
+					let Some(Message::Timeout { .. }) = Messages::get(id) else {
+						panic!("Message should be timed out!")
+					};
+				}
+			})
+	}
+
+	#[test]
+	fn cancel_query_removes_overflowing_timeout() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let messages = MaxXcmQueryTimeoutsPerBlock::get() as MessageId + 1;
+		let endowment =
+			existential_deposit() + (deposit() + xcm_response_fee()) * messages as Balance;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 1;
+				let id = (0..messages)
+					.map(|_| new_query(origin.clone(), RESPONSE_LOCATION, timeout, None).unwrap().0)
+					.last()
+					.unwrap();
+				let timeout = timeout_block(id);
+				assert!(XcmQueryTimeoutOverflow::contains_key(timeout, id));
+
+				assert_ok!(cancel_query(origin, id));
+
+				assert!(!XcmQueryTimeoutOverflow::contains_key(timeout, id));
+				run_to(timeout);
+				assert_eq!(XcmQueryTimeoutOverflowQueue::get(), (0, 0));
+			})
+	}
+
+	#[test]
+	fn cancel_query_releases_deposits() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let weight = Weight::from_parts(100_000_000, 100_000_000);
+		let callback = Callback::new(H160::zero(), Encoding::Scale, [1; 4], weight, 100_000_000);
+		let endowment = existential_deposit() +
+			deposit() + xcm_response_fee() +
+			WeightToFee::weight_to_fee(&weight);
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 1;
+				let (id, _) =
+					new_query(origin.clone(), RESPONSE_LOCATION, timeout, Some(callback)).unwrap();
+				assert_ne!(Balances::total_balance_on_hold(&origin.account), 0);
+
+				assert_ok!(cancel_query(origin.clone(), id));
+
+				assert_eq!(Fungibles::balance_on_hold(&Messaging.into(), &origin.account), 0);
+				assert_eq!(Fungibles::balance_on_hold(&CallbackGas.into(), &origin.account), 0);
+				assert_eq!(Fungibles::balance_on_hold(&ResponseFee.into(), &origin.account), 0);
+			})
+	}
+
+	#[test]
+	fn cancel_query_deregisters_query() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let endowment = existential_deposit() + deposit() + xcm_response_fee();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 1;
+				let (id, query_id) =
+					new_query(origin.clone(), RESPONSE_LOCATION, timeout, None).unwrap();
+				let timeout = timeout_block(id);
+				assert!(pallet_xcm::Pallet::<Test>::query(&query_id).is_some());
+
+				assert_eq!(cancel_query(origin, id), Ok(query_id));
+
+				assert!(Messages::get(id).is_none());
+				assert!(TransportMessages::get(MessageTransport::xcm::<Test>(query_id)).is_none());
+				assert!(PendingXcmQueries::get(query_id).is_none());
+				assert!(!XcmQueryTimeouts::get(timeout).contains(&id));
+				assert!(pallet_xcm::Pallet::<Test>::query(&query_id).is_none());
+			})
+	}
+
+	#[test]
+	fn cancel_query_rejects_late_responses() {
+		use xcm_executor::traits::OnResponse;
+
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let endowment = existential_deposit() + deposit() + xcm_response_fee();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 1;
+				let (id, query_id) =
+					new_query(origin.clone(), RESPONSE_LOCATION, timeout, None).unwrap();
+				let Some(pallet_xcm::QueryStatus::Pending {
+					responder,
+					maybe_match_querier: Some(querier),
+					..
+				}) = pallet_xcm::Pallet::<Test>::query(&query_id)
+				else {
+					panic!("expected pending query");
+				};
+				let (responder, querier) =
+					(Location::try_from(responder).unwrap(), Location::try_from(querier).unwrap());
+				assert!(pallet_xcm::Pallet::<Test>::expecting_response(
+					&responder,
+					query_id,
+					Some(&querier)
+				));
+
+				assert_ok!(cancel_query(origin, id));
+
+				// The response is no longer expected by `pallet-xcm`, nor accepted by the pallet.
+				assert!(!pallet_xcm::Pallet::<Test>::expecting_response(
+					&responder,
+					query_id,
+					Some(&querier)
+				));
+
+				assert_noop!(
+					super::Pallet::<Test>::xcm_response(root(), query_id, Response::Null),
+					Error::MessageNotFound
+				);
+				run_to(timeout + 1);
+				assert!(Messages::get(id).is_none());
+			})
+	}
+
+	#[test]
+	fn cancel_query_only_by_origin() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let endowment = existential_deposit() + deposit() + xcm_response_fee();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 1;
+				let (id, _) = new_query(origin, RESPONSE_LOCATION, timeout, None).unwrap();
+
+				assert_noop!(cancel_query(Origin::from((BOB_ADDR, BOB)), id), BadOrigin);
+			})
+	}
+
+	#[test]
+	fn cancel_query_fails_when_concluded() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let endowment = existential_deposit() + (deposit() + xcm_response_fee()) * 2;
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				assert_noop!(cancel_query(origin.clone(), 0), Error::MessageNotFound);
+
+				let timeout = System::block_number() + 1;
+				let (completed, query_id) =
+					new_query(origin.clone(), RESPONSE_LOCATION, timeout, None).unwrap();
+				assert_ok!(super::Pallet::<Test>::xcm_response(root(), query_id, Response::Null));
+				assert_noop!(cancel_query(origin.clone(), completed), Error::MessageCompleted);
+
+				let (timed_out, _) =
+					new_query(origin.clone(), RESPONSE_LOCATION, timeout, None).unwrap();
+				run_to(timeout + 1);
+				assert_noop!(cancel_query(origin, timed_out), Error::RequestTimedOut);
+			})
+	}
+
+	#[test]
+	fn xcm_response_rejected_whilst_migrating() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let endowment = existential_deposit() + deposit() + xcm_response_fee();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 1;
+				let (id, query_id) = new_query(origin, RESPONSE_LOCATION, timeout, None).unwrap();
+				StorageVersion::new(0).put::<super::Pallet<Test>>();
+
+				assert_noop!(
+					super::Pallet::<Test>::xcm_response(root(), query_id, Response::Null),
+					Error::MigrationOngoing
+				);
+				assert!(matches!(Messages::get(id), Some(Message::Pending { .. })));
+			})
+	}
+
+	#[test]
+	fn xcm_queries_not_timed_out_whilst_migrating() {
+		let origin = Origin::from((ALICE_ADDR, ALICE));
+		let endowment = existential_deposit() + deposit() + xcm_response_fee();
+		ExtBuilder::new()
+			.with_balances(vec![(origin.account.clone(), endowment)])
+			.build()
+			.execute_with(|| {
+				let timeout = System::block_number() + 1;
+				let (id, _) = new_query(origin, RESPONSE_LOCATION, timeout, None).unwrap();
+				StorageVersion::new(0).put::<super::Pallet<Test>>();
+
+				run_to(timeout + 1);
+				assert!(matches!(Messages::get(id), Some(Message::Pending { .. })));
+
+				// Processed once the migration completes.
+				StorageVersion::new(1).put::<super::Pallet<Test>>();
+				super::Pallet::<Test>::on_initialize(timeout);
+				assert!(matches!(Messages::get(id), Some(Message::Timeout { .. })));
+			})
+	}
+
 	pub(crate) fn deposit() -> Balance {
-		calculate_protocol_deposit::<Test, OnChainByteFee>(ProtocolStorageDeposit::XcmQueries) +
+		calculate_transport_deposit::<Test, XcmTransport, OnChainByteFee>() +
 			calculate_message_deposit::<Test, OnChainByteFee>()
 	}
 
@@ -348,9 +1014,37 @@ pub(crate) mod tests {
 		})
 	}
 
+	fn cancel_query(origin: Origin, id: MessageId) -> Result<QueryId, DispatchError> {
+		with_transaction(|| {
+			let result = super::cancel_query::<Test>(origin, id);
+			match &result {
+				Ok(_) => TransactionOutcome::Commit(result),
+				Err(_) => TransactionOutcome::Rollback(result),
+			}
+		})
+	}
+
+	// The block at which the query of a pending message is to time out.
+	fn timeout_block(id: MessageId) -> u32 {
+		let Some(Message::Pending { transport, .. }) = Messages::get(id) else {
+			panic!("Message should be a pending query!")
+		};
+		let query_id = transport.key::<Test, XcmTransport>().unwrap();
+		PendingXcmQueries::get(query_id).unwrap()
+	}
+
+	// The weight of handling a response of the maximum length, as covered by the prepaid fee.
+	fn response_weight() -> Weight {
+		WeightInfo::xcm_response(<MaxResponseLen as Get<u32>>::get())
+	}
+
 	pub(crate) fn xcm_response_fee() -> Balance {
-		WeightToFee::weight_to_fee(
-			&(WeightInfo::xcm_response() + CallbackExecutor::execution_weight()),
-		)
+		WeightToFee::weight_to_fee(&(response_weight() + CallbackExecutor::execution_weight()))
+	}
+
+	// The part of the prepaid fee held until the response is received.
+	fn held_response_fee() -> Balance {
+		WeightToFee::weight_to_fee(&response_weight()) -
+			WeightToFee::weight_to_fee(&WeightInfo::xcm_response(0))
 	}
 }
@@ -31,23 +31,62 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_api_vnext :: messaging`.
 pub trait WeightInfo {
+	fn accept_message() -> Weight;
 	fn block_number() -> Weight;
+	fn expire_message() -> Weight;
 	fn get_response() -> Weight;
 	fn id() -> Weight;
+	fn ismp_allow_source() -> Weight;
+	fn ismp_cancel_subscription() -> Weight;
+	fn ismp_conclude_batch_request(x: u32, ) -> Weight;
+	fn ismp_dispatch_subscription() -> Weight;
+	fn ismp_fund_request() -> Weight;
 	fn ismp_get(x: u32, y: u32, a: u32, ) -> Weight;
+	fn ismp_get_batch(x: u32, a: u32, ) -> Weight;
 	fn ismp_on_accept() -> Weight;
-	fn ismp_on_response(x: u32, ) -> Weight;
+	fn ismp_on_response(x: u32, y: u32, ) -> Weight;
 	fn ismp_on_timeout(x: u32, ) -> Weight;
 	fn ismp_post(t: u32, x: u32, y: u32, ) -> Weight;
+	fn ismp_revoke_source() -> Weight;
+	fn ismp_subscribe(x: u32, y: u32, ) -> Weight;
+	fn poll_batch() -> Weight;
 	fn poll_status() -> Weight;
+	fn quote() -> Weight;
 	fn remove(x: u32, ) -> Weight;
+	fn retry_deferred_callback() -> Weight;
+	fn set_pending_message_quota() -> Weight;
+	fn transfer_message() -> Weight;
+	fn update_callback() -> Weight;
+	fn xcm_cancel_query() -> Weight;
 	fn xcm_new_query(x: u32, ) -> Weight;
-	fn xcm_response() -> Weight;
+	fn xcm_response(x: u32, ) -> Weight;
+	fn xcm_timeout_query() -> Weight;
 }
 
 /// Weights for `pallet_api_vnext :: messaging` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageTransfers` (r:1 w:1)
+	/// Proof: `Messaging::MessageTransfers` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:2 w:2)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `781`
+		//  Estimated: `16247`
+		// Minimum execution time: 74_000_000 picoseconds.
+		Weight::from_parts(76_000_000, 16247)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
 	fn block_number() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -55,6 +94,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 1_000_000 picoseconds.
 		Weight::from_parts(1_000_000, 0)
 	}
+	/// Storage: `Messaging::MessageExpiries` (r:1 w:1)
+	/// Proof: `Messaging::MessageExpiries` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::DeferredCallbacks` (r:1 w:1)
+	/// Proof: `Messaging::DeferredCallbacks` (`max_values`: None, `max_size`: Some(13143), added: 15618, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::DeferredCallbackQueue` (r:1 w:1)
+	/// Proof: `Messaging::DeferredCallbackQueue` (`max_values`: Some(1), `max_size`: Some(801), added: 1296, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpRequests` (r:0 w:1)
+	/// Proof: `Messaging::IsmpRequests` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn expire_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13641`
+		//  Estimated: `16608`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 16608)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
 	/// Storage: `Messaging::Messages` (r:1 w:0)
 	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
 	fn get_response() -> Weight {
@@ -75,8 +137,122 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(4_000_000, 1489)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	/// Storage: `Messaging::IsmpPostRequestSources` (r:1 w:1)
+	/// Proof: `Messaging::IsmpPostRequestSources` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn ismp_allow_source() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `573`
+		//  Estimated: `3694`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Messaging::Subscriptions` (r:1 w:1)
+	/// Proof: `Messaging::Subscriptions` (`max_values`: None, `max_size`: Some(6884), added: 9359, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::SubscriptionSchedule` (r:0 w:1)
+	/// Proof: `Messaging::SubscriptionSchedule` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn ismp_cancel_subscription() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1092`
+		//  Estimated: `10349`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(50_000_000, 10349)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Messaging::IsmpBatchRequests` (r:1 w:1)
+	/// Proof: `Messaging::IsmpBatchRequests` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpBatches` (r:1 w:1)
+	/// Proof: `Messaging::IsmpBatches` (`max_values`: None, `max_size`: Some(1394), added: 3869, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpFees` (r:0 w:1)
+	/// Proof: `Messaging::IsmpFees` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiryQueue` (r:1 w:1)
+	/// Proof: `Messaging::MessageExpiryQueue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiries` (r:0 w:1)
+	/// Proof: `Messaging::MessageExpiries` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 1]`.
+	fn ismp_conclude_batch_request(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1987`
+		//  Estimated: `16247`
+		// Minimum execution time: 118_000_000 picoseconds.
+		Weight::from_parts(124_512_500, 16247)
+			// Standard Error: 61_237
+			.saturating_add(Weight::from_parts(1_487_500, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Messaging::Subscriptions` (r:1 w:1)
+	/// Proof: `Messaging::Subscriptions` (`max_values`: None, `max_size`: Some(6884), added: 9359, mode: `MaxEncodedLen`)
+	/// Storage: `Ismp::LatestStateMachineHeight` (r:2 w:0)
+	/// Proof: `Ismp::LatestStateMachineHeight` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::NextMessageId` (r:1 w:1)
+	/// Proof: `Messaging::NextMessageId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Ismp::Nonce` (r:1 w:1)
+	/// Proof: `Ismp::Nonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::SubscriptionSchedule` (r:0 w:2)
+	/// Proof: `Messaging::SubscriptionSchedule` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpRequests` (r:0 w:1)
+	/// Proof: `Messaging::IsmpRequests` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:0 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn ismp_dispatch_subscription() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1720`
+		//  Estimated: `16247`
+		// Minimum execution time: 142_000_000 picoseconds.
+		Weight::from_parts(149_000_000, 16247)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn ismp_fund_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1184`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 	/// Storage: `Skipped::Metadata` (r:0 w:0)
 	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 64]`.
 	/// The range of component `y` is `[0, 10]`.
 	/// The range of component `a` is `[0, 1]`.
@@ -92,15 +268,60 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(234_612, 0).saturating_mul(y.into()))
 			// Standard Error: 216_975
 			.saturating_add(Weight::from_parts(707_725, 0).saturating_mul(a.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpBatchRequests` (r:0 w:10)
+	/// Proof: `Messaging::IsmpBatchRequests` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpBatches` (r:0 w:1)
+	/// Proof: `Messaging::IsmpBatches` (`max_values`: None, `max_size`: Some(1394), added: 3869, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 10]`.
+	/// The range of component `a` is `[0, 1]`.
+	fn ismp_get_batch(x: u32, a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `804`
+		//  Estimated: `804`
+		// Minimum execution time: 131_000_000 picoseconds.
+		Weight::from_parts(112_464_228, 804)
+			// Standard Error: 41_306
+			.saturating_add(Weight::from_parts(27_912_341, 0).saturating_mul(x.into()))
+			// Standard Error: 243_019
+			.saturating_add(Weight::from_parts(1_104_558, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(x.into())))
+	}
+	/// Storage: `Messaging::IsmpPostRequestSources` (r:1 w:0)
+	/// Proof: `Messaging::IsmpPostRequestSources` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Ismp::Responded` (r:1 w:1)
+	/// Proof: `Ismp::Responded` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x52657175657374526563656970747300` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x52657175657374526563656970747300` (r:1 w:0)
+	/// Storage: `Ismp::Nonce` (r:1 w:1)
+	/// Proof: `Ismp::Nonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x526573706f6e7365436f6d6d69746d656e747300` (r:0 w:1)
+	/// Proof: UNKNOWN KEY `0x526573706f6e7365436f6d6d69746d656e747300` (r:0 w:1)
 	fn ismp_on_accept() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 0_000 picoseconds.
-		Weight::from_parts(0, 0)
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		// Minimum execution time: 94_000_000 picoseconds.
+		Weight::from_parts(98_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Messaging::IsmpRequests` (r:1 w:1)
 	/// Proof: `Messaging::IsmpRequests` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
@@ -110,20 +331,33 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 1]`.
-	fn ismp_on_response(_x: u32, ) -> Weight {
+	/// The range of component `y` is `[0, 512]`.
+	fn ismp_on_response(x: u32, y: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `579`
 		//  Estimated: `16247`
 		// Minimum execution time: 92_000_000 picoseconds.
-		Weight::from_parts(104_000_000, 16247)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		Weight::from_parts(93_204_081, 16247)
+			// Standard Error: 114_627
+			.saturating_add(Weight::from_parts(1_295_918, 0).saturating_mul(x.into()))
+			// Standard Error: 223
+			.saturating_add(Weight::from_parts(19_482, 0).saturating_mul(y.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Messaging::IsmpRequests` (r:1 w:0)
 	/// Proof: `Messaging::IsmpRequests` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// Storage: `Messaging::Messages` (r:1 w:1)
 	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiryQueue` (r:1 w:1)
+	/// Proof: `Messaging::MessageExpiryQueue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiries` (r:0 w:1)
+	/// Proof: `Messaging::MessageExpiries` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 2]`.
 	fn ismp_on_timeout(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -133,11 +367,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(15_990_331, 16247)
 			// Standard Error: 18_842
 			.saturating_add(Weight::from_parts(133_977, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Skipped::Metadata` (r:0 w:0)
 	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// The range of component `t` is `[0, 32]`.
 	/// The range of component `x` is `[0, 512]`.
 	/// The range of component `y` is `[0, 1]`.
@@ -153,8 +391,60 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(4_775, 0).saturating_mul(x.into()))
 			// Standard Error: 44_992
 			.saturating_add(Weight::from_parts(31_581_977, 0).saturating_mul(y.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Messaging::IsmpPostRequestSources` (r:1 w:1)
+	/// Proof: `Messaging::IsmpPostRequestSources` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn ismp_revoke_source() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `771`
+		//  Estimated: `3694`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(38_000_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Messaging::NextSubscriptionId` (r:1 w:1)
+	/// Proof: `Messaging::NextSubscriptionId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::SubscriptionSchedule` (r:0 w:1)
+	/// Proof: `Messaging::SubscriptionSchedule` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Subscriptions` (r:0 w:1)
+	/// Proof: `Messaging::Subscriptions` (`max_values`: None, `max_size`: Some(6884), added: 9359, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 64]`.
+	/// The range of component `y` is `[0, 10]`.
+	fn ismp_subscribe(x: u32, y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `296`
+		//  Estimated: `3694`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(55_418_220, 3694)
+			// Standard Error: 3_911
+			.saturating_add(Weight::from_parts(12_804, 0).saturating_mul(x.into()))
+			// Standard Error: 24_067
+			.saturating_add(Weight::from_parts(98_531, 0).saturating_mul(y.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Messaging::IsmpBatches` (r:1 w:0)
+	/// Proof: `Messaging::IsmpBatches` (`max_values`: None, `max_size`: Some(1394), added: 3869, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:1 w:0)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	fn poll_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1234`
+		//  Estimated: `16247`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 16247)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
 	/// Storage: `Messaging::Messages` (r:1 w:0)
 	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
@@ -166,6 +456,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(6_000_000, 16247)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	fn quote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_000_000 picoseconds.
+		Weight::from_parts(2_000_000, 0)
+	}
 	/// Storage: `Messaging::Messages` (r:100 w:100)
 	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
@@ -187,6 +484,90 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 15257).saturating_mul(x.into()))
 	}
+	/// Storage: `Messaging::DeferredCallbackQueue` (r:1 w:1)
+	/// Proof: `Messaging::DeferredCallbackQueue` (`max_values`: Some(1), `max_size`: Some(801), added: 1296, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::DeferredCallbacks` (r:1 w:1)
+	/// Proof: `Messaging::DeferredCallbacks` (`max_values`: None, `max_size`: Some(13143), added: 15618, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpRequests` (r:0 w:1)
+	/// Proof: `Messaging::IsmpRequests` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn retry_deferred_callback() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13569`
+		//  Estimated: `16608`
+		// Minimum execution time: 74_000_000 picoseconds.
+		Weight::from_parts(76_000_000, 16608)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Messaging::PendingMessageQuotas` (r:0 w:1)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn set_pending_message_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Messaging::Messages` (r:1 w:0)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageTransfers` (r:0 w:1)
+	/// Proof: `Messaging::MessageTransfers` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn transfer_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `533`
+		//  Estimated: `16247`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 16247)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_callback() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `561`
+		//  Estimated: `16247`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 16247)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmQueryTimeouts` (r:1 w:1)
+	/// Proof: `Messaging::XcmQueryTimeouts` (`max_values`: None, `max_size`: Some(814), added: 3289, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmQueries` (r:0 w:1)
+	/// Proof: `Messaging::XcmQueries` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::Queries` (r:1 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Messaging::XcmQueryTimeoutOverflow` (r:0 w:1)
+	/// Proof: `Messaging::XcmQueryTimeoutOverflow` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmResponseFees` (r:1 w:1)
+	/// Proof: `Messaging::XcmResponseFees` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn xcm_cancel_query() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `16247`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 16247)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
 	/// Storage: `Messaging::NextMessageId` (r:1 w:1)
 	/// Proof: `Messaging::NextMessageId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Messaging::XcmQueryTimeouts` (r:1 w:1)
@@ -201,6 +582,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
 	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Messaging::XcmQueryTimeoutOverflow` (r:0 w:1)
+	/// Proof: `Messaging::XcmQueryTimeoutOverflow` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmResponseFees` (r:0 w:1)
+	/// Proof: `Messaging::XcmResponseFees` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 1]`.
 	fn xcm_new_query(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -210,8 +599,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(69_351_020, 4279)
 			// Standard Error: 152_852
 			.saturating_add(Weight::from_parts(21_048_979, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Messaging::XcmQueries` (r:1 w:1)
 	/// Proof: `Messaging::XcmQueries` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
@@ -221,19 +610,70 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn xcm_response() -> Weight {
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmResponseFees` (r:1 w:1)
+	/// Proof: `Messaging::XcmResponseFees` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 512]`.
+	fn xcm_response(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `547`
 		//  Estimated: `16247`
 		// Minimum execution time: 81_000_000 picoseconds.
-		Weight::from_parts(81_000_000, 16247)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		Weight::from_parts(81_492_307, 16247)
+			// Standard Error: 187
+			.saturating_add(Weight::from_parts(15_873, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Messaging::XcmQueryTimeoutOverflow` (r:1 w:1)
+	/// Proof: `Messaging::XcmQueryTimeoutOverflow` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiryQueue` (r:1 w:1)
+	/// Proof: `Messaging::MessageExpiryQueue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiries` (r:0 w:1)
+	/// Proof: `Messaging::MessageExpiries` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmResponseFees` (r:1 w:1)
+	/// Proof: `Messaging::XcmResponseFees` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	fn xcm_timeout_query() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `16247`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 16247)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageTransfers` (r:1 w:1)
+	/// Proof: `Messaging::MessageTransfers` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:2 w:2)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `781`
+		//  Estimated: `16247`
+		// Minimum execution time: 74_000_000 picoseconds.
+		Weight::from_parts(76_000_000, 16247)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
 	fn block_number() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -241,6 +681,29 @@ impl WeightInfo for () {
 		// Minimum execution time: 1_000_000 picoseconds.
 		Weight::from_parts(1_000_000, 0)
 	}
+	/// Storage: `Messaging::MessageExpiries` (r:1 w:1)
+	/// Proof: `Messaging::MessageExpiries` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::DeferredCallbacks` (r:1 w:1)
+	/// Proof: `Messaging::DeferredCallbacks` (`max_values`: None, `max_size`: Some(13143), added: 15618, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::DeferredCallbackQueue` (r:1 w:1)
+	/// Proof: `Messaging::DeferredCallbackQueue` (`max_values`: Some(1), `max_size`: Some(801), added: 1296, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpRequests` (r:0 w:1)
+	/// Proof: `Messaging::IsmpRequests` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn expire_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13641`
+		//  Estimated: `16608`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 16608)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
 	/// Storage: `Messaging::Messages` (r:1 w:0)
 	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
 	fn get_response() -> Weight {
@@ -261,8 +724,122 @@ impl WeightInfo for () {
 		Weight::from_parts(4_000_000, 1489)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	/// Storage: `Messaging::IsmpPostRequestSources` (r:1 w:1)
+	/// Proof: `Messaging::IsmpPostRequestSources` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn ismp_allow_source() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `573`
+		//  Estimated: `3694`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Messaging::Subscriptions` (r:1 w:1)
+	/// Proof: `Messaging::Subscriptions` (`max_values`: None, `max_size`: Some(6884), added: 9359, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::SubscriptionSchedule` (r:0 w:1)
+	/// Proof: `Messaging::SubscriptionSchedule` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn ismp_cancel_subscription() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1092`
+		//  Estimated: `10349`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(50_000_000, 10349)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Messaging::IsmpBatchRequests` (r:1 w:1)
+	/// Proof: `Messaging::IsmpBatchRequests` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpBatches` (r:1 w:1)
+	/// Proof: `Messaging::IsmpBatches` (`max_values`: None, `max_size`: Some(1394), added: 3869, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpFees` (r:0 w:1)
+	/// Proof: `Messaging::IsmpFees` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiryQueue` (r:1 w:1)
+	/// Proof: `Messaging::MessageExpiryQueue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiries` (r:0 w:1)
+	/// Proof: `Messaging::MessageExpiries` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 1]`.
+	fn ismp_conclude_batch_request(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1987`
+		//  Estimated: `16247`
+		// Minimum execution time: 118_000_000 picoseconds.
+		Weight::from_parts(124_512_500, 16247)
+			// Standard Error: 61_237
+			.saturating_add(Weight::from_parts(1_487_500, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Messaging::Subscriptions` (r:1 w:1)
+	/// Proof: `Messaging::Subscriptions` (`max_values`: None, `max_size`: Some(6884), added: 9359, mode: `MaxEncodedLen`)
+	/// Storage: `Ismp::LatestStateMachineHeight` (r:2 w:0)
+	/// Proof: `Ismp::LatestStateMachineHeight` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::NextMessageId` (r:1 w:1)
+	/// Proof: `Messaging::NextMessageId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Ismp::Nonce` (r:1 w:1)
+	/// Proof: `Ismp::Nonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::SubscriptionSchedule` (r:0 w:2)
+	/// Proof: `Messaging::SubscriptionSchedule` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpRequests` (r:0 w:1)
+	/// Proof: `Messaging::IsmpRequests` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:0 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn ismp_dispatch_subscription() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1720`
+		//  Estimated: `16247`
+		// Minimum execution time: 142_000_000 picoseconds.
+		Weight::from_parts(149_000_000, 16247)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn ismp_fund_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1184`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 	/// Storage: `Skipped::Metadata` (r:0 w:0)
 	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 64]`.
 	/// The range of component `y` is `[0, 10]`.
 	/// The range of component `a` is `[0, 1]`.
@@ -278,15 +855,60 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(234_612, 0).saturating_mul(y.into()))
 			// Standard Error: 216_975
 			.saturating_add(Weight::from_parts(707_725, 0).saturating_mul(a.into()))
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpBatchRequests` (r:0 w:10)
+	/// Proof: `Messaging::IsmpBatchRequests` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpBatches` (r:0 w:1)
+	/// Proof: `Messaging::IsmpBatches` (`max_values`: None, `max_size`: Some(1394), added: 3869, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 10]`.
+	/// The range of component `a` is `[0, 1]`.
+	fn ismp_get_batch(x: u32, a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `804`
+		//  Estimated: `804`
+		// Minimum execution time: 131_000_000 picoseconds.
+		Weight::from_parts(112_464_228, 804)
+			// Standard Error: 41_306
+			.saturating_add(Weight::from_parts(27_912_341, 0).saturating_mul(x.into()))
+			// Standard Error: 243_019
+			.saturating_add(Weight::from_parts(1_104_558, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(x.into())))
+	}
+	/// Storage: `Messaging::IsmpPostRequestSources` (r:1 w:0)
+	/// Proof: `Messaging::IsmpPostRequestSources` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Ismp::Responded` (r:1 w:1)
+	/// Proof: `Ismp::Responded` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x52657175657374526563656970747300` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x52657175657374526563656970747300` (r:1 w:0)
+	/// Storage: `Ismp::Nonce` (r:1 w:1)
+	/// Proof: `Ismp::Nonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x526573706f6e7365436f6d6d69746d656e747300` (r:0 w:1)
+	/// Proof: UNKNOWN KEY `0x526573706f6e7365436f6d6d69746d656e747300` (r:0 w:1)
 	fn ismp_on_accept() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 0_000 picoseconds.
-		Weight::from_parts(0, 0)
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		// Minimum execution time: 94_000_000 picoseconds.
+		Weight::from_parts(98_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Messaging::IsmpRequests` (r:1 w:1)
 	/// Proof: `Messaging::IsmpRequests` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
@@ -296,20 +918,33 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 1]`.
-	fn ismp_on_response(_x: u32, ) -> Weight {
+	/// The range of component `y` is `[0, 512]`.
+	fn ismp_on_response(x: u32, y: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `579`
 		//  Estimated: `16247`
 		// Minimum execution time: 92_000_000 picoseconds.
-		Weight::from_parts(104_000_000, 16247)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		Weight::from_parts(93_204_081, 16247)
+			// Standard Error: 114_627
+			.saturating_add(Weight::from_parts(1_295_918, 0).saturating_mul(x.into()))
+			// Standard Error: 223
+			.saturating_add(Weight::from_parts(19_482, 0).saturating_mul(y.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Messaging::IsmpRequests` (r:1 w:0)
 	/// Proof: `Messaging::IsmpRequests` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// Storage: `Messaging::Messages` (r:1 w:1)
 	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiryQueue` (r:1 w:1)
+	/// Proof: `Messaging::MessageExpiryQueue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiries` (r:0 w:1)
+	/// Proof: `Messaging::MessageExpiries` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 2]`.
 	fn ismp_on_timeout(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -319,11 +954,15 @@ impl WeightInfo for () {
 		Weight::from_parts(15_990_331, 16247)
 			// Standard Error: 18_842
 			.saturating_add(Weight::from_parts(133_977, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Skipped::Metadata` (r:0 w:0)
 	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// The range of component `t` is `[0, 32]`.
 	/// The range of component `x` is `[0, 512]`.
 	/// The range of component `y` is `[0, 1]`.
@@ -339,8 +978,60 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(4_775, 0).saturating_mul(x.into()))
 			// Standard Error: 44_992
 			.saturating_add(Weight::from_parts(31_581_977, 0).saturating_mul(y.into()))
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Messaging::IsmpPostRequestSources` (r:1 w:1)
+	/// Proof: `Messaging::IsmpPostRequestSources` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn ismp_revoke_source() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `771`
+		//  Estimated: `3694`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(38_000_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Messaging::NextSubscriptionId` (r:1 w:1)
+	/// Proof: `Messaging::NextSubscriptionId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::SubscriptionSchedule` (r:0 w:1)
+	/// Proof: `Messaging::SubscriptionSchedule` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Subscriptions` (r:0 w:1)
+	/// Proof: `Messaging::Subscriptions` (`max_values`: None, `max_size`: Some(6884), added: 9359, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 64]`.
+	/// The range of component `y` is `[0, 10]`.
+	fn ismp_subscribe(x: u32, y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `296`
+		//  Estimated: `3694`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(55_418_220, 3694)
+			// Standard Error: 3_911
+			.saturating_add(Weight::from_parts(12_804, 0).saturating_mul(x.into()))
+			// Standard Error: 24_067
+			.saturating_add(Weight::from_parts(98_531, 0).saturating_mul(y.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Messaging::IsmpBatches` (r:1 w:0)
+	/// Proof: `Messaging::IsmpBatches` (`max_values`: None, `max_size`: Some(1394), added: 3869, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:1 w:0)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	fn poll_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1234`
+		//  Estimated: `16247`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 16247)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
 	/// Storage: `Messaging::Messages` (r:1 w:0)
 	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
@@ -352,6 +1043,13 @@ impl WeightInfo for () {
 		Weight::from_parts(6_000_000, 16247)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	fn quote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_000_000 picoseconds.
+		Weight::from_parts(2_000_000, 0)
+	}
 	/// Storage: `Messaging::Messages` (r:100 w:100)
 	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
@@ -373,6 +1071,90 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 15257).saturating_mul(x.into()))
 	}
+	/// Storage: `Messaging::DeferredCallbackQueue` (r:1 w:1)
+	/// Proof: `Messaging::DeferredCallbackQueue` (`max_values`: Some(1), `max_size`: Some(801), added: 1296, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::DeferredCallbacks` (r:1 w:1)
+	/// Proof: `Messaging::DeferredCallbacks` (`max_values`: None, `max_size`: Some(13143), added: 15618, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::IsmpRequests` (r:0 w:1)
+	/// Proof: `Messaging::IsmpRequests` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn retry_deferred_callback() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13569`
+		//  Estimated: `16608`
+		// Minimum execution time: 74_000_000 picoseconds.
+		Weight::from_parts(76_000_000, 16608)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Messaging::PendingMessageQuotas` (r:0 w:1)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn set_pending_message_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Messaging::Messages` (r:1 w:0)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageTransfers` (r:0 w:1)
+	/// Proof: `Messaging::MessageTransfers` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn transfer_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `533`
+		//  Estimated: `16247`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 16247)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_callback() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `561`
+		//  Estimated: `16247`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 16247)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmQueryTimeouts` (r:1 w:1)
+	/// Proof: `Messaging::XcmQueryTimeouts` (`max_values`: None, `max_size`: Some(814), added: 3289, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmQueries` (r:0 w:1)
+	/// Proof: `Messaging::XcmQueries` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::Queries` (r:1 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Messaging::XcmQueryTimeoutOverflow` (r:0 w:1)
+	/// Proof: `Messaging::XcmQueryTimeoutOverflow` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmResponseFees` (r:1 w:1)
+	/// Proof: `Messaging::XcmResponseFees` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn xcm_cancel_query() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `16247`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 16247)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
 	/// Storage: `Messaging::NextMessageId` (r:1 w:1)
 	/// Proof: `Messaging::NextMessageId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Messaging::XcmQueryTimeouts` (r:1 w:1)
@@ -387,6 +1169,14 @@ impl WeightInfo for () {
 	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
 	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Messaging::XcmQueryTimeoutOverflow` (r:0 w:1)
+	/// Proof: `Messaging::XcmQueryTimeoutOverflow` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessageQuotas` (r:1 w:0)
+	/// Proof: `Messaging::PendingMessageQuotas` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmResponseFees` (r:0 w:1)
+	/// Proof: `Messaging::XcmResponseFees` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[0, 1]`.
 	fn xcm_new_query(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -396,8 +1186,8 @@ impl WeightInfo for () {
 		Weight::from_parts(69_351_020, 4279)
 			// Standard Error: 152_852
 			.saturating_add(Weight::from_parts(21_048_979, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Messaging::XcmQueries` (r:1 w:1)
 	/// Proof: `Messaging::XcmQueries` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
@@ -407,14 +1197,44 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn xcm_response() -> Weight {
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmResponseFees` (r:1 w:1)
+	/// Proof: `Messaging::XcmResponseFees` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 512]`.
+	fn xcm_response(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `547`
 		//  Estimated: `16247`
 		// Minimum execution time: 81_000_000 picoseconds.
-		Weight::from_parts(81_000_000, 16247)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		Weight::from_parts(81_492_307, 16247)
+			// Standard Error: 187
+			.saturating_add(Weight::from_parts(15_873, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Messaging::XcmQueryTimeoutOverflow` (r:1 w:1)
+	/// Proof: `Messaging::XcmQueryTimeoutOverflow` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::Messages` (r:1 w:1)
+	/// Proof: `Messaging::Messages` (`max_values`: None, `max_size`: Some(12782), added: 15257, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiryQueue` (r:1 w:1)
+	/// Proof: `Messaging::MessageExpiryQueue` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::MessageExpiries` (r:0 w:1)
+	/// Proof: `Messaging::MessageExpiries` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::PendingMessages` (r:1 w:1)
+	/// Proof: `Messaging::PendingMessages` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Messaging::XcmResponseFees` (r:1 w:1)
+	/// Proof: `Messaging::XcmResponseFees` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	fn xcm_timeout_query() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `16247`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 16247)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 }
 
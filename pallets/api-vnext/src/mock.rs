@@ -1,7 +1,7 @@
 use codec::Compact;
 use frame_support::{
 	assert_ok, derive_impl,
-	pallet_prelude::ConstU32,
+	pallet_prelude::{ConstU128, ConstU32},
 	parameter_types,
 	sp_runtime::{traits::AccountIdLookup, AccountId32, BuildStorage},
 	traits::{AsEnsureOriginWithArg, Get, OnInitialize},
@@ -10,6 +10,7 @@ use frame_support::{
 use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_assets::AutoIncAssetId;
 pub(crate) use pallet_revive::test_utils::{ALICE, ALICE_ADDR, BOB, BOB_ADDR, CHARLIE};
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 
 use super::fungibles;
 
@@ -22,10 +23,12 @@ pub(crate) type TokenId = u32;
 
 pub(crate) const ERC20: u16 = 2;
 pub(crate) const FUNGIBLES: u16 = 1;
+pub(crate) const FUNGIBLES_V1: u16 = 7;
 #[cfg(feature = "messaging")]
 pub(crate) const ISMP: u16 = 4;
 #[cfg(feature = "messaging")]
 pub(crate) const MESSAGING: u16 = 3;
+pub(crate) const NATIVE_ERC20: u16 = 6;
 pub(crate) const UNIT: Balance = 10_000_000_000;
 #[cfg(feature = "messaging")]
 pub(crate) const XCM: u16 = 5;
@@ -71,6 +74,11 @@ mod runtime {
 	#[runtime::pallet_index(9)]
 	#[cfg(feature = "messaging")]
 	pub type Xcm = pallet_xcm::Pallet<Runtime>;
+	#[runtime::pallet_index(10)]
+	#[cfg(feature = "messaging-loopback")]
+	pub type Loopback = crate::messaging::transports::loopback::Pallet<Runtime>;
+	#[runtime::pallet_index(11)]
+	pub type NativeFungibles = fungibles::native::Pallet<Runtime>;
 }
 
 #[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
@@ -81,6 +89,8 @@ impl pallet_assets::Config for Test {
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<Self::AccountId>>;
 	type Currency = Balances;
 	type ForceOrigin = EnsureRoot<AccountId>;
+	// Low enough for tokens with many holders to require several calls to be destroyed.
+	type RemoveItemsLimit = ConstU32<5>;
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
@@ -96,7 +106,9 @@ impl pallet_revive::Config for Test {
 	type InstantiateOrigin = EnsureSigned<Self::AccountId>;
 	type Precompiles = (
 		fungibles::precompiles::v0::Fungibles<FUNGIBLES, Test>,
+		fungibles::precompiles::v1::Fungibles<FUNGIBLES_V1, Test>,
 		fungibles::precompiles::erc20::v0::Erc20<ERC20, Test>,
+		fungibles::precompiles::erc20::v0::native::NativeErc20<NATIVE_ERC20, Test, NativeMetadata>,
 		messaging::precompiles::v0::Messaging<MESSAGING, Test>,
 		messaging::precompiles::ismp::v0::Ismp<ISMP, Test>,
 		messaging::precompiles::xcm::v0::Xcm<XCM, Test>,
@@ -106,17 +118,37 @@ impl pallet_revive::Config for Test {
 }
 
 impl fungibles::Config for Test {
+	type MaxBatchSize = ConstU32<5>;
+	type TokenIdConverter = fungibles::NumericTokenId;
 	type WeightInfo = ();
 }
 
+impl fungibles::native::Config for Test {
+	type ApprovalDeposit = ConstU128<100>;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type WeightInfo = ();
+}
+
+pub(crate) struct NativeMetadata;
+impl fungibles::precompiles::erc20::v0::native::Metadata for NativeMetadata {
+	const DECIMALS: u8 = 10;
+	const NAME: &'static str = "Native";
+	const SYMBOL: &'static str = "NAT";
+}
+
 #[cfg(feature = "messaging")]
 pub(super) mod messaging {
 	use ::ismp::{host::StateMachine, module::IsmpModule, router::IsmpRouter};
-	use ::xcm::latest::{
-		InteriorLocation, Junction, Junction::Parachain, Junctions, Location, NetworkId,
+	use ::xcm::{
+		latest::{
+			AssetId, Assets, InteriorLocation, Junction, Junction::Parachain, Junctions, Location,
+			NetworkId, Outcome,
+		},
+		VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm,
 	};
 	use frame_support::{
-		dispatch::PostDispatchInfo,
+		dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
 		pallet_prelude::{DispatchResultWithPostInfo, EnsureOrigin, Pays, Weight},
 		traits::{tokens::imbalance::ResolveTo, Everything, NeverEnsureOrigin, OriginTrait},
 		weights::WeightToFee,
@@ -126,21 +158,38 @@ pub(super) mod messaging {
 	use xcm_builder::{EnsureXcmOrigin, FixedWeightBounds, SignedToAccountId32};
 
 	use super::*;
+	#[cfg(feature = "messaging-loopback")]
+	use crate::messaging::transports::loopback;
 	pub(super) use crate::messaging::*;
-	use crate::{messaging::transports::xcm::NotifyQueryHandler, H160};
+	use crate::{
+		messaging::transports::xcm::{
+			NotifyQueryHandler, XcmApi, XcmDryRunApiError, XcmDryRunEffects, XcmPaymentApiError,
+		},
+		H160,
+	};
 
 	pub(crate) const RESPONSE_LOCATION: Location =
 		Location { parents: 1, interior: Junctions::Here };
 
 	parameter_types! {
+		pub DeferredCallbackWeightLimit: Weight = Weight::from_parts(1_000_000_000, 1_000_000);
+		pub IsmpPostRequestGasLimit: Weight = Weight::from_parts(100_000, 100_000);
+		pub const IsmpPostRequestStorageDepositLimit: Balance = 100_000;
+		pub const MaxCallbackRetries: u32 = 3;
+		pub const MaxDeferredCallbacks: u32 = 10;
 		pub const MaxInstructions: u32 = 100;
+		pub const MaxPendingMessages: u32 = 50;
 		pub const MaxXcmQueryTimeoutsPerBlock: u32 = 10;
+		pub const MessageRetentionPeriod: u32 = 10;
+		pub const MinSubscriptionInterval: u32 = 2;
 		pub const OnChainByteFee: Balance = 10;
 		pub const OffChainByteFee: Balance = 5;
 		pub const RelayNetwork: Option<NetworkId> = Some(NetworkId::Polkadot);
 		pub Treasury: AccountId = PalletId(*b"py/trsry").into_account_truncating();
 		pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
 		pub UniversalLocation: InteriorLocation = Parachain(2_000).into();
+		// The output returned by the mock callback executor.
+		pub static CallbackOutput: Vec<u8> = Vec::default();
 	}
 
 	impl pallet_ismp::Config for Test {
@@ -180,29 +229,53 @@ pub(super) mod messaging {
 	}
 
 	impl crate::messaging::Config for Test {
+		type AddressConverter = AddressToAccountId<Test>;
 		type CallbackExecutor = AlwaysSuccessfullCallbackExecutor<Test>;
+		type DeferredCallbackWeightLimit = DeferredCallbackWeightLimit;
 		type FeeHandler = ResolveTo<Treasury, Balances>;
 		type Fungibles = Balances;
+		#[cfg(feature = "messaging-loopback")]
+		type IsmpDispatcher = loopback::Dispatcher<Test, pallet_ismp::Pallet<Test>>;
+		#[cfg(not(feature = "messaging-loopback"))]
 		type IsmpDispatcher = pallet_ismp::Pallet<Test>;
+		type IsmpPostRequestGasLimit = IsmpPostRequestGasLimit;
+		type IsmpPostRequestStorageDepositLimit = IsmpPostRequestStorageDepositLimit;
 		type Keccak256 = Ismp;
+		type MaxBatchSize = ConstU32<10>;
+		type MaxCallbackRetries = MaxCallbackRetries;
 		type MaxContextLen = ConstU32<64>;
 		type MaxDataLen = ConstU32<1024>;
+		type MaxDeferredCallbacks = MaxDeferredCallbacks;
 		type MaxKeyLen = ConstU32<32>;
 		type MaxKeys = ConstU32<10>;
+		type MaxPendingMessages = MaxPendingMessages;
 		type MaxRecipientLen = ConstU32<32>;
 		type MaxRemovals = ConstU32<1024>;
 		type MaxResponseLen = ConstU32<1024>;
 		type MaxXcmQueryTimeoutsPerBlock = MaxXcmQueryTimeoutsPerBlock;
+		type MessageRetentionPeriod = MessageRetentionPeriod;
+		type MinSubscriptionInterval = MinSubscriptionInterval;
 		type OffChainByteFee = OffChainByteFee;
 		type OnChainByteFee = OnChainByteFee;
 		type OriginConverter = AccountToLocation;
+		type QuotaOrigin = EnsureRoot<AccountId>;
 		type RuntimeHoldReason = RuntimeHoldReason;
 		type WeightInfo = ();
 		type WeightToFee = RefTimePlusProofTime;
+		#[cfg(feature = "messaging-loopback")]
+		type Xcm = loopback::QueryHandler<Test, QueryHandler>;
+		#[cfg(not(feature = "messaging-loopback"))]
 		type Xcm = QueryHandler;
+		type XcmApi = XcmRuntimeApis;
 		type XcmResponseOrigin = EnsureRootWithResponseSuccess;
 	}
 
+	#[cfg(feature = "messaging-loopback")]
+	impl loopback::Config for Test {
+		type ControlOrigin = EnsureRoot<AccountId>;
+		type ResponseOrigin = RootResponseOrigin;
+	}
+
 	pub struct AccountToLocation;
 	impl TryConvert<RuntimeOrigin, Location> for AccountToLocation {
 		fn try_convert(origin: RuntimeOrigin) -> Result<Location, RuntimeOrigin> {
@@ -216,8 +289,8 @@ pub(super) mod messaging {
 		}
 	}
 
-	/// Will return half of the weight in the post info.
-	/// Mocking a successfull execution, with refund.
+	/// Will return half of the weight in the post info, along with any configured output.
+	/// Mocking a successful execution, with refund.
 	pub struct AlwaysSuccessfullCallbackExecutor<T>(T);
 	impl<T: crate::messaging::Config> CallbackExecutor<T> for AlwaysSuccessfullCallbackExecutor<T> {
 		fn execute(
@@ -233,6 +306,17 @@ pub(super) mod messaging {
 			})
 		}
 
+		fn execute_with_output(
+			account: &<T as frame_system::Config>::AccountId,
+			contract: H160,
+			data: Vec<u8>,
+			gas_limit: sp_runtime::Weight,
+			storage_deposit_limit: BalanceOf<T>,
+		) -> Result<(PostDispatchInfo, Vec<u8>), DispatchErrorWithPostInfo> {
+			Self::execute(account, contract, data, gas_limit, storage_deposit_limit)
+				.map(|post_info| (post_info, CallbackOutput::get()))
+		}
+
 		// Will be used for prepayment of response fees.
 		fn execution_weight() -> Weight {
 			Weight::from_parts(100_000u64, 100_000u64)
@@ -257,6 +341,16 @@ pub(super) mod messaging {
 		}
 	}
 
+	/// Converts the location of a responder to the origin satisfying `XcmResponseOrigin`.
+	#[cfg(feature = "messaging-loopback")]
+	pub struct RootResponseOrigin;
+	#[cfg(feature = "messaging-loopback")]
+	impl sp_runtime::traits::Convert<Location, RuntimeOrigin> for RootResponseOrigin {
+		fn convert(_responder: Location) -> RuntimeOrigin {
+			RuntimeOrigin::root()
+		}
+	}
+
 	pub struct QueryHandler;
 	impl NotifyQueryHandler<Test> for QueryHandler {
 		type WeightInfo = pallet_xcm::Pallet<Test>;
@@ -269,6 +363,68 @@ pub(super) mod messaging {
 		) -> u64 {
 			Xcm::new_notify_query(responder, notify, timeout, match_querier)
 		}
+
+		fn cancel_query(query_id: u64) {
+			messaging::transports::xcm::remove_pallet_xcm_query::<Test>(query_id)
+		}
+	}
+
+	/// Mocks the XCM runtime APIs, with each instruction weighing `UnitWeightCost`, delivery only
+	/// possible to the relay chain and fees only payable in the relay chain asset.
+	pub struct XcmRuntimeApis;
+	impl XcmApi<Test> for XcmRuntimeApis {
+		fn dry_run_xcm(
+			origin: VersionedLocation,
+			message: VersionedXcm<RuntimeCall>,
+		) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			let message: ::xcm::latest::Xcm<RuntimeCall> =
+				message.try_into().map_err(|_| XcmDryRunApiError::VersionedConversionFailed)?;
+			// A dry run resets the events of the block, before emitting its own.
+			System::reset_events();
+			System::deposit_event(frame_system::Event::CodeUpdated);
+			Ok(XcmDryRunEffects {
+				execution_result: Outcome::Complete {
+					used: UnitWeightCost::get().saturating_mul(message.len() as u64),
+				},
+				emitted_events: System::events().into_iter().map(|record| record.event).collect(),
+				forwarded_xcms: vec![(
+					origin,
+					vec![VersionedXcm::from(::xcm::latest::Xcm::<()>::new())],
+				)],
+			})
+		}
+
+		fn query_delivery_fees(
+			destination: VersionedLocation,
+			_message: VersionedXcm<()>,
+		) -> Result<VersionedAssets, XcmPaymentApiError> {
+			match Location::try_from(destination) {
+				Ok(destination) if destination == Location::parent() =>
+					Ok(VersionedAssets::from(Assets::from(vec![
+						(Location::parent(), 100u128).into()
+					]))),
+				Ok(_) => Err(XcmPaymentApiError::Unroutable),
+				Err(_) => Err(XcmPaymentApiError::VersionedConversionFailed),
+			}
+		}
+
+		fn query_weight_to_asset_fee(
+			weight: Weight,
+			asset: VersionedAssetId,
+		) -> Result<u128, XcmPaymentApiError> {
+			match AssetId::try_from(asset) {
+				Ok(AssetId(location)) if location == Location::parent() =>
+					Ok(RefTimePlusProofTime::weight_to_fee(&weight)),
+				Ok(_) => Err(XcmPaymentApiError::AssetNotFound),
+				Err(_) => Err(XcmPaymentApiError::VersionedConversionFailed),
+			}
+		}
+
+		fn query_xcm_weight(message: VersionedXcm<()>) -> Result<Weight, XcmPaymentApiError> {
+			let message: ::xcm::latest::Xcm<()> =
+				message.try_into().map_err(|_| XcmPaymentApiError::VersionedConversionFailed)?;
+			Ok(UnitWeightCost::get().saturating_mul(message.len() as u64))
+		}
 	}
 
 	pub struct RefTimePlusProofTime;
@@ -416,13 +572,19 @@ impl ExtBuilder {
 		.unwrap();
 
 		let mut ext = sp_io::TestExternalities::new(t);
+		ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
 		ext.execute_with(|| {
 			System::set_block_number(1);
 
 			#[cfg(feature = "messaging")]
 			{
+				// The storage version of the pallet is set at genesis.
+				use frame_support::traits::GetStorageVersion;
+				Messaging::in_code_storage_version().put::<Messaging>();
+
 				if let Some(messages) = self.messages.take() {
 					for (account, id, message, deposit) in messages {
+						messaging::OriginMessages::<Test>::insert(message.origin(), id, ());
 						messaging::Messages::<Test>::insert(id, message);
 
 						use frame_support::traits::fungible::MutateHold;
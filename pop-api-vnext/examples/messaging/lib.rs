@@ -19,7 +19,7 @@ use pop_api::{
 	messaging::{
 		self as api, hashed_account,
 		ismp::{self, Get, OnGetResponse, StorageValue},
-		xcm::{self, Location, OnQueryResponse},
+		xcm::{self, abi::QueryResponse, Location, OnTypedQueryResponse},
 		Bytes, Callback, Encoding, MessageId,
 		MessageStatus::*,
 		Weight,
@@ -32,9 +32,10 @@ use pop_api::{
 #[ink::contract]
 mod messaging {
 
-	use super::*;
 	use Error::{DecodingFailed, NoPermission, Overflow};
 
+	use super::*;
+
 	#[cfg(ink_abi = "sol")]
 	const ENCODING: Encoding = Encoding::SolidityAbi;
 	#[cfg(any(ink_abi = "all", ink_abi = "ink"))]
@@ -75,7 +76,8 @@ mod messaging {
 		#[ink(message)]
 		pub fn complete(&mut self, id: MessageId) -> Result<Option<Bytes>, Error> {
 			match api::poll_status(id) {
-				NotFound => Err(Error::NotFound),
+				// Only the requests of a batch can fail.
+				NotFound | Failed => Err(Error::NotFound),
 				Pending => Err(Error::Pending),
 				Complete => {
 					// Only requestor can remove messages.
@@ -213,7 +215,7 @@ mod messaging {
 				Some(Callback::new(
 					self.env().address(),
 					ENCODING,
-					0x97dbf9fb,
+					0xc4f80cae,
 					self.gas_limit,
 					self.storage_deposit_limit,
 				)),
@@ -294,13 +296,13 @@ mod messaging {
 		}
 	}
 
-	impl OnQueryResponse for Messaging {
+	impl OnTypedQueryResponse for Messaging {
 		#[ink(message)]
-		fn onQueryResponse(&mut self, id: MessageId, response: Bytes) {
+		fn onQueryResponse(&mut self, id: MessageId, response: QueryResponse) {
 			if self.env().caller() != self.env().address() {
 				revert(&NoPermission);
 			}
-			self.env().emit_event(XcmCompleted { id, result: response });
+			self.env().emit_event(XcmCompleted { id, response });
 		}
 	}
 
@@ -363,7 +365,7 @@ mod messaging {
 	pub struct XcmCompleted {
 		#[ink(topic)]
 		pub id: MessageId,
-		pub result: Bytes,
+		pub response: QueryResponse,
 	}
 
 	#[ink::event]
@@ -1,6 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-use ink::{abi::Sol, contract_ref, prelude::vec::Vec, storage::Mapping, U256};
+use ink::{
+	abi::Sol, contract_ref, prelude::vec::Vec, scale::Encode, storage::Mapping, SolBytes, U256,
+};
 use pop_api::{
 	messaging::{
 		self as api,
@@ -8,7 +10,9 @@ use pop_api::{
 			self, Get, Ismp, IsmpCallback, IsmpGetCompleted, IsmpPostCompleted, OnGetResponse,
 			OnPostResponse, Post, StorageValue,
 		},
-		xcm::{self, OnQueryResponse, QueryId, Xcm, XcmCallback, XcmCompleted},
+		xcm::{
+			self, abi::QueryResponse, OnTypedQueryResponse, QueryId, Xcm, XcmCallback, XcmCompleted,
+		},
 		Bytes, Callback, Error, MessageId, MessageStatus, Weight,
 	},
 	Pop,
@@ -66,6 +70,11 @@ pub mod messaging {
 	}
 
 	impl Ismp for Messaging {
+		#[ink(message)]
+		fn fundRequest(&self, message: MessageId, amount: U256) -> Result<(), ismp::Error> {
+			ismp::fund_request(message, amount)
+		}
+
 		#[ink(message)]
 		fn get(&self, request: Get, fee: U256) -> Result<MessageId, ismp::Error> {
 			ismp::get(request, fee, None)
@@ -122,6 +131,12 @@ pub mod messaging {
 	}
 
 	impl Xcm for Messaging {
+		#[ink(message)]
+		fn cancel(&self, message: MessageId) {
+			let precompile: contract_ref!(Xcm, Pop, Sol) = xcm::PRECOMPILE_ADDRESS.into();
+			precompile.cancel(message)
+		}
+
 		#[ink(message)]
 		fn execute(&self, message: Bytes, weight: Weight) -> Bytes {
 			let precompile: contract_ref!(Xcm, Pop, Sol) = xcm::PRECOMPILE_ADDRESS.into();
@@ -154,14 +169,15 @@ pub mod messaging {
 		}
 	}
 
-	impl OnQueryResponse for Messaging {
+	impl OnTypedQueryResponse for Messaging {
 		#[ink(message)]
-		fn onQueryResponse(&mut self, id: MessageId, response: Bytes) {
+		fn onQueryResponse(&mut self, id: MessageId, response: QueryResponse) {
 			// Adding state requires storage deposit limit to be defined on callback. Deposit is
 			// moved from caller to contract and placed on hold. Deposit is claimed by anyone that
 			// removes state, so adequate controls should be implemented by contract as desired.
-			self.responses.insert(id, &Response::XcmQuery(response.0.clone()));
-			self.env().emit_event(XcmCompleted { id, result: response });
+			let response = Encode::encode(&response);
+			self.responses.insert(id, &Response::XcmQuery(response.clone()));
+			self.env().emit_event(XcmCompleted { id, result: SolBytes(response) });
 		}
 	}
 
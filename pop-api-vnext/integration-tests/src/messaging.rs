@@ -35,14 +35,16 @@ mod ismp {
 		precompiles::ismp::v0::{
 			Callback, Encoding, Weight,
 			IISMP::{
-				get_0Call, get_1Call, post_0Call, post_1Call, Get, GetDispatched_0,
-				GetDispatched_1, Post, PostDispatched_0, PostDispatched_1,
+				fundRequestCall, get_0Call, get_1Call, post_0Call, post_1Call, Get,
+				GetDispatched_0, GetDispatched_1, Post, PostDispatched_0, PostDispatched_1,
+				RequestFunded,
 			},
 		},
-		transports::ismp::ID as ISMP_MODULE_ID,
+		transports::ismp::{IsmpTransport, ID as ISMP_MODULE_ID},
 		Event::{IsmpGetResponseReceived, IsmpPostResponseReceived},
 	};
 	use pallet_ismp::offchain::Leaf;
+	use pallet_revive::H256;
 	use pop_api::messaging::ismp::{IsmpGetCompleted, IsmpPostCompleted, PRECOMPILE_ADDRESS};
 	#[cfg(feature = "devnet")]
 	use pop_runtime_devnet::config::ismp::Router;
@@ -50,6 +52,36 @@ mod ismp {
 
 	use super::*;
 
+	#[test]
+	fn fund_request_works() {
+		let origin = ALICE;
+		let request = Get {
+			destination: ASSET_HUB,
+			height: 0,
+			timeout: 100_000,
+			context: b"some_context".to_vec().into(),
+			keys: vec![b"some_key".to_vec().into()].into(),
+		};
+		let amount = 1 * UNIT / 100;
+		ExtBuilder::new().build().execute_with(|| {
+			let contract = Contract::new(&origin, INIT_VALUE);
+			let id = contract.get(request, U256::zero(), None).unwrap();
+
+			assert_ok!(contract.fund_request(id, amount.into()));
+
+			let commitment = commitment(id);
+			let expected = RequestFunded {
+				origin: contract.address.0.into(),
+				id,
+				commitment: commitment.0.into(),
+				amount: alloy::U256::from(amount),
+				fee: alloy::U256::from(amount),
+			}
+			.encode_data();
+			assert_eq!(last_contract_event(&PRECOMPILE_ADDRESS), expected);
+		});
+	}
+
 	#[test]
 	fn get_works() {
 		let origin = ALICE;
@@ -72,7 +104,7 @@ mod ismp {
 
             let id = contract.get(request, U256::zero(), None).unwrap();
             assert_eq!(contract.poll_status(id), MessageStatus::Pending);
-            let Some(Message::Ismp{ commitment, .. }) = Messaging::get(id) else { panic!() };
+            let commitment = commitment(id);
             let expected = GetDispatched_0 { origin: contract.address.0.into(), id, commitment: commitment.0.into() }.encode_data();
             assert_eq!(last_contract_event(&PRECOMPILE_ADDRESS), expected);
             assert!(System::events().iter().any(|e| {
@@ -132,7 +164,7 @@ mod ismp {
 			};
 			let id = contract.get(request, U256::zero(), Some(callback.clone())).unwrap();
 			assert_eq!(contract.poll_status(id), MessageStatus::Pending);
-			let Some(Message::Ismp { commitment, .. }) = Messaging::get(id) else { panic!() };
+			let commitment = commitment(id);
 			let expected = GetDispatched_1 {
 				origin: contract.address.0.into(),
 				id,
@@ -206,7 +238,7 @@ mod ismp {
 
             let id = contract.post(request,  U256::zero(), None).unwrap();
             assert_eq!(contract.poll_status(id), MessageStatus::Pending);
-            let Some(Message::Ismp{ commitment, .. }) = Messaging::get(id) else { panic!() };
+            let commitment = commitment(id);
             let expected = PostDispatched_0 { origin: contract.address.0.into(), id, commitment: commitment.0.into() }.encode_data();
             assert_eq!(last_contract_event(&PRECOMPILE_ADDRESS), expected);
             assert!(System::events().iter().any(|e| {
@@ -266,7 +298,7 @@ mod ismp {
 			};
             let id = contract.post(request,  U256::zero(), Some(callback.clone())).unwrap();
             assert_eq!(contract.poll_status(id), MessageStatus::Pending);
-            let Some(Message::Ismp{ commitment, .. }) = Messaging::get(id) else { panic!() };
+            let commitment = commitment(id);
             let expected = PostDispatched_1 { origin: contract.address.0.into(), id, commitment: commitment.0.into(), callback }.encode_data();
             assert_eq!(last_contract_event(&PRECOMPILE_ADDRESS), expected);
             assert!(System::events().iter().any(|e| {
@@ -309,6 +341,12 @@ mod ismp {
 	}
 
 	impl Contract {
+		fn fund_request(&self, message: MessageId, amount: U256) -> Result<(), ismp::Error> {
+			let amount = alloy::U256::from_be_bytes(amount.to_big_endian());
+			self.call(&self.creator, fundRequestCall { message, amount }, 0)?;
+			Ok(())
+		}
+
 		fn get(
 			&self,
 			request: Get,
@@ -349,6 +387,11 @@ mod ismp {
 	}
 
 	// Get the last ismp request.
+	fn commitment(id: MessageId) -> H256 {
+		let Some(Message::Pending { transport, .. }) = Messaging::get(id) else { panic!() };
+		transport.key::<Runtime, IsmpTransport>().unwrap()
+	}
+
 	fn get_ismp_request(ext: &mut TestExternalities) -> Request {
 		// Get commitment from last ismp request event.
 		let commitment = ext.execute_with(|| {
@@ -393,13 +436,37 @@ mod xcm {
 	use ::xcm::prelude::{Junction, Location, MaybeErrorCode::Success, NetworkId, Response};
 	use pallet_api_vnext::messaging::precompiles::xcm::v0::{
 		Callback, Encoding, Weight,
-		IXCM::{newQuery_0Call, newQuery_1Call, QueryCreated_0, QueryCreated_1},
+		IXCM::{
+			cancelCall, newQuery_0Call, newQuery_1Call, QueryCancelled, QueryCreated_0,
+			QueryCreated_1,
+		},
 	};
-	use pop_api::messaging::xcm::{QueryId, XcmCompleted, PRECOMPILE_ADDRESS};
+	use pop_api::messaging::xcm::{abi, QueryId, XcmCompleted, PRECOMPILE_ADDRESS};
 	use xcm_executor::traits::OnResponse;
 
 	use super::*;
 
+	#[test]
+	fn cancel_works() {
+		let origin = ALICE;
+		let responder = Location::new(1, [Junction::Parachain(ASSET_HUB)]);
+		let timeout = 100;
+		ExtBuilder::new().build().execute_with(|| {
+			let contract = Contract::new(&origin, INIT_VALUE);
+			let (id, query_id) = contract.new_query(responder.encode(), timeout, None).unwrap();
+
+			assert_ok!(contract.cancel(id));
+
+			let expected =
+				QueryCancelled { account: contract.address.0.into(), id, queryId: query_id }
+					.encode_data();
+			assert_eq!(last_contract_event(&PRECOMPILE_ADDRESS), expected);
+			assert_eq!(contract.poll_status(id), MessageStatus::NotFound);
+			// Any late response is ignored.
+			assert!(!PolkadotXcm::expecting_response(&responder, query_id, None));
+		});
+	}
+
 	#[test]
 	fn id_works() {
 		let origin = ALICE;
@@ -459,7 +526,7 @@ mod xcm {
 			let callback = Callback {
 				destination: contract.address.0.into(),
 				encoding: Encoding::SolidityAbi,
-				selector: 0x97dbf9fbu32.into(),
+				selector: 0xc4f80caeu32.into(),
 				gasLimit: Weight { refTime: 850_000_000, proofSize: 120_000 },
 				storageDepositLimit: alloy::U256::from(1 * UNIT / 5),
 			};
@@ -495,6 +562,25 @@ mod xcm {
 				)
 			}));
 
+			// The response is provided to the contract as a Solidity struct.
+			let response = abi::QueryResponse {
+				response_type: abi::ResponseType::DispatchResult,
+				assets: Vec::new(),
+				non_fungible_assets: Vec::new(),
+				execution_result: abi::ExecutionResult {
+					success: false,
+					index: 0,
+					error: 0,
+					trap_code: 0,
+				},
+				version: 0,
+				pallets_info: Vec::new(),
+				dispatch_result: abi::MaybeErrorCode {
+					success: true,
+					error: SolBytes(Vec::new()),
+					truncated: false,
+				},
+			};
 			assert_eq!(
 				contract.last_event(),
 				XcmCompleted { id, result: SolBytes(response.encode()) }.encode()
@@ -504,6 +590,11 @@ mod xcm {
 	}
 
 	impl Contract {
+		fn cancel(&self, message: MessageId) -> Result<(), xcm::Error> {
+			self.call(&self.creator, cancelCall { message }, 0)?;
+			Ok(())
+		}
+
 		fn id(&self) -> u32 {
 			self.call::<_, Error>(&self.creator, idCall {}, 0).unwrap()
 		}
@@ -13,5 +13,7 @@ pub mod erc20;
 
 /// The first version of the Fungibles API.
 pub mod v0;
+/// The second version of the Fungibles API, adding the administration of tokens.
+pub mod v1;
 
 pub type TokenId = u32;
@@ -0,0 +1,886 @@
+pub use errors::{Error, Error::*};
+pub use events::*;
+
+use super::{contract_ref, ensure, fixed_address, Address, Pop, Sol, String, TokenId, Vec, U256};
+
+pub(crate) mod errors;
+mod events;
+
+// Precompile index within the runtime
+const PRECOMPILE: u16 = 9;
+
+/// The fungibles API offers a streamlined interface for interacting with fungible
+/// tokens. The goal is to provide a simplified, consistent API that adheres to standards in
+/// the smart contract space.
+///
+/// This version extends the first with the administration of tokens, such as freezing accounts
+/// and changing the management team of a token.
+#[ink::trait_definition]
+pub trait Fungibles {
+	/// Transfers `value` amount of tokens from the caller's account to account `to`.
+	///
+	/// # Parameters
+	/// - `token` - The token to transfer.
+	/// - `to` - The recipient account.
+	/// - `value` - The number of tokens to transfer.
+	#[ink(message)]
+	fn transfer(&self, token: TokenId, to: Address, value: U256) -> Result<(), Error>;
+
+	/// Transfers `value` amount tokens on behalf of `from` to account `to`.
+	///
+	/// # Parameters
+	/// - `token` - The token to transfer.
+	/// - `from` - The account from which the token balance will be withdrawn.
+	/// - `to` - The recipient account.
+	/// - `value` - The number of tokens to transfer.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn transferFrom(
+		&self,
+		token: TokenId,
+		from: Address,
+		to: Address,
+		value: U256,
+	) -> Result<(), Error>;
+
+	/// Transfers `values[i]` amount of tokens from the caller's account to account `to[i]`, for
+	/// each recipient. Either all transfers succeed or none are applied.
+	///
+	/// # Parameters
+	/// - `token` - The token to transfer.
+	/// - `to` - The recipient accounts.
+	/// - `values` - The number of tokens to transfer to each recipient.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn transferBatch(
+		&self,
+		token: TokenId,
+		to: Vec<Address>,
+		values: Vec<U256>,
+	) -> Result<(), Error>;
+
+	/// Transfers `values[i]` amount of tokens on behalf of `from` to account `to[i]`, for each
+	/// recipient. Either all transfers succeed or none are applied.
+	///
+	/// # Parameters
+	/// - `token` - The token to transfer.
+	/// - `from` - The account from which the token balance will be withdrawn.
+	/// - `to` - The recipient accounts.
+	/// - `values` - The number of tokens to transfer to each recipient.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn transferFromBatch(
+		&self,
+		token: TokenId,
+		from: Address,
+		to: Vec<Address>,
+		values: Vec<U256>,
+	) -> Result<(), Error>;
+
+	/// Approves `spender` to spend `value` amount of tokens on behalf of the caller.
+	///
+	/// # Parameters
+	/// - `token` - The token to approve.
+	/// - `spender` - The account that is allowed to spend the tokens.
+	/// - `value` - The number of tokens to approve.
+	#[ink(message)]
+	fn approve(&self, token: TokenId, spender: Address, value: U256) -> Result<(), Error>;
+
+	/// Increases the allowance of `spender` by `value` amount of tokens.
+	///
+	/// # Parameters
+	/// - `token` - The token to have an allowance increased.
+	/// - `spender` - The account that is allowed to spend the tokens.
+	/// - `value` - The number of tokens to increase the allowance by.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn increaseAllowance(
+		&self,
+		token: TokenId,
+		spender: Address,
+		value: U256,
+	) -> Result<U256, Error>;
+
+	/// Decreases the allowance of `spender` by `value` amount of tokens.
+	///
+	/// # Parameters
+	/// - `token` - The token to have an allowance decreased.
+	/// - `spender` - The account that is allowed to spend the tokens.
+	/// - `value` - The number of tokens to decrease the allowance by.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn decreaseAllowance(
+		&self,
+		token: TokenId,
+		spender: Address,
+		value: U256,
+	) -> Result<U256, Error>;
+
+	/// Create a new token with an automatically generated identifier.
+	///
+	/// # Parameters
+	/// - `admin` - The account that will administer the token.
+	/// - `min_balance` - The minimum balance required for accounts holding this token.
+	///
+	/// NOTE: The minimum balance must be non-zero.
+	#[ink(message)]
+	fn create(&self, admin: Address, min_balance: U256) -> Result<TokenId, Error>;
+
+	/// Start the process of destroying a token.
+	///
+	/// # Parameters
+	/// - `token` - The token to be destroyed.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn startDestroy(&self, token: TokenId) -> Result<(), Error>;
+
+	/// Set the metadata for a token.
+	///
+	/// # Parameters
+	/// - `token`: The token to update.
+	/// - `name`: The user friendly name of this token.
+	/// - `symbol`: The exchange symbol for this token.
+	/// - `decimals`: The number of decimals this token uses to represent one unit.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn setMetadata(
+		&self,
+		token: TokenId,
+		name: String,
+		symbol: String,
+		decimals: u8,
+	) -> Result<(), Error>;
+
+	/// Clear the metadata for a token.
+	///
+	/// # Parameters
+	/// - `token` - The token to update.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn clearMetadata(&self, token: TokenId) -> Result<(), Error>;
+
+	/// Creates `value` amount of tokens and assigns them to `account`, increasing the total
+	/// supply.
+	///
+	/// # Parameters
+	/// - `token` - The token to mint.
+	/// - `account` - The account to be credited with the created tokens.
+	/// - `value` - The number of tokens to mint.
+	#[ink(message)]
+	fn mint(&self, token: TokenId, account: Address, value: U256) -> Result<(), Error>;
+
+	/// Destroys `value` amount of tokens from `account`, reducing the total supply.
+	///
+	/// # Parameters
+	/// - `token` - The token to burn.
+	/// - `account` - The account from which the tokens will be destroyed.
+	/// - `value` - The number of tokens to destroy.
+	#[ink(message)]
+	fn burn(&self, token: TokenId, account: Address, value: U256) -> Result<(), Error>;
+
+	/// Freezes `account`, preventing transfers of the token from the account.
+	///
+	/// Only callable by the freezer of the token.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	/// - `account` - The account to be frozen.
+	#[ink(message)]
+	fn freeze(&self, token: TokenId, account: Address) -> Result<(), Error>;
+
+	/// Thaws `account`, allowing transfers of the token from the account again.
+	///
+	/// Only callable by the admin of the token.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	/// - `account` - The account to be thawed.
+	#[ink(message)]
+	fn thaw(&self, token: TokenId, account: Address) -> Result<(), Error>;
+
+	/// Freezes the token, preventing all transfers of it.
+	///
+	/// Only callable by the freezer of the token.
+	///
+	/// # Parameters
+	/// - `token` - The token to be frozen.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn freezeToken(&self, token: TokenId) -> Result<(), Error>;
+
+	/// Thaws the token, allowing transfers of it again.
+	///
+	/// Only callable by the admin of the token.
+	///
+	/// # Parameters
+	/// - `token` - The token to be thawed.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn thawToken(&self, token: TokenId) -> Result<(), Error>;
+
+	/// Blocks `account`, preventing transfers of the token to and from the account.
+	///
+	/// Only callable by the freezer of the token. Blocked accounts can be unblocked via `thaw`.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	/// - `account` - The account to be blocked.
+	#[ink(message)]
+	fn block(&self, token: TokenId, account: Address) -> Result<(), Error>;
+
+	/// Changes the management team of the token.
+	///
+	/// Only callable by the owner of the token.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	/// - `issuer` - The account able to mint tokens.
+	/// - `admin` - The account able to burn tokens and thaw accounts.
+	/// - `freezer` - The account able to freeze and block accounts.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn setTeam(
+		&self,
+		token: TokenId,
+		issuer: Address,
+		admin: Address,
+		freezer: Address,
+	) -> Result<(), Error>;
+
+	/// Transfers the ownership of the token to `owner`, along with any deposits.
+	///
+	/// Only callable by the owner of the token.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	/// - `owner` - The new owner of the token.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn transferOwnership(&self, token: TokenId, owner: Address) -> Result<(), Error>;
+
+	/// Sets the minimum balance required for accounts holding the token.
+	///
+	/// Only callable by the owner of the token. The minimum balance can only be increased whilst
+	/// no accounts hold the token.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	/// - `min_balance` - The new minimum balance.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn setMinBalance(&self, token: TokenId, min_balance: U256) -> Result<(), Error>;
+
+	/// Creates an account of the caller for the token, by placing a deposit.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	#[ink(message)]
+	fn touch(&self, token: TokenId) -> Result<(), Error>;
+
+	/// Removes the account of the caller for the token, returning its deposit.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	/// - `allow_burn` - Whether any remaining balance of the account may be burned.
+	#[ink(message)]
+	fn refund(&self, token: TokenId, allow_burn: bool) -> Result<(), Error>;
+
+	/// Destroys the accounts holding a token being destroyed, returning their deposits.
+	///
+	/// Callable by any account once destruction has been started via `startDestroy`. At most a
+	/// fixed number of accounts are destroyed per call, so it may need to be called repeatedly
+	/// until no accounts remain.
+	///
+	/// # Parameters
+	/// - `token` - The token being destroyed.
+	///
+	/// # Returns
+	/// The number of accounts remaining.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn destroyAccounts(&self, token: TokenId) -> Result<u32, Error>;
+
+	/// Destroys the approvals of a token being destroyed, returning their deposits.
+	///
+	/// Callable by any account once destruction has been started via `startDestroy`. At most a
+	/// fixed number of approvals are destroyed per call, so it may need to be called repeatedly
+	/// until no approvals remain.
+	///
+	/// # Parameters
+	/// - `token` - The token being destroyed.
+	///
+	/// # Returns
+	/// The number of approvals remaining.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn destroyApprovals(&self, token: TokenId) -> Result<u32, Error>;
+
+	/// Completes the destruction of a token, returning the deposits of its owner.
+	///
+	/// Callable by any account once all accounts and approvals of the token have been destroyed
+	/// via `destroyAccounts` and `destroyApprovals`.
+	///
+	/// # Parameters
+	/// - `token` - The token being destroyed.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn finishDestroy(&self, token: TokenId) -> Result<(), Error>;
+
+	/// Total token supply for a specified token.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn totalSupply(&self, token: TokenId) -> U256;
+
+	/// Account balance for a specified `token` and `owner`.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	/// - `owner` - The owner of the token.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn balanceOf(&self, token: TokenId, owner: Address) -> U256;
+
+	/// Allowance for a `spender` approved by an `owner`, for a specified `token`.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	/// - `owner` - The owner of the token.
+	/// - `spender` - The spender with an allowance.
+	#[ink(message)]
+	fn allowance(&self, token: TokenId, owner: Address, spender: Address) -> U256;
+
+	/// Name of the specified token.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	#[ink(message)]
+	fn name(&self, token: TokenId) -> String;
+
+	/// Symbol for the specified token.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	#[ink(message)]
+	fn symbol(&self, token: TokenId) -> String;
+
+	/// Decimals for the specified token.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	#[ink(message)]
+	fn decimals(&self, token: TokenId) -> u8;
+
+	/// Whether the specified token exists.
+	///
+	/// # Parameters
+	/// - `token` - The token.
+	#[ink(message)]
+	fn exists(&self, token: TokenId) -> bool;
+}
+
+/// Allowance for a `spender` approved by an `owner`, for a specified `token`.
+///
+/// # Parameters
+/// - `token` - The token.
+/// - `owner` - The owner of the token.
+/// - `spender` - The spender with an allowance.
+#[inline]
+pub fn allowance(token: TokenId, owner: Address, spender: Address) -> U256 {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.allowance(token, owner, spender)
+}
+
+/// Approves `spender` to spend `value` amount of tokens on behalf of the caller.
+///
+/// # Parameters
+/// - `token` - The token to approve.
+/// - `spender` - The account that is allowed to spend the tokens.
+/// - `value` - The number of tokens to approve.
+#[inline]
+pub fn approve(token: TokenId, spender: Address, value: U256) -> Result<(), Error> {
+	ensure!(spender != Address::zero(), ZeroRecipientAddress);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.approve(token, spender, value)
+}
+
+/// Account balance for a specified `token` and `owner`.
+///
+/// # Parameters
+/// - `token` - The token.
+/// - `owner` - The owner of the token.
+#[inline]
+pub fn balance_of(token: TokenId, owner: Address) -> U256 {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.balanceOf(token, owner)
+}
+
+/// Blocks `account`, preventing transfers of the token to and from the account.
+///
+/// Only callable by the freezer of the token. Blocked accounts can be unblocked via [`thaw`].
+///
+/// # Parameters
+/// - `token` - The token.
+/// - `account` - The account to be blocked.
+#[inline]
+pub fn block(token: TokenId, account: Address) -> Result<(), Error> {
+	ensure!(account != Address::zero(), ZeroAccountAddress);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.block(token, account)
+}
+
+/// Destroys `value` amount of tokens from `account`, reducing the total supply.
+///
+/// # Parameters
+/// - `token` - The token to burn.
+/// - `account` - The account from which the tokens will be destroyed.
+/// - `value` - The number of tokens to destroy.
+#[inline]
+pub fn burn(token: TokenId, account: Address, value: U256) -> Result<(), Error> {
+	ensure!(account != Address::zero(), ZeroSenderAddress);
+	ensure!(value != U256::zero(), ZeroValue);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.burn(token, account, value)
+}
+
+/// Clear the metadata for a token.
+///
+/// # Parameters
+/// - `token` - The token to update.
+#[inline]
+pub fn clear_metadata(token: TokenId) -> Result<(), Error> {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.clearMetadata(token)
+}
+
+/// Create a new token with an automatically generated identifier.
+///
+/// # Parameters
+/// - `admin` - The account that will administer the token.
+/// - `min_balance` - The minimum balance required for accounts holding this token.
+///
+/// NOTE: The minimum balance must be non-zero.
+#[inline]
+pub fn create(admin: Address, min_balance: U256) -> Result<TokenId, Error> {
+	ensure!(admin != Address::zero(), ZeroAdminAddress);
+	ensure!(min_balance != U256::zero(), MinBalanceZero);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.create(admin, min_balance)
+}
+
+/// Decimals for the specified token.
+///
+/// # Parameters
+/// - `token` - The token.
+#[inline]
+pub fn decimals(token: TokenId) -> u8 {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.decimals(token)
+}
+
+/// Decreases the allowance of `spender` by `value` amount of tokens.
+///
+/// # Parameters
+/// - `token` - The token to have an allowance decreased.
+/// - `spender` - The account that is allowed to spend the tokens.
+/// - `value` - The number of tokens to decrease the allowance by.
+pub fn decrease_allowance(token: TokenId, spender: Address, value: U256) -> Result<U256, Error> {
+	ensure!(spender != Address::zero(), ZeroRecipientAddress);
+	ensure!(value != U256::zero(), ZeroValue);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.decreaseAllowance(token, spender, value)
+}
+
+/// Destroys the accounts holding a token being destroyed, returning their deposits.
+///
+/// Callable by any account once destruction has been started via [`start_destroy`]. At most a
+/// fixed number of accounts are destroyed per call, so it may need to be called repeatedly until
+/// no accounts remain.
+///
+/// # Parameters
+/// - `token` - The token being destroyed.
+///
+/// # Returns
+/// The number of accounts remaining.
+#[inline]
+pub fn destroy_accounts(token: TokenId) -> Result<u32, Error> {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.destroyAccounts(token)
+}
+
+/// Destroys the approvals of a token being destroyed, returning their deposits.
+///
+/// Callable by any account once destruction has been started via [`start_destroy`]. At most a
+/// fixed number of approvals are destroyed per call, so it may need to be called repeatedly until
+/// no approvals remain.
+///
+/// # Parameters
+/// - `token` - The token being destroyed.
+///
+/// # Returns
+/// The number of approvals remaining.
+#[inline]
+pub fn destroy_approvals(token: TokenId) -> Result<u32, Error> {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.destroyApprovals(token)
+}
+
+/// Whether the specified token exists.
+///
+/// # Parameters
+/// - `token` - The token.
+#[inline]
+pub fn exists(token: TokenId) -> bool {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.exists(token)
+}
+
+/// Completes the destruction of a token, returning the deposits of its owner.
+///
+/// Callable by any account once all accounts and approvals of the token have been destroyed via
+/// [`destroy_accounts`] and [`destroy_approvals`].
+///
+/// # Parameters
+/// - `token` - The token being destroyed.
+#[inline]
+pub fn finish_destroy(token: TokenId) -> Result<(), Error> {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.finishDestroy(token)
+}
+
+/// Freezes `account`, preventing transfers of the token from the account.
+///
+/// Only callable by the freezer of the token.
+///
+/// # Parameters
+/// - `token` - The token.
+/// - `account` - The account to be frozen.
+#[inline]
+pub fn freeze(token: TokenId, account: Address) -> Result<(), Error> {
+	ensure!(account != Address::zero(), ZeroAccountAddress);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.freeze(token, account)
+}
+
+/// Freezes the token, preventing all transfers of it.
+///
+/// Only callable by the freezer of the token.
+///
+/// # Parameters
+/// - `token` - The token to be frozen.
+#[inline]
+pub fn freeze_token(token: TokenId) -> Result<(), Error> {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.freezeToken(token)
+}
+
+/// Increases the allowance of `spender` by `value` amount of tokens.
+///
+/// # Parameters
+/// - `token` - The token to have an allowance increased.
+/// - `spender` - The account that is allowed to spend the tokens.
+/// - `value` - The number of tokens to increase the allowance by.
+pub fn increase_allowance(token: TokenId, spender: Address, value: U256) -> Result<U256, Error> {
+	ensure!(spender != Address::zero(), ZeroRecipientAddress);
+	ensure!(value != U256::zero(), ZeroValue);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.increaseAllowance(token, spender, value)
+}
+
+/// Creates `value` amount of tokens and assigns them to `account`, increasing the total
+/// supply.
+///
+/// # Parameters
+/// - `token` - The token to mint.
+/// - `account` - The account to be credited with the created tokens.
+/// - `value` - The number of tokens to mint.
+#[inline]
+pub fn mint(token: TokenId, account: Address, value: U256) -> Result<(), Error> {
+	ensure!(account != Address::zero(), ZeroRecipientAddress);
+	ensure!(value != U256::zero(), ZeroValue);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.mint(token, account, value)
+}
+
+/// Name of the specified token.
+///
+/// # Parameters
+/// - `token` - The token.
+#[inline]
+pub fn name(token: TokenId) -> String {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.name(token)
+}
+
+/// Removes the account of the caller for the token, returning its deposit.
+///
+/// # Parameters
+/// - `token` - The token.
+/// - `allow_burn` - Whether any remaining balance of the account may be burned.
+#[inline]
+pub fn refund(token: TokenId, allow_burn: bool) -> Result<(), Error> {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.refund(token, allow_burn)
+}
+
+/// Set the metadata for a token.
+///
+/// # Parameters
+/// - `token`: The token to update.
+/// - `name`: The user friendly name of this token.
+/// - `symbol`: The exchange symbol for this token.
+/// - `decimals`: The number of decimals this token uses to represent one unit.
+#[inline]
+pub fn set_metadata(
+	token: TokenId,
+	name: String,
+	symbol: String,
+	decimals: u8,
+) -> Result<(), Error> {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.setMetadata(token, name, symbol, decimals)
+}
+
+/// Sets the minimum balance required for accounts holding the token.
+///
+/// Only callable by the owner of the token. The minimum balance can only be increased whilst no
+/// accounts hold the token.
+///
+/// # Parameters
+/// - `token` - The token.
+/// - `min_balance` - The new minimum balance.
+#[inline]
+pub fn set_min_balance(token: TokenId, min_balance: U256) -> Result<(), Error> {
+	ensure!(min_balance != U256::zero(), MinBalanceZero);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.setMinBalance(token, min_balance)
+}
+
+/// Changes the management team of the token.
+///
+/// Only callable by the owner of the token.
+///
+/// # Parameters
+/// - `token` - The token.
+/// - `issuer` - The account able to mint tokens.
+/// - `admin` - The account able to burn tokens and thaw accounts.
+/// - `freezer` - The account able to freeze and block accounts.
+#[inline]
+pub fn set_team(
+	token: TokenId,
+	issuer: Address,
+	admin: Address,
+	freezer: Address,
+) -> Result<(), Error> {
+	ensure!(issuer != Address::zero(), ZeroAccountAddress);
+	ensure!(admin != Address::zero(), ZeroAdminAddress);
+	ensure!(freezer != Address::zero(), ZeroAccountAddress);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.setTeam(token, issuer, admin, freezer)
+}
+
+/// Start the process of destroying a token.
+///
+/// # Parameters
+/// - `token` - The token to be destroyed.
+#[inline]
+pub fn start_destroy(token: TokenId) -> Result<(), Error> {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.startDestroy(token)
+}
+
+/// Symbol for the specified token.
+///
+/// # Parameters
+/// - `token` - The token.
+#[inline]
+pub fn symbol(token: TokenId) -> String {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.symbol(token)
+}
+
+/// Thaws `account`, allowing transfers of the token from the account again.
+///
+/// Only callable by the admin of the token.
+///
+/// # Parameters
+/// - `token` - The token.
+/// - `account` - The account to be thawed.
+#[inline]
+pub fn thaw(token: TokenId, account: Address) -> Result<(), Error> {
+	ensure!(account != Address::zero(), ZeroAccountAddress);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.thaw(token, account)
+}
+
+/// Thaws the token, allowing transfers of it again.
+///
+/// Only callable by the admin of the token.
+///
+/// # Parameters
+/// - `token` - The token to be thawed.
+#[inline]
+pub fn thaw_token(token: TokenId) -> Result<(), Error> {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.thawToken(token)
+}
+
+/// Total token supply for a specified token.
+///
+/// # Parameters
+/// - `token` - The token.
+#[inline]
+pub fn total_supply(token: TokenId) -> U256 {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.totalSupply(token)
+}
+
+/// Creates an account of the caller for the token, by placing a deposit.
+///
+/// # Parameters
+/// - `token` - The token.
+#[inline]
+pub fn touch(token: TokenId) -> Result<(), Error> {
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.touch(token)
+}
+
+/// Transfers `value` amount of tokens from the caller's account to account `to`.
+///
+/// # Parameters
+/// - `token` - The token to transfer.
+/// - `to` - The recipient account.
+/// - `value` - The number of tokens to transfer.
+#[inline]
+pub fn transfer(token: TokenId, to: Address, value: U256) -> Result<(), Error> {
+	ensure!(to != Address::zero(), ZeroRecipientAddress);
+	ensure!(value != U256::zero(), ZeroValue);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.transfer(token, to, value)
+}
+
+/// Transfers `values[i]` amount of tokens from the caller's account to account `to[i]`, for each
+/// recipient. Either all transfers succeed or none are applied.
+///
+/// # Parameters
+/// - `token` - The token to transfer.
+/// - `to` - The recipient accounts.
+/// - `values` - The number of tokens to transfer to each recipient.
+///
+/// NOTE: The number of transfers is bounded by the runtime.
+#[inline]
+pub fn transfer_batch(token: TokenId, to: Vec<Address>, values: Vec<U256>) -> Result<(), Error> {
+	ensure!(!to.is_empty() && to.len() == values.len(), InvalidBatch);
+	ensure!(to.iter().all(|to| *to != Address::zero()), ZeroRecipientAddress);
+	ensure!(values.iter().all(|value| *value != U256::zero()), ZeroValue);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.transferBatch(token, to, values)
+}
+
+/// Transfers `value` amount tokens on behalf of `from` to account `to`.
+///
+/// # Parameters
+/// - `token` - The token to transfer.
+/// - `from` - The account from which the token balance will be withdrawn.
+/// - `to` - The recipient account.
+/// - `value` - The number of tokens to transfer.
+#[inline]
+pub fn transfer_from(token: TokenId, from: Address, to: Address, value: U256) -> Result<(), Error> {
+	ensure!(from != Address::zero(), ZeroSenderAddress);
+	ensure!(to != Address::zero(), ZeroRecipientAddress);
+	ensure!(to != from, InvalidRecipient(to));
+	ensure!(value != U256::zero(), ZeroValue);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.transferFrom(token, from, to, value)
+}
+
+/// Transfers `values[i]` amount of tokens on behalf of `from` to account `to[i]`, for each
+/// recipient. Either all transfers succeed or none are applied.
+///
+/// # Parameters
+/// - `token` - The token to transfer.
+/// - `from` - The account from which the token balance will be withdrawn.
+/// - `to` - The recipient accounts.
+/// - `values` - The number of tokens to transfer to each recipient.
+///
+/// NOTE: The number of transfers is bounded by the runtime.
+#[inline]
+pub fn transfer_from_batch(
+	token: TokenId,
+	from: Address,
+	to: Vec<Address>,
+	values: Vec<U256>,
+) -> Result<(), Error> {
+	ensure!(!to.is_empty() && to.len() == values.len(), InvalidBatch);
+	ensure!(from != Address::zero(), ZeroSenderAddress);
+	ensure!(to.iter().all(|to| *to != Address::zero()), ZeroRecipientAddress);
+	ensure!(!to.contains(&from), InvalidRecipient(from));
+	ensure!(values.iter().all(|value| *value != U256::zero()), ZeroValue);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.transferFromBatch(token, from, to, values)
+}
+
+/// Transfers the ownership of the token to `owner`, along with any deposits.
+///
+/// Only callable by the owner of the token.
+///
+/// # Parameters
+/// - `token` - The token.
+/// - `owner` - The new owner of the token.
+#[inline]
+pub fn transfer_ownership(token: TokenId, owner: Address) -> Result<(), Error> {
+	ensure!(owner != Address::zero(), ZeroAccountAddress);
+
+	let address = fixed_address(PRECOMPILE);
+	let precompile: contract_ref!(Fungibles, Pop, Sol) = address.into();
+	precompile.transferOwnership(token, owner)
+}
@@ -0,0 +1,167 @@
+//! A set of events for use in smart contracts interacting with the fungibles API.
+//!
+//! In addition to the events of the first version, events are provided for the administration
+//! of tokens (`AccountFrozen`, `AccountThawed`, `TokenFrozen`, `TokenThawed`, `AccountBlocked`,
+//! `TeamChanged`, `OwnerChanged`, `MinBalanceChanged`, `AccountTouched`, `AccountRefunded`) and the
+//! destruction of tokens (`AccountsDestroyed`, `ApprovalsDestroyed`, `Destroyed`).
+//!
+//! These events are not emitted by the API itself but can be used in your contracts to
+//! track token operations. Be mindful of the costs associated with emitting events.
+//!
+//! For more details, refer to [ink! events](https://use.ink/basics/events).
+
+use super::*;
+pub use crate::fungibles::v0::{
+	Approval, Created, DestroyStarted, MetadataCleared, MetadataSet, Transfer,
+};
+
+/// Event emitted when an account is frozen.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AccountFrozen {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+	/// The account frozen.
+	#[ink(topic)]
+	pub account: Address,
+}
+
+/// Event emitted when an account is thawed.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AccountThawed {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+	/// The account thawed.
+	#[ink(topic)]
+	pub account: Address,
+}
+
+/// Event emitted when a token is frozen.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct TokenFrozen {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+}
+
+/// Event emitted when a token is thawed.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct TokenThawed {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+}
+
+/// Event emitted when an account is blocked.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AccountBlocked {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+	/// The account blocked.
+	#[ink(topic)]
+	pub account: Address,
+}
+
+/// Event emitted when the management team of a token changes.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct TeamChanged {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+	/// The new issuer of the token.
+	pub issuer: Address,
+	/// The new admin of the token.
+	pub admin: Address,
+	/// The new freezer of the token.
+	pub freezer: Address,
+}
+
+/// Event emitted when the ownership of a token changes.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct OwnerChanged {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+	/// The new owner of the token.
+	#[ink(topic)]
+	pub owner: Address,
+}
+
+/// Event emitted when the minimum balance of a token changes.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct MinBalanceChanged {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+	/// The new minimum balance.
+	pub min_balance: U256,
+}
+
+/// Event emitted when an account is created for a token.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AccountTouched {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+	/// The account created.
+	#[ink(topic)]
+	pub account: Address,
+}
+
+/// Event emitted when an account is removed for a token, returning its deposit.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AccountRefunded {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+	/// The account removed.
+	#[ink(topic)]
+	pub account: Address,
+}
+
+/// Event emitted when accounts of a token being destroyed are destroyed.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AccountsDestroyed {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+	/// The number of accounts destroyed.
+	pub removed: u32,
+	/// The number of accounts remaining.
+	pub remaining: u32,
+}
+
+/// Event emitted when approvals of a token being destroyed are destroyed.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ApprovalsDestroyed {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+	/// The number of approvals destroyed.
+	pub removed: u32,
+	/// The number of approvals remaining.
+	pub remaining: u32,
+}
+
+/// Event emitted when a token has been destroyed.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Destroyed {
+	/// The token.
+	#[ink(topic)]
+	pub token: TokenId,
+}
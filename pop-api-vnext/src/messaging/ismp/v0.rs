@@ -1,7 +1,10 @@
 pub use errors::{Error, Error::*};
 use ink::{Address, SolBytes};
 
-use super::{super::v0::Callback, *};
+use super::{
+	super::v0::{Callback, Quote},
+	*,
+};
 
 mod errors;
 
@@ -10,10 +13,49 @@ const PRECOMPILE: u16 = 4;
 /// The address of the ISMP precompile.
 pub const PRECOMPILE_ADDRESS: Address = fixed_address(PRECOMPILE);
 
+/// The identifier of a subscription to recurring ISMP `Get` requests.
+pub type SubscriptionId = u64;
+
 /// The ISMP precompile offers a streamlined interface for messaging using the Interoperable State
 /// Machine Protocol.
 #[ink::trait_definition]
 pub trait Ismp {
+	/// Allow inbound ISMP `Post` requests from a module on a source state machine to be delivered
+	/// to the calling contract.
+	///
+	/// Inbound requests are only delivered to a contract, at the expense of the contract, once it
+	/// has allowed their source state machine and sending module. A deposit is held for the
+	/// registration, released once it is revoked.
+	///
+	/// # Parameters
+	/// - `source` - The identifier of the source state machine (e.g. `POLKADOT-2000`).
+	/// - `from` - The module on the source state machine which sends the requests.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn allowSource(&self, source: Bytes, from: Bytes) -> Result<(), Error>;
+
+	/// Cancel a subscription to recurring ISMP `Get` requests.
+	///
+	/// Releases the deposit of the subscription and the amounts prepaid for its remaining
+	/// executions. Only the origin of the subscription can cancel it.
+	///
+	/// # Parameters
+	/// - `subscription` - The subscription identifier.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn cancelSubscription(&self, subscription: SubscriptionId) -> Result<(), Error>;
+
+	/// Add to the fee paid to relayers for an outstanding request.
+	///
+	/// Only the origin of the request can fund it.
+	///
+	/// # Parameters
+	/// - `message` - The message identifier of the request to fund.
+	/// - `amount` - The amount to be added to the fee paid to relayers.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn fundRequest(&self, message: MessageId, amount: U256) -> Result<(), Error>;
+
 	/// Submit a new ISMP `Get` request.
 	///
 	/// # Parameters
@@ -25,6 +67,32 @@ pub trait Ismp {
 	#[ink(message)]
 	fn get(&self, request: Get, fee: U256) -> Result<MessageId, Error>;
 
+	/// Submit a new batch of ISMP `Get` requests.
+	///
+	/// The batch completes once every request has been answered or has timed out. A single
+	/// deposit is held for the batch.
+	///
+	/// # Parameters
+	/// - `requests` - The ISMP `Get` messages containing query details.
+	/// - `fee` - The fee to be paid to relayers for each request.
+	///
+	/// # Returns
+	/// A unique message identifier.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn getBatch(&self, requests: Vec<Get>, fee: U256) -> Result<MessageId, Error>;
+
+	/// Polls the status of a batch of `Get` requests, along with the status of each of its
+	/// requests.
+	///
+	/// The statuses of the requests of any other message are empty.
+	///
+	/// # Parameters
+	/// - `message` - The message identifier of the batch to poll.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn pollBatch(&self, message: MessageId) -> (MessageStatus, Vec<MessageStatus>);
+
 	/// Submit a new ISMP `Post` request.
 	///
 	/// Sends a `Post` message through ISMP with arbitrary data.
@@ -37,6 +105,40 @@ pub trait Ismp {
 	/// A unique message identifier.
 	#[ink(message)]
 	fn post(&self, request: Post, fee: U256) -> Result<MessageId, Error>;
+
+	/// Quote the amounts taken for a new ISMP `Get` request.
+	///
+	/// Excludes the fee paid to relayers, which is specified with the request.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn quoteGet(&self) -> Quote;
+
+	/// Quote the amounts taken for a new batch of ISMP `Get` requests.
+	///
+	/// Excludes the fees paid to relayers, which are specified with the batch.
+	///
+	/// # Parameters
+	/// - `requests` - The number of requests of the batch.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn quoteGetBatch(&self, requests: u32) -> Quote;
+
+	/// Quote the amounts taken for a new ISMP `Post` request.
+	///
+	/// Excludes the fee paid to relayers, which is specified with the request.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn quotePost(&self) -> Quote;
+
+	/// Revoke the allowance of inbound ISMP `Post` requests from a module on a source state machine
+	/// to be delivered to the calling contract, releasing the deposit held for the registration.
+	///
+	/// # Parameters
+	/// - `source` - The identifier of the source state machine (e.g. `POLKADOT-2000`).
+	/// - `from` - The module on the source state machine which sends the requests.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn revokeSource(&self, source: Bytes, from: Bytes) -> Result<(), Error>;
 }
 
 /// The ISMP precompile offers a streamlined interface for messaging using the Interoperable State
@@ -55,6 +157,28 @@ pub trait IsmpCallback {
 	#[ink(message)]
 	fn get(&self, request: Get, fee: U256, callback: Callback) -> Result<MessageId, Error>;
 
+	/// Submit a new batch of ISMP `Get` requests.
+	///
+	/// The batch completes once every request has been answered or has timed out, with the
+	/// outcome of every request delivered via a single execution of the callback. A single
+	/// deposit is held for the batch.
+	///
+	/// # Parameters
+	/// - `requests` - The ISMP `Get` messages containing query details.
+	/// - `fee` - The fee to be paid to relayers for each request.
+	/// - `callback` - The callback to execute once the batch completes.
+	///
+	/// # Returns
+	/// A unique message identifier.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn getBatch(
+		&self,
+		requests: Vec<Get>,
+		fee: U256,
+		callback: Callback,
+	) -> Result<MessageId, Error>;
+
 	/// Submit a new ISMP `Post` request.
 	///
 	/// Sends a `Post` message through ISMP with arbitrary data.
@@ -68,6 +192,71 @@ pub trait IsmpCallback {
 	/// A unique message identifier.
 	#[ink(message)]
 	fn post(&self, request: Post, fee: U256, callback: Callback) -> Result<MessageId, Error>;
+
+	/// Quote the amounts taken for a new ISMP `Get` request with a callback.
+	///
+	/// Excludes the fee paid to relayers, which is specified with the request.
+	///
+	/// # Parameters
+	/// - `callback` - The callback to execute upon receiving a response.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn quoteGet(&self, callback: Callback) -> Quote;
+
+	/// Quote the amounts taken for a new batch of ISMP `Get` requests with a callback.
+	///
+	/// Excludes the fees paid to relayers, which are specified with the batch.
+	///
+	/// # Parameters
+	/// - `requests` - The number of requests of the batch.
+	/// - `callback` - The callback to execute once the batch completes.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn quoteGetBatch(&self, requests: u32, callback: Callback) -> Quote;
+
+	/// Quote the amounts taken for a new ISMP `Post` request with a callback.
+	///
+	/// Excludes the fee paid to relayers, which is specified with the request.
+	///
+	/// # Parameters
+	/// - `callback` - The callback to execute upon receiving a response.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn quotePost(&self, callback: Callback) -> Quote;
+
+	/// Quote the amounts taken for a new subscription to recurring ISMP `Get` requests.
+	///
+	/// The amounts for all executions are taken upfront, including the fees paid to relayers.
+	///
+	/// # Parameters
+	/// - `executions` - The number of executions of the subscription.
+	/// - `fee` - The fee to be paid to relayers per execution.
+	/// - `callback` - The callback to execute upon receiving each response.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn quoteSubscribe(&self, executions: u32, fee: U256, callback: Callback) -> Quote;
+
+	/// Subscribe to recurring ISMP `Get` requests.
+	///
+	/// A `Get` request is dispatched at each interval, reading the destination at the latest
+	/// height known to this chain, until the number of executions is reached. Each response is
+	/// delivered via the callback.
+	///
+	/// # Parameters
+	/// - `subscription` - The template of the requests, along with their interval and number of
+	///   executions.
+	/// - `fee` - The fee to be paid to relayers per execution.
+	/// - `callback` - The callback to execute upon receiving each response.
+	///
+	/// # Returns
+	/// A unique subscription identifier.
+	#[ink(message)]
+	fn subscribe(
+		&self,
+		subscription: Subscription,
+		fee: U256,
+		callback: Callback,
+	) -> Result<SubscriptionId, Error>;
 }
 
 /// The messaging interface of the ISMP precompile offers a general interface for cross-chain
@@ -123,6 +312,48 @@ pub trait Messaging {
 	fn removeMany(&self, messages: Vec<MessageId>) -> Result<(), Error>;
 }
 
+/// Allow inbound ISMP `Post` requests from a module on a source state machine to be delivered to
+/// the calling contract.
+///
+/// Inbound requests are only delivered to a contract, at the expense of the contract, once it has
+/// allowed their source state machine and sending module. A deposit is held for the registration,
+/// released once it is revoked.
+///
+/// # Parameters
+/// - `source` - The identifier of the source state machine (e.g. `POLKADOT-2000`).
+/// - `from` - The module on the source state machine which sends the requests.
+#[inline]
+pub fn allow_source(source: Bytes, from: Bytes) -> Result<(), Error> {
+	let precompile: contract_ref!(Ismp, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.allowSource(source, from)
+}
+
+/// Cancel a subscription to recurring ISMP `Get` requests.
+///
+/// Releases the deposit of the subscription and the amounts prepaid for its remaining executions.
+/// Only the origin of the subscription can cancel it.
+///
+/// # Parameters
+/// - `subscription` - The subscription identifier.
+#[inline]
+pub fn cancel_subscription(subscription: SubscriptionId) -> Result<(), Error> {
+	let precompile: contract_ref!(Ismp, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.cancelSubscription(subscription)
+}
+
+/// Add to the fee paid to relayers for an outstanding request.
+///
+/// Only the origin of the request can fund it.
+///
+/// # Parameters
+/// - `message` - The message identifier of the request to fund.
+/// - `amount` - The amount to be added to the fee paid to relayers.
+#[inline]
+pub fn fund_request(message: MessageId, amount: U256) -> Result<(), Error> {
+	let precompile: contract_ref!(Ismp, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.fundRequest(message, amount)
+}
+
 /// Submit a new ISMP `Get` request.
 ///
 /// # Parameters
@@ -146,6 +377,37 @@ pub fn get(request: Get, fee: U256, callback: Option<Callback>) -> Result<Messag
 	}
 }
 
+/// Submit a new batch of ISMP `Get` requests.
+///
+/// The batch completes once every request has been answered or has timed out, with the outcome of
+/// every request delivered via a single execution of the callback, if any. A single deposit is
+/// held for the batch.
+///
+/// # Parameters
+/// - `requests` - The ISMP `Get` messages containing query details.
+/// - `fee` - The fee to be paid to relayers for each request.
+/// - `callback` - An optional callback to execute once the batch completes.
+///
+/// # Returns
+/// A unique message identifier.
+#[inline]
+pub fn get_batch(
+	requests: Vec<Get>,
+	fee: U256,
+	callback: Option<Callback>,
+) -> Result<MessageId, Error> {
+	match callback {
+		None => {
+			let precompile: contract_ref!(Ismp, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+			precompile.getBatch(requests, fee)
+		},
+		Some(callback) => {
+			let precompile: contract_ref!(IsmpCallback, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+			precompile.getBatch(requests, fee, callback)
+		},
+	}
+}
+
 /// Returns the response to a message.
 ///
 /// A non-existent message identifier will return an empty response, which could also be a valid
@@ -168,6 +430,18 @@ pub fn id() -> u32 {
 	precompile.id()
 }
 
+/// Polls the status of a batch of `Get` requests, along with the status of each of its requests.
+///
+/// The statuses of the requests of any other message are empty.
+///
+/// # Parameters
+/// - `message` - The message identifier of the batch to poll.
+#[inline]
+pub fn poll_batch(message: MessageId) -> (MessageStatus, Vec<MessageStatus>) {
+	let precompile: contract_ref!(Ismp, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.pollBatch(message)
+}
+
 /// Polls the status of a message.
 ///
 /// # Parameters
@@ -178,6 +452,81 @@ pub fn poll_status(message: MessageId) -> MessageStatus {
 	precompile.pollStatus(message)
 }
 
+/// Quote the amounts taken for a new ISMP `Get` request.
+///
+/// Excludes the fee paid to relayers, which is specified with the request.
+///
+/// # Parameters
+/// - `callback` - An optional callback to execute upon receiving a response.
+#[inline]
+pub fn quote_get(callback: Option<Callback>) -> Quote {
+	match callback {
+		None => {
+			let precompile: contract_ref!(Ismp, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+			precompile.quoteGet()
+		},
+		Some(callback) => {
+			let precompile: contract_ref!(IsmpCallback, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+			precompile.quoteGet(callback)
+		},
+	}
+}
+
+/// Quote the amounts taken for a new batch of ISMP `Get` requests.
+///
+/// Excludes the fees paid to relayers, which are specified with the batch.
+///
+/// # Parameters
+/// - `requests` - The number of requests of the batch.
+/// - `callback` - An optional callback to execute once the batch completes.
+#[inline]
+pub fn quote_get_batch(requests: u32, callback: Option<Callback>) -> Quote {
+	match callback {
+		None => {
+			let precompile: contract_ref!(Ismp, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+			precompile.quoteGetBatch(requests)
+		},
+		Some(callback) => {
+			let precompile: contract_ref!(IsmpCallback, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+			precompile.quoteGetBatch(requests, callback)
+		},
+	}
+}
+
+/// Quote the amounts taken for a new ISMP `Post` request.
+///
+/// Excludes the fee paid to relayers, which is specified with the request.
+///
+/// # Parameters
+/// - `callback` - An optional callback to execute upon receiving a response.
+#[inline]
+pub fn quote_post(callback: Option<Callback>) -> Quote {
+	match callback {
+		None => {
+			let precompile: contract_ref!(Ismp, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+			precompile.quotePost()
+		},
+		Some(callback) => {
+			let precompile: contract_ref!(IsmpCallback, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+			precompile.quotePost(callback)
+		},
+	}
+}
+
+/// Quote the amounts taken for a new subscription to recurring ISMP `Get` requests.
+///
+/// The amounts for all executions are taken upfront, including the fees paid to relayers.
+///
+/// # Parameters
+/// - `executions` - The number of executions of the subscription.
+/// - `fee` - The fee to be paid to relayers per execution.
+/// - `callback` - The callback to execute upon receiving each response.
+#[inline]
+pub fn quote_subscribe(executions: u32, fee: U256, callback: Callback) -> Quote {
+	let precompile: contract_ref!(IsmpCallback, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.quoteSubscribe(executions, fee, callback)
+}
+
 /// Submit a new ISMP `Post` request.
 ///
 /// Sends a `Post` message through ISMP with arbitrary data.
@@ -227,6 +576,43 @@ pub fn remove_many(messages: Vec<MessageId>) -> Result<(), Error> {
 	precompile.removeMany(messages)
 }
 
+/// Revoke the allowance of inbound ISMP `Post` requests from a module on a source state machine to
+/// be delivered to the calling contract, releasing the deposit held for the registration.
+///
+/// # Parameters
+/// - `source` - The identifier of the source state machine (e.g. `POLKADOT-2000`).
+/// - `from` - The module on the source state machine which sends the requests.
+#[inline]
+pub fn revoke_source(source: Bytes, from: Bytes) -> Result<(), Error> {
+	let precompile: contract_ref!(Ismp, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.revokeSource(source, from)
+}
+
+/// Subscribe to recurring ISMP `Get` requests.
+///
+/// A `Get` request is dispatched at each interval, reading the destination at the latest height
+/// known to this chain, until the number of executions is reached. Each response is delivered via
+/// the callback. The deposit of the subscription and the amounts for all executions are taken
+/// upfront, with those of any remaining executions released when the subscription is cancelled.
+///
+/// # Parameters
+/// - `subscription` - The template of the requests, along with their interval and number of
+///   executions.
+/// - `fee` - The fee to be paid to relayers per execution.
+/// - `callback` - The callback to execute upon receiving each response.
+///
+/// # Returns
+/// A unique subscription identifier.
+#[inline]
+pub fn subscribe(
+	subscription: Subscription,
+	fee: U256,
+	callback: Callback,
+) -> Result<SubscriptionId, Error> {
+	let precompile: contract_ref!(IsmpCallback, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.subscribe(subscription, fee, callback)
+}
+
 /// A GET request, intended to be used for sending outgoing requests.
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 #[derive(ink::SolDecode, ink::SolEncode)]
@@ -290,6 +676,46 @@ impl Post {
 	}
 }
 
+/// A subscription to recurring GET requests.
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[derive(ink::SolDecode, ink::SolEncode)]
+pub struct Subscription {
+	/// The destination state machine of the requests.
+	pub destination: u32,
+	/// Relative from the current timestamp at which each request expires in seconds.
+	pub timeout: u64,
+	/// Some application-specific metadata relating to the requests.
+	pub context: Bytes,
+	/// Raw Storage keys that would be used to fetch the values from the counterparty.
+	pub keys: Vec<Bytes>,
+	/// The number of blocks between executions.
+	pub interval: u32,
+	/// The number of executions.
+	pub executions: u32,
+}
+
+impl Subscription {
+	/// Creates a new subscription to recurring GET requests.
+	///
+	/// # Parameters
+	/// - `destination` - The destination state machine of the requests.
+	/// - `timeout` - Relative from the current timestamp at which each request expires in seconds.
+	/// - `context` - Some application-specific metadata relating to the requests.
+	/// - `keys` - Raw Storage keys that would be used to fetch the values from the counterparty.
+	/// - `interval` - The number of blocks between executions.
+	/// - `executions` - The number of executions.
+	pub fn new(
+		destination: u32,
+		timeout: u64,
+		context: Bytes,
+		keys: Vec<Bytes>,
+		interval: u32,
+		executions: u32,
+	) -> Self {
+		Self { destination, timeout, context, keys, interval, executions }
+	}
+}
+
 /// A verified storage value.
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 #[derive(Clone, Debug, ink::SolDecode, ink::SolEncode)]
@@ -300,6 +726,30 @@ pub struct StorageValue {
 	pub value: Option<Bytes>,
 }
 
+/// The outcome of an ISMP `Get` request of a batch.
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, ink::SolDecode, ink::SolEncode)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct GetResult {
+	/// The status of the request.
+	pub status: MessageStatus,
+	/// The values derived from the state proof, which are empty unless the request has completed.
+	pub values: Vec<StorageValue>,
+}
+
+/// A callback for handling the responses to a batch of ISMP `Get` requests.
+#[ink::trait_definition]
+pub trait OnGetBatchResponse {
+	/// Handles the responses to a batch of ISMP `Get` requests.
+	///
+	/// # Parameters
+	/// - `id` - The identifier of the originating message.
+	/// - `response` - The outcome of each request, in the order in which they were specified.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn onGetBatchResponse(&mut self, id: MessageId, response: Vec<GetResult>);
+}
+
 /// A callback for handling responses to ISMP `Get` requests.
 #[ink::trait_definition]
 pub trait OnGetResponse {
@@ -326,6 +776,16 @@ pub trait OnPostResponse {
 	fn onPostResponse(&mut self, id: MessageId, response: Bytes);
 }
 
+/// Event emitted when a batch of ISMP `Get` requests is completed.
+#[ink::event]
+pub struct IsmpGetBatchCompleted {
+	/// The identifier of the originating message.
+	#[ink(topic)]
+	pub id: MessageId,
+	/// The outcome of each request, in the order in which they were specified.
+	pub response: Vec<GetResult>,
+}
+
 /// Event emitted when a ISMP `Get` request is completed.
 #[ink::event]
 pub struct IsmpGetCompleted {
@@ -8,8 +8,9 @@ use crate::{
 	},
 	impl_sol_encoding_for_precompile,
 	messaging::v0::errors::{
-		ARITHMETIC, DISPATCH, MESSAGE_NOT_FOUND, MODULE, REQUEST_PENDING, TOKEN, TOO_MANY_MESSAGES,
-		TRANSACTIONAL, TRIE,
+		ARITHMETIC, DISPATCH, MESSAGE_COMPLETED, MESSAGE_NOT_FOUND, MODULE, REQUEST_PENDING,
+		REQUEST_TIMED_OUT, TOKEN, TOO_MANY_MESSAGES, TOO_MANY_PENDING_MESSAGES, TRANSACTIONAL,
+		TRIE,
 	},
 	sol::PrecompileError,
 };
@@ -22,6 +23,14 @@ pub enum Error {
 	Arithmetic(ArithmeticError),
 	/// Reason why a dispatch call failed.
 	Dispatch(DispatchError),
+	/// The batch has no requests or exceeds the maximum number of requests.
+	InvalidBatch,
+	/// The message is invalid.
+	InvalidMessage,
+	/// The identifier of the source state machine is invalid.
+	InvalidSource,
+	/// The subscription has no executions or its interval is below the minimum.
+	InvalidSubscription,
 	/// The context exceeds the maximum allowed size.
 	MaxContextExceeded,
 	/// The data exceeds the maximum allowed size.
@@ -30,6 +39,8 @@ pub enum Error {
 	MaxKeyExceeded,
 	/// The number of keys exceeds the maximum allowed size.
 	MaxKeysExceeded,
+	/// This is not possible as the message has completed.
+	MessageCompleted,
 	/// The message was not found.
 	MessageNotFound,
 	/// Reason why a pallet call failed.
@@ -41,10 +52,16 @@ pub enum Error {
 	},
 	/// The request is pending.
 	RequestPending,
+	/// The request has timed out.
+	RequestTimedOut,
+	/// The subscription was not found.
+	SubscriptionNotFound,
 	/// An error to do with tokens.
 	Token(TokenError),
 	/// The number of messages exceeds the limit.
 	TooManyMessages,
+	/// The origin has reached its maximum number of pending messages.
+	TooManyPendingMessages,
 	/// The number of transactional layers has been reached, or we are not in a transactional
 	/// layer.
 	Transactional(TransactionalError),
@@ -63,18 +80,26 @@ impl PrecompileError for Error {
 		match data[..4].try_into().expect("length checked above") {
 			ARITHMETIC => Ok(Self::Arithmetic(ArithmeticError::decode(&data[4..])?)),
 			DISPATCH => Ok(Self::Dispatch(DispatchError::decode(&data[4..])?)),
+			INVALID_BATCH => Ok(Self::InvalidBatch),
+			INVALID_MESSAGE => Ok(Self::InvalidMessage),
+			INVALID_SOURCE => Ok(Self::InvalidSource),
+			INVALID_SUBSCRIPTION => Ok(Self::InvalidSubscription),
 			MAX_CONTEXT_EXCEEDED => Ok(Self::MaxContextExceeded),
 			MAX_DATA_EXCEEDED => Ok(Self::MaxDataExceeded),
 			MAX_KEY_EXCEEDED => Ok(Self::MaxKeyExceeded),
 			MAX_KEYS_EXCEEDED => Ok(Self::MaxKeysExceeded),
+			MESSAGE_COMPLETED => Ok(Self::MessageCompleted),
 			MESSAGE_NOT_FOUND => Ok(Self::MessageNotFound),
 			MODULE => {
 				let ModuleError { index, error } = ModuleError::decode(&data[4..])?;
 				Ok(Self::Module { index, error })
 			},
 			REQUEST_PENDING => Ok(Self::RequestPending),
+			REQUEST_TIMED_OUT => Ok(Self::RequestTimedOut),
+			SUBSCRIPTION_NOT_FOUND => Ok(Self::SubscriptionNotFound),
 			TOKEN => Ok(Self::Token(TokenError::decode(&data[4..])?)),
 			TOO_MANY_MESSAGES => Ok(Self::TooManyMessages),
+			TOO_MANY_PENDING_MESSAGES => Ok(Self::TooManyPendingMessages),
 			TRANSACTIONAL => Ok(Self::Transactional(TransactionalError::decode(&data[4..])?)),
 			TRIE => Ok(Self::Trie(TrieError::decode(&data[4..])?)),
 			_ => Err(ink::sol::Error),
@@ -82,10 +107,15 @@ impl PrecompileError for Error {
 	}
 }
 
+const INVALID_BATCH: [u8; 4] = sol_error_selector!("InvalidBatch", ());
+const INVALID_MESSAGE: [u8; 4] = sol_error_selector!("InvalidMessage", ());
+const INVALID_SOURCE: [u8; 4] = sol_error_selector!("InvalidSource", ());
+const INVALID_SUBSCRIPTION: [u8; 4] = sol_error_selector!("InvalidSubscription", ());
 const MAX_CONTEXT_EXCEEDED: [u8; 4] = sol_error_selector!("MaxContextExceeded", ());
 const MAX_DATA_EXCEEDED: [u8; 4] = sol_error_selector!("MaxDataExceeded", ());
 const MAX_KEY_EXCEEDED: [u8; 4] = sol_error_selector!("MaxKeyExceeded", ());
 const MAX_KEYS_EXCEEDED: [u8; 4] = sol_error_selector!("MaxKeysExceeded", ());
+const SUBSCRIPTION_NOT_FOUND: [u8; 4] = sol_error_selector!("SubscriptionNotFound", ());
 
 #[test]
 fn error_decoding_works() {
@@ -100,21 +130,29 @@ fn error_decoding_works() {
 			"20c5a2a9000000000000000000000000000000000000000000000000000000000000000d",
 			Dispatch(DispatchError::RootNotAllowed),
 		),
+		("33b094a1", InvalidBatch),
+		("6eca2e4b", InvalidMessage),
+		("8154374b", InvalidSource),
+		("1f6a65b6", InvalidSubscription),
 		("8ad49075", MaxContextExceeded),
 		("deadaa39", MaxDataExceeded),
 		("3d903c2e", MaxKeyExceeded),
 		("81463867", MaxKeysExceeded),
+		("c51e0766", MessageCompleted),
 		("28915ac7", MessageNotFound),
 		(
 			"3323f3c100000000000000000000000000000000000000000000000000000000000000ffffffffff00000000000000000000000000000000000000000000000000000000",
 			Module { index: 255, error: SolBytes([255; 4]) },
 		),
 		("806d0f74", RequestPending),
+		("6512950c", RequestTimedOut),
+		("1a00354f", SubscriptionNotFound),
 		(
 			"57fdc3d80000000000000000000000000000000000000000000000000000000000000009",
 			Token(TokenError::Blocked),
 		),
 		("1ec0b2f7", TooManyMessages),
+		("24b4145b", TooManyPendingMessages),
 		(
 			"3008a37e0000000000000000000000000000000000000000000000000000000000000001",
 			Transactional(TransactionalError::NoLayer),
@@ -12,6 +12,18 @@ pub const PRECOMPILE_ADDRESS: Address = fixed_address(PRECOMPILE);
 /// The messaging precompile offers a general interface for cross-chain messaging operations.
 #[ink::trait_definition]
 pub trait Messaging {
+	/// Accept the ownership of a message which has been offered to the caller.
+	///
+	/// The deposits held for the message and the execution of any callback are moved to the
+	/// caller, which becomes the origin of the message. A pending message counts towards the
+	/// pending messages of the caller.
+	///
+	/// # Parameters
+	/// - `message` - The identifier of the message to accept.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn acceptMessage(&self, message: MessageId) -> Result<(), Error>;
+
 	/// Returns the response to a message.
 	///
 	/// A non-existent message identifier will return an empty response, which could also be a valid
@@ -56,6 +68,30 @@ pub trait Messaging {
 	#[ink(message)]
 	#[allow(non_snake_case)]
 	fn removeMany(&self, messages: Vec<MessageId>) -> Result<(), Error>;
+
+	/// Offer the ownership of a message to another account.
+	///
+	/// The ownership is only transferred once the new owner accepts the message, replacing any
+	/// previous offer. Offering a message to the caller withdraws any pending offer. Allows
+	/// contracts to hand messages over to a new address, such as when upgrading.
+	///
+	/// # Parameters
+	/// - `message` - The identifier of the message to transfer.
+	/// - `new_owner` - The address of the new owner of the message.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn transferMessage(&self, message: MessageId, new_owner: Address) -> Result<(), Error>;
+
+	/// Update the callback of a pending message.
+	///
+	/// The deposit held for the execution of the callback is adjusted to that of the new callback.
+	///
+	/// # Parameters
+	/// - `message` - The identifier of the message.
+	/// - `callback` - The callback to invoke upon receiving a response.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn updateCallback(&self, message: MessageId, callback: Callback) -> Result<(), Error>;
 }
 
 /// A message callback.
@@ -100,6 +136,28 @@ impl Callback {
 	}
 }
 
+/// The amounts taken for a new message.
+#[derive(Copy, Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Quote {
+	/// The deposit held for the message, released once the message is removed.
+	pub deposit: U256,
+	/// The fee prepaid for the handling of the response, which is not refunded.
+	pub prepaid_fee: U256,
+	/// The deposit held for the execution of the callback, if any.
+	pub callback_deposit: U256,
+}
+
+impl Quote {
+	/// The total amount taken for the message.
+	pub fn total(&self) -> U256 {
+		self.deposit
+			.saturating_add(self.prepaid_fee)
+			.saturating_add(self.callback_deposit)
+	}
+}
+
 /// The specificiation of how data must be encoded before being sent to a contract.
 #[derive(Copy, Clone, ink::SolDecode, ink::SolEncode)]
 #[ink::scale_derive(Decode, Encode, TypeInfo)]
@@ -121,6 +179,8 @@ pub enum MessageStatus {
 	Pending = 1,
 	Complete = 2,
 	Timeout = 3,
+	/// A request of a batch whose response could not be included in the response to the batch.
+	Failed = 4,
 }
 
 /// One or more messages have been removed for the account.
@@ -135,6 +195,63 @@ pub struct Removed {
 	pub messages: Vec<MessageId>,
 }
 
+/// The callback of a message has been updated.
+#[ink::event]
+pub struct CallbackUpdated {
+	/// The origin of the message.
+	#[ink(topic)]
+	pub account: Address,
+	/// The identifier of the message.
+	#[ink(topic)]
+	pub message: MessageId,
+	/// The new callback of the message.
+	pub callback: Callback,
+}
+
+/// The ownership of a message has been offered to another account.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct MessageTransferOffered {
+	/// The owner of the message.
+	#[ink(topic)]
+	pub account: Address,
+	/// The identifier of the message.
+	#[ink(topic)]
+	pub message: MessageId,
+	/// The address offered the ownership of the message.
+	#[ink(topic)]
+	pub new_owner: Address,
+}
+
+/// The ownership of a message has been transferred.
+#[ink::event]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct MessageTransferred {
+	/// The previous owner of the message.
+	#[ink(topic)]
+	pub account: Address,
+	/// The identifier of the message.
+	#[ink(topic)]
+	pub message: MessageId,
+	/// The new owner of the message.
+	#[ink(topic)]
+	pub new_owner: Address,
+}
+
+/// Accept the ownership of a message which has been offered to the caller.
+///
+/// The deposits held for the message and the execution of any callback are moved to the caller,
+/// which becomes the origin of the message. A pending message counts towards the pending messages
+/// of the caller.
+///
+/// # Parameters
+/// - `message` - The identifier of the message to accept.
+#[inline]
+pub fn accept_message(message: MessageId) -> Result<(), Error> {
+	let precompile: contract_ref!(Messaging, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.acceptMessage(message)
+}
+
 /// Returns the response to a message.
 ///
 /// A non-existent message identifier will return an empty response, which could also be a valid
@@ -190,3 +307,32 @@ pub fn remove_many(messages: Vec<MessageId>) -> Result<(), Error> {
 	let precompile: contract_ref!(Messaging, Pop, Sol) = PRECOMPILE_ADDRESS.into();
 	precompile.removeMany(messages)
 }
+
+/// Offer the ownership of a message to another account.
+///
+/// The ownership is only transferred once the new owner accepts the message (see
+/// [`accept_message`]), replacing any previous offer. Offering a message to the caller withdraws
+/// any pending offer. Allows contracts to hand messages over to a new address, such as when
+/// upgrading.
+///
+/// # Parameters
+/// - `message` - The identifier of the message to transfer.
+/// - `new_owner` - The address of the new owner of the message.
+#[inline]
+pub fn transfer_message(message: MessageId, new_owner: Address) -> Result<(), Error> {
+	let precompile: contract_ref!(Messaging, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.transferMessage(message, new_owner)
+}
+
+/// Update the callback of a pending message.
+///
+/// The deposit held for the execution of the callback is adjusted to that of the new callback.
+///
+/// # Parameters
+/// - `message` - The identifier of the message.
+/// - `callback` - The callback to invoke upon receiving a response.
+#[inline]
+pub fn update_callback(message: MessageId, callback: Callback) -> Result<(), Error> {
+	let precompile: contract_ref!(Messaging, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.updateCallback(message, callback)
+}
@@ -17,6 +17,8 @@ pub enum Error {
 	Arithmetic(ArithmeticError),
 	/// Reason why a dispatch call failed.
 	Dispatch(DispatchError),
+	/// This is not possible as the message has completed.
+	MessageCompleted,
 	/// The message was not found.
 	MessageNotFound,
 	/// Reason why a pallet call failed.
@@ -28,10 +30,14 @@ pub enum Error {
 	},
 	/// The request is pending.
 	RequestPending,
+	/// The request has timed out.
+	RequestTimedOut,
 	/// An error to do with tokens.
 	Token(TokenError),
 	/// The number of messages exceeds the limit.
 	TooManyMessages,
+	/// The origin has reached its maximum number of pending messages.
+	TooManyPendingMessages,
 	/// The number of transactional layers has been reached, or we are not in a transactional
 	/// layer.
 	Transactional(TransactionalError),
@@ -50,14 +56,17 @@ impl PrecompileError for Error {
 		match data[..4].try_into().expect("length checked above") {
 			ARITHMETIC => Ok(Self::Arithmetic(ArithmeticError::decode(&data[4..])?)),
 			DISPATCH => Ok(Self::Dispatch(DispatchError::decode(&data[4..])?)),
+			MESSAGE_COMPLETED => Ok(Self::MessageCompleted),
 			MESSAGE_NOT_FOUND => Ok(Self::MessageNotFound),
 			MODULE => {
 				let ModuleError { index, error } = ModuleError::decode(&data[4..])?;
 				Ok(Self::Module { index, error })
 			},
 			REQUEST_PENDING => Ok(Self::RequestPending),
+			REQUEST_TIMED_OUT => Ok(Self::RequestTimedOut),
 			TOKEN => Ok(Self::Token(TokenError::decode(&data[4..])?)),
 			TOO_MANY_MESSAGES => Ok(Self::TooManyMessages),
+			TOO_MANY_PENDING_MESSAGES => Ok(Self::TooManyPendingMessages),
 			TRANSACTIONAL => Ok(Self::Transactional(TransactionalError::decode(&data[4..])?)),
 			TRIE => Ok(Self::Trie(TrieError::decode(&data[4..])?)),
 			_ => Err(ink::sol::Error),
@@ -67,11 +76,15 @@ impl PrecompileError for Error {
 
 pub(crate) const ARITHMETIC: [u8; 4] = sol_error_selector!("Arithmetic", (u8,));
 pub(crate) const DISPATCH: [u8; 4] = sol_error_selector!("Dispatch", (u8,));
+pub(crate) const MESSAGE_COMPLETED: [u8; 4] = sol_error_selector!("MessageCompleted", ());
 pub(crate) const MESSAGE_NOT_FOUND: [u8; 4] = sol_error_selector!("MessageNotFound", ());
 pub(crate) const MODULE: [u8; 4] = sol_error_selector!("Module", (u8, FixedBytes<4>));
 pub(crate) const REQUEST_PENDING: [u8; 4] = sol_error_selector!("RequestPending", ());
+pub(crate) const REQUEST_TIMED_OUT: [u8; 4] = sol_error_selector!("RequestTimedOut", ());
 pub(crate) const TOKEN: [u8; 4] = sol_error_selector!("Token", (u8,));
 pub(crate) const TOO_MANY_MESSAGES: [u8; 4] = sol_error_selector!("TooManyMessages", ());
+pub(crate) const TOO_MANY_PENDING_MESSAGES: [u8; 4] =
+	sol_error_selector!("TooManyPendingMessages", ());
 pub(crate) const TRANSACTIONAL: [u8; 4] = sol_error_selector!("Transactional", (u8,));
 pub(crate) const TRIE: [u8; 4] = sol_error_selector!("Trie", (u8,));
 
@@ -88,17 +101,20 @@ fn error_decoding_works() {
 			"20c5a2a9000000000000000000000000000000000000000000000000000000000000000d",
 			Dispatch(DispatchError::RootNotAllowed),
 		),
+		("c51e0766", MessageCompleted),
 		("28915ac7", MessageNotFound),
 		(
 			"3323f3c100000000000000000000000000000000000000000000000000000000000000ffffffffff00000000000000000000000000000000000000000000000000000000",
 			Module { index: 255, error: SolBytes([255; 4]) },
 		),
 		("806d0f74", RequestPending),
+		("6512950c", RequestTimedOut),
 		(
 			"57fdc3d80000000000000000000000000000000000000000000000000000000000000009",
 			Token(TokenError::Blocked),
 		),
 		("1ec0b2f7", TooManyMessages),
+		("24b4145b", TooManyPendingMessages),
 		(
 			"3008a37e0000000000000000000000000000000000000000000000000000000000000001",
 			Transactional(TransactionalError::NoLayer),
@@ -1,9 +1,15 @@
 pub use errors::{Error, Error::*};
-pub use ink::xcm::prelude::{Location, VersionedLocation, VersionedResponse, VersionedXcm};
+pub use ink::xcm::prelude::{Asset, Location, VersionedLocation, VersionedResponse, VersionedXcm};
 use ink::{scale::Decode, Address, SolBytes};
 
-use super::{super::v0::Callback, *};
+use super::{
+	super::v0::{Callback, Quote},
+	*,
+};
 
+/// Solidity ABI representations of XCM types, as used by the typed operations of the XCM
+/// precompile.
+pub mod abi;
 mod errors;
 
 pub type QueryId = u64;
@@ -17,6 +23,30 @@ pub const PRECOMPILE_ADDRESS: Address = fixed_address(PRECOMPILE);
 /// Messaging (XCM).
 #[ink::trait_definition]
 pub trait Xcm {
+	/// Cancel a pending XCM query.
+	///
+	/// Deregisters the query, such that any subsequent response is ignored, and releases the
+	/// deposits held for the message. Only the origin of the query can cancel it.
+	///
+	/// # Parameters
+	/// - `message` - The message identifier of the query to cancel.
+	#[ink(message)]
+	fn cancel(&self, message: MessageId);
+
+	/// Dry-run an XCM message from a local, signed, origin.
+	///
+	/// Any changes made by the dry run are discarded. The weight required to execute the message
+	/// is charged.
+	///
+	/// # Parameters
+	/// - `message` - A SCALE-encoded versioned XCM message.
+	///
+	/// # Returns
+	/// The effects of the dry run.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn dryRunXcm(&self, message: Bytes) -> Result<abi::DryRunEffects, Error>;
+
 	/// Execute an XCM message from a local, signed, origin.
 	///
 	/// # Parameters
@@ -42,6 +72,57 @@ pub trait Xcm {
 	#[allow(non_snake_case)]
 	fn newQuery(&self, responder: Bytes, timeout: BlockNumber) -> (MessageId, QueryId);
 
+	/// Quote the amounts taken for a new XCM query.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn quoteNewQuery(&self) -> Quote;
+
+	/// Query the fees for delivering a message to a destination.
+	///
+	/// The fees do not include those for executing the message on the destination.
+	///
+	/// # Parameters
+	/// - `destination` - The SCALE-encoded versioned location for the destination of the message.
+	/// - `message` - A SCALE-encoded versioned XCM message.
+	///
+	/// # Returns
+	/// The delivery fees.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn queryDeliveryFees(
+		&self,
+		destination: Bytes,
+		message: Bytes,
+	) -> Result<Vec<abi::Asset>, Error>;
+
+	/// Query the fee for a weight of execution on this chain, payable in a given asset.
+	///
+	/// # Parameters
+	/// - `weight` - The weight of execution.
+	/// - `asset` - The location of the asset in which the fee is to be paid, relative to this
+	///   chain.
+	///
+	/// # Returns
+	/// The fee, in units of the asset.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn queryWeightToAssetFee(&self, weight: Weight, asset: abi::Location) -> Result<u128, Error>;
+
+	/// Transfer assets to a beneficiary on Asset Hub, using Asset Hub as the reserve.
+	///
+	/// # Parameters
+	/// - `beneficiary` - The location of the beneficiary on Asset Hub, relative to Asset Hub.
+	/// - `assets` - The assets to be transferred, relative to this chain.
+	/// - `fee_asset_index` - The index of the asset within `assets` to be used to pay fees.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn reserveTransferToAssetHub(
+		&self,
+		beneficiary: abi::Location,
+		assets: Vec<abi::Asset>,
+		fee_asset_index: u32,
+	) -> Result<(), Error>;
+
 	/// Send an XCM from a given origin.
 	///
 	/// # Parameters
@@ -52,6 +133,57 @@ pub trait Xcm {
 	/// A SCALE-encoded dispatch result.
 	#[ink(message)]
 	fn send(&self, destination: Bytes, message: Bytes) -> Bytes;
+
+	/// Teleport assets to a beneficiary on another chain.
+	///
+	/// # Parameters
+	/// - `destination` - The location of the destination chain, relative to this chain.
+	/// - `beneficiary` - The location of the beneficiary, relative to the destination.
+	/// - `assets` - The assets to be teleported, relative to this chain.
+	/// - `fee_asset_index` - The index of the asset within `assets` to be used to pay fees.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn teleportAssets(
+		&self,
+		destination: abi::Location,
+		beneficiary: abi::Location,
+		assets: Vec<abi::Asset>,
+		fee_asset_index: u32,
+	) -> Result<(), Error>;
+
+	/// Dispatch a call on another chain, from the sovereign account of the caller on that chain.
+	///
+	/// # Parameters
+	/// - `destination` - The location of the destination chain, relative to this chain.
+	/// - `call` - The SCALE-encoded call to be dispatched on the destination.
+	/// - `weight` - The maximum weight the call may consume on the destination.
+	/// - `fee_asset` - The asset used to pay for execution on the destination, relative to this
+	///   chain.
+	#[ink(message)]
+	fn transact(
+		&self,
+		destination: abi::Location,
+		call: Bytes,
+		weight: Weight,
+		fee_asset: abi::Asset,
+	) -> Result<(), Error>;
+
+	/// Transfer assets to a beneficiary on another chain.
+	///
+	/// # Parameters
+	/// - `destination` - The location of the destination chain, relative to this chain.
+	/// - `beneficiary` - The location of the beneficiary, relative to the destination.
+	/// - `assets` - The assets to be transferred, relative to this chain.
+	/// - `fee_asset_index` - The index of the asset within `assets` to be used to pay fees.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn transferAssets(
+		&self,
+		destination: abi::Location,
+		beneficiary: abi::Location,
+		assets: Vec<abi::Asset>,
+		fee_asset_index: u32,
+	) -> Result<(), Error>;
 }
 
 /// The XCM precompile offers a streamlined interface for messaging using Polkadot's Cross-Consensus
@@ -77,6 +209,14 @@ pub trait XcmCallback {
 		timeout: BlockNumber,
 		callback: Callback,
 	) -> (MessageId, QueryId);
+
+	/// Quote the amounts taken for a new XCM query with a callback.
+	///
+	/// # Parameters
+	/// - `callback` - The callback to execute upon receiving a response.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn quoteNewQuery(&self, callback: Callback) -> Quote;
 }
 
 /// The messaging interface of the XCM precompile offers a general interface for cross-chain
@@ -86,16 +226,16 @@ pub trait XcmCallback {
 /// the XCM precompile, so that users need only use a single precompile if desired.
 #[ink::trait_definition]
 pub trait Messaging {
-	/// Returns the response to a message.
+	/// Returns the response to a query.
 	///
-	/// A non-existent message identifier will return an empty response, which could also be a valid
-	/// response depending on the source message.
+	/// A non-existent message identifier will return a `Null` response, which could also be a
+	/// valid response depending on the query.
 	///
 	/// # Parameters
 	/// - `message` - The message identifier.
 	#[ink(message)]
 	#[allow(non_snake_case)]
-	fn getResponse(&self, message: MessageId) -> Bytes;
+	fn getResponse(&self, message: MessageId) -> abi::QueryResponse;
 
 	/// The identifier of this chain.
 	///
@@ -132,6 +272,35 @@ pub trait Messaging {
 	fn removeMany(&self, messages: Vec<MessageId>);
 }
 
+/// Cancel a pending XCM query.
+///
+/// Deregisters the query, such that any subsequent response is ignored, and releases the deposits
+/// held for the message. Only the origin of the query can cancel it.
+///
+/// # Parameters
+/// - `message` - The message identifier of the query to cancel.
+#[inline]
+pub fn cancel(message: MessageId) {
+	let precompile: contract_ref!(Xcm, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.cancel(message)
+}
+
+/// Dry-run an XCM message from a local, signed, origin.
+///
+/// Any changes made by the dry run are discarded. The weight required to execute the message is
+/// charged.
+///
+/// # Parameters
+/// - `message` - A XCM message.
+///
+/// # Returns
+/// The effects of the dry run.
+#[inline]
+pub fn dry_run_xcm<Call: Encode>(message: VersionedXcm<Call>) -> Result<abi::DryRunEffects, Error> {
+	let precompile: contract_ref!(Xcm, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.dryRunXcm(SolBytes(message.encode()))
+}
+
 /// Execute an XCM message from a local, signed, origin.
 ///
 /// # Parameters
@@ -149,15 +318,15 @@ pub fn execute<Call: Encode>(message: VersionedXcm<Call>, weight: Weight) -> Res
 		.map_err(|_| Error::ExecutionFailed(result))
 }
 
-/// Returns the response to a message.
+/// Returns the response to a query.
 ///
-/// A non-existent message identifier will return an empty response, which could also be a valid
-/// response depending on the source message.
+/// A non-existent message identifier will return a `Null` response, which could also be a valid
+/// response depending on the query.
 ///
 /// # Parameters
 /// - `message` - The message identifier.
 #[inline]
-pub fn get_response(message: MessageId) -> Bytes {
+pub fn get_response(message: MessageId) -> abi::QueryResponse {
 	let precompile: contract_ref!(Messaging, Pop, Sol) = PRECOMPILE_ADDRESS.into();
 	precompile.getResponse(message)
 }
@@ -211,6 +380,57 @@ pub fn poll_status(message: MessageId) -> MessageStatus {
 	precompile.pollStatus(message)
 }
 
+/// Quote the amounts taken for a new XCM query.
+///
+/// # Parameters
+/// - `callback` - An optional callback to execute upon receiving a response.
+#[inline]
+pub fn quote_new_query(callback: Option<Callback>) -> Quote {
+	match callback {
+		None => {
+			let precompile: contract_ref!(Xcm, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+			precompile.quoteNewQuery()
+		},
+		Some(callback) => {
+			let precompile: contract_ref!(XcmCallback, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+			precompile.quoteNewQuery(callback)
+		},
+	}
+}
+
+/// Query the fees for delivering a message to a destination.
+///
+/// The fees do not include those for executing the message on the destination.
+///
+/// # Parameters
+/// - `destination` - The destination of the message.
+/// - `message` - A XCM message.
+///
+/// # Returns
+/// The delivery fees.
+#[inline]
+pub fn query_delivery_fees<Call: Encode>(
+	destination: VersionedLocation,
+	message: VersionedXcm<Call>,
+) -> Result<Vec<abi::Asset>, Error> {
+	let precompile: contract_ref!(Xcm, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.queryDeliveryFees(SolBytes(destination.encode()), SolBytes(message.encode()))
+}
+
+/// Query the fee for a weight of execution on this chain, payable in a given asset.
+///
+/// # Parameters
+/// - `weight` - The weight of execution.
+/// - `asset` - The location of the asset in which the fee is to be paid, relative to this chain.
+///
+/// # Returns
+/// The fee, in units of the asset.
+#[inline]
+pub fn query_weight_to_asset_fee(weight: Weight, asset: &Location) -> Result<u128, Error> {
+	let precompile: contract_ref!(Xcm, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.queryWeightToAssetFee(weight, asset.try_into()?)
+}
+
 /// Remove a completed or timed-out message.
 ///
 /// Allows users to clean up storage and reclaim deposits for messages that have concluded.
@@ -235,6 +455,22 @@ pub fn remove_many(messages: Vec<MessageId>) {
 	precompile.removeMany(messages)
 }
 
+/// Transfer assets to a beneficiary on Asset Hub, using Asset Hub as the reserve.
+///
+/// # Parameters
+/// - `beneficiary` - The location of the beneficiary on Asset Hub, relative to Asset Hub.
+/// - `assets` - The assets to be transferred, relative to this chain.
+/// - `fee_asset_index` - The index of the asset within `assets` to be used to pay fees.
+#[inline]
+pub fn reserve_transfer_to_asset_hub(
+	beneficiary: &Location,
+	assets: &[Asset],
+	fee_asset_index: u32,
+) -> Result<(), Error> {
+	let precompile: contract_ref!(Xcm, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.reserveTransferToAssetHub(beneficiary.try_into()?, to_abi(assets)?, fee_asset_index)
+}
+
 /// Send an XCM from a given origin.
 ///
 /// # Parameters
@@ -255,19 +491,107 @@ pub fn send<Call: Encode>(
 		.map_err(|_| Error::SendingFailed(result))
 }
 
-/// A callback for handling responses to XCM queries.
+/// Teleport assets to a beneficiary on another chain.
+///
+/// # Parameters
+/// - `destination` - The location of the destination chain, relative to this chain.
+/// - `beneficiary` - The location of the beneficiary, relative to the destination.
+/// - `assets` - The assets to be teleported, relative to this chain.
+/// - `fee_asset_index` - The index of the asset within `assets` to be used to pay fees.
+#[inline]
+pub fn teleport_assets(
+	destination: &Location,
+	beneficiary: &Location,
+	assets: &[Asset],
+	fee_asset_index: u32,
+) -> Result<(), Error> {
+	let precompile: contract_ref!(Xcm, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.teleportAssets(
+		destination.try_into()?,
+		beneficiary.try_into()?,
+		to_abi(assets)?,
+		fee_asset_index,
+	)
+}
+
+/// Dispatch a call on another chain, from the sovereign account of the caller on that chain.
+///
+/// The fee asset is withdrawn from the sovereign account of the caller to pay for execution, with
+/// any surplus deposited back to it.
+///
+/// # Parameters
+/// - `destination` - The location of the destination chain, relative to this chain.
+/// - `call` - The SCALE-encoded call to be dispatched on the destination.
+/// - `weight` - The maximum weight the call may consume on the destination.
+/// - `fee_asset` - The asset used to pay for execution on the destination, relative to this chain.
+#[inline]
+pub fn transact(
+	destination: &Location,
+	call: Vec<u8>,
+	weight: Weight,
+	fee_asset: &Asset,
+) -> Result<(), Error> {
+	let precompile: contract_ref!(Xcm, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.transact(destination.try_into()?, SolBytes(call), weight, fee_asset.try_into()?)
+}
+
+/// Transfer assets to a beneficiary on another chain.
+///
+/// Whether assets are teleported or reserve transferred is determined by the runtime.
+///
+/// # Parameters
+/// - `destination` - The location of the destination chain, relative to this chain.
+/// - `beneficiary` - The location of the beneficiary, relative to the destination.
+/// - `assets` - The assets to be transferred, relative to this chain.
+/// - `fee_asset_index` - The index of the asset within `assets` to be used to pay fees.
+#[inline]
+pub fn transfer_assets(
+	destination: &Location,
+	beneficiary: &Location,
+	assets: &[Asset],
+	fee_asset_index: u32,
+) -> Result<(), Error> {
+	let precompile: contract_ref!(Xcm, Pop, Sol) = PRECOMPILE_ADDRESS.into();
+	precompile.transferAssets(
+		destination.try_into()?,
+		beneficiary.try_into()?,
+		to_abi(assets)?,
+		fee_asset_index,
+	)
+}
+
+// Converts assets into their Solidity ABI representation.
+fn to_abi(assets: &[Asset]) -> Result<Vec<abi::Asset>, Error> {
+	assets.iter().map(abi::Asset::try_from).collect()
+}
+
+/// A callback for handling SCALE-encoded responses to XCM queries, for callbacks using
+/// `Encoding::Scale`.
 #[ink::trait_definition]
 pub trait OnQueryResponse {
 	/// Handles a response to a XCM query.
 	///
 	/// # Parameters
 	/// - `id` - The identifier of the originating message.
-	/// - `response` - The response message.
+	/// - `response` - The SCALE-encoded response message.
 	#[ink(message)]
 	#[allow(non_snake_case)]
 	fn onQueryResponse(&mut self, id: MessageId, response: Bytes);
 }
 
+/// A callback for handling responses to XCM queries, for callbacks using `Encoding::SolidityAbi`.
+#[ink::trait_definition]
+pub trait OnTypedQueryResponse {
+	/// Handles a response to a XCM query.
+	///
+	/// # Parameters
+	/// - `id` - The identifier of the originating message.
+	/// - `response` - The response message.
+	#[ink(message)]
+	#[allow(non_snake_case)]
+	fn onQueryResponse(&mut self, id: MessageId, response: abi::QueryResponse);
+}
+
 /// Event emitted when a XCM query is completed.
 #[ink::event]
 pub struct XcmCompleted {
@@ -0,0 +1,367 @@
+use ink::{
+	prelude::string::String,
+	xcm::prelude::{Fungible, Junction as XcmJunction, NetworkId},
+};
+
+use super::*;
+use crate::messaging::{Bytes, FixedBytes};
+
+/// A fungible asset.
+#[derive(Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Asset {
+	/// The location of the asset, relative to this chain.
+	pub id: Location,
+	/// The amount of the asset.
+	pub amount: u128,
+}
+
+impl TryFrom<&super::Asset> for Asset {
+	type Error = Error;
+
+	fn try_from(asset: &super::Asset) -> Result<Self, Self::Error> {
+		let Fungible(amount) = asset.fun else { return Err(InvalidAsset) };
+		Ok(Self { id: (&asset.id.0).try_into()?, amount })
+	}
+}
+
+/// A single item within the interior of a location.
+#[derive(Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Junction {
+	/// The type of the junction.
+	pub junction_type: JunctionType,
+	/// The parachain identifier, pallet instance, general index, length of the general key or
+	/// index of the network, depending on the type of the junction.
+	pub index: u128,
+	/// The 32-byte account identifier, the 20-byte account key in the leading bytes, the general
+	/// key or the network-specific key, depending on the type of the junction.
+	pub key: FixedBytes<32>,
+}
+
+impl TryFrom<&XcmJunction> for Junction {
+	type Error = Error;
+
+	fn try_from(junction: &XcmJunction) -> Result<Self, Self::Error> {
+		use JunctionType::*;
+		let (junction_type, index, key) = match junction {
+			XcmJunction::Parachain(id) => (Parachain, *id as u128, [0; 32]),
+			XcmJunction::AccountId32 { network: None, id } => (AccountId32, 0, *id),
+			XcmJunction::AccountKey20 { network: None, key } => {
+				let mut padded = [0; 32];
+				padded[..20].copy_from_slice(key);
+				(AccountKey20, 0, padded)
+			},
+			XcmJunction::PalletInstance(instance) => (PalletInstance, *instance as u128, [0; 32]),
+			XcmJunction::GeneralIndex(index) => (GeneralIndex, *index, [0; 32]),
+			XcmJunction::GeneralKey { length, data } => (GeneralKey, *length as u128, *data),
+			XcmJunction::GlobalConsensus(network) => {
+				let (index, key) = match network {
+					NetworkId::ByGenesis(genesis) => (0, *genesis),
+					NetworkId::Polkadot => (2, [0; 32]),
+					NetworkId::Kusama => (3, [0; 32]),
+					NetworkId::Ethereum { chain_id } => {
+						let mut key = [0; 32];
+						key[24..].copy_from_slice(&chain_id.to_be_bytes());
+						(7, key)
+					},
+					NetworkId::BitcoinCore => (8, [0; 32]),
+					NetworkId::BitcoinCash => (9, [0; 32]),
+					NetworkId::PolkadotBulletin => (10, [0; 32]),
+					_ => return Err(InvalidLocation),
+				};
+				(GlobalConsensus, index, key)
+			},
+			_ => return Err(InvalidLocation),
+		};
+		Ok(Self { junction_type, index, key: SolBytes(key) })
+	}
+}
+
+/// The supported types of junction.
+#[derive(Copy, Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[repr(u8)]
+pub enum JunctionType {
+	/// A parachain, identified by its parachain identifier.
+	Parachain,
+	/// A 32-byte account identifier, without a network.
+	AccountId32,
+	/// A 20-byte account key, without a network.
+	AccountKey20,
+	/// A pallet, identified by its instance within the runtime.
+	PalletInstance,
+	/// A general index within the context of the location.
+	GeneralIndex,
+	/// A general key within the context of the location, of up to 32 bytes.
+	GeneralKey,
+	/// A global network, identified by the SCALE index of its `NetworkId` variant. The key holds
+	/// the genesis hash, or the big-endian chain identifier of an Ethereum network in its trailing
+	/// bytes.
+	GlobalConsensus,
+	/// A junction which cannot be represented, only ever provided within responses.
+	Unsupported,
+}
+
+/// A relative location within the consensus universe.
+#[derive(Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Location {
+	/// The number of parent junctions at the beginning of the location.
+	pub parents: u8,
+	/// The interior junctions of the location, up to a maximum of eight.
+	pub interior: Vec<Junction>,
+}
+
+impl TryFrom<&super::Location> for Location {
+	type Error = Error;
+
+	fn try_from(location: &super::Location) -> Result<Self, Self::Error> {
+		Ok(Self {
+			parents: location.parent_count(),
+			interior: location
+				.interior()
+				.iter()
+				.map(Junction::try_from)
+				.collect::<Result<Vec<_>, _>>()?,
+		})
+	}
+}
+
+/// A non-fungible asset.
+#[derive(Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct NonFungibleAsset {
+	/// The location of the asset class, relative to this chain.
+	pub id: Location,
+	/// The type of the asset instance.
+	pub instance_type: AssetInstanceType,
+	/// The index of the asset instance, for an `Index` instance.
+	pub index: u128,
+	/// The asset instance in the leading bytes, for an array instance.
+	pub data: FixedBytes<32>,
+}
+
+/// The types of asset instance.
+#[derive(Copy, Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[repr(u8)]
+pub enum AssetInstanceType {
+	/// Undefined, used if the asset class has only one instance.
+	Undefined,
+	/// A compact index.
+	Index,
+	/// A 4-byte fixed-length datum.
+	Array4,
+	/// An 8-byte fixed-length datum.
+	Array8,
+	/// A 16-byte fixed-length datum.
+	Array16,
+	/// A 32-byte fixed-length datum.
+	Array32,
+}
+
+/// The effects of dry-running an XCM message.
+#[derive(Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct DryRunEffects {
+	/// The result of the execution.
+	pub execution_result: ExecutionResult,
+	/// The weight used by the execution.
+	pub weight_used: Weight,
+	/// The SCALE-encoded runtime events emitted by the execution.
+	pub emitted_events: Vec<Bytes>,
+	/// The messages forwarded by the execution.
+	pub forwarded_messages: Vec<ForwardedMessages>,
+}
+
+/// The result of executing an XCM message.
+#[derive(Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ExecutionResult {
+	/// Whether execution succeeded.
+	pub success: bool,
+	/// The index of the instruction which failed, if execution failed.
+	pub index: u32,
+	/// The SCALE index of the XCM error, if execution failed.
+	pub error: u8,
+	/// The code provided by a `Trap` error.
+	pub trap_code: u64,
+}
+
+/// The result of a dispatch, possibly with an error code.
+#[derive(Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct MaybeErrorCode {
+	/// Whether the dispatch succeeded.
+	pub success: bool,
+	/// The error code, if the dispatch failed.
+	pub error: Bytes,
+	/// Whether the error code was truncated.
+	pub truncated: bool,
+}
+
+/// The messages forwarded to a destination.
+#[derive(Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ForwardedMessages {
+	/// The SCALE-encoded versioned location of the destination.
+	pub destination: Bytes,
+	/// The SCALE-encoded versioned XCM messages.
+	pub messages: Vec<Bytes>,
+}
+
+/// Information about a pallet on the responding chain.
+#[derive(Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PalletInfo {
+	/// The index of the pallet within the runtime.
+	pub index: u32,
+	/// The name of the pallet instance.
+	pub name: String,
+	/// The name of the crate providing the pallet.
+	pub module_name: String,
+	/// The major version of the crate.
+	pub major: u32,
+	/// The minor version of the crate.
+	pub minor: u32,
+	/// The patch version of the crate.
+	pub patch: u32,
+}
+
+/// A response to an XCM query.
+///
+/// Only the fields relevant to the type of the response are populated, with the remaining fields
+/// left as their defaults.
+#[derive(Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct QueryResponse {
+	/// The type of the response.
+	pub response_type: ResponseType,
+	/// The fungible assets, for an `Assets` response.
+	pub assets: Vec<Asset>,
+	/// The non-fungible assets, for an `Assets` response.
+	pub non_fungible_assets: Vec<NonFungibleAsset>,
+	/// The result of execution, for an `ExecutionResult` response.
+	pub execution_result: ExecutionResult,
+	/// The XCM version, for a `Version` response.
+	pub version: u32,
+	/// The pallet information, for a `PalletsInfo` response.
+	pub pallets_info: Vec<PalletInfo>,
+	/// The result of a dispatch, for a `DispatchResult` response.
+	pub dispatch_result: MaybeErrorCode,
+}
+
+/// The types of response to an XCM query.
+#[derive(Copy, Clone, ink::SolDecode, ink::SolEncode, PartialEq)]
+#[ink::scale_derive(Decode, Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[repr(u8)]
+pub enum ResponseType {
+	/// No response, or a response to a message which does not exist.
+	Null,
+	/// Some assets.
+	Assets,
+	/// The result of executing an XCM message.
+	ExecutionResult,
+	/// An XCM version.
+	Version,
+	/// Information about some pallets.
+	PalletsInfo,
+	/// The result of a dispatch.
+	DispatchResult,
+}
+
+#[test]
+fn location_conversion_works() {
+	let key = [255; 32];
+	let location = super::Location::new(
+		1,
+		[
+			XcmJunction::Parachain(1_000),
+			XcmJunction::PalletInstance(50),
+			XcmJunction::GeneralIndex(1_984),
+			XcmJunction::AccountId32 { network: None, id: key },
+			XcmJunction::AccountKey20 { network: None, key: [255; 20] },
+			XcmJunction::GeneralKey { length: 2, data: key },
+		],
+	);
+	let mut padded = [0; 32];
+	padded[..20].copy_from_slice(&[255; 20]);
+
+	assert_eq!(
+		Location::try_from(&location),
+		Ok(Location {
+			parents: 1,
+			interior: vec![
+				Junction {
+					junction_type: JunctionType::Parachain,
+					index: 1_000,
+					key: SolBytes([0; 32])
+				},
+				Junction {
+					junction_type: JunctionType::PalletInstance,
+					index: 50,
+					key: SolBytes([0; 32])
+				},
+				Junction {
+					junction_type: JunctionType::GeneralIndex,
+					index: 1_984,
+					key: SolBytes([0; 32])
+				},
+				Junction { junction_type: JunctionType::AccountId32, index: 0, key: SolBytes(key) },
+				Junction {
+					junction_type: JunctionType::AccountKey20,
+					index: 0,
+					key: SolBytes(padded)
+				},
+				Junction { junction_type: JunctionType::GeneralKey, index: 2, key: SolBytes(key) },
+			],
+		})
+	);
+}
+
+#[test]
+fn location_conversion_fails_when_unsupported() {
+	for junction in [
+		XcmJunction::AccountId32 { network: Some(NetworkId::Polkadot), id: [0; 32] },
+		XcmJunction::GlobalConsensus(NetworkId::ByFork { block_number: 0, block_hash: [0; 32] }),
+		XcmJunction::OnlyChild,
+	] {
+		let location = super::Location::new(0, [junction]);
+		assert_eq!(Location::try_from(&location), Err(InvalidLocation));
+	}
+}
+
+#[test]
+fn global_consensus_conversion_works() {
+	let mut chain_id = [0; 32];
+	chain_id[24..].copy_from_slice(&1u64.to_be_bytes());
+	for (network, index, key) in [
+		(NetworkId::ByGenesis([255; 32]), 0, [255; 32]),
+		(NetworkId::Polkadot, 2, [0; 32]),
+		(NetworkId::Kusama, 3, [0; 32]),
+		(NetworkId::Ethereum { chain_id: 1 }, 7, chain_id),
+	] {
+		assert_eq!(
+			Junction::try_from(&XcmJunction::GlobalConsensus(network)),
+			Ok(Junction {
+				junction_type: JunctionType::GlobalConsensus,
+				index,
+				key: SolBytes(key)
+			})
+		);
+	}
+}
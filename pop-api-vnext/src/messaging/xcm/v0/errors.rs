@@ -8,8 +8,9 @@ use crate::{
 	},
 	impl_sol_encoding_for_precompile,
 	messaging::v0::errors::{
-		ARITHMETIC, DISPATCH, MESSAGE_NOT_FOUND, MODULE, REQUEST_PENDING, TOKEN, TOO_MANY_MESSAGES,
-		TRANSACTIONAL, TRIE,
+		ARITHMETIC, DISPATCH, MESSAGE_COMPLETED, MESSAGE_NOT_FOUND, MODULE, REQUEST_PENDING,
+		REQUEST_TIMED_OUT, TOKEN, TOO_MANY_MESSAGES, TOO_MANY_PENDING_MESSAGES, TRANSACTIONAL,
+		TRIE,
 	},
 	sol::PrecompileError,
 };
@@ -20,6 +21,8 @@ use crate::{
 pub enum Error {
 	/// An arithmetic error occurred.
 	Arithmetic(ArithmeticError),
+	/// The asset is not accepted for the payment of fees.
+	AssetNotFound,
 	/// The input failed to decode.
 	DecodingFailed,
 	/// Reason why a dispatch call failed.
@@ -28,8 +31,14 @@ pub enum Error {
 	ExecutionFailed(Bytes),
 	/// Timeouts must be in the future.
 	FutureTimeoutMandatory,
-	/// Message block limit has been reached for this expiry block. Try a different timeout.
-	MaxMessageTimeoutPerBlockReached,
+	/// The asset is invalid or not supported.
+	InvalidAsset,
+	/// The location is invalid or not supported.
+	InvalidLocation,
+	/// The message is invalid.
+	InvalidMessage,
+	/// This is not possible as the message has completed.
+	MessageCompleted,
 	/// The message was not found.
 	MessageNotFound,
 	/// Reason why a pallet call failed.
@@ -43,17 +52,27 @@ pub enum Error {
 	OriginConversionFailed,
 	/// The request is pending.
 	RequestPending,
+	/// The request has timed out.
+	RequestTimedOut,
 	/// The sending of a XCM message failed.
 	SendingFailed(Bytes),
 	/// An error to do with tokens.
 	Token(TokenError),
 	/// The number of messages exceeds the limit.
 	TooManyMessages,
+	/// The origin has reached its maximum number of pending messages.
+	TooManyPendingMessages,
 	/// The number of transactional layers has been reached, or we are not in a transactional
 	/// layer.
 	Transactional(TransactionalError),
 	/// An error with tries.
 	Trie(TrieError),
+	/// The destination is not reachable.
+	Unroutable,
+	/// The operation is not supported by this chain.
+	Unsupported,
+	/// The weight of the message could not be computed.
+	WeightNotComputable,
 }
 
 impl_sol_encoding_for_precompile!(Error);
@@ -66,12 +85,16 @@ impl PrecompileError for Error {
 
 		match data[..4].try_into().expect("length checked above") {
 			ARITHMETIC => Ok(Self::Arithmetic(<ArithmeticError as SolDecode>::decode(&data[4..])?)),
+			ASSET_NOT_FOUND => Ok(Self::AssetNotFound),
 			DECODING_FAILED => Ok(Self::DecodingFailed),
 			DISPATCH => Ok(Self::Dispatch(<DispatchError as SolDecode>::decode(&data[4..])?)),
 			EXECUTION_FAILED =>
 				Ok(Self::ExecutionFailed(<Bytes as SolDecode>::decode(&data[4..])?)),
 			FUTURE_TIMEOUT_MANDATORY => Ok(Self::FutureTimeoutMandatory),
-			MAX_MESSAGE_TIMEOUT_PER_BLOCK_REACHED => Ok(Self::MaxMessageTimeoutPerBlockReached),
+			INVALID_ASSET => Ok(Self::InvalidAsset),
+			INVALID_LOCATION => Ok(Self::InvalidLocation),
+			INVALID_MESSAGE => Ok(Self::InvalidMessage),
+			MESSAGE_COMPLETED => Ok(Self::MessageCompleted),
 			MESSAGE_NOT_FOUND => Ok(Self::MessageNotFound),
 			MODULE => {
 				let ModuleError { index, error } = <ModuleError as SolDecode>::decode(&data[4..])?;
@@ -79,24 +102,34 @@ impl PrecompileError for Error {
 			},
 			ORIGIN_CONVERSION_FAILED => Ok(Self::OriginConversionFailed),
 			REQUEST_PENDING => Ok(Self::RequestPending),
+			REQUEST_TIMED_OUT => Ok(Self::RequestTimedOut),
 			SENDING_FAILED => Ok(Self::SendingFailed(<Bytes as SolDecode>::decode(&data[4..])?)),
 			TOKEN => Ok(Self::Token(<TokenError as SolDecode>::decode(&data[4..])?)),
 			TOO_MANY_MESSAGES => Ok(Self::TooManyMessages),
+			TOO_MANY_PENDING_MESSAGES => Ok(Self::TooManyPendingMessages),
 			TRANSACTIONAL =>
 				Ok(Self::Transactional(<TransactionalError as SolDecode>::decode(&data[4..])?)),
 			TRIE => Ok(Self::Trie(<TrieError as SolDecode>::decode(&data[4..])?)),
+			UNROUTABLE => Ok(Self::Unroutable),
+			UNSUPPORTED => Ok(Self::Unsupported),
+			WEIGHT_NOT_COMPUTABLE => Ok(Self::WeightNotComputable),
 			_ => Err(ink::sol::Error),
 		}
 	}
 }
 
+const ASSET_NOT_FOUND: [u8; 4] = sol_error_selector!("AssetNotFound", ());
 const DECODING_FAILED: [u8; 4] = sol_error_selector!("DecodingFailed", ());
 const EXECUTION_FAILED: [u8; 4] = sol_error_selector!("ExecutionFailed", (SolBytes<Vec<u8>>,));
 const FUTURE_TIMEOUT_MANDATORY: [u8; 4] = sol_error_selector!("FutureTimeoutMandatory", ());
-const MAX_MESSAGE_TIMEOUT_PER_BLOCK_REACHED: [u8; 4] =
-	sol_error_selector!("MaxMessageTimeoutPerBlockReached", ());
+const INVALID_ASSET: [u8; 4] = sol_error_selector!("InvalidAsset", ());
+const INVALID_LOCATION: [u8; 4] = sol_error_selector!("InvalidLocation", ());
+const INVALID_MESSAGE: [u8; 4] = sol_error_selector!("InvalidMessage", ());
 const ORIGIN_CONVERSION_FAILED: [u8; 4] = sol_error_selector!("OriginConversionFailed", ());
 const SENDING_FAILED: [u8; 4] = sol_error_selector!("SendingFailed", (SolBytes<Vec<u8>>,));
+const UNROUTABLE: [u8; 4] = sol_error_selector!("Unroutable", ());
+const UNSUPPORTED: [u8; 4] = sol_error_selector!("Unsupported", ());
+const WEIGHT_NOT_COMPUTABLE: [u8; 4] = sol_error_selector!("WeightNotComputable", ());
 
 #[test]
 fn error_decoding_works() {
@@ -107,6 +140,7 @@ fn error_decoding_works() {
 			"7fdb06c50000000000000000000000000000000000000000000000000000000000000001",
 			Arithmetic(ArithmeticError::Overflow),
 		),
+		("470cbf47", AssetNotFound),
 		("72065cff", DecodingFailed),
 		(
 			"20c5a2a9000000000000000000000000000000000000000000000000000000000000000d",
@@ -117,7 +151,10 @@ fn error_decoding_works() {
 			ExecutionFailed(SolBytes(Vec::default()))
 		),
 		("885a28b2", FutureTimeoutMandatory),
-		("fc952e0b", MaxMessageTimeoutPerBlockReached),
+		("c891add2", InvalidAsset),
+		("f80f1445", InvalidLocation),
+		("6eca2e4b", InvalidMessage),
+		("c51e0766", MessageCompleted),
 		("28915ac7", MessageNotFound),
 		(
 			"3323f3c100000000000000000000000000000000000000000000000000000000000000ffffffffff00000000000000000000000000000000000000000000000000000000",
@@ -125,6 +162,7 @@ fn error_decoding_works() {
 		),
 		("8926fba8", OriginConversionFailed),
 		("806d0f74", RequestPending),
+		("6512950c", RequestTimedOut),
 		(
 		    "0ff105a200000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000",
 			SendingFailed(SolBytes(Vec::default()))
@@ -134,6 +172,7 @@ fn error_decoding_works() {
 			Token(TokenError::Blocked),
 		),
 		("1ec0b2f7", TooManyMessages),
+		("24b4145b", TooManyPendingMessages),
 		(
 			"3008a37e0000000000000000000000000000000000000000000000000000000000000001",
 			Transactional(TransactionalError::NoLayer),
@@ -142,6 +181,9 @@ fn error_decoding_works() {
 			"3ea87b59000000000000000000000000000000000000000000000000000000000000000d",
 			Trie(TrieError::DecodeError),
 		),
+		("d1b98cf7", Unroutable),
+		("90a2caf2", Unsupported),
+		("a6aeab08", WeightNotComputable),
 	] {
 	    let data = hex::decode(encoded).unwrap();
 		let decoded = <Error as SolErrorDecode>::decode(data.as_slice()).expect(&format!("unable to decode {encoded}"));
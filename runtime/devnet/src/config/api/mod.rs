@@ -16,7 +16,9 @@ use sp_core::{ConstU32, ConstU8};
 use versioning::*;
 
 use crate::{
-	config::assets::{TrustBackedAssetsInstance, TrustBackedNftsInstance},
+	config::assets::{
+		ApprovalDeposit, ForeignAssetsInstance, TrustBackedAssetsInstance, TrustBackedNftsInstance,
+	},
 	fungibles, nonfungibles, AccountId, Balance, Balances, BlockNumber, DealWithFees, Ismp,
 	Runtime, RuntimeCall, RuntimeHoldReason, TransactionByteFee, H160,
 };
@@ -225,47 +227,112 @@ impl<T: frame_system::Config> Contains<RuntimeRead> for Filter<T> {
 }
 
 impl pallet_api_vnext::fungibles::Config<TrustBackedAssetsInstance> for Runtime {
+	type MaxBatchSize = ConstU32<20>;
+	type TokenIdConverter = pallet_api_vnext::fungibles::NumericTokenId;
+	type WeightInfo = ();
+}
+
+impl pallet_api_vnext::fungibles::Config<ForeignAssetsInstance> for Runtime {
+	type MaxBatchSize = ConstU32<20>;
+	// Foreign assets are identified by their location, from which a token identifier is derived.
+	type TokenIdConverter =
+		pallet_api_vnext::fungibles::HashedTokenId<Runtime, ForeignAssetsInstance>;
+	type WeightInfo = ();
+}
+
+impl pallet_api_vnext::fungibles::native::Config for Runtime {
+	type ApprovalDeposit = ApprovalDeposit;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = ();
 }
 
 mod messaging {
-	use pallet_api_vnext::messaging;
+	use frame_support::{dispatch::DispatchErrorWithPostInfo, weights::WeightToFee as _};
+	use frame_system::EnsureRoot;
+	#[cfg(feature = "messaging-loopback")]
+	use pallet_api_vnext::messaging::transports::loopback;
+	use pallet_api_vnext::messaging::{
+		self,
+		transports::xcm::{XcmDryRunApiError, XcmDryRunEffects, XcmPaymentApiError},
+	};
 	use pallet_xcm::Origin;
-	use xcm::latest::Location;
+	use xcm::{
+		latest::{AssetId, Location},
+		VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm,
+	};
 
 	use super::*;
-	use crate::config::xcm::LocalOriginToLocation;
+	use crate::{
+		config::xcm::{LocalOriginToLocation, RelayLocation, XcmConfig, XcmRouter},
+		BlockNumber, PolkadotXcm, RuntimeEvent, WeightToFee, DAYS, MINUTES, UNIT,
+	};
 
 	parameter_types! {
+			// Up to a tenth of a block may be used to retry deferred callbacks.
+			pub DeferredCallbackWeightLimit: Weight = crate::MAXIMUM_BLOCK_WEIGHT / 10;
+			pub IsmpPostRequestGasLimit: Weight = Weight::from_parts(1_000_000_000, 100_000);
+			pub const IsmpPostRequestStorageDepositLimit: Balance = 10 * UNIT;
+			// No single origin may hold enough pending messages to fill the query timeouts of a block.
+			pub const MaxPendingMessages: u32 = MaxXcmQueryTimeoutsPerBlock::get() / 2;
 			pub const MaxXcmQueryTimeoutsPerBlock: u32 = 100;
+			// Completed and timed out messages are removed a week after concluding.
+			pub const MessageRetentionPeriod: BlockNumber = 7 * DAYS;
+			// Subscriptions may read their destination at most once a minute.
+			pub const MinSubscriptionInterval: BlockNumber = MINUTES;
 	}
 
 	impl messaging::Config for Runtime {
+		type AddressConverter = messaging::AddressToAccountId<Runtime>;
 		type CallbackExecutor = CallbackExecutor;
+		type DeferredCallbackWeightLimit = DeferredCallbackWeightLimit;
 		type FeeHandler = DealWithFees;
 		type Fungibles = Balances;
+		// Requests and queries are only captured once the loopback has been enabled.
+		#[cfg(feature = "messaging-loopback")]
+		type IsmpDispatcher = loopback::Dispatcher<Runtime, Ismp>;
+		#[cfg(not(feature = "messaging-loopback"))]
 		type IsmpDispatcher = Ismp;
+		type IsmpPostRequestGasLimit = IsmpPostRequestGasLimit;
+		type IsmpPostRequestStorageDepositLimit = IsmpPostRequestStorageDepositLimit;
 		type Keccak256 = Ismp;
+		type MaxBatchSize = ConstU32<10>;
+		type MaxCallbackRetries = ConstU32<10>;
 		type MaxContextLen = ConstU32<64>;
 		type MaxDataLen = ConstU32<512>;
+		type MaxDeferredCallbacks = ConstU32<100>;
 		type MaxKeyLen = ConstU32<8>;
 		type MaxKeys = ConstU32<10>;
+		type MaxPendingMessages = MaxPendingMessages;
 		type MaxRecipientLen = ConstU32<32>;
 		// TODO: size appropriately
 		type MaxRemovals = ConstU32<100>;
 		// TODO: ensure within the contract buffer bounds
 		type MaxResponseLen = ConstU32<512>;
 		type MaxXcmQueryTimeoutsPerBlock = MaxXcmQueryTimeoutsPerBlock;
+		type MessageRetentionPeriod = MessageRetentionPeriod;
+		type MinSubscriptionInterval = MinSubscriptionInterval;
 		type OffChainByteFee = TransactionByteFee;
 		type OnChainByteFee = TransactionByteFee;
 		type OriginConverter = LocalOriginToLocation;
+		type QuotaOrigin = EnsureRoot<AccountId>;
 		type RuntimeHoldReason = RuntimeHoldReason;
 		type WeightInfo = ();
 		type WeightToFee = <Runtime as pallet_transaction_payment::Config>::WeightToFee;
+		#[cfg(feature = "messaging-loopback")]
+		type Xcm = loopback::QueryHandler<Runtime, QueryHandler>;
+		#[cfg(not(feature = "messaging-loopback"))]
 		type Xcm = QueryHandler;
+		type XcmApi = XcmRuntimeApis;
 		type XcmResponseOrigin = EnsureResponse;
 	}
 
+	#[cfg(feature = "messaging-loopback")]
+	impl loopback::Config for Runtime {
+		type ControlOrigin = EnsureRoot<AccountId>;
+		type ResponseOrigin = ResponseOrigin;
+	}
+
 	pub struct CallbackExecutor;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	impl messaging::CallbackExecutor<Runtime> for CallbackExecutor {
@@ -276,7 +343,17 @@ mod messaging {
 			gas_limit: Weight,
 			storage_deposit_limit: Balance,
 		) -> DispatchResultWithPostInfo {
-			use frame_support::dispatch::DispatchErrorWithPostInfo;
+			Self::execute_with_output(account, contract, data, gas_limit, storage_deposit_limit)
+				.map(|(post_info, _)| post_info)
+		}
+
+		fn execute_with_output(
+			account: &AccountId,
+			contract: H160,
+			data: Vec<u8>,
+			gas_limit: Weight,
+			storage_deposit_limit: Balance,
+		) -> Result<(PostDispatchInfo, Vec<u8>), DispatchErrorWithPostInfo> {
 			use pallet_revive::DepositLimit;
 
 			use crate::{Revive, RuntimeOrigin};
@@ -304,7 +381,7 @@ mod messaging {
 
 			output
 				.result
-				.map(|_| post_info)
+				.map(|return_value| (post_info, return_value.data))
 				.map_err(|e| DispatchErrorWithPostInfo { post_info, error: e })
 		}
 
@@ -315,7 +392,7 @@ mod messaging {
 	}
 	#[cfg(feature = "runtime-benchmarks")]
 	impl messaging::CallbackExecutor<Runtime> for CallbackExecutor {
-		/// A successfull callback is the most expensive case.
+		/// A successful callback is the most expensive case.
 		fn execute(
 			_account: &AccountId,
 			_contract: H160,
@@ -329,6 +406,22 @@ mod messaging {
 			})
 		}
 
+		/// A successful call returning a maximum length response is the most expensive case.
+		fn execute_with_output(
+			account: &AccountId,
+			contract: H160,
+			data: Vec<u8>,
+			gas_limit: Weight,
+			storage_deposit_limit: Balance,
+		) -> Result<(PostDispatchInfo, Vec<u8>), DispatchErrorWithPostInfo> {
+			use codec::Encode;
+			use frame_support::traits::Get;
+
+			let len = <Runtime as messaging::Config>::MaxDataLen::get() as usize;
+			Self::execute(account, contract, data, gas_limit, storage_deposit_limit)
+				.map(|post_info| (post_info, alloc::vec![255u8; len].encode()))
+		}
+
 		fn execution_weight() -> sp_runtime::Weight {
 			Default::default()
 		}
@@ -352,6 +445,16 @@ mod messaging {
 		}
 	}
 
+	/// Converts the location of a responder to a response origin, as used by `pallet-xcm`.
+	#[cfg(feature = "messaging-loopback")]
+	pub struct ResponseOrigin;
+	#[cfg(feature = "messaging-loopback")]
+	impl sp_runtime::traits::Convert<Location, crate::RuntimeOrigin> for ResponseOrigin {
+		fn convert(responder: Location) -> crate::RuntimeOrigin {
+			Origin::Response(responder).into()
+		}
+	}
+
 	pub struct QueryHandler;
 	impl messaging::transports::xcm::NotifyQueryHandler<Runtime> for QueryHandler {
 		type WeightInfo = pallet_xcm::Pallet<Runtime>;
@@ -364,6 +467,46 @@ mod messaging {
 		) -> u64 {
 			crate::PolkadotXcm::new_notify_query(responder, notify, timeout, match_querier)
 		}
+
+		fn cancel_query(query_id: u64) {
+			messaging::transports::xcm::remove_pallet_xcm_query::<Runtime>(query_id)
+		}
+	}
+
+	/// Dry-running and fee estimation of XCM messages, using the same logic as the XCM runtime
+	/// APIs.
+	pub struct XcmRuntimeApis;
+	impl messaging::transports::xcm::XcmApi<Runtime> for XcmRuntimeApis {
+		fn dry_run_xcm(
+			origin: VersionedLocation,
+			message: VersionedXcm<RuntimeCall>,
+		) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			PolkadotXcm::dry_run_xcm::<Runtime, XcmRouter, RuntimeCall, XcmConfig>(origin, message)
+		}
+
+		fn query_delivery_fees(
+			destination: VersionedLocation,
+			message: VersionedXcm<()>,
+		) -> Result<VersionedAssets, XcmPaymentApiError> {
+			PolkadotXcm::query_delivery_fees(destination, message)
+		}
+
+		fn query_weight_to_asset_fee(
+			weight: Weight,
+			asset: VersionedAssetId,
+		) -> Result<u128, XcmPaymentApiError> {
+			// Only the relay chain asset is accepted for the payment of fees.
+			match AssetId::try_from(asset) {
+				Ok(AssetId(location)) if location == RelayLocation::get() =>
+					Ok(WeightToFee::weight_to_fee(&weight)),
+				Ok(_) => Err(XcmPaymentApiError::AssetNotFound),
+				Err(_) => Err(XcmPaymentApiError::VersionedConversionFailed),
+			}
+		}
+
+		fn query_xcm_weight(message: VersionedXcm<()>) -> Result<Weight, XcmPaymentApiError> {
+			PolkadotXcm::query_xcm_weight(message)
+		}
 	}
 }
 
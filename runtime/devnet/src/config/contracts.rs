@@ -9,18 +9,27 @@ use pop_runtime_common::{DepositPerByte, DepositPerItem, UNIT};
 
 use super::api::{self, Config};
 use crate::{
-	config::assets::TrustBackedAssetsInstance, deposit, Balance, Balances, Perbill, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeHoldReason, Timestamp, TransactionPayment,
+	config::assets::{ForeignAssetsInstance, TrustBackedAssetsInstance},
+	deposit, Balance, Balances, Perbill, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason,
+	Timestamp, TransactionPayment,
 };
 
 type Erc20<const PREFIX: u16, I> =
 	pallet_api_vnext::fungibles::precompiles::erc20::v0::Erc20<PREFIX, Runtime, I>;
 type Fungibles<const FIXED: u16, I> =
 	pallet_api_vnext::fungibles::precompiles::v0::Fungibles<FIXED, Runtime, I>;
+type FungiblesV1<const FIXED: u16, I> =
+	pallet_api_vnext::fungibles::precompiles::v1::Fungibles<FIXED, Runtime, I>;
 type Ismp<const FIXED: u16> =
 	pallet_api_vnext::messaging::precompiles::ismp::v0::Ismp<FIXED, Runtime>;
 type Messaging<const FIXED: u16> =
 	pallet_api_vnext::messaging::precompiles::v0::Messaging<FIXED, Runtime>;
+type NativeErc20<const FIXED: u16> =
+	pallet_api_vnext::fungibles::precompiles::erc20::v0::native::NativeErc20<
+		FIXED,
+		Runtime,
+		NativeMetadata,
+	>;
 type Xcm<const FIXED: u16> = pallet_api_vnext::messaging::precompiles::xcm::v0::Xcm<FIXED, Runtime>;
 
 fn schedule<T: pallet_contracts::Config>() -> pallet_contracts::Schedule<T> {
@@ -123,6 +132,14 @@ impl pallet_revive::Config for Runtime {
 		Ismp<4>,
 		// 5: `Xcm` precompile v0
 		Xcm<5>,
+		// 6: native `Erc20` precompile v0
+		NativeErc20<6>,
+		// 7: `Fungibles` precompile v0 using `ForeignAssetsInstance` instances
+		Fungibles<7, ForeignAssetsInstance>,
+		// 8: `Erc20` precompile v0 using `ForeignAssetsInstance` instances
+		Erc20<8, ForeignAssetsInstance>,
+		// 9: `Fungibles` precompile v1 using `TrustBackedAssetsInstance` instances
+		FungiblesV1<9, TrustBackedAssetsInstance>,
 	);
 	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
@@ -137,6 +154,14 @@ impl pallet_revive::Config for Runtime {
 	type WeightPrice = TransactionPayment;
 }
 
+/// Metadata of the native token, as exposed by the native `Erc20` precompile.
+pub struct NativeMetadata;
+impl pallet_api_vnext::fungibles::precompiles::erc20::v0::native::Metadata for NativeMetadata {
+	const DECIMALS: u8 = 10;
+	const NAME: &'static str = "Paseo";
+	const SYMBOL: &'static str = "PAS";
+}
+
 impl TryFrom<RuntimeCall> for pallet_revive::Call<Runtime> {
 	type Error = ();
 
@@ -169,9 +194,10 @@ mod tests {
 	use sp_core::{bytes::to_hex, H160};
 	use sp_keyring::Sr25519Keyring::{Alice, Bob};
 	use sp_runtime::Weight;
+	use xcm::v5::{Junction::*, Location};
 
 	use super::*;
-	use crate::{Assets, Revive, RuntimeOrigin, System};
+	use crate::{Assets, ForeignAssets, Revive, RuntimeOrigin, System};
 
 	type AccountId32Mapper = pallet_revive::AccountId32Mapper<Runtime>;
 	type Asset = pallet_assets::Asset<Runtime, TrustBackedAssetsInstance>;
@@ -235,4 +261,61 @@ mod tests {
 			assert_eq!(Assets::balance(token, &Bob.to_account_id()), total_supply / 2);
 		})
 	}
+
+	#[test]
+	fn foreign_assets_precompiles_work() {
+		let caller = Alice.to_account_id();
+		let origin = RuntimeOrigin::signed(caller.clone());
+		let asset = Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)]);
+		let token =
+			pallet_api_vnext::fungibles::HashedTokenId::<Runtime, ForeignAssetsInstance>::token_id(
+				&asset,
+			);
+		let fungibles_addr: H160 = Fungibles::<7, ForeignAssetsInstance>::address().into();
+		let erc20_addr: H160 = Erc20::<8, ForeignAssetsInstance>::address(token).into();
+		let bob = AccountId32Mapper::to_address(&Bob.to_account_id());
+		let total_supply: Balance = 10_000;
+		let gas_limit = Weight::from_parts(600_000_000, 10_000);
+		new_test_ext().execute_with(|| {
+			assert_ok!(Revive::map_account(origin.clone()));
+			assert_ok!(Revive::map_account(RuntimeOrigin::signed(Bob.to_account_id())));
+
+			// Foreign assets are created by root, registering their token identifiers.
+			assert_ok!(ForeignAssets::force_create(
+				RuntimeOrigin::root(),
+				asset.clone(),
+				caller.clone().into(),
+				true,
+				1
+			));
+			assert_ok!(ForeignAssets::mint(
+				origin.clone(),
+				asset.clone(),
+				caller.clone().into(),
+				total_supply
+			));
+
+			// Transfer via fungibles precompile
+			let call = transferCall {
+				token,
+				to: bob.0.into(),
+				value: primitives::U256::from(total_supply / 4),
+			}
+			.abi_encode();
+			assert_ok!(Revive::call(origin.clone(), fungibles_addr, 0, gas_limit, 0, call));
+			assert_eq!(
+				ForeignAssets::balance(asset.clone(), &Bob.to_account_id()),
+				total_supply / 4
+			);
+
+			// Transfer via erc20 precompile
+			let call = IERC20::transferCall {
+				to: bob.0.into(),
+				value: primitives::U256::from(total_supply / 4),
+			}
+			.abi_encode();
+			assert_ok!(Revive::call(origin.clone(), erc20_addr, 0, gas_limit, 0, call));
+			assert_eq!(ForeignAssets::balance(asset, &Bob.to_account_id()), total_supply / 2);
+		})
+	}
 }
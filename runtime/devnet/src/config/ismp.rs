@@ -49,7 +49,10 @@ impl IsmpRouter for Router {
 	fn module_for_id(&self, id: Vec<u8>) -> Result<Box<dyn IsmpModule>, anyhow::Error> {
 		use pallet_api_vnext::messaging::transports::ismp as messaging;
 		match id {
-			id if id == messaging::ID => Ok(Box::new(messaging::Module::<Runtime>::new())),
+			// Responses to outbound requests are addressed to the module identifier, with inbound
+			// requests to contracts addressed to the module identifier followed by the contract
+			// address and encoding.
+			id if messaging::is_module_id(&id) => Ok(Box::new(messaging::Module::<Runtime>::new())),
 			_ => Err(Error::ModuleNotFound(id).into()),
 		}
 	}
@@ -62,7 +65,7 @@ impl pallet_ismp::weights::WeightProvider for WeightProvider {
 	) -> Option<Box<dyn pallet_ismp::weights::IsmpModuleWeight>> {
 		use pallet_api_vnext::messaging::transports::ismp as messaging;
 		match dest_module.to_bytes() {
-			dest_module if dest_module == messaging::ID =>
+			dest_module if messaging::is_module_id(&dest_module) =>
 				Some(Box::new(messaging::Module::<Runtime>::new())),
 			_ => None,
 		}
@@ -2,6 +2,9 @@
 // `construct_runtime!` does a lot of recursion and requires us to increase the limit to 256.
 #![recursion_limit = "256"]
 
+#[cfg(all(feature = "messaging-loopback", feature = "on-chain-release-build"))]
+compile_error!("The development-only messaging loopback must not be included in a release build.");
+
 // Make the WASM binary available.
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
@@ -52,6 +55,7 @@ use frame_system::{
 	CheckWeight, EnsureRoot,
 };
 use pallet_api::{fungibles, nonfungibles};
+use pallet_api_vnext::messaging::{MessageDetails, MessageId, MessageQuote};
 use pallet_balances::Call as BalancesCall;
 use pallet_ismp::offchain::{Leaf, Proof, ProofKeys};
 use pallet_revive::{
@@ -328,6 +332,7 @@ impl frame_system::Config for Runtime {
 	/// The type for hashing blocks and tries.
 	type Hash = Hash;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MultiBlockMigrator = MultiBlockMigrations;
 	/// The index type for storing how many extrinsics an account has signed.
 	type Nonce = Nonce;
 	/// The action to take on a Runtime Upgrade
@@ -343,6 +348,25 @@ impl cumulus_pallet_weight_reclaim::Config for Runtime {
 		pop_runtime_common::weights::cumulus_pallet_weight_reclaim::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub MbmServiceWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+	type CursorMaxLen = ConstU32<65_536>;
+	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MaxServiceWeight = MbmServiceWeight;
+	type MigrationStatusHandler = ();
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = (pallet_api_vnext::messaging::migrations::v1::MigrateToV1<Runtime>,);
+	// Benchmarks need mocked migrations to guarantee that they succeed.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pop_runtime_common::weights::pallet_migrations::WeightInfo<Runtime>;
+}
+
 impl pallet_timestamp::Config for Runtime {
 	type MinimumPeriod = ConstU64<0>;
 	/// A timestamp: milliseconds since the unix epoch.
@@ -472,13 +496,24 @@ impl pallet_message_queue::Config for Runtime {
 	>;
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
-	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	// Inbound messages are not processed whilst multi-block migrations are ongoing.
+	type QueuePausedQuery = (NarrowOriginToSibling<XcmpQueue>, PausedDuringMigrations);
 	type RuntimeEvent = RuntimeEvent;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type Size = u32;
 	type WeightInfo = ();
 }
 
+/// Pauses the processing of all message queues whilst multi-block migrations are ongoing, so that
+/// messages are not processed against partially migrated storage.
+pub struct PausedDuringMigrations;
+impl<Origin> frame_support::traits::QueuePausedQuery<Origin> for PausedDuringMigrations {
+	fn is_paused(_origin: &Origin) -> bool {
+		use frame_support::migrations::MultiStepMigrator;
+		MultiBlockMigrations::ongoing()
+	}
+}
+
 impl cumulus_pallet_aura_ext::Config for Runtime {}
 
 impl cumulus_pallet_xcmp_queue::Config for Runtime {
@@ -652,6 +687,8 @@ mod runtime {
 	pub type ParachainInfo = parachain_info::Pallet<Runtime>;
 	#[runtime::pallet_index(4)]
 	pub type WeightReclaim = cumulus_pallet_weight_reclaim::Pallet<Runtime>;
+	#[runtime::pallet_index(5)]
+	pub type MultiBlockMigrations = pallet_migrations::Pallet<Runtime>;
 
 	// Monetary stuff.
 	#[runtime::pallet_index(10)]
@@ -720,6 +757,8 @@ mod runtime {
 	pub type NftFractionalization = pallet_nft_fractionalization::Pallet<Runtime>;
 	#[runtime::pallet_index(52)]
 	pub type Assets = pallet_assets::Pallet<Runtime, Instance1>;
+	#[runtime::pallet_index(53)]
+	pub type ForeignAssets = pallet_assets::Pallet<Runtime, Instance2>;
 
 	// Pop API
 	#[runtime::pallet_index(150)]
@@ -730,6 +769,13 @@ mod runtime {
 	pub type FungiblesvNext = pallet_api_vnext::fungibles::Pallet<Runtime, Instance1>;
 	#[runtime::pallet_index(153)]
 	pub type Messaging = pallet_api_vnext::messaging::Pallet<Runtime>;
+	#[runtime::pallet_index(154)]
+	#[cfg(feature = "messaging-loopback")]
+	pub type MessagingLoopback = pallet_api_vnext::messaging::transports::loopback::Pallet<Runtime>;
+	#[runtime::pallet_index(155)]
+	pub type ForeignFungiblesvNext = pallet_api_vnext::fungibles::Pallet<Runtime, Instance2>;
+	#[runtime::pallet_index(156)]
+	pub type NativeFungiblesvNext = pallet_api_vnext::fungibles::native::Pallet<Runtime>;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -748,7 +794,9 @@ mod benches {
 		[cumulus_pallet_parachain_system, ParachainSystem]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[cumulus_pallet_weight_reclaim, WeightReclaim]
+		[pallet_migrations, MultiBlockMigrations]
 		[pallet_api_vnext::fungibles, FungiblesvNext]
+		[pallet_api_vnext::fungibles::native, NativeFungiblesvNext]
 		[pallet_api_vnext::messaging, Messaging]
 	);
 }
@@ -1043,6 +1091,53 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_api_vnext::messaging::runtime_api::MessagingApi<Block, Balance> for Runtime {
+		fn messages(
+			origin: H160,
+			start_after: Option<MessageId>,
+			limit: u32,
+		) -> Vec<MessageDetails<Balance>> {
+			Messaging::messages(origin, start_after, limit)
+		}
+
+		fn message(id: MessageId) -> Option<MessageDetails<Balance>> {
+			Messaging::message(id)
+		}
+
+		fn ismp_message(commitment: H256) -> Option<MessageDetails<Balance>> {
+			Messaging::ismp_message(commitment)
+		}
+
+		fn xcm_message(query_id: xcm::latest::QueryId) -> Option<MessageDetails<Balance>> {
+			Messaging::xcm_message(query_id)
+		}
+
+		fn held_deposit(origin: H160) -> Balance {
+			let account = <Runtime as pallet_revive::Config>::AddressMapper::to_account_id(&origin);
+			Messaging::held_deposit(&account)
+		}
+
+		fn quote_get(callback_gas_limit: Option<Weight>) -> MessageQuote<Balance> {
+			Messaging::quote_get(callback_gas_limit)
+		}
+
+		fn quote_post(callback_gas_limit: Option<Weight>) -> MessageQuote<Balance> {
+			Messaging::quote_post(callback_gas_limit)
+		}
+
+		fn quote_subscribe(
+			executions: u32,
+			fee: Balance,
+			callback_gas_limit: Weight,
+		) -> MessageQuote<Balance> {
+			Messaging::quote_subscribe(executions, fee, callback_gas_limit)
+		}
+
+		fn quote_new_query(callback_gas_limit: Option<Weight>) -> MessageQuote<Balance> {
+			Messaging::quote_new_query(callback_gas_limit)
+		}
+	}
+
 	impl pallet_revive::ReviveApi<Block, AccountId, Balance, Nonce, BlockNumber> for Runtime
 	{
 		fn balance(address: H160) -> U256 {
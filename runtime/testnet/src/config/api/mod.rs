@@ -279,19 +279,6 @@ impl<T: frame_system::Config<RuntimeCall = RuntimeCall>> Contains<RuntimeCall> f
 			)
 		};
 
-		let contain_messaging: bool = {
-			use messaging::Call::*;
-			matches!(
-				c,
-				RuntimeCall::Messaging(
-					send { .. } |
-						ismp_get { .. } | ismp_post { .. } |
-						xcm_new_query { .. } |
-						remove { .. },
-				)
-			)
-		};
-
 		let contain_nonfungibles: bool =
 			{
 				use nonfungibles::Call::*;
@@ -312,8 +299,9 @@ impl<T: frame_system::Config<RuntimeCall = RuntimeCall>> Contains<RuntimeCall> f
 				)
 			};
 
-		T::BaseCallFilter::contains(c) &&
-			(contain_fungibles | contain_messaging | contain_nonfungibles)
+		// Messages are no longer sent via `pallet_api::messaging`, whose messages are migrated to
+		// the messaging pallet of `pallet-revive`.
+		T::BaseCallFilter::contains(c) && (contain_fungibles | contain_nonfungibles)
 	}
 }
 
@@ -355,6 +343,203 @@ impl<T: frame_system::Config> Contains<RuntimeRead> for Filter<T> {
 	}
 }
 
+/// The messaging pallet for `pallet-revive`, to which the messages of `pallet_api::messaging` are
+/// migrated.
+pub(crate) mod messaging_vnext {
+	use frame_support::{dispatch::Pays, parameter_types, traits::Get, weights::WeightToFee as _};
+	use frame_system::EnsureRoot;
+	use pallet_api_vnext::messaging::{
+		self,
+		migrations::PalletApiMessaging,
+		transports::xcm::{XcmDryRunApiError, XcmDryRunEffects, XcmPaymentApiError},
+	};
+	use xcm::{
+		latest::{AssetId, Location},
+		VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm,
+	};
+
+	use super::*;
+	use crate::{
+		config::{
+			monetary::DealWithFees,
+			xcm::{RelayLocation, XcmConfig, XcmRouter},
+		},
+		Balance, RuntimeEvent, WeightToFee, DAYS, H160, MINUTES, UNIT,
+	};
+
+	parameter_types! {
+		// Up to a tenth of a block may be used to retry deferred callbacks.
+		pub DeferredCallbackWeightLimit: Weight = crate::MAXIMUM_BLOCK_WEIGHT / 10;
+		pub IsmpPostRequestGasLimit: Weight = Weight::from_parts(1_000_000_000, 100_000);
+		pub const IsmpPostRequestStorageDepositLimit: Balance = 10 * UNIT;
+		// No single origin may hold enough pending messages to fill the query timeouts of a block.
+		pub const MaxPendingMessages: u32 = MaxXcmQueryTimeoutsPerBlock::get() / 2;
+		pub const MaxXcmQueryTimeoutsPerBlock: u32 = 100;
+		// Completed and timed out messages are removed a week after concluding.
+		pub const MessageRetentionPeriod: BlockNumber = 7 * DAYS;
+		// Subscriptions may read their destination at most once a minute.
+		pub const MinSubscriptionInterval: BlockNumber = MINUTES;
+		pub const PalletApiName: &'static str = "Messaging";
+		pub PalletApiHoldReason: RuntimeHoldReason = pallet_api::messaging::HoldReason::Messaging.into();
+	}
+
+	impl messaging::Config for Runtime {
+		type AddressConverter = messaging::AddressToAccountId<Runtime>;
+		type CallbackExecutor = CallbackExecutor;
+		type DeferredCallbackWeightLimit = DeferredCallbackWeightLimit;
+		type FeeHandler = DealWithFees;
+		type Fungibles = Balances;
+		type IsmpDispatcher = Ismp;
+		type IsmpPostRequestGasLimit = IsmpPostRequestGasLimit;
+		type IsmpPostRequestStorageDepositLimit = IsmpPostRequestStorageDepositLimit;
+		type Keccak256 = Ismp;
+		type MaxBatchSize = ConstU32<10>;
+		type MaxCallbackRetries = ConstU32<10>;
+		type MaxContextLen = ConstU32<64>;
+		// Matches `pallet_api::messaging`, so that no migrated message exceeds the bounds.
+		type MaxDataLen = ConstU32<1024>;
+		type MaxDeferredCallbacks = ConstU32<100>;
+		type MaxKeyLen = ConstU32<8>;
+		type MaxKeys = ConstU32<10>;
+		type MaxPendingMessages = MaxPendingMessages;
+		type MaxRecipientLen = ConstU32<32>;
+		// TODO: size appropriately
+		type MaxRemovals = ConstU32<100>;
+		// Matches `pallet_api::messaging`, so that no migrated response is dropped.
+		type MaxResponseLen = ConstU32<1024>;
+		type MaxXcmQueryTimeoutsPerBlock = MaxXcmQueryTimeoutsPerBlock;
+		type MessageRetentionPeriod = MessageRetentionPeriod;
+		type MinSubscriptionInterval = MinSubscriptionInterval;
+		type OffChainByteFee = TransactionByteFee;
+		type OnChainByteFee = TransactionByteFee;
+		type OriginConverter = LocalOriginToLocation;
+		type QuotaOrigin = EnsureRoot<AccountId>;
+		type RuntimeHoldReason = RuntimeHoldReason;
+		type WeightInfo = ();
+		type WeightToFee = <Runtime as pallet_transaction_payment::Config>::WeightToFee;
+		type Xcm = QueryHandler;
+		type XcmApi = XcmRuntimeApis;
+		type XcmResponseOrigin = EnsureResponse;
+	}
+
+	/// `pallet_api::messaging`, from which messages are migrated by
+	/// [`messaging::migrations::MigrateFromPalletApi`].
+	pub struct PalletApi;
+	impl PalletApiMessaging<Runtime> for PalletApi {
+		type HoldReason = PalletApiHoldReason;
+		type PalletName = PalletApiName;
+	}
+
+	pub struct CallbackExecutor;
+	impl messaging::CallbackExecutor<Runtime> for CallbackExecutor {
+		fn execute(
+			account: &AccountId,
+			contract: H160,
+			data: Vec<u8>,
+			gas_limit: Weight,
+			storage_deposit_limit: Balance,
+		) -> DispatchResultWithPostInfo {
+			Self::execute_with_output(account, contract, data, gas_limit, storage_deposit_limit)
+				.map(|(post_info, _)| post_info)
+		}
+
+		fn execute_with_output(
+			account: &AccountId,
+			contract: H160,
+			data: Vec<u8>,
+			gas_limit: Weight,
+			storage_deposit_limit: Balance,
+		) -> Result<(PostDispatchInfo, Vec<u8>), DispatchErrorWithPostInfo> {
+			use pallet_revive::DepositLimit;
+
+			use crate::{Revive, RuntimeOrigin};
+
+			let mut output = Revive::bare_call(
+				RuntimeOrigin::signed(account.clone()),
+				contract,
+				Default::default(),
+				gas_limit,
+				DepositLimit::Balance(storage_deposit_limit),
+				data,
+			);
+
+			log::debug!(target: "pop-api", "callback weight consumed={:?}, weight required={:?}", output.gas_consumed, output.gas_required);
+			if let Ok(return_value) = &output.result {
+				if return_value.did_revert() {
+					output.result = Err(pallet_revive::Error::<Runtime>::ContractReverted.into());
+				}
+			}
+
+			let post_info =
+				PostDispatchInfo { actual_weight: Some(output.gas_consumed), pays_fee: Pays::No };
+
+			output
+				.result
+				.map(|return_value| (post_info, return_value.data))
+				.map_err(|e| DispatchErrorWithPostInfo { post_info, error: e })
+		}
+
+		fn execution_weight() -> Weight {
+			use pallet_revive::WeightInfo;
+			<Runtime as pallet_revive::Config>::WeightInfo::call()
+		}
+	}
+
+	pub struct QueryHandler;
+	impl messaging::transports::xcm::NotifyQueryHandler<Runtime> for QueryHandler {
+		type WeightInfo = pallet_xcm::Pallet<Runtime>;
+
+		fn new_notify_query(
+			responder: impl Into<Location>,
+			notify: messaging::Call<Runtime>,
+			timeout: BlockNumber,
+			match_querier: impl Into<Location>,
+		) -> u64 {
+			PolkadotXcm::new_notify_query(responder, notify, timeout, match_querier)
+		}
+
+		fn cancel_query(query_id: u64) {
+			messaging::transports::xcm::remove_pallet_xcm_query::<Runtime>(query_id)
+		}
+	}
+
+	/// Dry-running and fee estimation of XCM messages, using the same logic as the XCM runtime
+	/// APIs.
+	pub struct XcmRuntimeApis;
+	impl messaging::transports::xcm::XcmApi<Runtime> for XcmRuntimeApis {
+		fn dry_run_xcm(
+			origin: VersionedLocation,
+			message: VersionedXcm<RuntimeCall>,
+		) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			PolkadotXcm::dry_run_xcm::<Runtime, XcmRouter, RuntimeCall, XcmConfig>(origin, message)
+		}
+
+		fn query_delivery_fees(
+			destination: VersionedLocation,
+			message: VersionedXcm<()>,
+		) -> Result<VersionedAssets, XcmPaymentApiError> {
+			PolkadotXcm::query_delivery_fees(destination, message)
+		}
+
+		fn query_weight_to_asset_fee(
+			weight: Weight,
+			asset: VersionedAssetId,
+		) -> Result<u128, XcmPaymentApiError> {
+			// Only the relay chain asset is accepted for the payment of fees.
+			match AssetId::try_from(asset) {
+				Ok(AssetId(location)) if location == RelayLocation::get() =>
+					Ok(WeightToFee::weight_to_fee(&weight)),
+				Ok(_) => Err(XcmPaymentApiError::AssetNotFound),
+				Err(_) => Err(XcmPaymentApiError::VersionedConversionFailed),
+			}
+		}
+
+		fn query_xcm_weight(message: VersionedXcm<()>) -> Result<Weight, XcmPaymentApiError> {
+			PolkadotXcm::query_xcm_weight(message)
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use codec::Encode;
@@ -397,6 +582,23 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn filter_prevents_messaging_calls() {
+		use messaging::Call::*;
+
+		for call in [
+			RuntimeCall::Messaging(xcm_new_query {
+				id: 0,
+				responder: Location::parent(),
+				timeout: 0,
+				callback: None,
+			}),
+			RuntimeCall::Messaging(remove { messages: bounded_vec![] }),
+		] {
+			assert!(!Filter::<Runtime>::contains(&call))
+		}
+	}
+
 	#[test]
 	fn filter_allows_fungibles_calls() {
 		const CALLS: [RuntimeCall; 11] = [
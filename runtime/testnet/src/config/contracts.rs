@@ -13,6 +13,12 @@ use crate::{
 	Timestamp, TransactionPayment,
 };
 
+type Ismp<const FIXED: u16> =
+	pallet_api_vnext::messaging::precompiles::ismp::v0::Ismp<FIXED, Runtime>;
+type Messaging<const FIXED: u16> =
+	pallet_api_vnext::messaging::precompiles::v0::Messaging<FIXED, Runtime>;
+type Xcm<const FIXED: u16> = pallet_api_vnext::messaging::precompiles::xcm::v0::Xcm<FIXED, Runtime>;
+
 fn schedule<T: pallet_contracts::Config>() -> pallet_contracts::Schedule<T> {
 	pallet_contracts::Schedule {
 		limits: pallet_contracts::Limits {
@@ -102,7 +108,15 @@ impl pallet_revive::Config for Runtime {
 	type NativeToEthRatio = NativeToEthRatio;
 	// 512 MB. Used in an integrity test that verifies the runtime has enough memory.
 	type PVFMemory = ConstU32<{ 512 * 1024 * 1024 }>;
-	type Precompiles = ();
+	// The messaging precompiles use the same addresses as on devnet.
+	type Precompiles = (
+		// 3: `Messaging` precompile v0
+		Messaging<3>,
+		// 4: `Ismp` precompile v0
+		Ismp<4>,
+		// 5: `Xcm` precompile v0
+		Xcm<5>,
+	);
 	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -21,7 +21,7 @@ impl pallet_ismp::Config for Runtime {
 	type Router = Router;
 	type RuntimeEvent = RuntimeEvent;
 	type TimestampProvider = Timestamp;
-	type WeightProvider = ();
+	type WeightProvider = WeightProvider;
 }
 
 impl ismp_parachain::Config for Runtime {
@@ -47,10 +47,28 @@ impl Get<StateMachine> for HostStateMachine {
 pub struct Router;
 impl IsmpRouter for Router {
 	fn module_for_id(&self, id: Vec<u8>) -> Result<Box<dyn IsmpModule>, anyhow::Error> {
-		use pallet_api::messaging::transports::ismp::*;
-		if id == ID {
-			return Ok(Box::new(Handler::<Runtime>::new()));
+		use pallet_api_vnext::messaging::transports::ismp as messaging;
+		match id {
+			// The module identifier of `pallet_api::messaging` is handled by the messaging pallet
+			// of `pallet-revive`, to which its pending requests are migrated. Inbound requests to
+			// contracts are addressed to the module identifier followed by the contract address
+			// and encoding.
+			id if messaging::is_module_id(&id) => Ok(Box::new(messaging::Module::<Runtime>::new())),
+			_ => Err(Error::ModuleNotFound(id).into()),
+		}
+	}
+}
+
+pub struct WeightProvider;
+impl pallet_ismp::weights::WeightProvider for WeightProvider {
+	fn module_callback(
+		dest_module: pallet_ismp::ModuleId,
+	) -> Option<Box<dyn pallet_ismp::weights::IsmpModuleWeight>> {
+		use pallet_api_vnext::messaging::transports::ismp as messaging;
+		match dest_module.to_bytes() {
+			dest_module if messaging::is_module_id(&dest_module) =>
+				Some(Box::new(messaging::Module::<Runtime>::new())),
+			_ => None,
 		}
-		Err(Error::ModuleNotFound(id))?
 	}
 }
@@ -173,7 +173,14 @@ impl pallet_migrations::Config for Runtime {
 	type MaxServiceWeight = MbmServiceWeight;
 	type MigrationStatusHandler = ();
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = ();
+	type Migrations = (
+		// Migrates the messages of `pallet_api::messaging` to the messaging pallet of
+		// `pallet-revive`.
+		pallet_api_vnext::messaging::migrations::MigrateFromPalletApi<
+			Runtime,
+			super::api::messaging_vnext::PalletApi,
+		>,
+	);
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -190,3 +197,70 @@ impl pallet_timestamp::Config for Runtime {
 }
 
 impl parachain_info::Config for Runtime {}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::{
+		migrations::SteppedMigration,
+		traits::{
+			fungible::{InspectHold, Mutate, MutateHold},
+			Get,
+		},
+		weights::WeightMeter,
+	};
+	use pallet_api_vnext::messaging::{
+		self,
+		migrations::{v0, MigrateFromPalletApi},
+	};
+	use sp_core::H256;
+	use sp_runtime::BuildStorage;
+
+	use super::*;
+	use crate::{
+		config::api::messaging_vnext::{PalletApi, PalletApiHoldReason, PalletApiName},
+		Balances, UNIT,
+	};
+
+	type Migration = MigrateFromPalletApi<Runtime, PalletApi>;
+
+	#[test]
+	fn migrates_messages_from_pallet_api() {
+		let account = AccountId::from([1; 32]);
+		let commitment = H256::repeat_byte(1);
+		let deposit = UNIT;
+		let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+		ext.execute_with(|| {
+			Balances::set_balance(&account, 100 * UNIT);
+			Balances::hold(&PalletApiHoldReason::get(), &account, deposit).unwrap();
+			v0::Messages::<PalletApiName, Runtime>::insert(
+				&account,
+				1,
+				v0::Message::IsmpResponse { commitment, deposit, response: vec![1, 2, 3] },
+			);
+			v0::IsmpRequests::<PalletApiName, Runtime>::insert(commitment, (&account, 1));
+
+			// Runs the migration to completion, including its try-runtime checks.
+			#[cfg(feature = "try-runtime")]
+			let state = Migration::pre_upgrade().unwrap();
+			let mut cursor = None;
+			loop {
+				cursor = Migration::step(cursor, &mut WeightMeter::new()).unwrap();
+				if cursor.is_none() {
+					break;
+				}
+			}
+			#[cfg(feature = "try-runtime")]
+			Migration::post_upgrade(state).unwrap();
+
+			assert!(messaging::Pallet::<Runtime>::ismp_message(commitment).is_some());
+			assert_eq!(Balances::balance_on_hold(&PalletApiHoldReason::get(), &account), 0);
+			assert_eq!(
+				Balances::balance_on_hold(&messaging::HoldReason::Messaging.into(), &account),
+				deposit
+			);
+		});
+	}
+}
@@ -37,9 +37,9 @@ use crate::{
 		monetary::{TransactionByteFee, TreasuryAccount},
 		system::RuntimeBlockWeights,
 	},
-	AccountId, AllPalletsWithSystem, Balances, MessageQueue, ParachainInfo, ParachainSystem,
-	Perbill, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin,
-	WeightToFee, XcmpQueue,
+	AccountId, AllPalletsWithSystem, Balances, MessageQueue, MultiBlockMigrations, ParachainInfo,
+	ParachainSystem, Perbill, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason,
+	RuntimeOrigin, WeightToFee, XcmpQueue,
 };
 
 parameter_types! {
@@ -71,13 +71,24 @@ impl pallet_message_queue::Config for Runtime {
 	>;
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
-	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	// Inbound messages are not processed whilst multi-block migrations are ongoing.
+	type QueuePausedQuery = (NarrowOriginToSibling<XcmpQueue>, PausedDuringMigrations);
 	type RuntimeEvent = RuntimeEvent;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type Size = u32;
 	type WeightInfo = pallet_message_queue::weights::SubstrateWeight<Self>;
 }
 
+/// Pauses the processing of all message queues whilst multi-block migrations are ongoing, so that
+/// messages are not processed against partially migrated storage.
+pub struct PausedDuringMigrations;
+impl<Origin> frame_support::traits::QueuePausedQuery<Origin> for PausedDuringMigrations {
+	fn is_paused(_origin: &Origin) -> bool {
+		use frame_support::migrations::MultiStepMigrator;
+		MultiBlockMigrations::ongoing()
+	}
+}
+
 /// Type for specifying how a `Location` can be converted into an `AccountId`. This is used
 /// when determining ownership of accounts for asset transacting and when attempting to use XCM
 /// `Transact` in order to determine the dispatch Origin.
@@ -47,6 +47,7 @@ use frame_system::{
 	CheckWeight, EnsureRoot,
 };
 use pallet_api::{fungibles, messaging, nonfungibles};
+use pallet_api_vnext::messaging::{MessageDetails, MessageId, MessageQuote};
 use pallet_balances::Call as BalancesCall;
 use pallet_ismp::offchain::{Leaf, Proof, ProofKeys};
 use pallet_revive::{evm::H160, AddressMapper};
@@ -374,6 +375,9 @@ mod runtime {
 	pub type NonFungibles = nonfungibles::Pallet<Runtime>;
 	#[runtime::pallet_index(152)]
 	pub type Messaging = messaging::Pallet<Runtime>;
+	// The messaging pallet for `pallet-revive`, to which the messages of `Messaging` are migrated.
+	#[runtime::pallet_index(153)]
+	pub type MessagingvNext = pallet_api_vnext::messaging::Pallet<Runtime>;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -690,6 +694,53 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_api_vnext::messaging::runtime_api::MessagingApi<Block, Balance> for Runtime {
+		fn messages(
+			origin: H160,
+			start_after: Option<MessageId>,
+			limit: u32,
+		) -> Vec<MessageDetails<Balance>> {
+			MessagingvNext::messages(origin, start_after, limit)
+		}
+
+		fn message(id: MessageId) -> Option<MessageDetails<Balance>> {
+			MessagingvNext::message(id)
+		}
+
+		fn ismp_message(commitment: H256) -> Option<MessageDetails<Balance>> {
+			MessagingvNext::ismp_message(commitment)
+		}
+
+		fn xcm_message(query_id: xcm::latest::QueryId) -> Option<MessageDetails<Balance>> {
+			MessagingvNext::xcm_message(query_id)
+		}
+
+		fn held_deposit(origin: H160) -> Balance {
+			let account = <Runtime as pallet_revive::Config>::AddressMapper::to_account_id(&origin);
+			MessagingvNext::held_deposit(&account)
+		}
+
+		fn quote_get(callback_gas_limit: Option<Weight>) -> MessageQuote<Balance> {
+			MessagingvNext::quote_get(callback_gas_limit)
+		}
+
+		fn quote_post(callback_gas_limit: Option<Weight>) -> MessageQuote<Balance> {
+			MessagingvNext::quote_post(callback_gas_limit)
+		}
+
+		fn quote_subscribe(
+			executions: u32,
+			fee: Balance,
+			callback_gas_limit: Weight,
+		) -> MessageQuote<Balance> {
+			MessagingvNext::quote_subscribe(executions, fee, callback_gas_limit)
+		}
+
+		fn quote_new_query(callback_gas_limit: Option<Weight>) -> MessageQuote<Balance> {
+			MessagingvNext::quote_new_query(callback_gas_limit)
+		}
+	}
+
 	impl pallet_revive::ReviveApi<Block, AccountId, Balance, Nonce, BlockNumber> for Runtime
 	{
 		fn balance(address: H160) -> U256 {